@@ -1,5 +1,7 @@
 //! Sample data invoking the worst-case scenario.
 
+#![allow(clippy::redundant_static_lifetimes)]
+
 extern crate criterion;
 extern crate lexical_core;
 
@@ -1,3 +1,5 @@
+#![allow(clippy::excessive_precision, clippy::large_const_arrays)]
+
 extern crate criterion;
 extern crate dtoa;
 extern crate ryu_impl;
@@ -9,8 +11,8 @@ use ryu_impl::raw as ryu_raw;
 use lexical_core::write as lexical_write;
 
 // SHARED
-const F32_NAN: f32 = 0.0_f32 / 0.0_f32;
-const F64_NAN: f64 = 0.0_f64 / 0.0_f64;
+const F32_NAN: f32 = f32::NAN;
+const F64_NAN: f64 = f64::NAN;
 
 // BENCHMARK GENERATORS
 
@@ -1,3 +1,5 @@
+#![allow(clippy::large_const_arrays)]
+
 extern crate criterion;
 extern crate itoa;
 extern crate lexical_core;
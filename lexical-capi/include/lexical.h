@@ -85,6 +85,16 @@ extern void lexical_set_exponent_default_char(uint8_t ch);
     extern void lexical_set_float_rounding(int32_t rounding);
 #endif  // HAVE_ROUNDING
 
+// Overflow handling for integer-parsing.
+enum lexical_overflow_mode {
+    // Return an error on overflow.
+    lexical_checked = 0,
+    // Wrap around the bounds of the integer type.
+    lexical_wrapping = 1,
+    // Clamp to the bounds of the integer type.
+    lexical_saturating = 2,
+};
+
 extern int32_t lexical_get_nan_string(uint8_t** ptr, size_t* size);
 extern int32_t lexical_set_nan_string(uint8_t const* ptr, size_t size);
 extern int32_t lexical_get_inf_string(uint8_t** ptr, size_t* size);
@@ -183,6 +193,14 @@ enum lexical_error_code {
     lexical_exponent_without_fraction = -14,
     // Integer had invalid leading zeros.
     lexical_invalid_leading_zeros = -15,
+    // Parsed value was below the minimum allowed value.
+    lexical_below_minimum = -16,
+    // Parsed value was above the maximum allowed value.
+    lexical_above_maximum = -17,
+    // Parsed value was zero, but zero was not allowed.
+    lexical_invalid_zero = -18,
+    // Parsed value was NaN, but NaN was not allowed.
+    lexical_invalid_nan = -19,
 };
 
 // Declare extern to lexical function definitions.
@@ -271,6 +289,18 @@ enum lexical_error_code {
     #define lexical_case_sensitive_special                  0x400ull
     #define lexical_no_integer_leading_zeros                0x800ull
     #define lexical_no_float_leading_zeros                  0x1000ull
+    #define lexical_hexadecimal_prefix                      0x2000ull
+    #define lexical_octal_prefix                            0x4000ull
+    #define lexical_binary_prefix                           0x8000ull
+    #define lexical_case_sensitive_base_prefix              0x10000ull
+    #define lexical_rust_type_suffix                        0x20000ull
+    #define lexical_c_integer_suffix                        0x40000ull
+    #define lexical_c_float_suffix                          0x80000ull
+    #define lexical_java_integer_suffix                     0x100000ull
+    #define lexical_java_float_suffix                       0x200000ull
+    #define lexical_leading_whitespace                      0x400000ull
+    #define lexical_trailing_whitespace                     0x800000ull
+    #define lexical_surrounding_quotes                      0x1000000ull
 
     // DIGIT SEPARATOR FLAGS
     #define lexical_integer_internal_digit_separator        0x100000000ull
@@ -358,6 +388,25 @@ enum lexical_error_code {
         | lexical_exponent_consecutive_digit_separator                  \
     )
 
+    #define lexical_base_prefix_flag_mask (                             \
+        lexical_hexadecimal_prefix                                      \
+        | lexical_octal_prefix                                          \
+        | lexical_binary_prefix                                         \
+    )
+
+    #define lexical_type_suffix_flag_mask (                             \
+        lexical_rust_type_suffix                                        \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
+        | lexical_java_integer_suffix                                   \
+        | lexical_java_float_suffix                                     \
+    )
+
+    #define lexical_whitespace_flag_mask (                              \
+        lexical_leading_whitespace                                      \
+        | lexical_trailing_whitespace                                   \
+    )
+
     #define lexical_flag_mask (                                         \
         lexical_required_digits                                         \
         | lexical_no_positive_mantissa_sign                             \
@@ -370,6 +419,11 @@ enum lexical_error_code {
         | lexical_case_sensitive_special                                \
         | lexical_no_integer_leading_zeros                              \
         | lexical_no_float_leading_zeros                                \
+        | lexical_base_prefix_flag_mask                                 \
+        | lexical_case_sensitive_base_prefix                            \
+        | lexical_type_suffix_flag_mask                                 \
+        | lexical_whitespace_flag_mask                                  \
+        | lexical_surrounding_quotes                                    \
         | lexical_internal_digit_separator                              \
         | lexical_leading_digit_separator                               \
         | lexical_trailing_digit_separator                              \
@@ -391,6 +445,9 @@ enum lexical_error_code {
         | lexical_internal_digit_separator                              \
         | lexical_trailing_digit_separator                              \
         | lexical_consecutive_digit_separator                           \
+        | lexical_base_prefix_flag_mask                                 \
+        | lexical_case_sensitive_base_prefix                            \
+        | lexical_rust_type_suffix                                      \
     )
 
     // Float format to parse a Rust float from string.
@@ -406,6 +463,7 @@ enum lexical_error_code {
     #define lexical_python_literal (                                    \
         lexical_required_exponent_digits                                \
         | lexical_no_special                                            \
+        | lexical_base_prefix_flag_mask                                 \
     )
 
     // Float format to parse a Python float from string.
@@ -417,6 +475,10 @@ enum lexical_error_code {
         | lexical_required_exponent_digits                              \
         | lexical_case_sensitive_special                                \
         | lexical_internal_digit_separator                              \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_binary_prefix                                         \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format for a C++17 float from string.
@@ -428,6 +490,10 @@ enum lexical_error_code {
         | lexical_required_exponent_digits                              \
         | lexical_case_sensitive_special                                \
         | lexical_internal_digit_separator                              \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_binary_prefix                                         \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C++14 float from string.
@@ -437,6 +503,9 @@ enum lexical_error_code {
     #define lexical_cxx11_literal (                                     \
         lexical_required_exponent_digits                                \
         | lexical_case_sensitive_special                                \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C++11 float from string.
@@ -446,6 +515,9 @@ enum lexical_error_code {
     #define lexical_cxx03_literal (                                     \
         lexical_required_exponent_digits                                \
         | lexical_no_special                                            \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C++03 float from string.
@@ -455,6 +527,9 @@ enum lexical_error_code {
     #define lexical_cxx98_literal (                                     \
         lexical_required_exponent_digits                                \
         | lexical_no_special                                            \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C++98 float from string.
@@ -464,6 +539,9 @@ enum lexical_error_code {
     #define lexical_c18_literal (                                       \
         lexical_required_exponent_digits                                \
         | lexical_case_sensitive_special                                \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C18 float from string.
@@ -473,6 +551,9 @@ enum lexical_error_code {
     #define lexical_c11_literal (                                       \
         lexical_required_exponent_digits                                \
         | lexical_case_sensitive_special                                \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C11 float from string.
@@ -482,6 +563,9 @@ enum lexical_error_code {
     #define lexical_c99_literal (                                       \
         lexical_required_exponent_digits                                \
         | lexical_case_sensitive_special                                \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C99 float from string.
@@ -491,6 +575,9 @@ enum lexical_error_code {
     #define lexical_c90_literal (                                       \
         lexical_required_exponent_digits                                \
         | lexical_no_special                                            \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C90 float from string.
@@ -500,6 +587,9 @@ enum lexical_error_code {
     #define lexical_c89_literal (                                       \
         lexical_required_exponent_digits                                \
         | lexical_no_special                                            \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_c_integer_suffix                                      \
+        | lexical_c_float_suffix                                        \
     )
 
     // Float format to parse a C89 float from string.
@@ -596,6 +686,10 @@ enum lexical_error_code {
         | lexical_no_special                                            \
         | lexical_internal_digit_separator                              \
         | lexical_consecutive_digit_separator                           \
+        | lexical_hexadecimal_prefix                                    \
+        | lexical_binary_prefix                                         \
+        | lexical_java_integer_suffix                                   \
+        | lexical_java_float_suffix                                     \
     )
 
     // Float format to parse a Java float from string.
@@ -1461,6 +1555,96 @@ enum lexical_error_code {
         return lexical_number_format_intersects(format, lexical_no_float_leading_zeros);
     }
 
+    // Get if a `0x` prefix sets the radix to 16.
+    inline bool lexical_number_format_hexadecimal_prefix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_hexadecimal_prefix);
+    }
+
+    // Get if a `0o` prefix sets the radix to 8.
+    inline bool lexical_number_format_octal_prefix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_octal_prefix);
+    }
+
+    // Get if a `0b` prefix sets the radix to 2.
+    inline bool lexical_number_format_binary_prefix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_binary_prefix);
+    }
+
+    // Get if any base prefix is allowed.
+    inline bool lexical_number_format_base_prefix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_base_prefix_flag_mask);
+    }
+
+    // Get if base prefixes are case-sensitive.
+    inline bool lexical_number_format_case_sensitive_base_prefix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_case_sensitive_base_prefix);
+    }
+
+    // Get if Rust type suffixes are allowed.
+    inline bool lexical_number_format_rust_type_suffix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_rust_type_suffix);
+    }
+
+    // Get if C integer suffixes are allowed.
+    inline bool lexical_number_format_c_integer_suffix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_c_integer_suffix);
+    }
+
+    // Get if C float suffixes are allowed.
+    inline bool lexical_number_format_c_float_suffix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_c_float_suffix);
+    }
+
+    // Get if Java integer suffixes are allowed.
+    inline bool lexical_number_format_java_integer_suffix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_java_integer_suffix);
+    }
+
+    // Get if Java float suffixes are allowed.
+    inline bool lexical_number_format_java_float_suffix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_java_float_suffix);
+    }
+
+    // Get if any type suffix is allowed.
+    inline bool lexical_number_format_type_suffix(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_type_suffix_flag_mask);
+    }
+
+    // Get if whitespace is allowed before the number.
+    inline bool lexical_number_format_leading_whitespace(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_leading_whitespace);
+    }
+
+    // Get if whitespace is allowed after the number.
+    inline bool lexical_number_format_trailing_whitespace(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_trailing_whitespace);
+    }
+
+    // Get if any whitespace is allowed.
+    inline bool lexical_number_format_whitespace(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_whitespace_flag_mask);
+    }
+
+    // Get if the number may be surrounded by double quotes.
+    inline bool lexical_number_format_surrounding_quotes(uint64_t format)
+    {
+        return lexical_number_format_intersects(format, lexical_surrounding_quotes);
+    }
+
     // Get if digit separators are allowed between integer digits.
     inline bool lexical_number_format_integer_internal_digit_separator(uint64_t format)
     {
@@ -1592,6 +1776,10 @@ lexical_is_error(invalid_positive_exponent_sign);
 lexical_is_error(missing_exponent_sign);
 lexical_is_error(exponent_without_fraction);
 lexical_is_error(invalid_leading_zeros);
+lexical_is_error(below_minimum);
+lexical_is_error(above_maximum);
+lexical_is_error(invalid_zero);
+lexical_is_error(invalid_nan);
 
 // RESULT TAG
 
@@ -1897,6 +2085,63 @@ lexical_partial_result(f64);
         lexical_partial_decimal_from_range(type)
 #endif  // HAVE_RADIX
 
+// FROM LEXICAL OVERFLOW
+
+// Declare extern from lexical with an overflow mode function definitions for type.
+#define lexical_overflow_decimal_from_range(type)                               \
+    extern                                                                      \
+    lexical_result_type(type)                                                   \
+    lexical_ato##type##_overflow(                                               \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        int32_t mode                                                            \
+    )
+
+// Declare extern partial from lexical with an overflow mode function definitions for type.
+#define lexical_overflow_partial_decimal_from_range(type)                       \
+    extern                                                                      \
+    lexical_partial_result_type(type)                                           \
+    lexical_ato##type##_partial_overflow(                                       \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        int32_t mode                                                            \
+    )
+
+// Declare extern from lexical with an overflow mode function definitions for type.
+#define lexical_overflow_radix_from_range(type)                                 \
+    extern                                                                      \
+    lexical_result_type(type)                                                   \
+    lexical_ato##type##_overflow_radix(                                         \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        uint8_t radix,                                                          \
+        int32_t mode                                                            \
+    )
+
+// Declare extern partial from lexical with an overflow mode function definitions for type.
+#define lexical_overflow_partial_radix_from_range(type)                         \
+    extern                                                                      \
+    lexical_partial_result_type(type)                                           \
+    lexical_ato##type##_partial_overflow_radix(                                 \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        uint8_t radix,                                                          \
+        int32_t mode                                                            \
+    )
+
+// Declare extern from lexical with an overflow mode function definitions.
+#ifdef HAVE_RADIX
+    #define lexical_from_lexical_overflow(type)                                 \
+        lexical_overflow_decimal_from_range(type);                              \
+        lexical_overflow_partial_decimal_from_range(type);                      \
+        lexical_overflow_radix_from_range(type);                                \
+        lexical_overflow_partial_radix_from_range(type)
+#else   // !HAVE_RADIX
+    #define lexical_from_lexical_overflow(type)                                 \
+        lexical_overflow_decimal_from_range(type);                              \
+        lexical_overflow_partial_decimal_from_range(type)
+#endif  // HAVE_RADIX
+
 // FROM LEXICAL LOSSY
 
 // Declare extern lossy from lexical function definitions for type.
@@ -2086,6 +2331,18 @@ lexical_from_lexical(u32);
 lexical_from_lexical(u64);
 lexical_from_lexical(usize);
 
+lexical_from_lexical_overflow(i8);
+lexical_from_lexical_overflow(i16);
+lexical_from_lexical_overflow(i32);
+lexical_from_lexical_overflow(i64);
+lexical_from_lexical_overflow(isize);
+
+lexical_from_lexical_overflow(u8);
+lexical_from_lexical_overflow(u16);
+lexical_from_lexical_overflow(u32);
+lexical_from_lexical_overflow(u64);
+lexical_from_lexical_overflow(usize);
+
 // FTOA
 lexical_to_lexical(f32);
 lexical_to_lexical(f64);
@@ -2149,6 +2406,11 @@ lexical_to_lexical(usize);
 #undef lexical_radix_from_range
 #undef lexical_partial_radix_from_range
 #undef lexical_from_lexical
+#undef lexical_overflow_decimal_from_range
+#undef lexical_overflow_partial_decimal_from_range
+#undef lexical_overflow_radix_from_range
+#undef lexical_overflow_partial_radix_from_range
+#undef lexical_from_lexical_overflow
 #undef lexical_lossy_decimal_from_range
 #undef lexical_lossy_partial_decimal_from_range
 #undef lexical_lossy_radix_from_range
@@ -102,6 +102,13 @@ inline void set_exponent_default_char(uint8_t ch)
     }
 #endif  // HAVE_ROUNDING
 
+// Overflow handling for integer-parsing.
+enum class overflow_mode: int32_t {
+    checked = ::lexical_checked,
+    wrapping = ::lexical_wrapping,
+    saturating = ::lexical_saturating,
+};
+
 #ifdef HAVE_FORMAT
     // BITFLAGS
 
@@ -121,6 +128,18 @@ inline void set_exponent_default_char(uint8_t ch)
         case_sensitive_special = lexical_case_sensitive_special,
         no_integer_leading_zeros = lexical_no_integer_leading_zeros,
         no_float_leading_zeros = lexical_no_float_leading_zeros,
+        hexadecimal_prefix = lexical_hexadecimal_prefix,
+        octal_prefix = lexical_octal_prefix,
+        binary_prefix = lexical_binary_prefix,
+        case_sensitive_base_prefix = lexical_case_sensitive_base_prefix,
+        rust_type_suffix = lexical_rust_type_suffix,
+        c_integer_suffix = lexical_c_integer_suffix,
+        c_float_suffix = lexical_c_float_suffix,
+        java_integer_suffix = lexical_java_integer_suffix,
+        java_float_suffix = lexical_java_float_suffix,
+        leading_whitespace = lexical_leading_whitespace,
+        trailing_whitespace = lexical_trailing_whitespace,
+        surrounding_quotes = lexical_surrounding_quotes,
         integer_internal_digit_separator = lexical_integer_internal_digit_separator,
         fraction_internal_digit_separator = lexical_fraction_internal_digit_separator,
         exponent_internal_digit_separator = lexical_exponent_internal_digit_separator,
@@ -146,6 +165,9 @@ inline void set_exponent_default_char(uint8_t ch)
         fraction_digit_separator_flag_mask = lexical_fraction_digit_separator_flag_mask,
         exponent_digit_separator_flag_mask = lexical_exponent_digit_separator_flag_mask,
         exponent_flag_mask = lexical_exponent_flag_mask,
+        base_prefix_flag_mask = lexical_base_prefix_flag_mask,
+        type_suffix_flag_mask = lexical_type_suffix_flag_mask,
+        whitespace_flag_mask = lexical_whitespace_flag_mask,
         flag_mask = lexical_flag_mask,
 
         // PRE-DEFINED
@@ -576,6 +598,111 @@ inline void set_exponent_default_char(uint8_t ch)
         return ::lexical_number_format_no_float_leading_zeros(f);
     }
 
+    // Get if a `0x` prefix sets the radix to 16.
+    inline bool number_format_hexadecimal_prefix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_hexadecimal_prefix(f);
+    }
+
+    // Get if a `0o` prefix sets the radix to 8.
+    inline bool number_format_octal_prefix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_octal_prefix(f);
+    }
+
+    // Get if a `0b` prefix sets the radix to 2.
+    inline bool number_format_binary_prefix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_binary_prefix(f);
+    }
+
+    // Get if any base prefix is allowed.
+    inline bool number_format_base_prefix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_base_prefix(f);
+    }
+
+    // Get if base prefixes are case-sensitive.
+    inline bool number_format_case_sensitive_base_prefix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_case_sensitive_base_prefix(f);
+    }
+
+    // Get if Rust type suffixes are allowed.
+    inline bool number_format_rust_type_suffix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_rust_type_suffix(f);
+    }
+
+    // Get if C integer suffixes are allowed.
+    inline bool number_format_c_integer_suffix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_c_integer_suffix(f);
+    }
+
+    // Get if C float suffixes are allowed.
+    inline bool number_format_c_float_suffix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_c_float_suffix(f);
+    }
+
+    // Get if Java integer suffixes are allowed.
+    inline bool number_format_java_integer_suffix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_java_integer_suffix(f);
+    }
+
+    // Get if Java float suffixes are allowed.
+    inline bool number_format_java_float_suffix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_java_float_suffix(f);
+    }
+
+    // Get if any type suffix is allowed.
+    inline bool number_format_type_suffix(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_type_suffix(f);
+    }
+
+    // Get if whitespace is allowed before the number.
+    inline bool number_format_leading_whitespace(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_leading_whitespace(f);
+    }
+
+    // Get if whitespace is allowed after the number.
+    inline bool number_format_trailing_whitespace(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_trailing_whitespace(f);
+    }
+
+    // Get if any whitespace is allowed.
+    inline bool number_format_whitespace(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_whitespace(f);
+    }
+
+    // Get if the number may be surrounded by double quotes.
+    inline bool number_format_surrounding_quotes(number_format format)
+    {
+        auto f = static_cast<uint64_t>(format);
+        return ::lexical_number_format_surrounding_quotes(f);
+    }
+
     // Get if digit separators are allowed between integer digits.
     inline bool number_format_integer_internal_digit_separator(number_format format)
     {
@@ -825,6 +952,10 @@ enum class error_code: int32_t {
     missing_exponent_sign = ::lexical_missing_exponent_sign,
     exponent_without_fraction = ::lexical_exponent_without_fraction,
     invalid_leading_zeros = ::lexical_invalid_leading_zeros,
+    below_minimum = ::lexical_below_minimum,
+    above_maximum = ::lexical_above_maximum,
+    invalid_zero = ::lexical_invalid_zero,
+    invalid_nan = ::lexical_invalid_nan,
 };
 
 // Determine if an error code matches the desired code.
@@ -855,6 +986,10 @@ struct error
     lexical_is_error(missing_exponent_sign);
     lexical_is_error(exponent_without_fraction);
     lexical_is_error(invalid_leading_zeros);
+    lexical_is_error(below_minimum);
+    lexical_is_error(above_maximum);
+    lexical_is_error(invalid_zero);
+    lexical_is_error(invalid_nan);
 
     inline friend bool operator==(const error& lhs, const error& rhs)
     {
@@ -1271,6 +1406,111 @@ lexical_dispatcher(f64);
 lexical_lossy_dispatcher(f32);
 lexical_lossy_dispatcher(f64);
 
+// OVERFLOW DISPATCHER
+
+// Dispatch function for from_lexical_overflow.
+#define lexical_from_lexical_overflow(type)                                     \
+    inline static                                                               \
+    result<type>                                                                \
+    from_lexical_overflow(                                                      \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        overflow_mode mode                                                      \
+    )                                                                           \
+    {                                                                           \
+        using result_type = result<type>;                                       \
+        auto m = static_cast<int32_t>(mode);                                    \
+        auto r = ::lexical_ato##type##_overflow(first, last, m);                \
+        return result_type::from(r);                                            \
+    }
+
+// Dispatch function for from_lexical_partial_overflow.
+#define lexical_from_lexical_partial_overflow(type)                             \
+    inline static                                                               \
+    partial_result<type>                                                        \
+    from_lexical_partial_overflow(                                              \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        overflow_mode mode                                                      \
+    )                                                                           \
+    {                                                                           \
+        using partial_result_type = partial_result<type>;                       \
+        auto m = static_cast<int32_t>(mode);                                    \
+        auto r = ::lexical_ato##type##_partial_overflow(first, last, m);        \
+        return partial_result_type::from(r);                                    \
+    }
+
+// Dispatch function for from_lexical_overflow_radix.
+#define lexical_from_lexical_overflow_radix(type)                               \
+    inline static                                                               \
+    result<type>                                                                \
+    from_lexical_overflow_radix(                                                \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        uint8_t radix,                                                          \
+        overflow_mode mode                                                      \
+    )                                                                           \
+    {                                                                           \
+        using result_type = result<type>;                                       \
+        auto m = static_cast<int32_t>(mode);                                    \
+        auto r = ::lexical_ato##type##_overflow_radix(first, last, radix, m);   \
+        return result_type::from(r);                                            \
+    }
+
+// Dispatch function for from_lexical_partial_overflow_radix.
+#define lexical_from_lexical_partial_overflow_radix(type)                       \
+    inline static                                                               \
+    partial_result<type>                                                        \
+    from_lexical_partial_overflow_radix(                                        \
+        uint8_t const* first,                                                   \
+        uint8_t const* last,                                                    \
+        uint8_t radix,                                                          \
+        overflow_mode mode                                                      \
+    )                                                                           \
+    {                                                                           \
+        using partial_result_type = partial_result<type>;                       \
+        auto m = static_cast<int32_t>(mode);                                    \
+        auto r = ::lexical_ato##type##_partial_overflow_radix(first, last, radix, m);\
+        return partial_result_type::from(r);                                    \
+    }
+
+// Get type name for lexical overflow dispatcher
+#define lexical_overflow_dispatcher_type(type) type##_overflow_dispatcher
+
+// Define an overflow dispatcher for a given type. This
+// allows us to use std::conditional to get the proper
+// type (a type) from the type. Every single function will
+// be static.
+#ifdef HAVE_RADIX
+    #define lexical_overflow_dispatcher(type)                                   \
+        struct lexical_overflow_dispatcher_type(type)                           \
+        {                                                                       \
+            lexical_from_lexical_overflow(type)                                 \
+            lexical_from_lexical_partial_overflow(type)                         \
+            lexical_from_lexical_overflow_radix(type)                           \
+            lexical_from_lexical_partial_overflow_radix(type)                   \
+        }
+#else   // !HAVE_RADIX
+    #define lexical_overflow_dispatcher(type)                                   \
+        struct lexical_overflow_dispatcher_type(type)                           \
+        {                                                                       \
+            lexical_from_lexical_overflow(type)                                 \
+            lexical_from_lexical_partial_overflow(type)                         \
+        }
+#endif  // HAVE_RADIX
+
+lexical_overflow_dispatcher(i8);
+lexical_overflow_dispatcher(i16);
+lexical_overflow_dispatcher(i32);
+lexical_overflow_dispatcher(i64);
+lexical_overflow_dispatcher(isize);
+
+lexical_overflow_dispatcher(u8);
+lexical_overflow_dispatcher(u16);
+lexical_overflow_dispatcher(u32);
+lexical_overflow_dispatcher(u64);
+lexical_overflow_dispatcher(usize);
+
 #ifdef HAVE_FORMAT
     // FORMAT DISPATCHER
 
@@ -1532,6 +1772,31 @@ using lossy_dispatcher = lexical_lossy_conditional(f32,
     lexical_lossy_conditional(f64, void)
 );
 
+// GET OVERFLOW DISPATCHER
+
+// Conditional to simplify long recursive statements.
+#define lexical_overflow_conditional(name, fallback)                            \
+    typename std::conditional<                                                  \
+        lexical_is_same(name),                                                  \
+        lexical_overflow_dispatcher_type(name),                                 \
+        fallback                                                                \
+    >::type
+
+// Create a single template that resolves to our overflow dispatcher **or**
+// evaluates to void.
+template <typename T>
+using overflow_dispatcher = lexical_overflow_conditional(
+    i8, lexical_overflow_conditional(i16, lexical_overflow_conditional(i32,
+        lexical_overflow_conditional(i64, lexical_overflow_conditional(isize,
+            lexical_overflow_conditional(u8, lexical_overflow_conditional(u16,
+                lexical_overflow_conditional(u32, lexical_overflow_conditional(u64,
+                    lexical_overflow_conditional(usize, void)
+                ))
+            ))
+        ))
+    ))
+);
+
 #ifdef HAVE_FORMAT
     // GET FORMAT DISPATCHER
 
@@ -1678,6 +1943,56 @@ inline partial_result<T> parse_partial_lossy(string_type string)
     return disp::from_lexical_partial_lossy(first, last);
 }
 
+// High-level function to parse an integer from string with an overflow mode.
+template <typename T>
+inline result<T> parse_overflow(string_type string, overflow_mode mode)
+{
+    using disp = overflow_dispatcher<T>;
+    static_assert(!std::is_void<disp>::value, "Invalid type passed to parse_overflow.");
+
+    auto* first = reinterpret_cast<uint8_t const*>(string.data());
+    auto* last = first + string.length();
+    return disp::from_lexical_overflow(first, last, mode);
+}
+
+// High-level function to partially parse an integer from string with an overflow mode.
+template <typename T>
+inline partial_result<T> parse_partial_overflow(string_type string, overflow_mode mode)
+{
+    using disp = overflow_dispatcher<T>;
+    static_assert(!std::is_void<disp>::value, "Invalid type passed to parse_partial_overflow.");
+
+    auto* first = reinterpret_cast<uint8_t const*>(string.data());
+    auto* last = first + string.length();
+    return disp::from_lexical_partial_overflow(first, last, mode);
+}
+
+#ifdef HAVE_RADIX
+    // High-level function to parse an integer from string with a custom radix and an overflow mode.
+    template <typename T>
+    inline result<T> parse_overflow_radix(string_type string, uint8_t radix, overflow_mode mode)
+    {
+        using disp = overflow_dispatcher<T>;
+        static_assert(!std::is_void<disp>::value, "Invalid type passed to parse_overflow_radix.");
+
+        auto* first = reinterpret_cast<uint8_t const*>(string.data());
+        auto* last = first + string.length();
+        return disp::from_lexical_overflow_radix(first, last, radix, mode);
+    }
+
+    // High-level function to partially parse an integer from string with a custom radix and an overflow mode.
+    template <typename T>
+    inline partial_result<T> parse_partial_overflow_radix(string_type string, uint8_t radix, overflow_mode mode)
+    {
+        using disp = overflow_dispatcher<T>;
+        static_assert(!std::is_void<disp>::value, "Invalid type passed to parse_partial_overflow_radix.");
+
+        auto* first = reinterpret_cast<uint8_t const*>(string.data());
+        auto* last = first + string.length();
+        return disp::from_lexical_partial_overflow_radix(first, last, radix, mode);
+    }
+#endif  // HAVE_RADIX
+
 #ifdef HAVE_RADIX
     // High-level function to parse a value from string with a custom radix.
     template <typename T>
@@ -1848,6 +2163,13 @@ inline partial_result<T> parse_partial_lossy(string_type string)
 #undef lexical_dispatcher_type
 #undef lexical_lossy_dispatcher
 #undef lexical_lossy_dispatcher_type
+#undef lexical_from_lexical_overflow
+#undef lexical_from_lexical_partial_overflow
+#undef lexical_from_lexical_overflow_radix
+#undef lexical_from_lexical_partial_overflow_radix
+#undef lexical_overflow_dispatcher
+#undef lexical_overflow_dispatcher_type
+#undef lexical_overflow_conditional
 #undef lexical_is_same
 #undef lexical_conditional
 #undef lexical_lossy_conditional
@@ -171,6 +171,13 @@ if HAVE_ROUNDING:
         '''Set the default rounding scheme.'''
         LIB.lexical_set_float_rounding(c_int(rounding.value))
 
+class OverflowMode(enum.Enum):
+    '''Overflow handling for integer-parsing.'''
+
+    Checked = 0
+    Wrapping = 1
+    Saturating = 2
+
 if HAVE_FORMAT:
 
     def digit_separator_to_flags(digit_separator):
@@ -233,6 +240,18 @@ if HAVE_FORMAT:
         CaseSensitiveSpecial                = 0b0000000000000000000000000000000000000000000000000000010000000000
         NoIntegerLeadingZeros               = 0b0000000000000000000000000000000000000000000000000000100000000000
         NoFloatLeadingZeros                 = 0b0000000000000000000000000000000000000000000000000001000000000000
+        HexadecimalPrefix                   = 0b0000000000000000000000000000000000000000000000000010000000000000
+        OctalPrefix                         = 0b0000000000000000000000000000000000000000000000000100000000000000
+        BinaryPrefix                        = 0b0000000000000000000000000000000000000000000000001000000000000000
+        CaseSensitiveBasePrefix             = 0b0000000000000000000000000000000000000000000000010000000000000000
+        RustTypeSuffix                      = 0b0000000000000000000000000000000000000000000000100000000000000000
+        CIntegerSuffix                      = 0b0000000000000000000000000000000000000000000001000000000000000000
+        CFloatSuffix                        = 0b0000000000000000000000000000000000000000000010000000000000000000
+        JavaIntegerSuffix                   = 0b0000000000000000000000000000000000000000000100000000000000000000
+        JavaFloatSuffix                     = 0b0000000000000000000000000000000000000000001000000000000000000000
+        LeadingWhitespace                   = 0b0000000000000000000000000000000000000000010000000000000000000000
+        TrailingWhitespace                  = 0b0000000000000000000000000000000000000000100000000000000000000000
+        SurroundingQuotes                   = 0b0000000000000000000000000000000000000001000000000000000000000000
 
         # DIGIT SEPARATOR FLAGS
         IntegerInternalDigitSeparator       = 0b0000000000000000000000000000000100000000000000000000000000000000
@@ -321,6 +340,25 @@ if HAVE_FORMAT:
             | ExponentConsecutiveDigitSeparator
         )
 
+        BasePrefixFlagMask = (
+            HexadecimalPrefix
+            | OctalPrefix
+            | BinaryPrefix
+        )
+
+        TypeSuffixFlagMask = (
+            RustTypeSuffix
+            | CIntegerSuffix
+            | CFloatSuffix
+            | JavaIntegerSuffix
+            | JavaFloatSuffix
+        )
+
+        WhitespaceFlagMask = (
+            LeadingWhitespace
+            | TrailingWhitespace
+        )
+
         FlagMask = (
             RequiredDigits
             | NoPositiveMantissaSign
@@ -333,6 +371,11 @@ if HAVE_FORMAT:
             | CaseSensitiveSpecial
             | NoIntegerLeadingZeros
             | NoFloatLeadingZeros
+            | BasePrefixFlagMask
+            | CaseSensitiveBasePrefix
+            | TypeSuffixFlagMask
+            | WhitespaceFlagMask
+            | SurroundingQuotes
             | InternalDigitSeparator
             | LeadingDigitSeparator
             | TrailingDigitSeparator
@@ -626,6 +669,81 @@ if HAVE_FORMAT:
             '''Get if leading zeros before a float are not allowed.'''
             return self.intersects(NumberFormatFlags.NoFloatLeadingZeros)
 
+        @property
+        def hexadecimal_prefix(self):
+            '''Get if a `0x` prefix sets the radix to 16.'''
+            return self.intersects(NumberFormatFlags.HexadecimalPrefix)
+
+        @property
+        def octal_prefix(self):
+            '''Get if a `0o` prefix sets the radix to 8.'''
+            return self.intersects(NumberFormatFlags.OctalPrefix)
+
+        @property
+        def binary_prefix(self):
+            '''Get if a `0b` prefix sets the radix to 2.'''
+            return self.intersects(NumberFormatFlags.BinaryPrefix)
+
+        @property
+        def base_prefix(self):
+            '''Get if any base prefix is allowed.'''
+            return self.intersects(NumberFormatFlags.BasePrefixFlagMask)
+
+        @property
+        def case_sensitive_base_prefix(self):
+            '''Get if base prefixes are case-sensitive.'''
+            return self.intersects(NumberFormatFlags.CaseSensitiveBasePrefix)
+
+        @property
+        def rust_type_suffix(self):
+            '''Get if Rust type suffixes are allowed.'''
+            return self.intersects(NumberFormatFlags.RustTypeSuffix)
+
+        @property
+        def c_integer_suffix(self):
+            '''Get if C integer suffixes are allowed.'''
+            return self.intersects(NumberFormatFlags.CIntegerSuffix)
+
+        @property
+        def c_float_suffix(self):
+            '''Get if C float suffixes are allowed.'''
+            return self.intersects(NumberFormatFlags.CFloatSuffix)
+
+        @property
+        def java_integer_suffix(self):
+            '''Get if Java integer suffixes are allowed.'''
+            return self.intersects(NumberFormatFlags.JavaIntegerSuffix)
+
+        @property
+        def java_float_suffix(self):
+            '''Get if Java float suffixes are allowed.'''
+            return self.intersects(NumberFormatFlags.JavaFloatSuffix)
+
+        @property
+        def type_suffix(self):
+            '''Get if any type suffix is allowed.'''
+            return self.intersects(NumberFormatFlags.TypeSuffixFlagMask)
+
+        @property
+        def leading_whitespace(self):
+            '''Get if whitespace is allowed before the number.'''
+            return self.intersects(NumberFormatFlags.LeadingWhitespace)
+
+        @property
+        def trailing_whitespace(self):
+            '''Get if whitespace is allowed after the number.'''
+            return self.intersects(NumberFormatFlags.TrailingWhitespace)
+
+        @property
+        def whitespace(self):
+            '''Get if any whitespace is allowed.'''
+            return self.intersects(NumberFormatFlags.WhitespaceFlagMask)
+
+        @property
+        def surrounding_quotes(self):
+            '''Get if the number may be surrounded by double quotes.'''
+            return self.intersects(NumberFormatFlags.SurroundingQuotes)
+
         @property
         def integer_internal_digit_separator(self):
             '''Get if digit separators are allowed between integer digits.'''
@@ -1523,6 +1641,10 @@ class ErrorCode(enum.Enum):
     MissingExponentSign = -13
     ExponentWithoutFraction = -14
     InvalidLeadingZeros = -15
+    BelowMinimum = -16
+    AboveMaximum = -17
+    InvalidZero = -18
+    InvalidNan = -19
 
 class Error(Structure):
     '''C-compatible error for FFI.'''
@@ -1587,6 +1709,18 @@ class Error(Structure):
     def is_invalid_leading_zeros(self):
         return self.code == ErrorCode.InvalidLeadingZeros
 
+    def is_below_minimum(self):
+        return self.code == ErrorCode.BelowMinimum
+
+    def is_above_maximum(self):
+        return self.code == ErrorCode.AboveMaximum
+
+    def is_invalid_zero(self):
+        return self.code == ErrorCode.InvalidZero
+
+    def is_invalid_nan(self):
+        return self.code == ErrorCode.InvalidNan
+
 class LexicalError(Exception):
     '''Python-native exception raised during errors in lexical parsing.'''
 
@@ -1625,6 +1759,14 @@ class LexicalError(Exception):
             return 'Exponent found without fraction, starting at index {}'.format(self.error.index)
         elif code == ErrorCode.InvalidPositiveExponentSign:
             return 'Number was found with invalid leading zeros at index {}'.format(self.error.index)
+        elif code == ErrorCode.BelowMinimum:
+            return 'Number below the minimum value found, starting at index {}'.format(self.error.index)
+        elif code == ErrorCode.AboveMaximum:
+            return 'Number above the maximum value found, starting at index {}'.format(self.error.index)
+        elif code == ErrorCode.InvalidZero:
+            return 'Disallowed zero was found, starting at index {}'.format(self.error.index)
+        elif code == ErrorCode.InvalidNan:
+            return 'Disallowed NaN was found, starting at index {}'.format(self.error.index)
         else:
             raise ValueError('Invalid ErrorCode for lexical error.')
 
@@ -2296,6 +2438,251 @@ if HAVE_RADIX:
         '''Lossily parse 64-bit float and the number of processed bytes from input data.'''
         return _parse_radix('lexical_atof64_partial_lossy_radix', data, radix)
 
+# PARSE OVERFLOW
+
+def _parse_overflow(name, data, mode):
+    if isinstance(data, str):
+        data = data.encode('ascii')
+    if not isinstance(data, (bytes, bytearray)):
+        raise TypeError("Must parse from bytes.")
+    if not isinstance(mode, OverflowMode):
+        raise TypeError("Must use an OverflowMode.")
+    cb = getattr(LIB, name)
+    first = _to_u8_ptr(data)
+    last = _to_u8_ptr(_to_address(first) + len(data))
+    result = cb(first, last, c_int(mode.value))
+    return result.into()
+
+# COMPLETE PARSE OVERFLOW
+
+LIB.lexical_atoi8_overflow.restype = ResultI8
+LIB.lexical_atoi16_overflow.restype = ResultI16
+LIB.lexical_atoi32_overflow.restype = ResultI32
+LIB.lexical_atoi64_overflow.restype = ResultI64
+LIB.lexical_atoisize_overflow.restype = ResultIsize
+LIB.lexical_atou8_overflow.restype = ResultU8
+LIB.lexical_atou16_overflow.restype = ResultU16
+LIB.lexical_atou32_overflow.restype = ResultU32
+LIB.lexical_atou64_overflow.restype = ResultU64
+LIB.lexical_atousize_overflow.restype = ResultUsize
+
+def atoi8_overflow(data, mode):
+    '''Parse 8-bit signed integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi8_overflow', data, mode)
+
+def atoi16_overflow(data, mode):
+    '''Parse 16-bit signed integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi16_overflow', data, mode)
+
+def atoi32_overflow(data, mode):
+    '''Parse 32-bit signed integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi32_overflow', data, mode)
+
+def atoi64_overflow(data, mode):
+    '''Parse 64-bit signed integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi64_overflow', data, mode)
+
+def atoisize_overflow(data, mode):
+    '''Parse ssize_t from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoisize_overflow', data, mode)
+
+def atou8_overflow(data, mode):
+    '''Parse 8-bit unsigned integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou8_overflow', data, mode)
+
+def atou16_overflow(data, mode):
+    '''Parse 16-bit unsigned integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou16_overflow', data, mode)
+
+def atou32_overflow(data, mode):
+    '''Parse 32-bit unsigned integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou32_overflow', data, mode)
+
+def atou64_overflow(data, mode):
+    '''Parse 64-bit unsigned integer from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou64_overflow', data, mode)
+
+def atousize_overflow(data, mode):
+    '''Parse size_t from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atousize_overflow', data, mode)
+
+# PARTIAL PARSE OVERFLOW
+
+LIB.lexical_atoi8_partial_overflow.restype = PartialResultI8
+LIB.lexical_atoi16_partial_overflow.restype = PartialResultI16
+LIB.lexical_atoi32_partial_overflow.restype = PartialResultI32
+LIB.lexical_atoi64_partial_overflow.restype = PartialResultI64
+LIB.lexical_atoisize_partial_overflow.restype = PartialResultIsize
+LIB.lexical_atou8_partial_overflow.restype = PartialResultU8
+LIB.lexical_atou16_partial_overflow.restype = PartialResultU16
+LIB.lexical_atou32_partial_overflow.restype = PartialResultU32
+LIB.lexical_atou64_partial_overflow.restype = PartialResultU64
+LIB.lexical_atousize_partial_overflow.restype = PartialResultUsize
+
+def atoi8_partial_overflow(data, mode):
+    '''Parse 8-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi8_partial_overflow', data, mode)
+
+def atoi16_partial_overflow(data, mode):
+    '''Parse 16-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi16_partial_overflow', data, mode)
+
+def atoi32_partial_overflow(data, mode):
+    '''Parse 32-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi32_partial_overflow', data, mode)
+
+def atoi64_partial_overflow(data, mode):
+    '''Parse 64-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoi64_partial_overflow', data, mode)
+
+def atoisize_partial_overflow(data, mode):
+    '''Parse ssize_t and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atoisize_partial_overflow', data, mode)
+
+def atou8_partial_overflow(data, mode):
+    '''Parse 8-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou8_partial_overflow', data, mode)
+
+def atou16_partial_overflow(data, mode):
+    '''Parse 16-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou16_partial_overflow', data, mode)
+
+def atou32_partial_overflow(data, mode):
+    '''Parse 32-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou32_partial_overflow', data, mode)
+
+def atou64_partial_overflow(data, mode):
+    '''Parse 64-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atou64_partial_overflow', data, mode)
+
+def atousize_partial_overflow(data, mode):
+    '''Parse size_t and the number of processed bytes from input data, with an overflow mode.'''
+    return _parse_overflow('lexical_atousize_partial_overflow', data, mode)
+
+if HAVE_RADIX:
+    # PARSE OVERFLOW RADIX
+
+    def _parse_overflow_radix(name, data, radix, mode):
+        if isinstance(data, str):
+            data = data.encode('ascii')
+        if not isinstance(data, (bytes, bytearray)):
+            raise TypeError("Must parse from bytes.")
+        if not isinstance(radix, c_uint8):
+            radix = c_uint8(radix)
+        if not isinstance(mode, OverflowMode):
+            raise TypeError("Must use an OverflowMode.")
+        cb = getattr(LIB, name)
+        first = _to_u8_ptr(data)
+        last = _to_u8_ptr(_to_address(first) + len(data))
+        result = cb(first, last, radix, c_int(mode.value))
+        return result.into()
+
+    # COMPLETE PARSE OVERFLOW RADIX
+
+    LIB.lexical_atoi8_overflow_radix.restype = ResultI8
+    LIB.lexical_atoi16_overflow_radix.restype = ResultI16
+    LIB.lexical_atoi32_overflow_radix.restype = ResultI32
+    LIB.lexical_atoi64_overflow_radix.restype = ResultI64
+    LIB.lexical_atoisize_overflow_radix.restype = ResultIsize
+    LIB.lexical_atou8_overflow_radix.restype = ResultU8
+    LIB.lexical_atou16_overflow_radix.restype = ResultU16
+    LIB.lexical_atou32_overflow_radix.restype = ResultU32
+    LIB.lexical_atou64_overflow_radix.restype = ResultU64
+    LIB.lexical_atousize_overflow_radix.restype = ResultUsize
+
+    def atoi8_overflow_radix(data, radix, mode):
+        '''Parse 8-bit signed integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi8_overflow_radix', data, radix, mode)
+
+    def atoi16_overflow_radix(data, radix, mode):
+        '''Parse 16-bit signed integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi16_overflow_radix', data, radix, mode)
+
+    def atoi32_overflow_radix(data, radix, mode):
+        '''Parse 32-bit signed integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi32_overflow_radix', data, radix, mode)
+
+    def atoi64_overflow_radix(data, radix, mode):
+        '''Parse 64-bit signed integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi64_overflow_radix', data, radix, mode)
+
+    def atoisize_overflow_radix(data, radix, mode):
+        '''Parse ssize_t from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoisize_overflow_radix', data, radix, mode)
+
+    def atou8_overflow_radix(data, radix, mode):
+        '''Parse 8-bit unsigned integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou8_overflow_radix', data, radix, mode)
+
+    def atou16_overflow_radix(data, radix, mode):
+        '''Parse 16-bit unsigned integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou16_overflow_radix', data, radix, mode)
+
+    def atou32_overflow_radix(data, radix, mode):
+        '''Parse 32-bit unsigned integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou32_overflow_radix', data, radix, mode)
+
+    def atou64_overflow_radix(data, radix, mode):
+        '''Parse 64-bit unsigned integer from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou64_overflow_radix', data, radix, mode)
+
+    def atousize_overflow_radix(data, radix, mode):
+        '''Parse size_t from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atousize_overflow_radix', data, radix, mode)
+
+    # PARTIAL PARSE OVERFLOW RADIX
+
+    LIB.lexical_atoi8_partial_overflow_radix.restype = PartialResultI8
+    LIB.lexical_atoi16_partial_overflow_radix.restype = PartialResultI16
+    LIB.lexical_atoi32_partial_overflow_radix.restype = PartialResultI32
+    LIB.lexical_atoi64_partial_overflow_radix.restype = PartialResultI64
+    LIB.lexical_atoisize_partial_overflow_radix.restype = PartialResultIsize
+    LIB.lexical_atou8_partial_overflow_radix.restype = PartialResultU8
+    LIB.lexical_atou16_partial_overflow_radix.restype = PartialResultU16
+    LIB.lexical_atou32_partial_overflow_radix.restype = PartialResultU32
+    LIB.lexical_atou64_partial_overflow_radix.restype = PartialResultU64
+    LIB.lexical_atousize_partial_overflow_radix.restype = PartialResultUsize
+
+    def atoi8_partial_overflow_radix(data, radix, mode):
+        '''Parse 8-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi8_partial_overflow_radix', data, radix, mode)
+
+    def atoi16_partial_overflow_radix(data, radix, mode):
+        '''Parse 16-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi16_partial_overflow_radix', data, radix, mode)
+
+    def atoi32_partial_overflow_radix(data, radix, mode):
+        '''Parse 32-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi32_partial_overflow_radix', data, radix, mode)
+
+    def atoi64_partial_overflow_radix(data, radix, mode):
+        '''Parse 64-bit signed integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoi64_partial_overflow_radix', data, radix, mode)
+
+    def atoisize_partial_overflow_radix(data, radix, mode):
+        '''Parse ssize_t and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atoisize_partial_overflow_radix', data, radix, mode)
+
+    def atou8_partial_overflow_radix(data, radix, mode):
+        '''Parse 8-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou8_partial_overflow_radix', data, radix, mode)
+
+    def atou16_partial_overflow_radix(data, radix, mode):
+        '''Parse 16-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou16_partial_overflow_radix', data, radix, mode)
+
+    def atou32_partial_overflow_radix(data, radix, mode):
+        '''Parse 32-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou32_partial_overflow_radix', data, radix, mode)
+
+    def atou64_partial_overflow_radix(data, radix, mode):
+        '''Parse 64-bit unsigned integer and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atou64_partial_overflow_radix', data, radix, mode)
+
+    def atousize_partial_overflow_radix(data, radix, mode):
+        '''Parse size_t and the number of processed bytes from input data, with an overflow mode.'''
+        return _parse_overflow_radix('lexical_atousize_partial_overflow_radix', data, radix, mode)
+
 # PARSE FORMAT
 
 if HAVE_FORMAT:
@@ -260,6 +260,117 @@ macro_rules! from_lexical_lossy_format {
     );
 }
 
+// FROM LEXICAL OVERFLOW
+
+/// Create the integer parse options for the radix and overflow mode.
+#[inline]
+fn overflow_options(radix: u8, mode: lexical_core::OverflowMode)
+    -> lexical_core::ParseIntegerOptions
+{
+    let builder = lexical_core::ParseIntegerOptions::builder().overflow_mode(mode);
+    #[cfg(feature = "radix")]
+    let builder = builder.radix(radix);
+    #[cfg(not(feature = "radix"))]
+    let _ = radix;
+    builder.build().expect("Numerical base must be from 2-36.")
+}
+
+/// Macro to generate complete parser with an overflow mode from a pointer range.
+macro_rules! lexical_overflow_from_range {
+    (
+        fn $name:ident,
+        type => $type:ty,
+        args => $($argname:ident : $argtype:ty ;)*,
+        radix => $radix:expr,
+        condition => $($condition:tt)*
+    ) => (
+        #[doc(hidden)]
+        #[no_mangle]
+        $($condition)*
+        pub unsafe extern fn $name(first: *const u8, last: *const u8 $(,$argname : $argtype)*, mode: lexical_core::OverflowMode)
+            -> $crate::result::Result<$type>
+        {
+            assert!(first <= last && !first.is_null() && !last.is_null());
+            let bytes = $crate::lib::slice::from_raw_parts(first, distance(first, last));
+            let options = overflow_options($radix, mode);
+            lexical_core::parse_with_options::<$type>(bytes, &options).into()
+        }
+    );
+}
+
+/// Macro to generate partial parser with an overflow mode from a pointer range.
+macro_rules! lexical_partial_overflow_from_range {
+    (
+        fn $name:ident,
+        type => $type:ty,
+        args => $($argname:ident : $argtype:ty ;)*,
+        radix => $radix:expr,
+        condition => $($condition:tt)*
+    ) => (
+        #[doc(hidden)]
+        #[no_mangle]
+        $($condition)*
+        pub unsafe extern fn $name(first: *const u8, last: *const u8 $(,$argname : $argtype)*, mode: lexical_core::OverflowMode)
+            -> $crate::result::Result<$crate::result::Tuple<$type, usize>>
+        {
+            assert!(first <= last && !first.is_null() && !last.is_null());
+            let bytes = $crate::lib::slice::from_raw_parts(first, distance(first, last));
+            let options = overflow_options($radix, mode);
+            match lexical_core::parse_partial_with_options::<$type>(bytes, &options) {
+                Ok(v)  => Ok(v.into()),
+                Err(e) => Err(e),
+            }.into()
+        }
+    );
+}
+
+// Macro to generate integer parsers with an overflow mode.
+macro_rules! from_lexical_overflow {
+    (
+        type => $type:ty,
+        decimal => $decimal_name:ident,
+        partial_decimal => $partial_decimal_name:ident,
+        radix => $radix_name:ident,
+        partial_radix => $partial_radix_name:ident
+    ) => (
+        // Decimal.
+        lexical_overflow_from_range!(
+            fn $decimal_name,
+            type => $type,
+            args =>,
+            radix => 10,
+            condition =>
+        );
+
+        // Partial decimal.
+        lexical_partial_overflow_from_range!(
+            fn $partial_decimal_name,
+            type => $type,
+            args =>,
+            radix => 10,
+            condition =>
+        );
+
+        // Radix.
+        lexical_overflow_from_range!(
+            fn $radix_name,
+            type => $type,
+            args => radix: u8 ;,
+            radix => radix,
+            condition => #[cfg(feature = "radix")]
+        );
+
+        // Partial radix.
+        lexical_partial_overflow_from_range!(
+            fn $partial_radix_name,
+            type => $type,
+            args => radix: u8 ;,
+            radix => radix,
+            condition => #[cfg(feature = "radix")]
+        );
+    );
+}
+
 // TO LEXICAL
 
 /// Macro to generate the lexical to_string API using a range.
@@ -549,6 +660,93 @@ from_lexical_format!(
     partial_radix => lexical_atoi128_partial_format_radix
 );
 
+// ATOI OVERFLOW
+from_lexical_overflow!(
+    type => u8,
+    decimal => lexical_atou8_overflow,
+    partial_decimal => lexical_atou8_partial_overflow,
+    radix => lexical_atou8_overflow_radix,
+    partial_radix => lexical_atou8_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => u16,
+    decimal => lexical_atou16_overflow,
+    partial_decimal => lexical_atou16_partial_overflow,
+    radix => lexical_atou16_overflow_radix,
+    partial_radix => lexical_atou16_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => u32,
+    decimal => lexical_atou32_overflow,
+    partial_decimal => lexical_atou32_partial_overflow,
+    radix => lexical_atou32_overflow_radix,
+    partial_radix => lexical_atou32_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => u64,
+    decimal => lexical_atou64_overflow,
+    partial_decimal => lexical_atou64_partial_overflow,
+    radix => lexical_atou64_overflow_radix,
+    partial_radix => lexical_atou64_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => usize,
+    decimal => lexical_atousize_overflow,
+    partial_decimal => lexical_atousize_partial_overflow,
+    radix => lexical_atousize_overflow_radix,
+    partial_radix => lexical_atousize_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => u128,
+    decimal => lexical_atou128_overflow,
+    partial_decimal => lexical_atou128_partial_overflow,
+    radix => lexical_atou128_overflow_radix,
+    partial_radix => lexical_atou128_partial_overflow_radix
+);
+
+from_lexical_overflow!(
+    type => i8,
+    decimal => lexical_atoi8_overflow,
+    partial_decimal => lexical_atoi8_partial_overflow,
+    radix => lexical_atoi8_overflow_radix,
+    partial_radix => lexical_atoi8_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => i16,
+    decimal => lexical_atoi16_overflow,
+    partial_decimal => lexical_atoi16_partial_overflow,
+    radix => lexical_atoi16_overflow_radix,
+    partial_radix => lexical_atoi16_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => i32,
+    decimal => lexical_atoi32_overflow,
+    partial_decimal => lexical_atoi32_partial_overflow,
+    radix => lexical_atoi32_overflow_radix,
+    partial_radix => lexical_atoi32_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => i64,
+    decimal => lexical_atoi64_overflow,
+    partial_decimal => lexical_atoi64_partial_overflow,
+    radix => lexical_atoi64_overflow_radix,
+    partial_radix => lexical_atoi64_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => isize,
+    decimal => lexical_atoisize_overflow,
+    partial_decimal => lexical_atoisize_partial_overflow,
+    radix => lexical_atoisize_overflow_radix,
+    partial_radix => lexical_atoisize_partial_overflow_radix
+);
+from_lexical_overflow!(
+    type => i128,
+    decimal => lexical_atoi128_overflow,
+    partial_decimal => lexical_atoi128_partial_overflow,
+    radix => lexical_atoi128_overflow_radix,
+    partial_radix => lexical_atoi128_partial_overflow_radix
+);
+
 // FTOA
 to_lexical!(
     type => f32,
@@ -1,6 +1,5 @@
 //! Big integer type definition.
 
-use crate::float::*;
 use crate::util::*;
 use super::math::*;
 
@@ -14,15 +13,15 @@ if #[cfg(feature = "radix")] {
     // Maximum denominator is 767 mantissa digits + 324 exponent,
     // or 1091 digits, or approximately 3600 bits (round up to 4k).
     #[cfg(limb_width_32)]
-    type IntStorageType = arrayvec::ArrayVec<[Limb; 128]>;
+    type IntStorageType = StackVec<[Limb; 128]>;
 
     #[cfg(limb_width_64)]
-    type IntStorageType = arrayvec::ArrayVec<[Limb; 64]>;
+    type IntStorageType = StackVec<[Limb; 64]>;
 }}  // cfg_if
 
 perftools_inline!{
 /// Calculate the integral ceiling of the binary factor from a basen number.
-pub(super) fn integral_binary_factor(radix: u32)
+pub(crate) fn integral_binary_factor(radix: u32)
     -> u32
 {
     debug_assert_radix!(radix);
@@ -113,14 +112,90 @@ impl SmallOps for Bigint {
 impl LargeOps for Bigint {
 }
 
+/// Get the bounds of the binary exponent of `radix^n`.
+///
+/// Returns the exponents `(lo, hi)` so `2^lo <= radix^n <= 2^hi`.
+#[cfg(any(feature = "f16", feature = "f128"))]
+pub(crate) fn binary_exp_bounds(radix: u32, n: i64) -> (i64, i64) {
+    let ceil = integral_binary_factor(radix) as i64;
+    let floor = match radix.is_power_of_two() {
+        true  => ceil,
+        false => ceil - 1,
+    };
+    match n < 0 {
+        true  => (n * ceil, n * floor),
+        false => (n * floor, n * ceil),
+    }
+}
+
+// EXTENDED BIGINT
+
+cfg_if! {
+if #[cfg(feature = "f128")] {
+
+cfg_if! {
+if #[cfg(feature = "radix")] {
+    type ExtendedStorageType = Vec<Limb>;
+} else {
+    // Maximum value is ~11,600 mantissa digits scaled by the denominator
+    // of the exponent, for the quad-precision limits, which requires
+    // ~43,000 bits.
+    #[cfg(limb_width_32)]
+    type ExtendedStorageType = StackVec<[Limb; 2048]>;
+
+    #[cfg(limb_width_64)]
+    type ExtendedStorageType = StackVec<[Limb; 768]>;
+}}  // cfg_if
+
+/// Storage for a big integer type for extended-precision floats.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Debug))]
+pub(crate) struct ExtendedBigint {
+    /// Internal storage for the Bigint, in little-endian order.
+    pub(crate) data: ExtendedStorageType,
+}
+
+impl Default for ExtendedBigint {
+    fn default() -> Self {
+        ExtendedBigint { data: ExtendedStorageType::default() }
+    }
+}
+
+impl SharedOps for ExtendedBigint {
+    type StorageType = ExtendedStorageType;
+
+    perftools_inline_always!{
+    fn data<'a>(&'a self) -> &'a Self::StorageType {
+        &self.data
+    }}
+
+    perftools_inline_always!{
+    fn data_mut<'a>(&'a mut self) -> &'a mut Self::StorageType {
+        &mut self.data
+    }}
+}
+
+impl SmallOps for ExtendedBigint {
+}
+
+impl LargeOps for ExtendedBigint {
+}
+
+}}  // cfg_if
+
 // BIGFLOAT
 
+cfg_if! {
+if #[cfg(feature = "correct")] {
+
+use crate::float::*;
+
 // Adjust the storage capacity for the underlying array.
 cfg_if! {
 if #[cfg(limb_width_64)] {
-    type FloatStorageType = arrayvec::ArrayVec<[Limb; 20]>;
+    type FloatStorageType = StackVec<[Limb; 20]>;
 } else {
-    type FloatStorageType = arrayvec::ArrayVec<[Limb; 36]>;
+    type FloatStorageType = StackVec<[Limb; 36]>;
 }}   // cfg_if
 
 /// Storage for a big floating-point type.
@@ -206,10 +281,12 @@ impl ToBigfloat<u128> for ExtendedFloat<u128> {
     }}
 }
 
+}}  // cfg_if
+
 // TESTS
 // -----
 
-#[cfg(all(test, feature = "correct", feature = "radix"))]
+#[cfg(all(test, feature = "radix"))]
 mod test {
     use super::*;
 
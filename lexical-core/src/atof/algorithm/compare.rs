@@ -0,0 +1,186 @@
+//! Compare the exact value of a float string to a float.
+//!
+//! Used to break ties when a double parsed from a string is exactly
+//! halfway between two half-precision floats. The digits are compared
+//! to the double with big integers, so the result is exact with either
+//! the correct or the incorrect parser, and any rounding scheme.
+
+use crate::lib::cmp;
+use crate::util::*;
+use super::bignum::{binary_exp_bounds, Bigint};
+use super::components::to_components;
+use super::math::{Limb, SharedOps, SmallOps};
+
+// Maximum number of significant digits compared exactly.
+//
+// Every double exactly halfway between two half-precision floats has
+// fewer significant digits, so any truncated digit only breaks the tie.
+const MAX_DIGITS: i64 = 192;
+
+// Range of the binary exponent of every half-precision float.
+const MIN_BINARY_EXP: i64 = -150;
+const MAX_BINARY_EXP: i64 = 130;
+
+// Maximum number of bits in the powers of the radix and exponent base.
+const MAX_POWER_BITS: i64 = 2048;
+
+// Multiply the big integers by `radix^n`, the numerator if `n` is
+// positive, otherwise, the denominator.
+#[inline]
+fn imul_power(num: &mut Bigint, den: &mut Bigint, radix: u32, n: i64) {
+    match n < 0 {
+        true  => den.imul_power(radix, -n as u32),
+        false => num.imul_power(radix, n as u32),
+    }
+}
+
+/// Compare the exact value of a float string to the float it was parsed to.
+///
+/// The float string must be non-special, non-zero, and positive, and the
+/// float must be in the range of the half-precision floats.
+pub(crate) fn compare(bytes: &[u8], float: f64, options: &ParseFloatOptions)
+    -> cmp::Ordering
+{
+    let radix = options.radix_u32();
+    #[cfg(not(feature = "radix"))]
+    let exponent_base = radix;
+    #[cfg(feature = "radix")]
+    let exponent_base = options.exponent_base_u32();
+
+    let (integer, fraction, exponent) = match to_components(bytes, options) {
+        Ok((integer, fraction, exponent, _)) => (integer, fraction, exponent),
+        Err(_) => return cmp::Ordering::Equal,
+    };
+
+    // Get the significant digits as `digits * radix^scale`, skipping
+    // the digit separators.
+    let small_radix: Limb = as_cast(radix);
+    let mut digits = Bigint::default();
+    let mut count: i64 = 0;
+    let mut scale: i64 = 0;
+    let mut is_truncated = false;
+    for (index, &c) in integer.iter().chain(fraction.iter()).enumerate() {
+        let is_fraction = index >= integer.len();
+        let digit = match (c as char).to_digit(radix) {
+            Some(digit) => digit,
+            None        => continue,
+        };
+        if count < MAX_DIGITS {
+            if count != 0 || digit != 0 {
+                digits.imul_small(small_radix);
+                digits.iadd_small(as_cast(digit));
+                count += 1;
+            }
+            scale -= is_fraction as i64;
+        } else {
+            scale += !is_fraction as i64;
+            is_truncated |= digit != 0;
+        }
+    }
+    if count == 0 {
+        return cmp::Ordering::Less;
+    }
+
+    // The value is `digits * radix^scale * exponent_base^exponent`.
+    let (scale, exponent) = match radix == exponent_base {
+        true  => (scale.saturating_add(exponent), 0),
+        false => (scale, exponent),
+    };
+    let (scale_min, _) = binary_exp_bounds(radix, scale);
+    let (_, scale_max) = binary_exp_bounds(radix, scale + count);
+    let (exponent_min, exponent_max) = binary_exp_bounds(exponent_base, exponent);
+    if scale_max.saturating_add(exponent_max) < MIN_BINARY_EXP {
+        return cmp::Ordering::Less;
+    } else if scale_min.saturating_add(exponent_min) > MAX_BINARY_EXP {
+        return cmp::Ordering::Greater;
+    } else if scale_min.min(exponent_min) < -MAX_POWER_BITS || scale_max.max(exponent_max) > MAX_POWER_BITS {
+        // The powers are too large to compare, and cancel out.
+        return cmp::Ordering::Equal;
+    }
+
+    // Compare `digits * radix^scale * exponent_base^exponent` to `mant * 2^exp`.
+    let bits = float.to_bits();
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let mantissa = bits & 0x000F_FFFF_FFFF_FFFF;
+    let (mant, exp) = match biased {
+        0 => (mantissa, -1074),
+        _ => (mantissa | (1 << 52), biased - 1075),
+    };
+    let mut float = Bigint::from_u64(mant);
+    imul_power(&mut digits, &mut float, radix, scale);
+    imul_power(&mut digits, &mut float, exponent_base, exponent);
+    match exp < 0 {
+        true  => digits.ishl(-exp as usize),
+        false => float.ishl(exp as usize),
+    }
+    match digits.compare(&float) {
+        cmp::Ordering::Equal if is_truncated => cmp::Ordering::Greater,
+        ordering                             => ordering,
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_test() {
+        let options = ParseFloatOptions::new();
+        // Halfway between 1 and the next half-precision float.
+        let halfway = 1.00048828125f64;
+        assert_eq!(compare(b"1.00048828125", halfway, &options), cmp::Ordering::Equal);
+        assert_eq!(compare(b"100048828125e-11", halfway, &options), cmp::Ordering::Equal);
+        assert_eq!(compare(b"1.000488281250000000000000001", halfway, &options), cmp::Ordering::Greater);
+        assert_eq!(compare(b"1.000488281249999999999999999", halfway, &options), cmp::Ordering::Less);
+        assert_eq!(compare(b"0.00000000000000000000000000000000000000000000000001", halfway, &options), cmp::Ordering::Less);
+        assert_eq!(compare(b"1e50", halfway, &options), cmp::Ordering::Greater);
+
+        // Digits past the maximum only break the tie.
+        let mut bytes = [b'0'; 320];
+        bytes[..13].copy_from_slice(b"1.00048828125");
+        assert_eq!(compare(&bytes, halfway, &options), cmp::Ordering::Equal);
+        bytes[319] = b'1';
+        assert_eq!(compare(&bytes, halfway, &options), cmp::Ordering::Greater);
+
+        // Many significant digits for a small value.
+        let halfway = f64::from_bits(0x3790000000000000);
+        let mut bytes = [b'0'; 200];
+        bytes[..2].copy_from_slice(b"1.");
+        bytes[195..].copy_from_slice(b"1e-41");
+        assert_eq!(compare(&bytes, halfway, &options), cmp::Ordering::Less);
+        bytes[195..].copy_from_slice(b"1e-40");
+        assert_eq!(compare(&bytes, halfway, &options), cmp::Ordering::Greater);
+
+        // Smallest denormal half-precision float, halved.
+        let halfway = 2.98023223876953125e-8f64;
+        assert_eq!(compare(b"2.98023223876953125e-8", halfway, &options), cmp::Ordering::Equal);
+        assert_eq!(compare(b"2.980232238769531250000001e-8", halfway, &options), cmp::Ordering::Greater);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn compare_radix_test() {
+        let options = ParseFloatOptions::builder()
+            .radix(2)
+            .build()
+            .unwrap();
+        // Halfway between 1 and the next half-precision float.
+        let halfway = 1.00048828125f64;
+        assert_eq!(compare(b"1.00000000001", halfway, &options), cmp::Ordering::Equal);
+        assert_eq!(compare(b"1.000000000010000000000000000000000000000000000000000000000001", halfway, &options), cmp::Ordering::Greater);
+        assert_eq!(compare(b"1.000000000001", halfway, &options), cmp::Ordering::Less);
+
+        let options = ParseFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        assert_eq!(compare(b"1.002p0", halfway, &options), cmp::Ordering::Equal);
+        assert_eq!(compare(b"0.8010000000000000001p1", halfway, &options), cmp::Ordering::Greater);
+    }
+}
@@ -0,0 +1,67 @@
+//! Extract the components of a float string.
+//!
+//! The components are extracted and validated with the same data
+//! interface as the float parsers, but without trimming any digits.
+
+use crate::util::*;
+use super::format::*;
+
+// Extract the integer, fraction, and exponent.
+fn to_parts<'a, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: u8, exponent_radix: u32)
+    -> ParseResult<(&'a [u8], &'a [u8], i64, *const u8)>
+    where Data: FastDataInterface<'a>
+{
+    let ptr = data.extract_untrimmed(bytes, radix, exponent, exponent_radix)?;
+    let exponent = exact_exponent(&data, exponent_radix)?;
+    Ok((data.integer(), data.fraction().unwrap_or(&[]), exponent, ptr))
+}
+
+// Get the tokens expected where extraction stopped.
+fn to_expected<'a, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: u8, exponent_radix: u32)
+    -> Expected
+    where Data: FastDataInterface<'a>
+{
+    // Any error is ignored, the components are extracted up to the error.
+    let _ = data.extract_untrimmed(bytes, radix, exponent, exponent_radix);
+    data.expected()
+}
+
+/// Extract the components of a float string in the radix of the options.
+///
+/// The float string must be non-special and positive. Returns the
+/// integer and fraction digits, and the value of the exponent.
+pub(crate) fn to_components<'a>(bytes: &'a [u8], options: &ParseFloatOptions)
+    -> ParseResult<(&'a [u8], &'a [u8], i64, *const u8)>
+{
+    let radix = options.radix_u32();
+    let exponent = options.exponent_char();
+    let format = options.number_format();
+
+    #[cfg(not(feature = "radix"))]
+    let exponent_radix = radix;
+
+    #[cfg(feature = "radix")]
+    let exponent_radix = options.exponent_radix_u32();
+
+    apply_interface!(to_parts, format, bytes, radix, exponent, exponent_radix)
+}
+
+/// Get the tokens that could continue a float string where the parser
+/// stopped extracting its components.
+///
+/// The float string must be non-special.
+pub(crate) fn expected_components(bytes: &[u8], options: &ParseFloatOptions)
+    -> Expected
+{
+    let radix = options.radix_u32();
+    let exponent = options.exponent_char();
+    let format = options.number_format();
+
+    #[cfg(not(feature = "radix"))]
+    let exponent_radix = radix;
+
+    #[cfg(feature = "radix")]
+    let exponent_radix = options.exponent_radix_u32();
+
+    apply_interface!(to_expected, format, bytes, radix, exponent, exponent_radix)
+}
@@ -263,13 +263,11 @@ pub(super) fn moderate_path<F, M>(mantissa: M, radix: u32, exponent: i32, trunca
 
 /// Fallback method. Do not inline so the stack requirements only occur
 /// if required.
-fn pown_fallback<'a, F, Data>(data: Data, mantissa: u64, radix: u32, lossy: bool, sign: Sign)
+fn pown_fallback<'a, F, Data>(data: Data, mantissa: u64, radix: u32, lossy: bool, kind: RoundingKind)
     -> F
     where F: FloatType,
           Data: SlowDataInterface<'a>
 {
-    let kind = global_rounding(sign);
-
     // Moderate path (use an extended 80-bit representation).
     let exponent = data.mantissa_exponent();
     let is_truncated = data.truncated_digits() != 0;
@@ -291,13 +289,13 @@ fn pown_fallback<'a, F, Data>(data: Data, mantissa: u64, radix: u32, lossy: bool
 }
 
 /// Parse non-power-of-two radix string to native float.
-fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign)
+fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: u8, lossy: bool, kind: RoundingKind)
     -> ParseResult<(F, *const u8)>
     where F: FloatType,
           Data: FastDataInterface<'a>
 {
     // Parse the mantissa and exponent.
-    let ptr = data.extract(bytes, radix)?;
+    let ptr = data.extract(bytes, radix, exponent, radix)?;
     let (mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
 
     // Process the state to a float.
@@ -313,12 +311,12 @@ fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, loss
             float
         } else {
             let slow = data.to_slow(truncated);
-            pown_fallback(slow, mantissa, radix, lossy, sign)
+            pown_fallback(slow, mantissa, radix, lossy, kind)
         }
     } else {
         // Can only use the moderate/slow path.
         let slow = data.to_slow(truncated);
-        pown_fallback(slow, mantissa, radix, lossy, sign)
+        pown_fallback(slow, mantissa, radix, lossy, kind)
     };
     Ok((float, ptr))
 }
@@ -326,15 +324,19 @@ fn pown_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, loss
 // POW2
 
 /// Parse power-of-two radix string to native float.
+///
+/// The exponent base must also be a power of two, so the value is
+/// the mantissa scaled by an exact binary exponent.
 #[cfg(feature = "radix")]
-fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, pow2_exp: i32, sign: Sign)
+fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: u8, exponent_radix: u32, pow2_exp: i32, exponent_pow2: i32, kind: RoundingKind)
     -> ParseResult<(F, *const u8)>
     where F: FloatType,
           Data: FastDataInterface<'a>
 {
     // Parse the mantissa and exponent.
-    let ptr = data.extract(bytes, radix)?;
+    let ptr = data.extract(bytes, radix, exponent, exponent_radix)?;
     let (mut mantissa, truncated) = process_mantissa::<u64, _>(&data, radix);
+    let binary_exp = data.binary_exponent(truncated, pow2_exp, exponent_pow2);
 
     // We have a power of 2, can get an exact value even if the mantissa
     // was truncated. Check to see if there are any truncated digits, depending
@@ -342,47 +344,44 @@ fn pow2_to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, pow2
     let mantissa_size = F::MANTISSA_SIZE + 1;
     let float = if !truncated.is_zero() {
         // Truncated mantissa.
-        let kind = global_rounding(sign);
         let slow = data.to_slow(truncated);
         if kind != RoundingKind::Downward {
+            // Check if there were any non-zero truncated digits.
+            let index = slow.mantissa_digits() - slow.truncated_digits();
+            let iter = slow.integer_iter().chain(slow.fraction_iter()).skip(index);
+            let count = iter.take_while(|&&c| c == b'0').count();
+            let is_truncated = count < slow.truncated_digits();
+
             if cfg!(feature = "rounding") || kind == RoundingKind::NearestTieEven {
-                // Need to check if we're exactly halfway and if there are truncated digits.
+                // Need to check if we're exactly halfway and odd. Otherwise,
+                // non-zero truncated digits are below every bit of the
+                // mantissa, so set the lowest bit to round values above
+                // halfway up, including denormal values.
                 if is_halfway::<F>(mantissa) && is_odd::<F>(mantissa) {
                     mantissa += 1;
+                } else if is_truncated {
+                    mantissa |= 1;
                 }
             } else if kind == RoundingKind::NearestTieAwayZero {
                 // Need to check if we're exactly halfway and if there are truncated digits.
                 if is_halfway::<F>(mantissa) {
                     mantissa += 1;
                 }
-            } else {
-                // Need to check if there are any bytes present.
-                // Check if there were any truncated bytes.
-                let index = slow.mantissa_digits() - slow.truncated_digits();
-                let iter = slow.integer_iter().chain(slow.fraction_iter()).skip(index);
-                let count = iter.take_while(|&&c| c == b'0').count();
-                let is_truncated = count < slow.truncated_digits();
-                if is_truncated {
-                    mantissa += 1;
-                }
+            } else if is_truncated {
+                mantissa += 1;
             }
         }
 
         // Create exact representation and return.
-        let exponent = slow.mantissa_exponent().saturating_mul(pow2_exp);
-        let fp = ExtendedFloat { mant: mantissa, exp: exponent };
+        let fp = ExtendedFloat { mant: mantissa, exp: binary_exp };
         fp.into_rounded_float_impl::<F>(kind)
     } else if mantissa >> mantissa_size != 0 {
         // Would be truncated, use the extended float.
-        let kind = global_rounding(sign);
-        let slow = data.to_slow(truncated);
-        let exponent = slow.mantissa_exponent().saturating_mul(pow2_exp);
-        let fp = ExtendedFloat { mant: mantissa, exp: exponent };
+        let fp = ExtendedFloat { mant: mantissa, exp: binary_exp };
         fp.into_rounded_float_impl::<F>(kind)
     } else {
         // Nothing above the hidden bit, so no rounding-error, can use the fast path.
-        let mant_exp = data.mantissa_exponent(0);
-        pow2_fast_path(mantissa, radix, pow2_exp, mant_exp)
+        pow2_fast_path(mantissa, 2, 1, binary_exp)
     };
     Ok((float, ptr))
 }
@@ -406,19 +405,32 @@ fn pow2_exponent(radix: u32) -> i32 {
 //
 // The float string must be non-special, non-zero, and positive.
 perftools_inline!{
-fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn to_native<F>(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
     where F: FloatType
 {
+    let radix = options.radix_u32();
+    let exponent = options.exponent_char();
+    let kind = internal_rounding(options.rounding_kind(), sign);
+    let format = options.number_format();
+
     #[cfg(not(feature = "radix"))] {
-        apply_interface!(pown_to_native, format, bytes, radix,  lossy, sign)
+        let lossy = options.lossy();
+        apply_interface!(pown_to_native, format, bytes, radix, exponent, lossy, kind)
     }
 
     #[cfg(feature = "radix")] {
         let pow2_exp = pow2_exponent(radix);
         match pow2_exp {
-            0 => apply_interface!(pown_to_native, format, bytes, radix, lossy, sign),
-            _ => apply_interface!(pow2_to_native, format, bytes, radix, pow2_exp, sign)
+            0 => {
+                let lossy = options.lossy();
+                apply_interface!(pown_to_native, format, bytes, radix, exponent, lossy, kind)
+            },
+            _ => {
+                let exponent_radix = options.exponent_radix_u32();
+                let exponent_pow2 = pow2_exponent(options.exponent_base_u32());
+                apply_interface!(pow2_to_native, format, bytes, radix, exponent, exponent_radix, pow2_exp, exponent_pow2, kind)
+            },
         }
     }
 }}
@@ -428,18 +440,18 @@ fn to_native<F>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: Numbe
 
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atof(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(f32, *const u8)>
 {
-    to_native::<f32>(bytes, radix, lossy, sign, format)
+    to_native::<f32>(bytes, sign, options)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atod(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(f64, *const u8)>
 {
-    to_native::<f64>(bytes, radix, lossy, sign, format)
+    to_native::<f64>(bytes, sign, options)
 }}
 
 // TESTS
@@ -726,7 +738,7 @@ mod tests {
 
     #[test]
     fn atof_test() {
-        let atof10 = move |x| match atof(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, Sign::Positive, &ParseFloatOptions::from_config(false, 10, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -780,7 +792,7 @@ mod tests {
 
     #[test]
     fn atod_test() {
-        let adod_impl = move | x, r | match atod(x, r, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let adod_impl = move | x, r | match atod(x, Sign::Positive, &ParseFloatOptions::from_config(false, r, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -881,7 +893,7 @@ mod tests {
 
     #[test]
     fn atof_lossy_test() {
-        let atof10 = move |x| match atof(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, Sign::Positive, &ParseFloatOptions::from_config(true, 10, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -894,7 +906,7 @@ mod tests {
 
     #[test]
     fn atod_lossy_test() {
-        let atod10 = move |x| match atod(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atod10 = move |x| match atod(x, Sign::Positive, &ParseFloatOptions::from_config(true, 10, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
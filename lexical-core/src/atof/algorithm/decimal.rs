@@ -0,0 +1,44 @@
+//! Exact algorithm for string-to-decimal conversions.
+//!
+//! The float components are extracted and validated with the same
+//! data interface as the float parsers, but the digits are kept as-is
+//! rather than rounded to a binary float.
+
+use crate::lib::vec::Vec;
+use crate::util::*;
+use super::format::*;
+
+// Collect the significant digits and the exponent of the least-significant digit.
+fn to_parts<'a, Data>(mut data: Data, bytes: &'a [u8], exponent: u8)
+    -> ParseResult<(Vec<u8>, i64, *const u8)>
+    where Data: FastDataInterface<'a>
+{
+    let ptr = data.extract(bytes, 10, exponent, 10)?;
+    let fraction_digits = data.fraction_iter().count();
+    let digits: Vec<u8> = data.integer_iter()
+        .chain(data.fraction_iter())
+        .skip_while(|&&c| c == b'0')
+        .cloned()
+        .collect();
+    // The exponent of the most-significant digit must also fit in an
+    // i64, so the decimal can be normalized and written.
+    let overflow = || (ErrorCode::Overflow, option_as_ptr(data.exponent()));
+    let exponent = exact_exponent(&data, 10)?
+        .checked_sub(fraction_digits as i64)
+        .ok_or_else(overflow)?;
+    exponent.checked_add(digits.len() as i64).ok_or_else(overflow)?;
+    Ok((digits, exponent, ptr))
+}
+
+/// Parse the exact decimal value of a float string.
+///
+/// The float string must be non-special and positive, and is always
+/// parsed as a decimal string. Returns the significant digits, which may
+/// have trailing zeros, and the exponent of the least-significant digit.
+pub(crate) fn to_decimal(bytes: &[u8], options: &ParseFloatOptions)
+    -> ParseResult<(Vec<u8>, i64, *const u8)>
+{
+    let exponent = options.exponent_char();
+    let format = options.number_format();
+    apply_interface!(to_parts, format, bytes, exponent)
+}
@@ -4,6 +4,7 @@ use crate::atoi;
 use crate::lib::slice;
 use crate::util::*;
 use super::traits::*;
+use super::validate::option_as_ptr;
 
 /// The actual float-type doesn't matter, it just needs to be used for
 /// signed/unsigned detection during sign parsing.
@@ -45,6 +46,37 @@ pub(super) fn mantissa_exponent(raw_exponent: i32, fraction_digits: usize, trunc
     }
 }}
 
+// Parse the extracted exponent exactly, without saturating.
+//
+// The raw exponent saturates at the limits of an i32, which is
+// sufficient for floats but not for parsers that keep every digit.
+// Returns an overflow error if the exponent does not fit in an i64.
+pub(crate) fn exact_exponent<'a, Data>(data: &Data, radix: u32)
+    -> ParseResult<i64>
+    where Data: FastDataInterface<'a>
+{
+    let overflow = || (ErrorCode::Overflow, option_as_ptr(data.exponent()));
+    let mut iter = data.exponent_iter().peekable();
+    let sign = iter.peek().map(|&&c| c);
+    let is_negative = sign == Some(b'-');
+    if is_negative || sign == Some(b'+') {
+        iter.next();
+    }
+    let mut value: i64 = 0;
+    for &c in iter {
+        let digit = match (c as char).to_digit(radix) {
+            Some(v) => v as i64,
+            None    => break,
+        };
+        let shifted = value.checked_mul(radix as i64).ok_or_else(overflow)?;
+        value = match is_negative {
+            true  => shifted.checked_sub(digit),
+            false => shifted.checked_add(digit),
+        }.ok_or_else(overflow)?;
+    }
+    Ok(value)
+}
+
 // EXPONENT EXTRACTORS
 
 // Extract exponent substring and parse exponent.
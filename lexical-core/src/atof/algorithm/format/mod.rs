@@ -22,6 +22,8 @@ if #[cfg(feature = "format")] {
 }}
 
 // Re-export interface and traits.
+pub(super) use exponent::exact_exponent;
+pub(super) use validate::option_as_ptr;
 pub(super) use standard::*;
 pub(super) use traits::*;
 
@@ -180,6 +180,19 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
         mantissa_exponent(self.raw_exponent(), self.fraction_iter().count(), truncated_digits)
     }}
 
+    perftools_inline!{
+    /// Get the binary exponent from the raw exponent, for power-of-two
+    /// radixes and exponent bases.
+    ///
+    /// `pow2_exp` and `exponent_pow2` are the powers of two of the radix
+    /// and the exponent base, respectively.
+    #[cfg(all(feature = "correct", feature = "radix"))]
+    fn binary_exponent(&self, truncated_digits: usize, pow2_exp: i32, exponent_pow2: i32) -> i32 {
+        let digits_exponent = mantissa_exponent(0, self.fraction_iter().count(), truncated_digits);
+        let exponent = self.raw_exponent().saturating_mul(exponent_pow2);
+        exponent.saturating_add(digits_exponent.saturating_mul(pow2_exp))
+    }}
+
     // EXTRACT
 
     // Consume integer digits until a non-digit character is found.
@@ -249,14 +262,16 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
     }}
 
     perftools_inline!{
-    /// Extract float subcomponents from input bytes.
-    fn extract(&mut self, bytes: &'a [u8], radix: u32) -> ParseResult<*const u8> {
+    /// Extract and validate float subcomponents from input bytes, without trimming.
+    ///
+    /// The exponent digits are parsed with the exponent radix.
+    fn extract_untrimmed(&mut self, bytes: &'a [u8], radix: u32, exponent: u8, exponent_radix: u32) -> ParseResult<*const u8> {
         // Parse the integer, aka, the digits preceding any control characters.
         let mut digits = bytes;
         digits = self.extract_integer(digits, radix);
 
         // Parse and validate a fraction, if present.
-        let exp_char = exponent_notation_char(radix).to_ascii_lowercase();
+        let exp_char = exponent.to_ascii_lowercase();
         if let Some(&b'.') = digits.first() {
             digits = self.extract_fraction(digits, radix);
         }
@@ -265,17 +280,40 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
         // Parse and validate an exponent, if present.
         if let Some(&c) = digits.first() {
             if c.to_ascii_lowercase() == exp_char {
-                digits = self.extract_exponent(digits, radix);
+                digits = self.extract_exponent(digits, exponent_radix);
             }
         }
         self.validate_exponent()?;
         self.validate_exponent_fraction()?;
         self.validate_exponent_sign()?;
 
+        Ok(digits.as_ptr())
+    }}
+
+    perftools_inline!{
+    /// Get the tokens that could continue the float where extraction stopped.
+    fn expected(&self) -> Expected {
+        match (self.fraction(), self.exponent()) {
+            (_, Some(exponent)) => match exponent {
+                []              => Expected::DIGIT | Expected::SIGN,
+                [b'+']          |
+                [b'-']          => Expected::DIGIT,
+                _               => Expected::DIGIT | Expected::END,
+            },
+            (Some(_), None)     => Expected::DIGIT | Expected::EXPONENT | Expected::END,
+            (None, None)        => Expected::DIGIT | Expected::SEPARATOR | Expected::EXPONENT | Expected::END,
+        }
+    }}
+
+    perftools_inline!{
+    /// Extract float subcomponents from input bytes.
+    fn extract(&mut self, bytes: &'a [u8], radix: u32, exponent: u8, exponent_radix: u32) -> ParseResult<*const u8> {
+        let ptr = self.extract_untrimmed(bytes, radix, exponent, exponent_radix)?;
+
         // Trim the remaining digits.
         self.trim();
 
-        Ok(digits.as_ptr())
+        Ok(ptr)
     }}
 
     // TO SLOW DATA
@@ -312,7 +350,7 @@ pub(crate) trait FastDataInterface<'a>: FastDataInterfaceImpl<'a> {
     #[cfg(test)]
     fn check_extract(&mut self, digits: &'a [u8], expected: &ParseTestResult<Self>) {
         let expected = expected.as_ref();
-        match self.extract(digits, 10) {
+        match self.extract(digits, 10, b'e', 10) {
             Ok(_)       => {
                 let expected = expected.unwrap();
                 assert_eq!(self.integer(), expected.integer());
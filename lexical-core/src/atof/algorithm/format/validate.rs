@@ -44,7 +44,7 @@ fn has_exponent<'a, Data>(data: &Data)
 
 // Unwrap option to get the pointer.
 perftools_inline!{
-fn option_as_ptr(option: Option<&[u8]>) -> *const u8
+pub(crate) fn option_as_ptr(option: Option<&[u8]>) -> *const u8
 {
     option.unwrap().as_ptr()
 }}
@@ -50,27 +50,33 @@ fn process_fraction<'a, F, Data>(data: &Data, radix: u32)
 
 // Convert the float string to a native floating-point number.
 perftools_inline!{
-fn to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32)
+fn to_native<'a, F, Data>(mut data: Data, bytes: &'a [u8], radix: u32, exponent: u8, exponent_base: u32, exponent_radix: u32)
     -> ParseResult<(F, *const u8)>
     where F: StablePower,
           Data: FastDataInterface<'a>
 {
-    let ptr = data.extract(bytes, radix)?;
+    let ptr = data.extract(bytes, radix, exponent, exponent_radix)?;
     let integer: F = process_integer(&data, radix);
     let fraction: F = process_fraction(&data, radix);
     let mut value = integer + fraction;
     if !data.raw_exponent().is_zero() && !value.is_zero() {
-        value = value.iterative_pow(radix, data.raw_exponent());
+        value = value.iterative_pow(exponent_base, data.raw_exponent());
     }
     Ok((value, ptr))
 }}
 
 perftools_inline!{
-pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], radix: u32, _: bool, _: Sign, format: NumberFormat)
+pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], _: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
     where F: StablePower
 {
-    apply_interface!(to_native, format, bytes, radix)
+    let radix = options.radix_u32();
+    let exponent = options.exponent_char();
+    #[cfg(feature = "radix")]
+    let (exponent_base, exponent_radix) = (options.exponent_base_u32(), options.exponent_radix_u32());
+    #[cfg(not(feature = "radix"))]
+    let (exponent_base, exponent_radix) = (radix, radix);
+    apply_interface!(to_native, options.number_format(), bytes, radix, exponent, exponent_base, exponent_radix)
 }}
 
 // ATOF/ATOD
@@ -78,18 +84,18 @@ pub(crate) fn atof_generic<'a, F>(bytes: &'a [u8], radix: u32, _: bool, _: Sign,
 
 // Parse 32-bit float from string.
 perftools_inline!{
-pub(crate) fn atof<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atof<'a>(bytes: &'a [u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(f32, *const u8)>
 {
-    atof_generic(bytes, radix, lossy, sign, format)
+    atof_generic(bytes, sign, options)
 }}
 
 // Parse 64-bit float from string.
 perftools_inline!{
-pub(crate) fn atod<'a>(bytes: &'a [u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+pub(crate) fn atod<'a>(bytes: &'a [u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(f64, *const u8)>
 {
-    atof_generic(bytes, radix, lossy, sign, format)
+    atof_generic(bytes, sign, options)
 }}
 
 // TESTS
@@ -129,7 +135,7 @@ mod tests {
 
     #[test]
     fn atof_test() {
-        let atof10 = move |x| match atof(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, Sign::Positive, &ParseFloatOptions::from_config(false, 10, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -142,7 +148,7 @@ mod tests {
 
     #[test]
     fn atod_test() {
-        let atod10 = move |x| match atod(x, 10, false, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atod10 = move |x| match atod(x, Sign::Positive, &ParseFloatOptions::from_config(false, 10, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -159,7 +165,7 @@ mod tests {
 
     #[test]
     fn atof_lossy_test() {
-        let atof10 = move |x| match atof(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atof10 = move |x| match atof(x, Sign::Positive, &ParseFloatOptions::from_config(true, 10, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -172,7 +178,7 @@ mod tests {
 
     #[test]
     fn atod_lossy_test() {
-        let atod10 = move |x| match atod(x, 10, true, Sign::Positive, NumberFormat::standard().unwrap()) {
+        let atod10 = move |x| match atod(x, Sign::Positive, &ParseFloatOptions::from_config(true, 10, NumberFormat::standard().unwrap())) {
             Ok((v, p))  => Ok((v, distance(x.as_ptr(), p))),
             Err((v, p)) => Err((v, distance(x.as_ptr(), p))),
         };
@@ -1804,6 +1804,10 @@ fn karatsuba_mul<T>(x: &[Limb], y: &[Limb])
     if y.len() <= KARATSUBA_CUTOFF {
         // Bottom-out to long division for small cases.
         long_mul(x, y)
+    } else if x.is_empty() {
+        // The high half of `x` is empty if `x` has exactly `y.len() / 2`
+        // limbs, and the product is zero.
+        T::default()
     } else if x.len() < y.len() / 2 {
         karatsuba_uneven_mul(x, y)
     } else {
@@ -2250,7 +2254,7 @@ macro_rules! imul_power {
 /// None of these are implemented using normal traits, since these
 /// are very expensive operations, and we want to deliberately
 /// and explicitly use these functions.
-pub(in crate::atof::algorithm) trait SharedOps: Clone + Sized + Default {
+pub(crate) trait SharedOps: Clone + Sized + Default {
     /// Underlying storage type for a SmallOps.
     type StorageType: CloneableVecLike<Limb>;
 
@@ -2482,7 +2486,7 @@ pub(in crate::atof::algorithm) trait SharedOps: Clone + Sized + Default {
 }
 
 /// Trait for small operations for arbitrary-precision numbers.
-pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
+pub(crate) trait SmallOps: SharedOps {
     // SMALL POWERS
 
     perftools_inline!{
@@ -2819,7 +2823,7 @@ pub(in crate::atof::algorithm) trait SmallOps: SharedOps {
 }
 
 /// Trait for large operations for arbitrary-precision numbers.
-pub(in crate::atof::algorithm) trait LargeOps: SmallOps {
+pub(crate) trait LargeOps: SmallOps {
     // ADDITION
 
     perftools_inline!{
@@ -3499,6 +3503,20 @@ mod tests {
         let y = Bigint { data: from_u32(&[4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37]) };
         x.imul_large(&y);
         assert_eq!(x.data, from_u32(&[4, 13, 28, 50, 80, 119, 168, 228, 300, 385, 484, 598, 728, 875, 1040, 1224, 1360, 1496, 1632, 1768, 1904, 2040, 2176, 2312, 2448, 2584, 2720, 2856, 2992, 3128, 3264, 3400, 3536, 3672, 3770, 3829, 3848, 3826, 3762, 3655, 3504, 3308, 3066, 2777, 2440, 2054, 1618, 1131, 592]));
+
+
+        // Test cases where `x` has exactly half the limbs of `y`.
+        let mut x = [0u32; 34];
+        x[33] = 1;
+        let mut x = Bigint { data: from_u32(&x) };
+        let mut y = [0u32; 68];
+        for (i, yi) in y.iter_mut().enumerate() {
+            *yi = i as u32 + 1;
+        }
+        let y = Bigint { data: from_u32(&y) };
+        let expected = y.shl(33 * 32);
+        x.imul_large(&y);
+        assert_eq!(x.data, expected.data);
     }
 
     #[test]
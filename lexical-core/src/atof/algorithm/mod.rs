@@ -4,18 +4,10 @@
 #[macro_use]
 mod format;
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-mod bhcomp;
-mod bigcomp;
-mod alias;
-mod bignum;
-mod cached;
-mod cached_float80;
-mod cached_float160;
-mod errors;
+// Big-integer arithmetic, shared with the exact float writers.
+pub(crate) mod bignum;
+pub(crate) mod math;
 mod large_powers;
-mod math;
 mod small_powers;
 
 #[cfg(limb_width_32)]
@@ -30,11 +22,28 @@ mod large_powers_64;
 // Required for fast-path, keep on all platforms.
 mod small_powers_64;
 
+cfg_if! {
+if #[cfg(feature = "correct")] {
+mod bhcomp;
+mod bigcomp;
+mod alias;
+mod cached;
+mod cached_float80;
+mod cached_float160;
+mod errors;
 }}  // cfg_if
 
 // Export algorithms.
+pub(crate) mod components;
+
+#[cfg(feature = "f16")]
+pub(crate) mod compare;
+
 #[cfg(feature = "correct")]
 pub(crate) mod correct;
 
 #[cfg(not(feature = "correct"))]
 pub(crate) mod incorrect;
+
+#[cfg(feature = "std")]
+pub(crate) mod decimal;
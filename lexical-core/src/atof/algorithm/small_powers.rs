@@ -1,6 +1,7 @@
 //! Precalculated small powers.
 
 use super::math::Limb;
+#[cfg(feature = "correct")]
 use super::small_powers_64;
 
 #[cfg(limb_width_32)]
@@ -10,11 +11,15 @@ use super::small_powers_32::*;
 use super::small_powers_64::*;
 
 // ASSERTIONS
+
+cfg_if! {
+if #[cfg(feature = "correct")] {
 const_assert!(POW5[1] / POW5[0] == 5);
 const_assert!(POW10[1] / POW10[0] == 10);
+}} //cfg_if
 
 cfg_if! {
-if #[cfg(feature = "radix")] {
+if #[cfg(all(feature = "correct", feature = "radix"))] {
 // Ensure our small powers are valid.
 const_assert!(POW2[1] / POW2[0] == 2);
 const_assert!(POW3[1] / POW3[0] == 3);
@@ -109,6 +114,7 @@ pub(in crate::atof::algorithm) fn get_small_powers(radix: u32)
 }
 
 /// Get the correct 64-bit small power from the radix.
+#[cfg(feature = "correct")]
 pub(in crate::atof::algorithm) fn get_small_powers_64(radix: u32)
     -> &'static [u64]
 {
@@ -5,6 +5,14 @@
 use crate::lib::slice;
 use crate::util::*;
 
+use super::algorithm::components::expected_components;
+
+#[cfg(feature = "f128")]
+use super::extended::parse_validated;
+
+#[cfg(feature = "f16")]
+use crate::lib::cmp;
+
 // Select the back-end
 cfg_if! {
 if #[cfg(feature = "correct")] {
@@ -18,24 +26,24 @@ if #[cfg(feature = "correct")] {
 /// Trait to define parsing of a string to float.
 trait StringToFloat: Float {
     /// Serialize string to float, favoring correctness.
-    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat) -> ParseResult<(Self, *const u8)>;
+    fn default(bytes: &[u8], sign: Sign, options: &ParseFloatOptions) -> ParseResult<(Self, *const u8)>;
 }
 
 impl StringToFloat for f32 {
     perftools_inline_always!{
-    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+    fn default(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
         -> ParseResult<(f32, *const u8)>
     {
-        algorithm::atof(bytes, radix, lossy, sign, format)
+        algorithm::atof(bytes, sign, options)
     }}
 }
 
 impl StringToFloat for f64 {
     perftools_inline_always!{
-    fn default(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+    fn default(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
         -> ParseResult<(f64, *const u8)>
     {
-        algorithm::atod(bytes, radix, lossy, sign, format)
+        algorithm::atod(bytes, sign, options)
     }}
 }
 
@@ -55,16 +63,138 @@ fn to_iter_s<'a>(bytes: &'a [u8], digit_separator: u8) -> SkipValueIterator<'a,
     SkipValueIterator::new(bytes, digit_separator)
 }}
 
+// Parse the integer in a NaN payload, like `strtoull` with a base of 0.
+// Invalid payloads are ignored, and large payloads saturate.
+perftools_inline!{
+fn parse_nan_payload_integer(bytes: &[u8]) -> u64 {
+    let is_hex = bytes.len() > 2 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X');
+    let (radix, digits) = match bytes.first() {
+        Some(&b'0') if is_hex   => (16, &index!(bytes[2..])),
+        Some(&b'0')             => (8, &index!(bytes[1..])),
+        _                       => (10, bytes),
+    };
+    let mut value: u64 = 0;
+    for &c in digits {
+        let digit = match (c as char).to_digit(radix) {
+            Some(digit) => digit as u64,
+            None        => return 0,
+        };
+        value = value.checked_mul(radix as u64)
+            .and_then(|v| v.checked_add(digit))
+            .unwrap_or(u64::max_value());
+    }
+    value
+}}
+
+// Parse an optional payload in parentheses after NaN.
+//
+// The payload is only consumed if the parentheses are closed, and
+// is 0 if there is no payload.
+perftools_inline!{
+fn parse_nan_payload(bytes: &[u8], ptr: *const u8, options: &ParseFloatOptions)
+    -> (u64, *const u8)
+{
+    if !options.nan_payload() {
+        return (0, ptr);
+    }
+
+    let rest = &index!(bytes[distance(bytes.as_ptr(), ptr)..]);
+    if rest.first() != Some(&b'(') {
+        return (0, ptr);
+    }
+    let is_payload = |c: &&u8| c.is_ascii_alphanumeric() || **c == b'_';
+    let count = rest[1..].iter().take_while(is_payload).count();
+    if rest.get(count + 1) != Some(&b')') {
+        return (0, ptr);
+    }
+
+    let ptr = index!(rest[count + 2..]).as_ptr();
+    (parse_nan_payload_integer(&index!(rest[1..count + 1])), ptr)
+}}
+
+// Create NaN from the payload, like glibc's `strtod` and `nans`.
+//
+// The payload is stored in the mantissa bits below the quiet bit. Quiet
+// NaNs set the quiet bit, and signaling NaNs with a payload of 0 use
+// the highest bit below the quiet bit, since the mantissa cannot be 0.
+perftools_inline!{
+fn nan_from_payload<F: StringToFloat>(payload: u64, is_signaling: bool)
+    -> F
+{
+    let quiet = F::HIDDEN_BIT_MASK >> 1;
+    let payload: F::Unsigned = as_cast(payload);
+    let payload = payload & (quiet - F::Unsigned::ONE);
+    match (is_signaling, payload.is_zero()) {
+        (false, true)   => F::NAN,
+        (false, false)  => F::from_bits(F::EXPONENT_MASK | quiet | payload),
+        (true, true)    => F::from_bits(F::EXPONENT_MASK | (quiet >> 1)),
+        (true, false)   => F::from_bits(F::EXPONENT_MASK | payload),
+    }
+}}
+
+// Parse a quiet NaN, with an optional payload, from the bytes after
+// the NaN string.
+perftools_inline!{
+fn parse_quiet_nan<F: StringToFloat>(bytes: &[u8], ptr: *const u8, options: &ParseFloatOptions)
+    -> (F, *const u8)
+{
+    let (payload, ptr) = parse_nan_payload(bytes, ptr, options);
+    (nan_from_payload(payload, false), ptr)
+}}
+
+// Parse a signaling NaN, spelled as `s` before the NaN string, such as
+// `sNaN(0x1)`, with an optional payload.
+//
+// Signaling NaNs are only parsed with NaN payloads, with the same
+// case-sensitivity as the NaN string, and without digit separators.
+perftools_inline!{
+fn parse_signaling_nan<F: StringToFloat>(bytes: &[u8], options: &ParseFloatOptions, case_sensitive: bool)
+    -> Option<(F, *const u8)>
+{
+    let nan = options.nan_string();
+    let is_match = options.nan_payload() && bytes.len() > nan.len() && match case_sensitive {
+        true  => bytes[0] == b's' && bytes[1..].starts_with(nan),
+        false => bytes[0].eq_ignore_ascii_case(&b's') && bytes[1..nan.len() + 1].eq_ignore_ascii_case(nan),
+    };
+    if !is_match {
+        return None;
+    }
+
+    let ptr = index!(bytes[nan.len() + 1..]).as_ptr();
+    let (payload, ptr) = parse_nan_payload(bytes, ptr, options);
+    Some((nan_from_payload(payload, true), ptr))
+}}
+
+// Parse an additional spelling of a special value from string.
+//
+// Aliases may start with any character, so they are checked before
+// any other special values or numbers.
+perftools_inline!{
+fn parse_special_alias<F: StringToFloat>(bytes: &[u8], options: &ParseFloatOptions, case_sensitive: bool)
+    -> Option<(F, *const u8)>
+{
+    let starts_with = |alias: &&&[u8]| match case_sensitive {
+        true  => bytes.starts_with(*alias),
+        false => bytes.len() >= alias.len() && bytes[..alias.len()].eq_ignore_ascii_case(*alias),
+    };
+    if let Some(alias) = options.nan_aliases().iter().find(starts_with) {
+        let ptr = index!(bytes[alias.len()..]).as_ptr();
+        Some(parse_quiet_nan(bytes, ptr, options))
+    } else if let Some(alias) = options.inf_aliases().iter().find(starts_with) {
+        Some((F::INFINITY, index!(bytes[alias.len()..]).as_ptr()))
+    } else {
+        None
+    }
+}}
+
 // PARSER
 
 // Parse infinity from string.
 perftools_inline!{
 fn parse_infinity<'a, ToIter, StartsWith, Iter, F>(
     bytes: &'a [u8],
-    radix: u32,
-    lossy: bool,
     sign: Sign,
-    format: NumberFormat,
+    options: &ParseFloatOptions,
     to_iter: ToIter,
     starts_with: StartsWith
 )
@@ -74,16 +204,17 @@ fn parse_infinity<'a, ToIter, StartsWith, Iter, F>(
           Iter: AsPtrIterator<'a, u8>,
           StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter)
 {
-    let infinity = get_infinity_string();
-    let inf = get_inf_string();
-    if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), infinity.iter()) {
+    let infinity = options.infinity_string();
+    let inf = options.inf_string();
+    let digit_separator = options.number_format().digit_separator();
+    if let (true, iter) = starts_with(to_iter(bytes, digit_separator), infinity.iter()) {
         Ok((F::INFINITY, iter.as_ptr()))
-    } else if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), inf.iter()) {
+    } else if let (true, iter) = starts_with(to_iter(bytes, digit_separator), inf.iter()) {
         Ok((F::INFINITY, iter.as_ptr()))
     } else {
         // Not infinity, may be valid with a different radix.
         if cfg!(feature = "radix"){
-            F::default(bytes, radix, lossy, sign, format)
+            F::default(bytes, sign, options)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
@@ -94,10 +225,8 @@ fn parse_infinity<'a, ToIter, StartsWith, Iter, F>(
 perftools_inline!{
 fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
     bytes: &'a [u8],
-    radix: u32,
-    lossy: bool,
     sign: Sign,
-    format: NumberFormat,
+    options: &ParseFloatOptions,
     to_iter: ToIter,
     starts_with: StartsWith
 )
@@ -107,13 +236,14 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
           Iter: AsPtrIterator<'a, u8>,
           StartsWith: Fn(Iter, slice::Iter<'a, u8>) -> (bool, Iter)
 {
-    let nan = get_nan_string();
-    if let (true, iter) = starts_with(to_iter(bytes, format.digit_separator()), nan.iter()) {
-        Ok((F::NAN, iter.as_ptr()))
+    let nan = options.nan_string();
+    let digit_separator = options.number_format().digit_separator();
+    if let (true, iter) = starts_with(to_iter(bytes, digit_separator), nan.iter()) {
+        Ok(parse_quiet_nan(bytes, iter.as_ptr(), options))
     } else {
         // Not NaN, may be valid with a different radix.
         if cfg!(feature = "radix"){
-            F::default(bytes, radix, lossy, sign, format)
+            F::default(bytes, sign, options)
         } else {
             Err((ErrorCode::InvalidDigit, bytes.as_ptr()))
         }
@@ -126,16 +256,16 @@ fn parse_nan<'a, ToIter, StartsWith, Iter, F>(
 // Special values are allowed, the match is case-insensitive,
 // and no digit separators are allowed.
 perftools_inline!{
-fn parse_float_standard<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_standard<F: StringToFloat>(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
 {
     // Use predictive parsing to filter special cases. This leads to
     // dramatic performance gains.
     let starts_with = case_insensitive_starts_with_iter;
     match index!(bytes[0]) {
-        b'i' | b'I' => parse_infinity(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        b'N' | b'n' => parse_nan(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        _           => F::default(bytes, radix, lossy, sign, format),
+        b'i' | b'I' => parse_infinity(bytes, sign, options, to_iter, starts_with),
+        b'N' | b'n' => parse_nan(bytes, sign, options, to_iter, starts_with),
+        _           => F::default(bytes, sign, options),
     }
 }}
 
@@ -144,15 +274,15 @@ fn parse_float_standard<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool,
 // and digit separators are allowed.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float_cs<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_cs<F: StringToFloat>(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
 {
-    let digit_separator = format.digit_separator();
+    let digit_separator = options.number_format().digit_separator();
     let starts_with = starts_with_iter;
     match SkipValueIterator::new(bytes, digit_separator).next()  {
-        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        _                           => F::default(bytes, radix, lossy, sign, format),
+        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, sign, options, to_iter_s, starts_with),
+        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, sign, options, to_iter_s, starts_with),
+        _                           => F::default(bytes, sign, options),
     }
 }}
 
@@ -161,16 +291,16 @@ fn parse_float_cs<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign:
 // and no digit separators are allowed.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float_c<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_c<F: StringToFloat>(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
 {
     // Use predictive parsing to filter special cases. This leads to
     // dramatic performance gains.
     let starts_with = starts_with_iter;
     match index!(bytes[0]) {
-        b'i' | b'I' => parse_infinity(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        b'N' | b'n' => parse_nan(bytes, radix, lossy, sign, format, to_iter, starts_with),
-        _           => F::default(bytes, radix, lossy, sign, format),
+        b'i' | b'I' => parse_infinity(bytes, sign, options, to_iter, starts_with),
+        b'N' | b'n' => parse_nan(bytes, sign, options, to_iter, starts_with),
+        _           => F::default(bytes, sign, options),
     }
 }}
 
@@ -179,68 +309,84 @@ fn parse_float_c<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign:
 // and digit separators are allowed.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float_s<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float_s<F: StringToFloat>(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
 {
-    let digit_separator = format.digit_separator();
+    let digit_separator = options.number_format().digit_separator();
     let starts_with = case_insensitive_starts_with_iter;
     match SkipValueIterator::new(bytes, digit_separator).next()  {
-        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, radix, lossy, sign, format, to_iter_s, starts_with),
-        _                           => F::default(bytes, radix, lossy, sign, format),
+        Some(&b'i') | Some(&b'I')   => parse_infinity(bytes, sign, options, to_iter_s, starts_with),
+        Some(&b'n') | Some(&b'N')   => parse_nan(bytes, sign, options, to_iter_s, starts_with),
+        _                           => F::default(bytes, sign, options),
     }
 }}
 
 // Parse special or float values with the default formatter.
 perftools_inline!{
 #[cfg(not(feature = "format"))]
-fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float<F: StringToFloat>(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
 {
-    parse_float_standard(bytes, radix, lossy, sign, format)
+    if let Some(result) = parse_special_alias(bytes, options, false) {
+        Ok(result)
+    } else if let Some(result) = parse_signaling_nan(bytes, options, false) {
+        Ok(result)
+    } else {
+        parse_float_standard(bytes, sign, options)
+    }
 }}
 
 // Parse special or float values with the default formatter.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn parse_float<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, sign: Sign, format: NumberFormat)
+fn parse_float<F: StringToFloat>(bytes: &[u8], sign: Sign, options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
 {
     // Need to consider 3 possibilities:
     //  1). No special values are allowed.
     //  2). Special values are case-sensitive.
     //  3). Digit separators are allowed in the special.
+    let format = options.number_format();
     let no_special = format.no_special();
     let case = format.case_sensitive_special();
     let has_sep = format.special_digit_separator();
-    match (no_special, case, has_sep) {
-        (true, _, _)            => F::default(bytes, radix, lossy, sign, format),
-        (false, true, true)     => parse_float_cs(bytes, radix, lossy, sign, format),
-        (false, false, true)    => parse_float_s(bytes, radix, lossy, sign, format),
-        (false, true, false)    => parse_float_c(bytes, radix, lossy, sign, format),
-        (false, false, false)   => parse_float_standard(bytes, radix, lossy, sign, format),
+    if no_special {
+        return F::default(bytes, sign, options);
+    } else if let Some(result) = parse_special_alias(bytes, options, case) {
+        return Ok(result);
+    } else if let Some(result) = parse_signaling_nan(bytes, options, case) {
+        return Ok(result);
+    }
+    match (case, has_sep) {
+        (true, true)    => parse_float_cs(bytes, sign, options),
+        (false, true)   => parse_float_s(bytes, sign, options),
+        (true, false)   => parse_float_c(bytes, sign, options),
+        (false, false)  => parse_float_standard(bytes, sign, options),
     }
 }}
 
 // Validate sign byte is valid.
 perftools_inline!{
 #[cfg(not(feature = "format"))]
-fn validate_sign(_: &[u8], _: &[u8], _: Sign, _: NumberFormat)
+fn validate_sign(_: &[u8], _: &[u8], _: Sign, _: bool, _: NumberFormat)
     -> ParseResult<()>
 {
     Ok(())
 }}
 
 // Validate sign byte is valid.
+//
+// Special values do not require a mantissa sign, since the writer
+// only writes the sign before finite floats.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn validate_sign(bytes: &[u8], digits: &[u8], sign: Sign, format: NumberFormat)
+fn validate_sign(bytes: &[u8], digits: &[u8], sign: Sign, is_special: bool, format: NumberFormat)
     -> ParseResult<()>
 {
     let has_sign = bytes.as_ptr() != digits.as_ptr();
     if format.no_positive_mantissa_sign() && has_sign && sign == Sign::Positive {
         Err((ErrorCode::InvalidPositiveMantissaSign, bytes.as_ptr()))
-    } else if format.required_mantissa_sign() && !has_sign {
+    } else if format.required_mantissa_sign() && !has_sign && !is_special {
         Err((ErrorCode::MissingMantissaSign, bytes.as_ptr()))
     } else {
         Ok(())
@@ -257,41 +403,82 @@ fn to_signed<F: StringToFloat>(float: F, sign: Sign) -> F
     }
 }}
 
+// Parse a number surrounded by the whitespace and quotes allowed
+// by the format, where the callback parses the number itself.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn trimmed<'a, T, Cb>(bytes: &'a [u8], options: &ParseFloatOptions, cb: Cb)
+    -> Result<(T, usize)>
+    where Cb: FnOnce(&'a [u8]) -> Result<(T, usize)>
+{
+    parse_trimmed(bytes, options.number_format(), options.whitespace(), cb)
+}}
+
+perftools_inline!{
+#[cfg(not(feature = "format"))]
+fn trimmed<'a, T, Cb>(bytes: &'a [u8], _: &ParseFloatOptions, cb: Cb)
+    -> Result<(T, usize)>
+    where Cb: FnOnce(&'a [u8]) -> Result<(T, usize)>
+{
+    cb(bytes)
+}}
+
 // Standalone atof processor.
 perftools_inline!{
-fn atof<F: StringToFloat>(bytes: &[u8], radix: u32, lossy: bool, format: NumberFormat)
+fn atof<F: StringToFloat>(bytes: &[u8], options: &ParseFloatOptions)
     -> ParseResult<(F, *const u8)>
 {
+    let format = options.number_format();
     let (sign, digits) = parse_sign::<F>(bytes, format);
     if digits.is_empty() {
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
-    let (float, ptr): (F, *const u8) = parse_float(digits, radix, lossy, sign, format)?;
-    validate_sign(bytes, digits, sign, format)?;
+    #[cfg(feature = "format")]
+    let float_digits = skip_float_base_prefix(digits, options.radix_u32(), format);
+    #[cfg(not(feature = "format"))]
+    let float_digits = digits;
+
+    let (float, ptr): (F, *const u8) = parse_float(float_digits, sign, options)?;
+    validate_sign(bytes, digits, sign, float.is_special(), format)?;
+
+    #[cfg(feature = "format")]
+    let ptr = consume_type_suffix(bytes, ptr, format, true);
 
     Ok((to_signed(float, sign), ptr))
 }}
 
 perftools_inline!{
-fn atof_lossy<F: StringToFloat>(bytes: &[u8], radix: u32)
+fn atof_untrimmed<F: StringToFloat>(bytes: &[u8], options: &ParseFloatOptions)
     -> Result<(F, usize)>
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, true, NumberFormat::standard().unwrap()) {
+    match atof::<F>(bytes, options) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }}
 
+perftools_inline!{
+fn atof_options<F: StringToFloat>(bytes: &[u8], options: &ParseFloatOptions)
+    -> Result<(F, usize)>
+{
+    trimmed(bytes, options, |bytes| atof_untrimmed::<F>(bytes, options))
+}}
+
+perftools_inline!{
+fn atof_lossy<F: StringToFloat>(bytes: &[u8], radix: u32)
+    -> Result<(F, usize)>
+{
+    let format = NumberFormat::standard().unwrap();
+    atof_options::<F>(bytes, &ParseFloatOptions::from_config(true, radix, format))
+}}
+
 perftools_inline!{
 fn atof_nonlossy<F: StringToFloat>(bytes: &[u8], radix: u32)
     -> Result<(F, usize)>
 {
-    let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, false, NumberFormat::standard().unwrap()) {
-        Ok((value, ptr)) => Ok((value, index(ptr))),
-        Err((code, ptr)) => Err((code, index(ptr)).into()),
-    }
+    let format = NumberFormat::standard().unwrap();
+    atof_options::<F>(bytes, &ParseFloatOptions::from_config(false, radix, format))
 }}
 
 perftools_inline!{
@@ -299,24 +486,239 @@ perftools_inline!{
 fn atof_format<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat)
     -> Result<(F, usize)>
 {
-    let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, false, format) {
-        Ok((value, ptr)) => Ok((value, index(ptr))),
-        Err((code, ptr)) => Err((code, index(ptr)).into()),
-    }
+    atof_options::<F>(bytes, &ParseFloatOptions::from_config(false, radix, format))
 }}
 
 perftools_inline!{
 #[cfg(feature = "format")]
 fn atof_lossy_format<F: StringToFloat>(bytes: &[u8], radix: u32, format: NumberFormat)
     -> Result<(F, usize)>
+{
+    atof_options::<F>(bytes, &ParseFloatOptions::from_config(true, radix, format))
+}}
+
+// CONTEXT
+
+// Get the tokens the float parser expected at the index of an error.
+//
+// Empty mantissas are reported at the start of the mantissa. Otherwise,
+// the parser stopped at an invalid digit, and accepted the bytes before
+// it, so the components extracted from those bytes record the tokens
+// that could have continued the float. Special values, which do not
+// start with a digit or decimal point, can only be followed by the end
+// of the number.
+perftools_inline!{
+pub(crate) fn atof_expected(bytes: &[u8], code: ErrorCode, index: usize, options: &ParseFloatOptions)
+    -> Expected
+{
+    let format = options.number_format();
+    let (_, digits) = parse_sign::<f64>(bytes, format);
+    let mantissa = match digits.len() == bytes.len() {
+        true  => Expected::DIGIT | Expected::SIGN | Expected::SEPARATOR,
+        false => Expected::DIGIT | Expected::SEPARATOR,
+    };
+    #[cfg(feature = "format")]
+    let digits = skip_float_base_prefix(digits, options.radix_u32(), format);
+
+    let start = bytes.len() - digits.len();
+    let accepted = &index!(digits[..index.saturating_sub(start).min(digits.len())]);
+    match (code, accepted.first()) {
+        (ErrorCode::InvalidDigit, Some(&c)) => match c == b'.' || (c as char).to_digit(options.radix_u32()).is_some() {
+            true  => expected_components(accepted, options),
+            false => Expected::END,
+        },
+        _                                   => mantissa,
+    }
+}}
+
+// HALF
+
+// Find if the exact value of a float string is below, at, or above the
+// float it was parsed to.
+perftools_inline!{
+#[cfg(feature = "f16")]
+fn compare(bytes: &[u8], value: f64, options: &ParseFloatOptions)
+    -> cmp::Ordering
+{
+    let format = options.number_format();
+    let (_, digits) = parse_sign::<f64>(bytes, format);
+    #[cfg(feature = "format")]
+    let digits = skip_float_base_prefix(digits, options.radix_u32(), format);
+    super::algorithm::compare::compare(digits, value.abs(), options)
+}}
+
+// Parse a half-precision float with custom options.
+//
+// The float is parsed as a double, and then rounded to the format,
+// which is only incorrect if the double is exactly halfway between
+// two half-precision floats, but the exact value is not.
+perftools_inline!{
+#[cfg(feature = "f16")]
+fn atoh_options<H: Half>(bytes: &[u8], options: &ParseFloatOptions)
+    -> Result<(H, usize)>
+{
+    trimmed(bytes, options, |bytes| {
+        let (value, count) = atof_untrimmed::<f64>(bytes, options)?;
+        let kind = options.rounding_kind();
+        let ordering = match is_nearest(kind) && !options.lossy() && H::is_halfway(value) {
+            true  => compare(&index!(bytes[..count]), value, options),
+            false => cmp::Ordering::Equal,
+        };
+        Ok((H::from_f64_rounded(value, kind, ordering), count))
+    })
+}}
+
+perftools_inline!{
+#[cfg(feature = "f16")]
+fn atoh_lossy<H: Half>(bytes: &[u8], radix: u32)
+    -> Result<(H, usize)>
+{
+    let format = NumberFormat::standard().unwrap();
+    atoh_options::<H>(bytes, &ParseFloatOptions::from_config(true, radix, format))
+}}
+
+perftools_inline!{
+#[cfg(feature = "f16")]
+fn atoh_nonlossy<H: Half>(bytes: &[u8], radix: u32)
+    -> Result<(H, usize)>
+{
+    let format = NumberFormat::standard().unwrap();
+    atoh_options::<H>(bytes, &ParseFloatOptions::from_config(false, radix, format))
+}}
+
+perftools_inline!{
+#[cfg(all(feature = "f16", feature = "format"))]
+fn atoh_format<H: Half>(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> Result<(H, usize)>
+{
+    atoh_options::<H>(bytes, &ParseFloatOptions::from_config(false, radix, format))
+}}
+
+perftools_inline!{
+#[cfg(all(feature = "f16", feature = "format"))]
+fn atoh_lossy_format<H: Half>(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> Result<(H, usize)>
+{
+    atoh_options::<H>(bytes, &ParseFloatOptions::from_config(true, radix, format))
+}}
+
+// COMPONENTS
+
+// Extract the components of a number with custom options.
+//
+// The options must use a decimal radix.
+fn atocomponents_untrimmed<'a>(bytes: &'a [u8], options: &ParseFloatOptions)
+    -> Result<(NumberComponents<'a>, usize)>
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
-    match atof::<F>(bytes, radix, true, format) {
-        Ok((value, ptr)) => Ok((value, index(ptr))),
+    let format = options.number_format();
+    let (sign, digits) = parse_sign::<f64>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, index(digits.as_ptr())).into());
+    }
+    let is_negative = sign == Sign::Negative;
+    let result = match super::algorithm::components::to_components(digits, options) {
+        Ok((integer, fraction, exponent, ptr)) => {
+            let is_special = false;
+            Ok((NumberComponents { is_negative, integer, fraction, exponent, is_special }, ptr))
+        },
+        // Special values do not have any mantissa digits, while the
+        // exponent overflows for numbers that would parse as infinity.
+        Err(error) if error.0 != ErrorCode::Overflow => match parse_float::<f64>(digits, sign, options) {
+            Ok((value, ptr)) if !value.is_finite() => {
+                let is_special = true;
+                Ok((NumberComponents { is_negative, is_special, ..Default::default() }, ptr))
+            },
+            _ => Err(error),
+        },
+        Err(error) => Err(error),
+    };
+    match result.and_then(|x| validate_sign(bytes, digits, sign, x.0.is_special, format).map(|_| x)) {
+        Ok((components, ptr)) => Ok((components, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
-}}
+}
+
+// Extract the components of a number with custom options, and the
+// whitespace and quotes allowed by the format.
+fn atocomponents_options<'a>(bytes: &'a [u8], options: &ParseFloatOptions)
+    -> Result<(NumberComponents<'a>, usize)>
+{
+    trimmed(bytes, options, |bytes| atocomponents_untrimmed(bytes, options))
+}
+
+// Extract the components of a number, returning the components and
+// the number of processed bytes.
+pub(crate) fn atocomponents(bytes: &[u8])
+    -> Result<(NumberComponents, usize)>
+{
+    let format = NumberFormat::standard().unwrap();
+    atocomponents_options(bytes, &ParseFloatOptions::from_config(false, 10, format))
+}
+
+// Extract the components of a number with a custom numerical format,
+// returning the components and the number of processed bytes.
+#[cfg(feature = "format")]
+pub(crate) fn atocomponents_format(bytes: &[u8], format: NumberFormat)
+    -> Result<(NumberComponents, usize)>
+{
+    atocomponents_options(bytes, &ParseFloatOptions::from_config(false, 10, format))
+}
+
+// DECIMAL
+
+// Parse an exact decimal number with custom options.
+//
+// The options must use a decimal radix. Special values cannot be
+// represented, and are rejected as invalid digits.
+#[cfg(feature = "std")]
+fn atodecimal_untrimmed(bytes: &[u8], options: &ParseFloatOptions)
+    -> Result<(Decimal, usize)>
+{
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    let format = options.number_format();
+    let (sign, digits) = parse_sign::<f64>(bytes, format);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, index(digits.as_ptr())).into());
+    }
+    let result = super::algorithm::decimal::to_decimal(digits, options)
+        .and_then(|parts| validate_sign(bytes, digits, sign, false, format).map(|_| parts));
+    match result {
+        Ok((mantissa, exponent, ptr)) => {
+            let is_negative = sign == Sign::Negative;
+            Ok((Decimal::new(is_negative, &mantissa, exponent), index(ptr)))
+        },
+        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    }
+}
+
+// Parse an exact decimal number with custom options, and the
+// whitespace and quotes allowed by the format.
+#[cfg(feature = "std")]
+fn atodecimal_options(bytes: &[u8], options: &ParseFloatOptions)
+    -> Result<(Decimal, usize)>
+{
+    trimmed(bytes, options, |bytes| atodecimal_untrimmed(bytes, options))
+}
+
+// Parse an exact decimal number, returning the value and the number
+// of processed bytes.
+#[cfg(feature = "std")]
+pub(crate) fn atodecimal(bytes: &[u8])
+    -> Result<(Decimal, usize)>
+{
+    let format = NumberFormat::standard().unwrap();
+    atodecimal_options(bytes, &ParseFloatOptions::from_config(false, 10, format))
+}
+
+// Parse an exact decimal number with a custom numerical format,
+// returning the value and the number of processed bytes.
+#[cfg(all(feature = "std", feature = "format"))]
+pub(crate) fn atodecimal_format(bytes: &[u8], format: NumberFormat)
+    -> Result<(Decimal, usize)>
+{
+    atodecimal_options(bytes, &ParseFloatOptions::from_config(false, 10, format))
+}
 
 // FROM LEXICAL
 // ------------
@@ -325,6 +727,8 @@ from_lexical!(atof_nonlossy, f32);
 from_lexical!(atof_nonlossy, f64);
 from_lexical_lossy!(atof_lossy, f32);
 from_lexical_lossy!(atof_lossy, f64);
+from_lexical_options!(atof_options, ParseFloatOptions, f32);
+from_lexical_options!(atof_options, ParseFloatOptions, f64);
 
 cfg_if!{
 if #[cfg(feature = "format")] {
@@ -334,6 +738,46 @@ if #[cfg(feature = "format")] {
     from_lexical_lossy_format!(atof_lossy_format, f64);
 }}
 
+cfg_if!{
+if #[cfg(feature = "f16")] {
+    from_lexical!(atoh_nonlossy, f16);
+    from_lexical!(atoh_nonlossy, bf16);
+    from_lexical_lossy!(atoh_lossy, f16);
+    from_lexical_lossy!(atoh_lossy, bf16);
+    from_lexical_options!(atoh_options, ParseFloatOptions, f16);
+    from_lexical_options!(atoh_options, ParseFloatOptions, bf16);
+}}
+
+cfg_if!{
+if #[cfg(all(feature = "f16", feature = "format"))] {
+    from_lexical_format!(atoh_format, f16);
+    from_lexical_format!(atoh_format, bf16);
+    from_lexical_lossy_format!(atoh_lossy_format, f16);
+    from_lexical_lossy_format!(atoh_lossy_format, bf16);
+}}
+
+// EXTENDED
+
+// Parse an extended-precision float with custom options.
+//
+// The float string is validated by parsing it as a double, and the
+// value is then rounded from the exact digits.
+perftools_inline!{
+#[cfg(feature = "f128")]
+pub(crate) fn atoe_options<T: ExtendedPrecision>(bytes: &[u8], options: &ParseFloatOptions)
+    -> Result<(T, usize)>
+{
+    trimmed(bytes, options, |bytes| {
+        let (value, count) = atof_untrimmed::<f64>(bytes, options)?;
+        let format = options.number_format();
+        let (sign, digits) = parse_sign::<f64>(&index!(bytes[..count]), format);
+        #[cfg(feature = "format")]
+        let digits = skip_float_base_prefix(digits, options.radix_u32(), format);
+        let parts = parse_validated::<T>(sign == Sign::Negative, digits, value, options);
+        Ok((T::from_parts(parts), count))
+    })
+}}
+
 // TESTS
 // -----
 
@@ -668,6 +1112,9 @@ mod tests {
         assert!(f64::from_lexical_format(b"+3.0", format).is_ok());
         assert!(f64::from_lexical_format(b"-3.0", format).is_ok());
         assert!(f64::from_lexical_format(b"3.0", format).is_err());
+        assert!(f64::from_lexical_format(b"NaN", format).unwrap().is_nan());
+        assert_eq!(f64::from_lexical_format(b"inf", format), Ok(f64::INFINITY));
+        assert_eq!(f64::from_lexical_format(b"-inf", format), Ok(f64::NEG_INFINITY));
     }
 
     #[test]
@@ -893,6 +1340,68 @@ mod tests {
         assert!(f64::from_lexical_format(b"1.", format).is_err());
     }
 
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_type_suffix_test() {
+        let format = NumberFormat::RUST_LITERAL;
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"1.5f64", format));
+        assert_eq!(Ok(1.0), f64::from_lexical_format(b"1f32", format));
+        assert_eq!(Ok(1e5), f64::from_lexical_format(b"1e5_f64", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), f64::from_lexical_format(b"1u8", format));
+
+        let format = NumberFormat::C_LITERAL;
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"1.5f", format));
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"1.5L", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f64::from_lexical_format(b"1.5u", format));
+
+        let format = NumberFormat::JAVA_LITERAL;
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"1.5D", format));
+        assert_eq!(Ok((1.5, 4)), f64::from_lexical_partial_format(b"1.5fl", format));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_whitespace_test() {
+        let format = NumberFormat::WHITESPACE_FLAG_MASK;
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b" 1.5", format));
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"1.5\r\n", format));
+        assert_eq!(Ok(-1e5), f64::from_lexical_format(b"\t-1e5 ", format));
+        assert!(f64::from_lexical_format(b" NaN ", format).unwrap().is_nan());
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_format(b" inf", format));
+        assert_eq!(Ok((1.5, 4)), f64::from_lexical_partial_format(b"1.5 x", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), f64::from_lexical_format(b"  1.5x", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), f64::from_lexical_format(b"1.5 2", format));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), f64::from_lexical_format(b" ", format));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_format(b" 1.5", NumberFormat::STANDARD));
+
+        // Custom whitespace.
+        let options = ParseFloatOptions::builder()
+            .format(format)
+            .whitespace(b"\xA0")
+            .build()
+            .unwrap();
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"\xA01.5\xA0", &options));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), f64::from_lexical_with_options(b" 1.5", &options));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_surrounding_quotes_test() {
+        let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"\"1.5\"", format));
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b" \"1.5\" ", format));
+        assert_eq!(Ok(-0.5), f64::from_lexical_format(b"\"-.5\"", format));
+        assert_eq!(Ok((1.5, 5)), f64::from_lexical_partial_format(b"\"1.5\",", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), f64::from_lexical_format(b"\"1.5", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), f64::from_lexical_format(b"\"1.5x\"", format));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 1).into()), f64::from_lexical_format(b"\"\"", format));
+
+        // Quotes surround type suffixes.
+        let format = NumberFormat::C_LITERAL | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(Ok(1.5), f64::from_lexical_format(b"\"1.5f\"", format));
+        assert_eq!(Ok(1.5), f64::from_lexical_lossy_format(b"\"1.5f\"", format));
+    }
+
     #[test]
     #[cfg(feature = "format")]
     fn f64_json_no_leading_zero() {
@@ -903,6 +1412,423 @@ mod tests {
         assert!(f64::from_lexical_format(b"-012.0", format).is_err());
     }
 
+    #[test]
+    fn f64_options_test() {
+        let options = ParseFloatOptions::new();
+        assert_eq!(Ok(1.5e2), f64::from_lexical_with_options(b"1.5e2", &options));
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+
+        let options = ParseFloatOptions::builder()
+            .exponent_default_char(b'p')
+            .nan_string(b"nan(0)")
+            .inf_string(b"i")
+            .build()
+            .unwrap();
+        assert_eq!(Ok(1.5e2), f64::from_lexical_with_options(b"1.5p2", &options));
+        assert_eq!(Ok((1.5, 3)), f64::from_lexical_partial_with_options(b"1.5e2", &options));
+        assert!(f64::from_lexical_with_options(b"nan(0)", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"nan", &options).is_err());
+        assert_eq!(Ok(f64::NEG_INFINITY), f64::from_lexical_with_options(b"-i", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"infinity", &options));
+    }
+
+    #[test]
+    fn f64_special_alias_test() {
+        let options = ParseFloatOptions::builder()
+            .nan_aliases(&[b"1.#QNAN", b"1.#IND"])
+            .inf_aliases(&[b"1.#INF"])
+            .build()
+            .unwrap();
+        assert!(f64::from_lexical_with_options(b"1.#QNAN", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"1.#qnan", &options).unwrap().is_nan());
+        assert!(f64::from_lexical_with_options(b"-1.#IND", &options).unwrap().is_sign_negative());
+        assert!(f64::from_lexical_with_options(b"NaN", &options).unwrap().is_nan());
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1.#INF", &options));
+        assert_eq!(Ok(f64::NEG_INFINITY), f64::from_lexical_with_options(b"-1.#INF", &options));
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"Infinity", &options));
+        assert_eq!(Ok(1.5), f64::from_lexical_with_options(b"1.5", &options));
+        assert_eq!(Ok((1.0, 2)), f64::from_lexical_partial_with_options(b"1.#", &options));
+
+        assert!(ParseFloatOptions::builder().nan_aliases(&[b""]).build().is_none());
+        assert!(ParseFloatOptions::builder().inf_aliases(&[b""]).build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn f64_special_alias_format_test() {
+        let format = NumberFormat::standard().unwrap() | NumberFormat::CASE_SENSITIVE_SPECIAL;
+        let options = ParseFloatOptions::builder()
+            .format(format)
+            .inf_aliases(&[b"1.#INF"])
+            .build()
+            .unwrap();
+        assert_eq!(Ok(f64::INFINITY), f64::from_lexical_with_options(b"1.#INF", &options));
+        assert!(f64::from_lexical_with_options(b"1.#inf", &options).is_err());
+
+        let format = NumberFormat::standard().unwrap() | NumberFormat::NO_SPECIAL;
+        let options = ParseFloatOptions::builder()
+            .format(format)
+            .inf_aliases(&[b"1.#INF"])
+            .build()
+            .unwrap();
+        assert!(f64::from_lexical_with_options(b"1.#INF", &options).is_err());
+    }
+
+    #[test]
+    fn f64_nan_payload_test() {
+        let options = ParseFloatOptions::builder()
+            .nan_payload(true)
+            .build()
+            .unwrap();
+        let parse = | bytes | f64::from_lexical_with_options(bytes, &options).unwrap().to_bits();
+        assert_eq!(0x7FF8000000000000, parse(b"nan"));
+        assert_eq!(0x7FF80000000007FF, parse(b"nan(0x7ff)"));
+        assert_eq!(0x7FF80000000007FF, parse(b"NaN(0X7FF)"));
+        assert_eq!(0x7FF8000000000001, parse(b"nan(0x1)"));
+        assert_eq!(0x7FF80000000001FF, parse(b"nan(0777)"));
+        assert_eq!(0x7FF800000000007B, parse(b"nan(123)"));
+        assert_eq!(0x7FF8000000000000, parse(b"nan(0)"));
+        assert_eq!(0x7FF8000000000000, parse(b"nan()"));
+        assert_eq!(0x7FF8000000000000, parse(b"nan(abc_1)"));
+        assert_eq!(0xFFF80000000007FF, parse(b"-nan(0x7ff)"));
+        assert_eq!(0xFFF8000000000001, parse(b"-nan(0x1)"));
+        // Payloads are truncated to the bits below the quiet bit.
+        assert_eq!(0x7FF8000000000000, parse(b"nan(0x8000000000000)"));
+        assert_eq!(0x7FFFFFFFFFFFFFFF, parse(b"nan(0xFFFFFFFFFFFFFFFFFF)"));
+
+        // Signaling NaNs are spelled with an `s` before the NaN string.
+        assert_eq!(0x7FF00000000007FF, parse(b"snan(0x7ff)"));
+        assert_eq!(0x7FF0000000000001, parse(b"sNaN(0x1)"));
+        assert_eq!(0xFFF0000000000001, parse(b"-SNAN(1)"));
+        assert_eq!(0x7FF4000000000000, parse(b"snan"));
+        assert_eq!(0x7FF4000000000000, parse(b"snan(0)"));
+        assert_eq!(0x7FF7FFFFFFFFFFFF, parse(b"snan(0xFFFFFFFFFFFFFFFF)"));
+        assert!(f64::from_lexical_with_options(b"sna", &options).is_err());
+
+        // Unclosed or invalid payloads are not consumed.
+        let parse_partial = | bytes | f64::from_lexical_partial_with_options(bytes, &options).unwrap().1;
+        assert_eq!(3, parse_partial(b"nan(0x7ff"));
+        assert_eq!(3, parse_partial(b"nan(0x7ff )"));
+        assert_eq!(10, parse_partial(b"nan(0x7ff)1"));
+        assert_eq!(4, parse_partial(b"snan(0x7ff"));
+
+        // Payloads are not parsed by default.
+        assert_eq!(Ok((0x7FF8000000000000, 3)), f64::from_lexical_partial(b"nan(0x7ff)").map(|(x, n)| (x.to_bits(), n)));
+        assert!(f64::from_lexical(b"snan").is_err());
+    }
+
+    #[test]
+    fn f32_nan_payload_test() {
+        let options = ParseFloatOptions::builder()
+            .nan_payload(true)
+            .nan_aliases(&[b"1.#QNAN"])
+            .build()
+            .unwrap();
+        let parse = | bytes | f32::from_lexical_with_options(bytes, &options).unwrap().to_bits();
+        assert_eq!(0x7FC00000, parse(b"NaN"));
+        assert_eq!(0x7FC0007F, parse(b"nan(0x7f)"));
+        assert_eq!(0xFFC00001, parse(b"-1.#QNAN(1)"));
+        assert_eq!(0x7FFFFFFF, parse(b"nan(0xFFFFFFFF)"));
+        assert_eq!(0x7F80007F, parse(b"snan(0x7f)"));
+        assert_eq!(0x7FA00000, parse(b"snan"));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_exponent_base_test() {
+        let options = ParseFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        let parse = | bytes | f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(12.0), parse(b"1.8p3"));
+        assert_eq!(Ok(12.0), parse(b"1.8P3"));
+        assert_eq!(Ok(12.0), parse(b"C"));
+        assert_eq!(Ok(0.75), parse(b".Cp0"));
+        assert_eq!(Ok(-0.1), parse(b"-1.999999999999Ap-4"));
+        assert_eq!(Ok(1024.0), parse(b"1p10"));
+        assert_eq!(Ok(f64::MAX), parse(b"1.FFFFFFFFFFFFFp1023"));
+        assert_eq!(Ok(f64::MIN_POSITIVE), parse(b"1p-1022"));
+        assert_eq!(Ok(5e-324), parse(b"1p-1074"));
+        assert_eq!(Ok(0.0), parse(b"1p-1076"));
+        assert_eq!(Ok(f64::INFINITY), parse(b"1p1024"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), parse(b"0x1.8p3"));
+
+        // Halfway cases round to even.
+        assert_eq!(Ok(1.0), parse(b"1.00000000000008p0"));
+        assert_eq!(Ok(1.0000000000000004), parse(b"1.00000000000018p0"));
+
+        // Truncated digits above halfway round up.
+        assert_eq!(Ok(1.0000000000000002), parse(b"1.0000000000000801p0"));
+        assert_eq!(Ok(1.0000000000000002), parse(b"1.000000000000080000000001p0"));
+        assert_eq!(Ok(1.0), parse(b"1.000000000000080000000000p0"));
+        assert_eq!(Ok(1.0000000000000004), parse(b"1.000000000000180000000000p0"));
+        assert_eq!(Ok(f64::from_bits(0x0010000000000001)), parse(b"1.000000000000080000001p-1022"));
+        assert_eq!(Ok(f64::from_bits(0x0010000000000000)), parse(b"1.000000000000080000000p-1022"));
+        assert_eq!(Ok(f64::from_bits(0x0000000000000001)), parse(b"0.0000000000000800000001p-1022"));
+        assert_eq!(Ok(f64::from_bits(0x0000000000000000)), parse(b"0.0000000000000800000000p-1022"));
+        assert_eq!(Ok(f64::from_bits(0x0001000000000001)), parse(b"0.10000000000008000001p-1022"));
+        assert_eq!(Ok(f64::from_bits(0x0001000000000000)), parse(b"0.10000000000008000000p-1022"));
+
+        // The exponent is written in the exponent radix.
+        let options = ParseFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(16)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        assert_eq!(Ok(1024.0), f64::from_lexical_with_options(b"1pA", &options));
+
+        let options = ParseFloatOptions::builder()
+            .radix(2)
+            .exponent_base(16)
+            .exponent_radix(10)
+            .build()
+            .unwrap();
+        assert_eq!(Ok(256.0), f64::from_lexical_with_options(b"1e2", &options));
+        assert_eq!(Ok(3.0 / 256.0), f64::from_lexical_with_options(b"11e-2", &options));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f32_exponent_base_test() {
+        let options = ParseFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        let parse = | bytes | f32::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(0.1), parse(b"1.99999Ap-4"));
+        assert_eq!(Ok(f32::MAX), parse(b"1.FFFFFEp127"));
+        assert_eq!(Ok(1e-45), parse(b"1p-149"));
+        assert_eq!(Ok(1.0), parse(b"1.000001p0"));
+        assert_eq!(Ok(1.0000001), parse(b"1.0000010000000000001p0"));
+    }
+
+    #[cfg(all(feature = "radix", feature = "format"))]
+    #[test]
+    fn f64_hexadecimal_float_test() {
+        let options = ParseFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .format(NumberFormat::C_LITERAL)
+            .build()
+            .unwrap();
+        let parse = | bytes | f64::from_lexical_with_options(bytes, &options);
+        assert_eq!(Ok(12.0), parse(b"0x1.8p3"));
+        assert_eq!(Ok(12.0), parse(b"0X1.8P3"));
+        assert_eq!(Ok(-0.75), parse(b"-0x.Cp0"));
+        assert_eq!(Ok(12.0), parse(b"1.8p3"));
+        assert_eq!(Ok((0.0, 1)), f64::from_lexical_partial_with_options(b"0xp3", &options));
+    }
+
+    #[test]
+    #[cfg(all(feature = "correct", feature = "rounding"))]
+    fn f64_options_rounding_test() {
+        let options = ParseFloatOptions::builder()
+            .rounding(RoundingKind::TowardZero)
+            .build()
+            .unwrap();
+        assert_eq!(Ok(1.0), f64::from_lexical_with_options(b"1.0000000000000002", &options));
+        assert_eq!(Ok(1.0000000000000002), f64::from_lexical(b"1.0000000000000002"));
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn f16_decimal_test() {
+        let parse = | bytes | f16::from_lexical(bytes).unwrap().to_bits();
+        assert_eq!(0x0000, parse(b"0"));
+        assert_eq!(0x8000, parse(b"-0.0"));
+        assert_eq!(0x3C00, parse(b"1"));
+        assert_eq!(0x2E66, parse(b"0.1"));
+        assert_eq!(0x7BFF, parse(b"65504"));
+        assert_eq!(0x7BFF, parse(b"65519.99"));
+        assert_eq!(0x7C00, parse(b"65520"));
+        assert_eq!(0xFC00, parse(b"-1e10"));
+        assert_eq!(0x0001, parse(b"6e-8"));
+        assert_eq!(0x0000, parse(b"2.98023223876953125e-8"));
+        assert_eq!(0x0000, parse(b"1e-300"));
+        assert!(f16::from_lexical(b"NaN").unwrap().is_nan());
+        assert_eq!(0x7C00, parse(b"inf"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f16::from_lexical(b"1.5x"));
+        assert_eq!(Ok((f16::from_f32(1.5), 3)), f16::from_lexical_partial(b"1.5x"));
+
+        // Exactly halfway, and just below and above halfway between
+        // 1 and the next float, which all round to halfway as a double.
+        assert_eq!(0x3C00, parse(b"1.00048828125"));
+        assert_eq!(0x3C02, parse(b"1.00146484375"));
+        assert_eq!(0x3C00, parse(b"1.000488281249999999999999"));
+        assert_eq!(0x3C01, parse(b"1.000488281250000000000001"));
+        assert_eq!(0x0001, parse(b"2.980232238769531250000001e-8"));
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn bf16_decimal_test() {
+        let parse = | bytes | bf16::from_lexical(bytes).unwrap().to_bits();
+        assert_eq!(0x3F80, parse(b"1"));
+        assert_eq!(0x4049, parse(b"3.14"));
+        assert_eq!(0x7F7F, parse(b"3.3895314e38"));
+        assert_eq!(0x7F80, parse(b"3.4e38"));
+        assert_eq!(0x0001, parse(b"9.2e-41"));
+        assert_eq!(0x3F80, parse(b"1.00390625"));
+        assert_eq!(0x3F81, parse(b"1.003906250000000000000001"));
+    }
+
+    #[cfg(all(feature = "f16", feature = "rounding"))]
+    #[test]
+    fn f16_options_test() {
+        let options = ParseFloatOptions::builder()
+            .rounding(RoundingKind::TowardZero)
+            .build()
+            .unwrap();
+        let parse = | bytes | f16::from_lexical_with_options(bytes, &options).unwrap().to_bits();
+        assert_eq!(0x3C00, parse(b"1.0009"));
+        assert_eq!(0xBC00, parse(b"-1.0009"));
+        assert_eq!(0x7BFF, parse(b"1e10"));
+
+        let options = ParseFloatOptions::builder()
+            .rounding(RoundingKind::TowardPositiveInfinity)
+            .build()
+            .unwrap();
+        let parse = | bytes | f16::from_lexical_with_options(bytes, &options).unwrap().to_bits();
+        assert_eq!(0x3C01, parse(b"1.0000001"));
+        assert_eq!(0xBC00, parse(b"-1.0000001"));
+        assert_eq!(0x3C00, parse(b"1"));
+
+        let options = ParseFloatOptions::builder()
+            .rounding(RoundingKind::NearestTieAwayZero)
+            .build()
+            .unwrap();
+        let parse = | bytes | f16::from_lexical_with_options(bytes, &options).unwrap().to_bits();
+        assert_eq!(0x3C01, parse(b"1.00048828125"));
+        assert_eq!(0xBC01, parse(b"-1.00048828125"));
+        assert_eq!(0x3C00, parse(b"1.000488281249999999999999"));
+    }
+
+    #[cfg(all(feature = "f16", feature = "format"))]
+    #[test]
+    fn f16_surrounding_quotes_test() {
+        let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(Ok(f16::from_bits(0x3C00)), f16::from_lexical_format(b" \"1\" ", format));
+        // Halfway cases compare the digits inside the quotes.
+        assert_eq!(Ok(f16::from_bits(0x3C00)), f16::from_lexical_format(b"\"1.00048828125\"", format));
+        assert_eq!(Ok(f16::from_bits(0x3C01)), f16::from_lexical_format(b"\"1.000488281250001\"", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), f16::from_lexical_format(b" \"1", format));
+    }
+
+    #[test]
+    fn components_test() {
+        let parse = | bytes | super::atocomponents(bytes).map(|(x, n)| {
+            (x.is_negative, x.integer, x.fraction, x.exponent, x.is_special, n)
+        });
+        assert_eq!(Ok((false, &b"0"[..], &b""[..], 0, false, 1)), parse(b"0"));
+        assert_eq!(Ok((true, &b"001"[..], &b"2500"[..], 0, false, 9)), parse(b"-001.2500"));
+        assert_eq!(Ok((false, &b""[..], &b"5"[..], 12, false, 6)), parse(b"+.5e12"));
+        assert_eq!(Ok((false, &b"1"[..], &b""[..], -3, false, 5)), parse(b"1.E-3"));
+        assert_eq!(Ok((false, &b"1"[..], &b"5"[..], 0, false, 3)), parse(b"1.5x"));
+        assert_eq!(Ok((false, &b"1"[..], &b""[..], 99999999999, false, 13)), parse(b"1e99999999999"));
+        assert_eq!(Ok((false, &b"1"[..], &b""[..], i64::min_value(), false, 22)), parse(b"1e-9223372036854775808"));
+        assert_eq!(Ok((false, &b""[..], &b""[..], 0, true, 3)), parse(b"NaN"));
+        assert_eq!(Ok((true, &b""[..], &b""[..], 0, true, 9)), parse(b"-Infinity"));
+
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse(b""));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parse(b"+"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse(b"."));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parse(b"1e-"));
+        assert_eq!(Err((ErrorCode::Overflow, 2).into()), parse(b"1e99999999999999999999"));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn components_format_test() {
+        let parse = | bytes, format | super::atocomponents_format(bytes, format).map(|(x, n)| {
+            (x.is_negative, x.integer, x.fraction, x.exponent, x.is_special, n)
+        });
+        let format = NumberFormat::from_separator(b'_')
+            | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR
+            | NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR;
+        assert_eq!(Ok((false, &b"1_000"[..], &b"25"[..], 10, false, 12)), parse(b"1_000.25e1_0", format));
+        assert_eq!(Ok((false, &b"1"[..], &b"2"[..], 0, false, 3)), parse(b"1.2_5", format));
+
+        let format = NumberFormat::JSON;
+        assert_eq!(Ok((true, &b"0"[..], &b"5"[..], 2, false, 7)), parse(b"-0.5e+2", format));
+        assert!(parse(b"01", format).is_err());
+        assert!(parse(b".5", format).is_err());
+        assert!(parse(b"+1", format).is_err());
+        assert!(parse(b"NaN", format).is_err());
+        assert!(parse(b"-inf", format).is_err());
+
+        let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(Ok((false, &b"1"[..], &b"5"[..], 0, false, 7)), parse(b" \"1.5\" ", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parse(b"\"1.5", format));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn decimal_test() {
+        let parse = | bytes | super::atodecimal(bytes).map(|(x, n)| (x.to_string(), n));
+        assert_eq!(Ok(("0".to_string(), 1)), parse(b"0"));
+        assert_eq!(Ok(("-0".to_string(), 6)), parse(b"-0.000"));
+        assert_eq!(Ok(("1".to_string(), 1)), parse(b"1"));
+        assert_eq!(Ok(("123.45".to_string(), 9)), parse(b"+00123.45"));
+        assert_eq!(Ok(("0.0012".to_string(), 8)), parse(b"0.001200"));
+        assert_eq!(Ok(("0.0012".to_string(), 6)), parse(b"1.2e-3"));
+        assert_eq!(Ok(("12000".to_string(), 5)), parse(b"1.2E4"));
+        assert_eq!(Ok(("1.5".to_string(), 3)), parse(b"1.5x"));
+        assert_eq!(Ok(("0.1".to_string(), 2)), parse(b".1"));
+        assert_eq!(Ok(("1".to_string(), 2)), parse(b"1."));
+
+        // Digits beyond the precision of any float are kept.
+        let (decimal, _) = super::atodecimal(b"9007199254740993.0000000000000000000000000000001").unwrap();
+        assert_eq!(decimal.digits, b"90071992547409930000000000000000000000000000001".to_vec());
+        assert_eq!(decimal.exponent, -31);
+
+        // Exponents beyond the range of an i32 are kept exactly.
+        let (decimal, _) = super::atodecimal(b"1.5e3000000000").unwrap();
+        assert_eq!(decimal.exponent, 2999999999);
+        assert_eq!(Ok(("1.5e3000000000".to_string(), 14)), parse(b"1.5e3000000000"));
+        assert_eq!(Ok(("1e-9223372036854775808".to_string(), 22)), parse(b"1e-9223372036854775808"));
+        assert_eq!(Err((ErrorCode::Overflow, 2).into()), parse(b"1e9223372036854775808"));
+        assert_eq!(Err((ErrorCode::Overflow, 6).into()), parse(b"10.01e-9223372036854775807"));
+
+        assert_eq!(Err((ErrorCode::Empty, 0).into()), parse(b""));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), parse(b"-"));
+        assert_eq!(Err((ErrorCode::EmptyMantissa, 0).into()), parse(b"."));
+        assert_eq!(Err((ErrorCode::EmptyExponent, 2).into()), parse(b"1e"));
+        assert!(parse(b"NaN").is_err());
+        assert!(parse(b"-inf").is_err());
+    }
+
+    #[cfg(all(feature = "std", feature = "format"))]
+    #[test]
+    fn decimal_format_test() {
+        let parse = | bytes, format | super::atodecimal_format(bytes, format).map(|(x, n)| (x.to_string(), n));
+        let format = NumberFormat::from_separator(b'_')
+            | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR
+            | NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR;
+        assert_eq!(Ok(("1000000.25".to_string(), 13)), parse(b"1_000_000.2_5", format));
+        assert_eq!(Ok(("1".to_string(), 1)), parse(b"1_", format));
+
+        let format = NumberFormat::JSON;
+        assert_eq!(Ok(("-1200".to_string(), 6)), parse(b"-12e+2", format));
+        assert!(parse(b"012.0", format).is_err());
+        assert!(parse(b"1.", format).is_err());
+        assert!(parse(b"+1", format).is_err());
+
+        let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(Ok(("1.5".to_string(), 7)), parse(b" \"1.5\" ", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 4).into()), parse(b"\"1.5x\"", format));
+    }
+
     #[cfg(feature = "std")]
     proptest! {
         #[test]
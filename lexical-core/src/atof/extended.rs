@@ -0,0 +1,296 @@
+//! Correct string-to-float conversion for extended-precision floats.
+//!
+//! The syntax is validated by the double-precision parser with the
+//! same options, and the value is then rounded from the exact digits,
+//! using big-integer arithmetic scaled to the precision of the format.
+
+use crate::util::*;
+use super::algorithm::bignum::{binary_exp_bounds, ExtendedBigint};
+use super::algorithm::components::to_components;
+use super::algorithm::math::{LargeOps, Limb, SharedOps, SmallOps};
+
+/// Maximum number of significant digits kept in the mantissa.
+///
+/// The exact representation of any halfway point of the quadruple-precision
+/// format has at most ~11,560 significant digits in decimal, and ~13,300
+/// in any radix, so any digits past the limit only break ties.
+#[cfg(not(feature = "radix"))]
+const MAX_DIGITS: usize = 11600;
+
+/// Maximum number of significant digits kept in the mantissa.
+#[cfg(feature = "radix")]
+const MAX_DIGITS: usize = 13400;
+
+// Multiply the big integers by `radix^n`, the numerator if `n` is
+// positive, otherwise, the denominator.
+//
+// Only the odd factor of the power is multiplied, to keep the big
+// integers small, and the binary exponent of the power is returned.
+#[inline]
+fn imul_power(num: &mut ExtendedBigint, den: &mut ExtendedBigint, radix: u32, n: i64) -> i64 {
+    let shift = radix.trailing_zeros();
+    let odd = radix >> shift;
+    if odd != 1 {
+        match n < 0 {
+            true  => den.imul_power(odd, -n as u32),
+            false => num.imul_power(odd, n as u32),
+        }
+    }
+    n * shift as i64
+}
+
+// Round the digits to the nearest float, with ties to even.
+//
+// The value is `digits * radix^scale * exponent_base^exponent`, where
+// `digits` is the significant digits of the integer and fraction.
+// Any bytes that are not digits in the radix, such as digit separators,
+// are skipped.
+fn to_parts<T: ExtendedPrecision>(is_negative: bool, integer: &[u8], fraction: &[u8], exponent: i64, options: &ParseFloatOptions)
+    -> FloatParts
+{
+    let precision = T::MANTISSA_DIGITS as i64;
+    let radix = options.radix_u32();
+    #[cfg(not(feature = "radix"))]
+    let exponent_base = radix;
+    #[cfg(feature = "radix")]
+    let exponent_base = options.exponent_base_u32();
+
+    // Get the significant digits, in chunks that fit in 32 bits, marking
+    // any truncated, non-zero digits with an extra digit.
+    let mut mantissa = ExtendedBigint::default();
+    let mut count = 0;
+    let mut chunk = 0u32;
+    let mut chunk_power = 1u32;
+    let mut scale: i64 = 0;
+    let mut is_truncated = false;
+    for (index, &c) in integer.iter().chain(fraction.iter()).enumerate() {
+        let is_fraction = index >= integer.len();
+        let digit = match (c as char).to_digit(radix) {
+            Some(digit) => digit,
+            None        => continue,
+        };
+        if count == MAX_DIGITS {
+            scale += !is_fraction as i64;
+            is_truncated |= digit != 0;
+            continue;
+        }
+        scale -= is_fraction as i64;
+        if count == 0 && digit == 0 {
+            continue;
+        }
+        chunk = chunk * radix + digit;
+        chunk_power *= radix;
+        count += 1;
+        if chunk_power > u32::max_value() / radix {
+            mantissa.imul_small(chunk_power as Limb);
+            mantissa.iadd_small(chunk as Limb);
+            chunk = 0;
+            chunk_power = 1;
+        }
+    }
+    if count == 0 {
+        return round_extended::<T>(is_negative, 0, 0, false);
+    }
+    mantissa.imul_small(chunk_power as Limb);
+    mantissa.iadd_small(chunk as Limb);
+    if is_truncated {
+        mantissa.imul_small(radix as Limb);
+        mantissa.iadd_small(1);
+        scale -= 1;
+    }
+
+    // Fast checks for overflow and underflow, where the value is in
+    // `[2^(bits-1), 2^bits)` before scaling by the powers.
+    let (scale, exponent) = match radix == exponent_base {
+        true  => (scale.saturating_add(exponent), 0),
+        false => (scale, exponent),
+    };
+    let bits = mantissa.bit_length() as i64;
+    let (scale_min, scale_max) = binary_exp_bounds(radix, scale);
+    let (exponent_min, exponent_max) = binary_exp_bounds(exponent_base, exponent);
+    if scale_min.saturating_add(exponent_min).saturating_add(bits - 1) >= T::MAX_EXP as i64 {
+        return FloatParts::Infinite { is_negative };
+    } else if scale_max.saturating_add(exponent_max).saturating_add(bits) < T::MIN_EXP as i64 - precision {
+        return round_extended::<T>(is_negative, 0, 0, false);
+    }
+
+    // Scale the value to have at least 2 more bits than the format,
+    // so `value = (bits + fraction) * 2^exp`, where `fraction < 1`.
+    let mut num = mantissa;
+    let mut den = ExtendedBigint::from_u64(1);
+    let exp = imul_power(&mut num, &mut den, radix, scale)
+        + imul_power(&mut num, &mut den, exponent_base, exponent);
+    let shift = precision + 2 - num.bit_length() as i64 + den.bit_length() as i64;
+    match shift > 0 {
+        true  => num.ishl(shift as usize),
+        false => den.ishl(-shift as usize),
+    }
+    let mut rem = num.idiv_large(&den);
+    rem.normalize();
+    let (value, _) = num.hi128();
+    let value = value >> (128 - num.bit_length());
+
+    round_extended::<T>(is_negative, value, (exp - shift) as i32, !rem.is_zero())
+}
+
+/// Parse an extended-precision float from a validated float string.
+///
+/// The float string must not have a sign or a base prefix, and `value`
+/// is the double-precision float it was parsed to, which is only used
+/// for special values and exponents that overflow.
+pub(super) fn parse_validated<T: ExtendedPrecision>(is_negative: bool, bytes: &[u8], value: f64, options: &ParseFloatOptions)
+    -> FloatParts
+{
+    match to_components(bytes, options) {
+        Ok((integer, fraction, exponent, _)) => to_parts::<T>(is_negative, integer, fraction, exponent, options),
+        Err(_) if value.is_nan()             => FloatParts::Nan,
+        // The exponent overflowed, so the value underflows to zero.
+        Err(_) if value == 0.0               => round_extended::<T>(is_negative, 0, 0, false),
+        Err(_)                               => FloatParts::Infinite { is_negative },
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::iter;
+    use crate::{parse_partial_extended, parse_partial_extended_with_options};
+    use super::*;
+
+    fn parse<T: ExtendedPrecision>(bytes: &[u8]) -> T {
+        let (value, count) = parse_partial_extended::<T>(bytes).unwrap();
+        assert_eq!(count, bytes.len());
+        value
+    }
+
+    #[test]
+    #[cfg(feature = "correct")]
+    fn f64_test() {
+        let check = |bytes: &[u8]| {
+            let expected = f64::from_lexical(bytes).unwrap();
+            assert_eq!(parse::<f64>(bytes).to_bits(), expected.to_bits());
+        };
+        check(b"0");
+        check(b"-0.0");
+        check(b"1");
+        check(b"0.1");
+        check(b"000123.456000e-2");
+        check(b"1e308");
+        check(b"1.7976931348623157e308");
+        check(b"1.7976931348623158e308");
+        check(b"1e309");
+        check(b"5e-324");
+        check(b"2.4703282292062327e-324");
+        check(b"2.4703282292062328e-324");
+        check(b"1e-400");
+        check(b"9007199254740993");
+        check(b"9007199254740993.000000000000000000000000000000000000000000000000000000000000000000000000001");
+        check(b"2.2250738585072011e-308");
+        check(b"inf");
+        check(b"-Infinity");
+        check(b"1e99999999999999999999");
+        check(b"1e-99999999999999999999");
+        assert!(parse::<f64>(b"NaN").is_nan());
+    }
+
+    #[test]
+    fn f128_test() {
+        assert_eq!(parse::<f128>(b"1").to_bits(), 0x3FFF << 112);
+        assert_eq!(parse::<f128>(b"-2.5").to_bits(), (1 << 127) | (0x4000 << 112) | (1 << 110));
+        assert_eq!(parse::<f128>(b"0.1").to_bits(), 0x3FFB_9999_9999_9999_9999_9999_9999_999A);
+        assert_eq!(parse::<f128>(b"1.18973149535723176508575932662800702e4932").to_bits(), 0x7FFE_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF);
+        assert_eq!(parse::<f128>(b"1.2e4932").to_bits(), 0x7FFF << 112);
+        assert_eq!(parse::<f128>(b"6.47517511943802511092443895822764655e-4966").to_bits(), 1);
+        assert_eq!(parse::<f128>(b"3.3e-4966").to_bits(), 1);
+        assert_eq!(parse::<f128>(b"3.2e-4966").to_bits(), 0);
+        assert_eq!(parse::<f128>(b"3.36210314311209350626267781732175260e-4932").to_bits(), 1 << 112);
+        assert_eq!(parse::<f128>(b"10384593717069655257060992658440193").to_bits(), 0x4070 << 112);
+        assert_eq!(parse::<f128>(b"10384593717069655257060992658440193.000000000000000000001").to_bits(), (0x4070 << 112) | 1);
+        assert_eq!(parse::<f128>(b"10384593717069655257060992658440195").to_bits(), (0x4070 << 112) | 2);
+        assert!(parse::<f128>(b"nan").is_nan());
+        assert_eq!(parse::<f128>(b"-inf").to_bits(), 0xFFFF << 112);
+    }
+
+    #[test]
+    fn f80_test() {
+        assert_eq!(parse::<f80>(b"1").to_bits(), 0x3FFF << 64 | 1 << 63);
+        assert_eq!(parse::<f80>(b"0.1").to_bits(), 0x3FFB_CCCC_CCCC_CCCC_CCCD);
+        assert_eq!(parse::<f80>(b"1.18973149535723176502e4932").to_bits(), 0x7FFE_FFFF_FFFF_FFFF_FFFF);
+        assert_eq!(parse::<f80>(b"3.64519953188247460253e-4951").to_bits(), 1);
+        assert_eq!(parse::<f80>(b"18446744073709551617").to_bits(), 0x403F << 64 | 1 << 63);
+        assert_eq!(parse::<f80>(b"18446744073709551619").to_bits(), 0x403F << 64 | 1 << 63 | 2);
+    }
+
+    #[test]
+    fn error_test() {
+        assert_eq!(parse_partial_extended::<f128>(b"").err(), Some((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse_partial_extended::<f128>(b"1e").err(), Some((ErrorCode::EmptyExponent, 2).into()));
+        assert_eq!(parse_partial_extended::<f128>(b"1.5x").map(|(x, n)| (x.to_bits(), n)), Ok(((0x3FFF << 112) | (1 << 111), 3)));
+    }
+
+    #[test]
+    fn options_test() {
+        let parse = |bytes: &[u8], options: &ParseFloatOptions| {
+            parse_partial_extended_with_options::<f128>(bytes, options).map(|(x, n)| (x.to_bits(), n))
+        };
+        let options = ParseFloatOptions::builder()
+            .exponent_default_char(b'^')
+            .nan_string(b"nan")
+            .build()
+            .unwrap();
+        assert_eq!(parse(b"1^2", &options), Ok((0x4005_9000_0000_0000_0000_0000_0000_0000, 3)));
+        assert_eq!(parse(b"1e2", &options), Ok((0x3FFF << 112, 1)));
+        assert_eq!(parse(b"-nan", &options).map(|(x, _)| f128::from_bits(x).is_nan()), Ok(true));
+        assert_eq!(parse(b"1^", &options), Err((ErrorCode::EmptyExponent, 2).into()));
+
+        #[cfg(feature = "format")] {
+            let format = NumberFormat::from_separator(b'_') | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
+            let options = ParseFloatOptions::builder()
+                .format(format)
+                .build()
+                .unwrap();
+            assert_eq!(parse(b"1_000.5", &options), Ok((0x4008_F440_0000_0000_0000_0000_0000_0000, 7)));
+        }
+
+        #[cfg(feature = "radix")] {
+            let options = ParseFloatOptions::builder()
+                .radix(2)
+                .build()
+                .unwrap();
+            assert_eq!(parse(b"1.1e1", &options), Ok((0x4000_8000_0000_0000_0000_0000_0000_0000, 5)));
+            let options = ParseFloatOptions::builder()
+                .radix(3)
+                .build()
+                .unwrap();
+            assert_eq!(parse(b"0.1", &options), Ok((0x3FFD_5555_5555_5555_5555_5555_5555_5555, 3)));
+        }
+    }
+
+    #[test]
+    fn long_test() {
+        // Halfway between 1 and the next float, followed by many digits.
+        let mut bytes = b"1.00000000000000000000000000000000009629649721936179265279889712924636592690508241076940976199693977832794189453125".to_vec();
+        assert_eq!(parse::<f128>(&bytes).to_bits(), 0x3FFF << 112);
+        bytes.extend(iter::repeat(b'0').take(20000));
+        assert_eq!(parse::<f128>(&bytes).to_bits(), 0x3FFF << 112);
+        bytes.push(b'1');
+        assert_eq!(parse::<f128>(&bytes).to_bits(), 0x3FFF << 112 | 1);
+    }
+
+    #[cfg(all(feature = "std", feature = "correct"))]
+    proptest! {
+        #[test]
+        fn f64_proptest(i in r"[+-]?[0-9]{1,30}(\.[0-9]{0,30})?([eE][+-]?[0-9]{1,3})?") {
+            let expected = f64::from_lexical(i.as_bytes()).unwrap();
+            prop_assert_eq!(parse::<f64>(i.as_bytes()).to_bits(), expected.to_bits());
+        }
+
+        #[test]
+        fn f32_proptest(i in r"[+-]?[0-9]{1,30}(\.[0-9]{0,30})?([eE][+-]?[0-9]{1,2})?") {
+            let expected = f32::from_lexical(i.as_bytes()).unwrap();
+            prop_assert_eq!(parse::<f32>(i.as_bytes()).to_bits(), expected.to_bits());
+        }
+    }
+}
@@ -4,5 +4,16 @@
 mod algorithm;
 mod api;
 
+#[cfg(feature = "f128")]
+mod extended;
+
 // Re-exports
 pub use self::api::*;
+pub(crate) use self::algorithm::bignum::{integral_binary_factor, Bigint};
+pub(crate) use self::algorithm::math::{LargeOps, Limb, SharedOps, SmallOps};
+
+#[cfg(feature = "f128")]
+pub(crate) use self::algorithm::bignum::ExtendedBigint;
+
+#[cfg(feature = "f128")]
+pub(crate) use self::api::atoe_options;
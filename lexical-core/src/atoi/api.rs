@@ -1,5 +1,6 @@
 //! Fast lexical string-to-integer conversion routines.
 
+use crate::lib::num;
 use crate::util::*;
 use super::generic::*;
 
@@ -90,17 +91,121 @@ pub(crate) fn atoi<'a, T>(bytes: &'a [u8], radix: u32)
 
 perftools_inline!{
 #[cfg(feature = "format")]
-pub(crate) fn atoi_format<'a, T>(bytes: &'a [u8], radix: u32, format: NumberFormat)
+fn atoi_format_untrimmed<'a, T>(bytes: &'a [u8], radix: u32, format: NumberFormat)
     -> Result<(T, usize)>
     where T: Atoi
 {
     let index = | ptr | distance(bytes.as_ptr(), ptr);
     match T::atoi_format(bytes, radix, format) {
+        Ok((value, ptr)) => Ok((value, index(consume_type_suffix(bytes, ptr, format, false)))),
+        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    }
+}}
+
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(crate) fn atoi_format<'a, T>(bytes: &'a [u8], radix: u32, format: NumberFormat)
+    -> Result<(T, usize)>
+    where T: Atoi
+{
+    parse_trimmed(bytes, format, ASCII_WHITESPACE, |bytes| atoi_format_untrimmed(bytes, radix, format))
+}}
+
+perftools_inline!{
+fn atoi_overflow<'a, T>(bytes: &'a [u8], options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: Atoi
+{
+    let radix = options.radix_u32();
+    let mode = options.overflow_mode();
+
+    #[cfg(not(feature = "format"))]
+    let result = standalone_overflow_no_separator::<T>(bytes, radix, mode);
+
+    #[cfg(feature = "format")]
+    let result = standalone_overflow_separator::<T>(bytes, radix, options.number_format(), mode)
+        .map(|(value, ptr)| (value, consume_type_suffix(bytes, ptr, options.number_format(), false)));
+
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    match result {
+        Ok((value, ptr)) => Ok((value, index(ptr))),
+        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    }
+}}
+
+perftools_inline!{
+#[cfg(feature = "radix")]
+fn atoi_alphabet<'a, T>(bytes: &'a [u8], alphabet: &DigitAlphabet, options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: Atoi
+{
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    match standalone_alphabet::<T>(bytes, alphabet, options.overflow_mode()) {
         Ok((value, ptr)) => Ok((value, index(ptr))),
         Err((code, ptr)) => Err((code, index(ptr)).into()),
     }
 }}
 
+perftools_inline!{
+fn atoi_options_untrimmed<'a, T>(bytes: &'a [u8], options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: Atoi
+{
+    #[cfg(feature = "radix")] {
+        if let Some(alphabet) = options.alphabet() {
+            return atoi_alphabet(bytes, alphabet, options);
+        }
+    }
+
+    if options.overflow_mode() != OverflowMode::Checked {
+        return atoi_overflow(bytes, options);
+    }
+
+    #[cfg(not(feature = "format"))] {
+        atoi(bytes, options.radix_u32())
+    }
+
+    #[cfg(feature = "format")] {
+        atoi_format_untrimmed(bytes, options.radix_u32(), options.number_format())
+    }
+}}
+
+perftools_inline!{
+pub(crate) fn atoi_options<'a, T>(bytes: &'a [u8], options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: Atoi
+{
+    #[cfg(not(feature = "format"))] {
+        atoi_options_untrimmed(bytes, options)
+    }
+
+    #[cfg(feature = "format")] {
+        let format = options.number_format();
+        parse_trimmed(bytes, format, options.whitespace(), |bytes| atoi_options_untrimmed(bytes, options))
+    }
+}}
+
+// CONTEXT
+// -------
+
+// Get the tokens the integer parser expected at the index of an error.
+//
+// The parser stops after the sign and any digits, so a digit is expected
+// after the sign, and a digit or the end of the number after any digits.
+perftools_inline!{
+pub(crate) fn atoi_expected<T>(bytes: &[u8], index: usize)
+    -> Expected
+    where T: Number
+{
+    let format = NumberFormat::standard().unwrap();
+    let start = bytes.len() - parse_sign::<T>(bytes, format).1.len();
+    match index {
+        0                   => Expected::DIGIT | Expected::SIGN,
+        i if i <= start     => Expected::DIGIT,
+        _                   => Expected::DIGIT | Expected::END,
+    }
+}}
+
 // FROM LEXICAL
 // ------------
 
@@ -135,6 +240,119 @@ if #[cfg(feature = "format")] {
     from_lexical_format!(atoi_format, i128);
 }}
 
+from_lexical_options!(atoi_options, ParseIntegerOptions, u8);
+from_lexical_options!(atoi_options, ParseIntegerOptions, u16);
+from_lexical_options!(atoi_options, ParseIntegerOptions, u32);
+from_lexical_options!(atoi_options, ParseIntegerOptions, u64);
+from_lexical_options!(atoi_options, ParseIntegerOptions, usize);
+from_lexical_options!(atoi_options, ParseIntegerOptions, u128);
+
+from_lexical_options!(atoi_options, ParseIntegerOptions, i8);
+from_lexical_options!(atoi_options, ParseIntegerOptions, i16);
+from_lexical_options!(atoi_options, ParseIntegerOptions, i32);
+from_lexical_options!(atoi_options, ParseIntegerOptions, i64);
+from_lexical_options!(atoi_options, ParseIntegerOptions, isize);
+from_lexical_options!(atoi_options, ParseIntegerOptions, i128);
+
+// NON-ZERO
+// --------
+
+pub(crate) trait AtoiNonZero: Sized {
+    // Integer type with the same layout.
+    type Value: Atoi + FromLexicalOptions<Options = ParseIntegerOptions>;
+
+    // Create non-zero integer, if the value is not zero.
+    fn new(value: Self::Value) -> Option<Self>;
+}
+
+// Implement atoi for non-zero type.
+macro_rules! atoi_nonzero_impl {
+    ($($t:ident $v:ty ; )*) => ($(
+        impl AtoiNonZero for num::$t {
+            type Value = $v;
+
+            perftools_inline_always!{
+            fn new(value: $v) -> Option<Self> {
+                num::$t::new(value)
+            }}
+        }
+    )*);
+}
+
+atoi_nonzero_impl! {
+    NonZeroU8 u8 ;
+    NonZeroU16 u16 ;
+    NonZeroU32 u32 ;
+    NonZeroU64 u64 ;
+    NonZeroU128 u128 ;
+    NonZeroUsize usize ;
+    NonZeroI8 i8 ;
+    NonZeroI16 i16 ;
+    NonZeroI32 i32 ;
+    NonZeroI64 i64 ;
+    NonZeroI128 i128 ;
+    NonZeroIsize isize ;
+}
+
+// Convert the parsed integer to a non-zero integer.
+// Zero values are rejected at the start of the number.
+perftools_inline!{
+fn to_nonzero<T>(result: Result<(T::Value, usize)>, start: usize)
+    -> Result<(T, usize)>
+    where T: AtoiNonZero
+{
+    let (value, count) = result?;
+    match T::new(value) {
+        Some(value) => Ok((value, count)),
+        None        => Err((ErrorCode::InvalidZero, start).into()),
+    }
+}}
+
+perftools_inline!{
+pub(crate) fn atoi_nonzero<'a, T>(bytes: &'a [u8], radix: u32)
+    -> Result<(T, usize)>
+    where T: AtoiNonZero
+{
+    to_nonzero(atoi::<T::Value>(bytes, radix), 0)
+}}
+
+perftools_inline!{
+pub(crate) fn atoi_nonzero_options<'a, T>(bytes: &'a [u8], options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: AtoiNonZero
+{
+    let start = T::Value::number_start(bytes, options);
+    to_nonzero(atoi_options::<T::Value>(bytes, options), start)
+}}
+
+from_lexical_value!(atoi_nonzero, num::NonZeroU8);
+from_lexical_value!(atoi_nonzero, num::NonZeroU16);
+from_lexical_value!(atoi_nonzero, num::NonZeroU32);
+from_lexical_value!(atoi_nonzero, num::NonZeroU64);
+from_lexical_value!(atoi_nonzero, num::NonZeroUsize);
+from_lexical_value!(atoi_nonzero, num::NonZeroU128);
+
+from_lexical_value!(atoi_nonzero, num::NonZeroI8);
+from_lexical_value!(atoi_nonzero, num::NonZeroI16);
+from_lexical_value!(atoi_nonzero, num::NonZeroI32);
+from_lexical_value!(atoi_nonzero, num::NonZeroI64);
+from_lexical_value!(atoi_nonzero, num::NonZeroIsize);
+from_lexical_value!(atoi_nonzero, num::NonZeroI128);
+
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroU8);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroU16);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroU32);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroU64);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroUsize);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroU128);
+
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroI8);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroI16);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroI32);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroI64);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroIsize);
+from_lexical_value_options!(atoi_nonzero_options, ParseIntegerOptions, num::NonZeroI128);
+
 // TESTS
 // -----
 
@@ -289,6 +507,21 @@ mod tests {
         assert_eq!(Err((ErrorCode::Overflow, 19).into()), i64::from_lexical(b"406260572150672006000066000000060060007667760000000000000000000+00000006766767766666767665670000000000000000000000666"));
     }
 
+    #[test]
+    fn u64_8digits_test() {
+        assert_eq!(Ok(1234567812345678), u64::from_lexical(b"1234567812345678"));
+        assert_eq!(Ok(9999999999999999), u64::from_lexical(b"9999999999999999"));
+        assert_eq!(Ok(-99999999), i32::from_lexical(b"-99999999"));
+        assert_eq!(Err((ErrorCode::Underflow, 10).into()), i32::from_lexical(b"-9999999999"));
+        for index in 0..16 {
+            for &c in [b'/', b':', b'a', b'\xB0', b'\xFA'].iter() {
+                let mut bytes = *b"1234567812345678";
+                bytes[index] = c;
+                assert_eq!(Err((ErrorCode::InvalidDigit, index).into()), u64::from_lexical(&bytes));
+            }
+        }
+    }
+
     #[test]
     fn u128_decimal_test() {
         assert_eq!(Ok(0), u128::from_lexical(b"0"));
@@ -369,6 +602,241 @@ mod tests {
         assert!(i32::from_lexical_format(b"-012", format).is_err());
     }
 
+    #[test]
+    fn overflow_mode_test() {
+        let wrapping = ParseIntegerOptions::builder().overflow_mode(OverflowMode::Wrapping).build().unwrap();
+        let saturating = ParseIntegerOptions::builder().overflow_mode(OverflowMode::Saturating).build().unwrap();
+
+        assert_eq!(Ok(44), u8::from_lexical_with_options(b"300", &wrapping));
+        assert_eq!(Ok(255), u8::from_lexical_with_options(b"300", &saturating));
+        assert_eq!(Ok(-128), i8::from_lexical_with_options(b"128", &wrapping));
+        assert_eq!(Ok(127), i8::from_lexical_with_options(b"128", &saturating));
+        assert_eq!(Ok(127), i8::from_lexical_with_options(b"-129", &wrapping));
+        assert_eq!(Ok(-128), i8::from_lexical_with_options(b"-99999", &saturating));
+        assert_eq!(Ok(i64::max_value()), i64::from_lexical_with_options(b"99999999999999999999999", &saturating));
+        assert_eq!(Ok(0), u128::from_lexical_with_options(b"340282366920938463463374607431768211456", &wrapping));
+        assert_eq!(Ok(u128::max_value()), u128::from_lexical_with_options(b"340282366920938463463374607431768211456", &saturating));
+        assert_eq!(Ok(12), i32::from_lexical_with_options(b"12", &saturating));
+
+        // Every digit is consumed.
+        assert_eq!(Ok((255, 5)), u8::from_lexical_partial_with_options(b"99999a", &saturating));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), u8::from_lexical_with_options(b"99999a", &wrapping));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i8::from_lexical_with_options(b"-", &saturating));
+    }
+
+    #[test]
+    #[cfg(feature = "radix")]
+    fn alphabet_test() {
+        let base58 = ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE58)).build().unwrap();
+        assert_eq!(Ok(0), u32::from_lexical_with_options(b"1", &base58));
+        assert_eq!(Ok(57), u32::from_lexical_with_options(b"z", &base58));
+        assert_eq!(Ok(58), u32::from_lexical_with_options(b"21", &base58));
+        assert_eq!(Ok(-58), i32::from_lexical_with_options(b"-21", &base58));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), u32::from_lexical_with_options(b"20", &base58));
+        assert_eq!(Err((ErrorCode::Overflow, 1).into()), u8::from_lexical_with_options(b"zzz", &base58));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical_with_options(b"-", &base58));
+        assert_eq!(Ok((58, 2)), u32::from_lexical_partial_with_options(b"21.", &base58));
+
+        let base62 = ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE62)).build().unwrap();
+        assert_eq!(Ok(u64::max_value()), u64::from_lexical_with_options(b"LygHa16AHYF", &base62));
+        assert_eq!(Ok(10), u64::from_lexical_with_options(b"A", &base62));
+        assert_eq!(Ok(36), u64::from_lexical_with_options(b"a", &base62));
+
+        // Signs are only parsed if they are not digits.
+        let base64 = ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE64)).build().unwrap();
+        assert_eq!(Ok(62 * 64 + 63), i32::from_lexical_with_options(b"+/", &base64));
+        assert_eq!(Ok(-1), i32::from_lexical_with_options(b"-B", &base64));
+        let base64 = ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE64_URL)).build().unwrap();
+        assert_eq!(Ok(62 * 64 + 63), i32::from_lexical_with_options(b"-_", &base64));
+        assert_eq!(Ok(1), i32::from_lexical_with_options(b"+B", &base64));
+
+        // Case-insensitive alphabets.
+        let base32 = ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE32)).build().unwrap();
+        assert_eq!(Ok(32 + 26), u32::from_lexical_with_options(b"b2", &base32));
+        assert_eq!(Ok(32 + 26), u32::from_lexical_with_options(b"B2", &base32));
+
+        // Overflow modes and non-zero integers.
+        let wrapping = ParseIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE58))
+            .overflow_mode(OverflowMode::Wrapping)
+            .build()
+            .unwrap();
+        assert_eq!(Ok((57 * 58 + 57) as u8), u8::from_lexical_with_options(b"zz", &wrapping));
+        let saturating = ParseIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE58))
+            .overflow_mode(OverflowMode::Saturating)
+            .build()
+            .unwrap();
+        assert_eq!(Ok(-128), i8::from_lexical_with_options(b"-zz", &saturating));
+        assert_eq!(Err((ErrorCode::InvalidZero, 0).into()), crate::lib::num::NonZeroU32::from_lexical_with_options(b"11", &base58));
+
+        assert!(ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE58)).radix(10).build().is_none());
+        assert!(ParseIntegerOptions::builder().radix(58).build().is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn overflow_mode_format_test() {
+        let format = NumberFormat::ignore(b'_').unwrap();
+        let options = ParseIntegerOptions::builder()
+            .format(format)
+            .overflow_mode(OverflowMode::Saturating)
+            .build()
+            .unwrap();
+        assert_eq!(Ok((65535, 10)), u16::from_lexical_partial_with_options(b"1_000_000_", &options));
+
+        let options = ParseIntegerOptions::builder()
+            .format(NumberFormat::JSON)
+            .overflow_mode(OverflowMode::Wrapping)
+            .build()
+            .unwrap();
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), u8::from_lexical_with_options(b"0300", &options));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn base_prefix_test() {
+        let format = NumberFormat::RUST_LITERAL;
+        assert_eq!(Ok(255), u8::from_lexical_format(b"0xFF", format));
+        assert_eq!(Ok(255), u8::from_lexical_format(b"0xff", format));
+        assert_eq!(Ok(15), i32::from_lexical_format(b"0o17", format));
+        assert_eq!(Ok(10), i32::from_lexical_format(b"0b1010", format));
+        assert_eq!(Ok(10), i32::from_lexical_format(b"0b10_10", format));
+        assert_eq!(Ok(-128), i8::from_lexical_format(b"-0x80", format));
+        assert_eq!(Ok(0), i8::from_lexical_format(b"0x00", format));
+        assert_eq!(Ok(u128::max_value()), u128::from_lexical_format(b"0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", format));
+        assert_eq!(Ok(i128::min_value()), i128::from_lexical_format(b"-0x80000000000000000000000000000000", format));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), u8::from_lexical_format(b"0x100", format));
+        assert_eq!(Err((ErrorCode::Underflow, 4).into()), i8::from_lexical_format(b"-0x81", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_format(b"0XFF", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_format(b"0x", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), i32::from_lexical_format(b"0b12", format));
+        assert_eq!(Ok((0, 1)), i32::from_lexical_partial_format(b"0xg", format));
+
+        let format = NumberFormat::PYTHON3_LITERAL;
+        assert_eq!(Ok(255), i32::from_lexical_format(b"0XFF", format));
+        assert_eq!(Ok(8), i32::from_lexical_format(b"0O10", format));
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), i32::from_lexical_format(b"010", format));
+
+        // Base prefixes are not parsed by default.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_format(b"0xFF", NumberFormat::STANDARD));
+
+        let options = ParseIntegerOptions::builder()
+            .format(NumberFormat::JAVA_LITERAL)
+            .overflow_mode(OverflowMode::Wrapping)
+            .build()
+            .unwrap();
+        assert_eq!(Ok(-1), i32::from_lexical_with_options(b"0xFFFFFFFFL", &options));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn type_suffix_test() {
+        let format = NumberFormat::RUST_LITERAL;
+        assert_eq!(Ok(100), u8::from_lexical_format(b"100u8", format));
+        assert_eq!(Ok(100), i64::from_lexical_format(b"100_i64", format));
+        assert_eq!(Ok(255), u32::from_lexical_format(b"0xFFusize", format));
+        assert_eq!(Ok((100, 5)), u8::from_lexical_partial_format(b"100u8x", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), u8::from_lexical_format(b"100f32", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 5).into()), u8::from_lexical_format(b"100u88", format));
+
+        let format = NumberFormat::C_LITERAL;
+        assert_eq!(Ok(10), u64::from_lexical_format(b"10ULL", format));
+        assert_eq!(Ok(16), u64::from_lexical_format(b"0x10llu", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), u64::from_lexical_format(b"10lL", format));
+
+        let format = NumberFormat::JAVA_LITERAL;
+        assert_eq!(Ok(10), i64::from_lexical_format(b"10L", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), i64::from_lexical_format(b"10u", format));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn whitespace_test() {
+        let format = NumberFormat::WHITESPACE_FLAG_MASK;
+        assert_eq!(Ok(42), i32::from_lexical_format(b" 42", format));
+        assert_eq!(Ok(42), i32::from_lexical_format(b"42\n", format));
+        assert_eq!(Ok(-42), i32::from_lexical_format(b"\t-42\r\n", format));
+        assert_eq!(Ok((42, 4)), i32::from_lexical_partial_format(b"42  x", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), i32::from_lexical_format(b"  4x", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), i32::from_lexical_format(b"4 2", format));
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), i32::from_lexical_format(b"  ", format));
+        assert_eq!(Err((ErrorCode::Empty, 2).into()), i32::from_lexical_format(b" -", format));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), u8::from_lexical_format(b"  256", format));
+
+        // Whitespace is rejected by default.
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), i32::from_lexical_format(b" 42", NumberFormat::STANDARD));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), i32::from_lexical_format(b"42\n", NumberFormat::STANDARD));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), i32::from_lexical_format(b" 42", NumberFormat::TRAILING_WHITESPACE));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), i32::from_lexical_format(b"42 ", NumberFormat::LEADING_WHITESPACE));
+
+        // Whitespace surrounds base prefixes and type suffixes.
+        let format = NumberFormat::RUST_LITERAL | NumberFormat::WHITESPACE_FLAG_MASK;
+        assert_eq!(Ok(255), u32::from_lexical_format(b" 0xFFu32 ", format));
+
+        // Custom whitespace and overflow modes.
+        let options = ParseIntegerOptions::builder()
+            .format(format)
+            .whitespace(b"\t")
+            .overflow_mode(OverflowMode::Saturating)
+            .build()
+            .unwrap();
+        assert_eq!(Ok(255), u8::from_lexical_with_options(b"\t300\t", &options));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), u8::from_lexical_with_options(b" 3", &options));
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn surrounding_quotes_test() {
+        let format = NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(Ok(42), i32::from_lexical_format(b"\"42\"", format));
+        assert_eq!(Ok(42), i32::from_lexical_format(b"42", format));
+        assert_eq!(Ok(-42), i32::from_lexical_format(b"\"-42\"", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 3).into()), i32::from_lexical_format(b"\"42", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), i32::from_lexical_format(b"42\"", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 2).into()), i32::from_lexical_format(b"\"4x\"", format));
+        assert_eq!(Err((ErrorCode::Empty, 1).into()), i32::from_lexical_format(b"\"\"", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_format(b"\"x\"", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 0).into()), i32::from_lexical_format(b"\"42\"", NumberFormat::STANDARD));
+
+        // Quotes are inside the whitespace.
+        let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(Ok(42), i32::from_lexical_format(b" \"42\"\n", format));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), i32::from_lexical_format(b"\" 42\"", format));
+
+        let options = ParseIntegerOptions::builder().format(format).build().unwrap();
+        assert_eq!(Ok((42, 6)), u64::from_lexical_partial_with_options(b" \"42\" ,", &options));
+        assert_eq!(Err((ErrorCode::InvalidZero, 1).into()), crate::lib::num::NonZeroU32::from_lexical_with_options(b"\"0\"", &options));
+        assert_eq!(Err((ErrorCode::InvalidZero, 2).into()), crate::lib::num::NonZeroU32::from_lexical_with_options(b" \"0\" ", &options));
+    }
+
+    #[test]
+    fn nonzero_test() {
+        use crate::lib::num::{NonZeroI32, NonZeroU16, NonZeroU128};
+
+        assert_eq!(NonZeroU16::new(8080), NonZeroU16::from_lexical(b"8080").ok());
+        assert_eq!(Err((ErrorCode::InvalidZero, 0).into()), NonZeroU16::from_lexical(b"0"));
+        assert_eq!(Err((ErrorCode::InvalidZero, 0).into()), NonZeroU16::from_lexical(b"000"));
+        assert_eq!(Err((ErrorCode::Overflow, 4).into()), NonZeroU16::from_lexical(b"65536"));
+        assert_eq!(Err((ErrorCode::InvalidDigit, 1).into()), NonZeroU16::from_lexical(b"1a"));
+        assert_eq!(Ok((NonZeroU16::new(1).unwrap(), 1)), NonZeroU16::from_lexical_partial(b"1a"));
+        assert_eq!(NonZeroI32::new(-5), NonZeroI32::from_lexical(b"-5").ok());
+        assert_eq!(Err((ErrorCode::InvalidZero, 0).into()), NonZeroI32::from_lexical(b"-0"));
+        assert_eq!(NonZeroU128::new(u128::max_value()), NonZeroU128::from_lexical(b"340282366920938463463374607431768211455").ok());
+    }
+
+    #[test]
+    #[cfg(feature = "format")]
+    fn nonzero_format_test() {
+        use crate::lib::num::NonZeroU32;
+
+        let options = ParseIntegerOptions::builder().format(NumberFormat::JSON).build().unwrap();
+        assert_eq!(NonZeroU32::new(12), NonZeroU32::from_lexical_with_options(b"12", &options).ok());
+        assert_eq!(Err((ErrorCode::InvalidLeadingZeros, 0).into()), NonZeroU32::from_lexical_with_options(b"012", &options));
+        assert_eq!(Err((ErrorCode::InvalidZero, 0).into()), NonZeroU32::from_lexical_with_options(b"0", &options));
+        assert_eq!(Ok((NonZeroU32::new(12).unwrap(), 2)), NonZeroU32::from_lexical_partial_with_options(b"12,", &options));
+    }
+
     #[cfg(feature = "std")]
     proptest! {
         #[test]
@@ -747,6 +1215,11 @@ mod tests {
             prop_assert_eq!(code, ErrorCode::Empty);
         }
 
+        #[test]
+        fn u64_8digits_proptest(i in r"[0-9]{1,20}") {
+            prop_assert_eq!(u64::from_lexical(i.as_bytes()).ok(), i.parse::<u64>().ok());
+        }
+
         #[test]
         fn i64_trailing_digits_proptest(i in r"[+-]?[0-9]{18}\D[0-9]{2}") {
             let result = i64::from_lexical(i.as_bytes());
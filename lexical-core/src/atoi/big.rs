@@ -0,0 +1,215 @@
+//! String-to-integer conversions for arbitrary-precision integers.
+//!
+//! Digits are parsed in chunks that fit in a 32-bit integer, and each
+//! chunk is added to the big integer via `BigInteger::mul_add_small`,
+//! so the number of big-integer operations is minimized.
+
+use crate::util::*;
+use super::shared::*;
+
+#[cfg(feature = "format")]
+use super::generic::validate_no_leading_zeros;
+
+// Parse the digits in chunks, starting from zero.
+perftools_inline!{
+fn parse_digits<'a, T, Iter>(digits: &[u8], mut iter: Iter, radix: u32, sign: Sign)
+    -> ParseResult<(T, *const u8)>
+    where T: BigInteger,
+          Iter: AsPtrIterator<'a, u8>
+{
+    let (step, _) = big_integer_step(radix);
+    let mut value = T::zero();
+    loop {
+        let mut chunk: u32 = 0;
+        let mut count = 0;
+        let mut invalid = None;
+        while count < step {
+            match iter.next() {
+                Some(c) => match to_digit!(*c, radix) {
+                    Some(digit) => {
+                        // Don't have to worry about overflows.
+                        chunk = chunk * radix + digit;
+                        count += 1;
+                    },
+                    None        => {
+                        invalid = Some(c as *const u8);
+                        break;
+                    },
+                },
+                None    => break,
+            }
+        }
+
+        // Add the chunk to the total value.
+        if count != 0 {
+            let multiplier = radix.pow(count.as_u32());
+            let ptr = invalid.unwrap_or_else(|| iter.as_ptr());
+            match sign {
+                Sign::Positive if !value.mul_add_small(multiplier, chunk) => {
+                    return Err((ErrorCode::Overflow, ptr));
+                },
+                Sign::Negative if !value.mul_sub_small(multiplier, chunk) => {
+                    return Err((ErrorCode::Underflow, ptr));
+                },
+                _ => (),
+            }
+        }
+
+        if let Some(ptr) = invalid {
+            return Ok((value, ptr));
+        } else if count < step {
+            return Ok((value, last_ptr(digits)));
+        }
+    }
+}}
+
+// Standalone atoi processor for big integers without a digit separator.
+perftools_inline!{
+fn standalone_big<T>(bytes: &[u8], radix: u32)
+    -> ParseResult<(T, *const u8)>
+    where T: BigInteger
+{
+    let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
+    let iter = iterate_digits_no_separator(digits, b'\x00');
+    parse_digits(digits, iter, radix, sign)
+}}
+
+// Standalone atoi processor for big integers with a numerical format.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn standalone_big_format<T>(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> ParseResult<(T, *const u8)>
+    where T: BigInteger
+{
+    let digit_separator = format.digit_separator();
+    let (value, ptr) = if format.intersects(NumberFormat::INTEGER_DIGIT_SEPARATOR_FLAG_MASK) {
+        // Extract the integer subslice, then parse.
+        let (sign, digits) = match T::IS_SIGNED {
+            true  => parse_sign_separator::<i128>(bytes, format),
+            false => parse_sign_separator::<u128>(bytes, format),
+        };
+        if digits.is_empty() {
+            return Err((ErrorCode::Empty, digits.as_ptr()));
+        }
+        let leading = consume_integer_digits_separator(digits, radix, format).0;
+        let iter = iterate_digits_ignore_separator(leading, digit_separator);
+        parse_digits(leading, iter, radix, sign)?
+    } else {
+        standalone_big(bytes, radix)?
+    };
+
+    // Check if we have any leading zeros.
+    if format.no_integer_leading_zeros() {
+        validate_no_leading_zeros(bytes, digit_separator, ptr)?;
+    }
+
+    Ok((value, ptr))
+}}
+
+// ATOBIG
+
+perftools_inline!{
+pub(crate) fn atobig_options<'a, T>(bytes: &'a [u8], options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+    where T: BigInteger
+{
+    #[cfg(not(feature = "format"))]
+    let result = standalone_big::<T>(bytes, options.radix_u32());
+
+    #[cfg(feature = "format")]
+    let result = standalone_big_format::<T>(bytes, options.radix_u32(), options.number_format());
+
+    let index = | ptr | distance(bytes.as_ptr(), ptr);
+    match result {
+        Ok((value, ptr)) => Ok((value, index(ptr))),
+        Err((code, ptr)) => Err((code, index(ptr)).into()),
+    }
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Signed 192-bit integer, stored as sign and magnitude.
+    #[derive(Clone, Debug, PartialEq)]
+    struct I192(bool, [u64; 3]);
+
+    impl BigInteger for I192 {
+        const IS_SIGNED: bool = true;
+
+        fn zero() -> Self {
+            I192(false, [0; 3])
+        }
+
+        fn is_zero(&self) -> bool {
+            self.1.is_zero()
+        }
+
+        fn is_negative(&self) -> bool {
+            self.0
+        }
+
+        fn mul_add_small(&mut self, multiplier: u32, addend: u32) -> bool {
+            self.1.mul_add_small(multiplier, addend)
+        }
+
+        fn mul_sub_small(&mut self, multiplier: u32, subtrahend: u32) -> bool {
+            self.0 = true;
+            self.1.mul_add_small(multiplier, subtrahend)
+        }
+
+        fn divrem_small(&mut self, divisor: u32) -> u32 {
+            self.1.divrem_small(divisor)
+        }
+    }
+
+    fn parse<T: BigInteger>(bytes: &[u8]) -> Result<(T, usize)> {
+        atobig_options::<T>(bytes, &ParseIntegerOptions::new())
+    }
+
+    #[test]
+    fn atobig_test() {
+        assert_eq!(parse::<[u64; 2]>(b"0"), Ok(([0, 0], 1)));
+        assert_eq!(parse::<[u64; 2]>(b"+12345"), Ok(([12345, 0], 6)));
+        assert_eq!(parse::<[u64; 2]>(b"18446744073709551616"), Ok(([0, 1], 20)));
+        assert_eq!(parse::<[u64; 2]>(b"340282366920938463463374607431768211455"), Ok(([u64::max_value(); 2], 39)));
+        assert_eq!(parse::<[u64; 3]>(b"340282366920938463463374607431768211456"), Ok(([0, 0, 1], 39)));
+        assert_eq!(parse::<[u64; 2]>(b"123456789012a"), Ok(([123456789012, 0], 12)));
+        assert_eq!(parse::<[u64; 2]>(b"123456789a"), Ok(([123456789, 0], 9)));
+        assert_eq!(parse::<[u64; 2]>(b"340282366920938463463374607431768211456"), Err((ErrorCode::Overflow, 39).into()));
+        assert_eq!(parse::<[u64; 2]>(b""), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse::<[u64; 2]>(b"+"), Err((ErrorCode::Empty, 1).into()));
+        assert_eq!(parse::<[u64; 2]>(b"-1"), Ok(([0, 0], 0)));
+    }
+
+    #[test]
+    fn atobig_signed_test() {
+        assert_eq!(parse::<I192>(b"-18446744073709551616"), Ok((I192(true, [0, 1, 0]), 21)));
+        assert_eq!(parse::<I192>(b"-"), Err((ErrorCode::Empty, 1).into()));
+        assert_eq!(parse::<I192>(b"-6277101735386680763835789423207666416102355444464034512896"), Err((ErrorCode::Underflow, 59).into()));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn atobig_radix_test() {
+        let options = ParseIntegerOptions::builder().radix(16).build().unwrap();
+        let result = atobig_options::<[u64; 2]>(b"1000000000000000F", &options);
+        assert_eq!(result, Ok(([15, 1], 17)));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn atobig_format_test() {
+        let format = NumberFormat::ignore(b'_').unwrap();
+        let options = ParseIntegerOptions::builder().format(format).build().unwrap();
+        let result = atobig_options::<[u64; 2]>(b"18_446_744_073_709_551_616", &options);
+        assert_eq!(result, Ok(([0, 1], 26)));
+
+        let options = ParseIntegerOptions::builder().format(NumberFormat::JSON).build().unwrap();
+        let result = atobig_options::<[u64; 2]>(b"01", &options);
+        assert_eq!(result, Err((ErrorCode::InvalidLeadingZeros, 0).into()));
+    }
+}
@@ -86,7 +86,7 @@ use super::shared::*;
 // Validate the extracted integer has no leading zeros.
 perftools_inline!{
 #[cfg(feature = "format")]
-fn validate_no_leading_zeros<'a>(digits: &[u8], digit_separator: u8, ptr: *const u8)
+pub(super) fn validate_no_leading_zeros<'a>(digits: &[u8], digit_separator: u8, ptr: *const u8)
     -> ParseResult<()>
 {
     // Check if the next character is a sign symbol.
@@ -136,14 +136,13 @@ macro_rules! parse_digits {
     );
 }
 
-// Parse the digits for the atoi processor.
+// Parse the digits for the atoi processor, starting from `value`.
 perftools_inline_always!{
-fn parse_digits<'a, T, Iter>(digits: &[u8], mut iter: Iter, radix: u32, sign: Sign)
+fn parse_digits<'a, T, Iter>(mut value: T, digits: &[u8], mut iter: Iter, radix: u32, sign: Sign)
     -> ParseResult<(T, *const u8)>
     where T: Integer,
           Iter: AsPtrIterator<'a, u8>
 {
-    let mut value = T::ZERO;
     if sign == Sign::Positive {
         parse_digits!(value, iter, radix, checked_add, Overflow);
     } else {
@@ -152,6 +151,40 @@ fn parse_digits<'a, T, Iter>(digits: &[u8], mut iter: Iter, radix: u32, sign: Si
     Ok((value, last_ptr(digits)))
 }}
 
+// Parse leading chunks of 8 decimal digits, until the value would overflow.
+//
+// Returns the value and the number of digits processed, and the remaining
+// digits are parsed one at a time, so overflow is detected at the same
+// digit. Only types that can store `10^8` use the fast path.
+perftools_inline_always!{
+fn parse_8digits_chunks<T>(digits: &[u8], radix: u32, sign: Sign)
+    -> (T, usize)
+    where T: Integer
+{
+    let mut value = T::ZERO;
+    let mut index = 0;
+    if radix != 10 || T::BITS < 32 {
+        return (value, index);
+    }
+    while digits.len() - index >= 8 {
+        let chunk = match parse_8digits(&index!(digits[index..index+8])) {
+            Some(v) => v,
+            None    => break,
+        };
+        let shifted = value.checked_mul(as_cast(100_000_000u32));
+        let next = match sign {
+            Sign::Positive => shifted.and_then(|v| v.checked_add(as_cast(chunk))),
+            Sign::Negative => shifted.and_then(|v| v.checked_sub(as_cast(chunk))),
+        };
+        value = match next {
+            Some(v) => v,
+            None    => break,
+        };
+        index += 8;
+    }
+    (value, index)
+}}
+
 // PARSE THEN EXTRACT
 
 // Standalone atoi processor without a digit separator.
@@ -161,8 +194,9 @@ fn standalone<T>(bytes: &[u8], radix: u32)
     where T: Integer
 {
     let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
-    let iter = iterate_digits_no_separator(digits, b'\x00');
-    parse_digits(digits, iter, radix, sign)
+    let (value, index) = parse_8digits_chunks::<T>(digits, radix, sign);
+    let iter = iterate_digits_no_separator(&index!(digits[index..]), b'\x00');
+    parse_digits(value, digits, iter, radix, sign)
 }}
 
 // Standalone atoi processor with digit separators.
@@ -178,7 +212,7 @@ fn standalone_iltc<T>(bytes: &[u8], radix: u32, digit_separator: u8)
         return Err((ErrorCode::Empty, digits.as_ptr()));
     }
     let iter = iterate_digits_ignore_separator(digits, digit_separator);
-    parse_digits(digits, iter, radix, sign)
+    parse_digits(T::ZERO, digits, iter, radix, sign)
 }}
 
 // EXTRACT THEN PARSE
@@ -210,7 +244,7 @@ macro_rules! standalone_atoi_separator {
             let leading = $consume(digits, radix, digit_separator).0;
             let iter = iterate_digits_ignore_separator(leading, digit_separator);
 
-            parse_digits(leading, iter, radix, sign)
+            parse_digits(T::ZERO, leading, iter, radix, sign)
         }}
     );
 }
@@ -293,6 +327,31 @@ standalone_atoi_separator!(
     consume => consume_digits_ilt
 );
 
+// Consume the digits after a base prefix.
+perftools_inline_always!{
+#[cfg(feature = "format")]
+fn consume_prefixed_digits<'a>(digits: &'a [u8], radix: u32, format: NumberFormat)
+    -> &'a [u8]
+{
+    match format.intersects(NumberFormat::INTEGER_DIGIT_SEPARATOR_FLAG_MASK) {
+        true  => consume_integer_digits_separator(digits, radix, format).0,
+        false => consume_digits_no_separator(digits, radix, format).0,
+    }
+}}
+
+// Standalone atoi processor for the digits after a base prefix.
+// Leading zeros are always allowed after the prefix.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn standalone_prefix<T>(digits: &[u8], radix: u32, format: NumberFormat, sign: Sign)
+    -> ParseResult<(T, *const u8)>
+    where T: Integer
+{
+    let leading = consume_prefixed_digits(digits, radix, format);
+    let iter = iterate_digits_ignore_separator(leading, format.digit_separator());
+    parse_digits(T::ZERO, leading, iter, radix, sign)
+}}
+
 // API
 
 // Standalone atoi processor without a digit separator.
@@ -311,6 +370,10 @@ pub(crate) fn standalone_separator<V>(bytes: &[u8], radix: u32, format: NumberFo
     -> ParseResult<(V, *const u8)>
     where V: Integer
 {
+    if let Some((sign, radix, digits)) = parse_base_prefix::<V>(bytes, format) {
+        return standalone_prefix(digits, radix, format, sign);
+    }
+
     const I: NumberFormat = NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
     const L: NumberFormat = NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR;
     const T: NumberFormat = NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR;
@@ -426,7 +489,7 @@ fn parse_digits_128_fast<'a, W, N, Iter>(digits: &[u8], iter: Iter, radix: u32,
           N: Integer,
           Iter: ConsumedIterator<Item=&'a u8> + AsPtrIterator<'a, u8>
 {
-    let (value, ptr) = parse_digits::<N, _>(digits, iter, radix, sign)?;
+    let (value, ptr) = parse_digits::<N, _>(N::ZERO, digits, iter, radix, sign)?;
     Ok((as_cast(value), ptr))
 }}
 
@@ -635,6 +698,10 @@ pub(crate) fn standalone_128_separator<W, N>(bytes: &[u8], radix: u32, format: N
     where W: Integer,
           N: Integer
 {
+    if let Some((sign, radix, digits)) = parse_base_prefix::<W>(bytes, format) {
+        return standalone_prefix(digits, radix, format, sign);
+    }
+
     const I: NumberFormat = NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
     const L: NumberFormat = NumberFormat::INTEGER_LEADING_DIGIT_SEPARATOR;
     const T: NumberFormat = NumberFormat::INTEGER_TRAILING_DIGIT_SEPARATOR;
@@ -678,3 +745,171 @@ pub(crate) fn standalone_128_separator<W, N>(bytes: &[u8], radix: u32, format: N
 
     Ok((value, ptr))
 }}
+
+// STANDALONE OVERFLOW
+// -------------------
+
+/// Iterate over the digits and process them without overflow checks.
+macro_rules! parse_digits_overflow {
+    ($value:ident, $iter:ident, $radix:ident, $mul:ident, $op:ident) => (
+        while let Some(c) = $iter.next() {
+            let digit = match to_digit!(*c, $radix) {
+                Some(v) => v,
+                None    => return ($value, c),
+            };
+            $value = $value.$mul(as_cast($radix)).$op(as_cast(digit));
+        }
+    );
+}
+
+// Parse the digits, wrapping or saturating on overflow.
+//
+// Saturating arithmetic clamps the value to the limit once it
+// overflows, and wrapping arithmetic is modulo `2^N`, so every
+// digit is consumed in both modes.
+perftools_inline!{
+fn parse_digits_overflow<'a, T, Iter>(digits: &[u8], mut iter: Iter, radix: u32, sign: Sign, mode: OverflowMode)
+    -> (T, *const u8)
+    where T: Integer,
+          Iter: AsPtrIterator<'a, u8>
+{
+    let mut value = T::ZERO;
+    match (mode, sign) {
+        (OverflowMode::Wrapping, Sign::Positive)    => parse_digits_overflow!(value, iter, radix, wrapping_mul, wrapping_add),
+        (OverflowMode::Wrapping, Sign::Negative)    => parse_digits_overflow!(value, iter, radix, wrapping_mul, wrapping_sub),
+        (OverflowMode::Saturating, Sign::Positive)  => parse_digits_overflow!(value, iter, radix, saturating_mul, saturating_add),
+        (OverflowMode::Saturating, Sign::Negative)  => parse_digits_overflow!(value, iter, radix, saturating_mul, saturating_sub),
+        (OverflowMode::Checked, _)                  => unreachable!(),
+    }
+    (value, last_ptr(digits))
+}}
+
+// Standalone atoi processor with an overflow mode, without a digit separator.
+perftools_inline!{
+pub(crate) fn standalone_overflow_no_separator<T>(bytes: &[u8], radix: u32, mode: OverflowMode)
+    -> ParseResult<(T, *const u8)>
+    where T: Integer
+{
+    let (sign, digits) = parse_sign!(bytes, T::IS_SIGNED, Empty);
+    let iter = iterate_digits_no_separator(digits, b'\x00');
+    Ok(parse_digits_overflow(digits, iter, radix, sign, mode))
+}}
+
+// Standalone atoi processor with an overflow mode, with a digit separator.
+perftools_inline!{
+#[cfg(feature = "format")]
+pub(crate) fn standalone_overflow_separator<T>(bytes: &[u8], radix: u32, format: NumberFormat, mode: OverflowMode)
+    -> ParseResult<(T, *const u8)>
+    where T: Integer
+{
+    if let Some((sign, radix, digits)) = parse_base_prefix::<T>(bytes, format) {
+        let leading = consume_prefixed_digits(digits, radix, format);
+        let iter = iterate_digits_ignore_separator(leading, format.digit_separator());
+        return Ok(parse_digits_overflow(leading, iter, radix, sign, mode));
+    }
+
+    let digit_separator = format.digit_separator();
+    let (value, ptr) = if format.intersects(NumberFormat::INTEGER_DIGIT_SEPARATOR_FLAG_MASK) {
+        // Extract the integer subslice, then parse.
+        let (sign, digits) = parse_sign_separator::<T>(bytes, format);
+        if digits.is_empty() {
+            return Err((ErrorCode::Empty, digits.as_ptr()));
+        }
+        let leading = consume_integer_digits_separator(digits, radix, format).0;
+        let iter = iterate_digits_ignore_separator(leading, digit_separator);
+        parse_digits_overflow(leading, iter, radix, sign, mode)
+    } else {
+        standalone_overflow_no_separator(bytes, radix, mode)?
+    };
+
+    // Check if we have any leading zeros.
+    if format.no_integer_leading_zeros() {
+        validate_no_leading_zeros(bytes, digit_separator, ptr)?;
+    }
+
+    Ok((value, ptr))
+}}
+
+// STANDALONE ALPHABET
+// -------------------
+
+/// Iterate over the digits in an alphabet with overflow checks.
+#[cfg(feature = "radix")]
+macro_rules! parse_alphabet_digits {
+    ($value:ident, $digits:ident, $alphabet:ident, $op:ident, $code:ident) => (
+        let radix = $alphabet.radix();
+        for c in $digits.iter() {
+            let digit = match $alphabet.to_digit(*c) {
+                Some(v) => v,
+                None    => return Ok(($value, c)),
+            };
+            $value = match $value.checked_mul(as_cast(radix)) {
+                Some(v) => v,
+                None    => return Err((ErrorCode::$code, c)),
+            };
+            $value = match $value.$op(as_cast(digit)) {
+                Some(v) => v,
+                None    => return Err((ErrorCode::$code, c)),
+            };
+        }
+    );
+}
+
+/// Iterate over the digits in an alphabet without overflow checks.
+#[cfg(feature = "radix")]
+macro_rules! parse_alphabet_digits_overflow {
+    ($value:ident, $digits:ident, $alphabet:ident, $mul:ident, $op:ident) => (
+        let radix = $alphabet.radix();
+        for c in $digits.iter() {
+            let digit = match $alphabet.to_digit(*c) {
+                Some(v) => v,
+                None    => return Ok(($value, c)),
+            };
+            $value = $value.$mul(as_cast(radix)).$op(as_cast(digit));
+        }
+    );
+}
+
+// Parse the sign before digits in an alphabet.
+//
+// A `+` or `-` is only a sign if it is not a digit, such as `+` in base64.
+perftools_inline!{
+#[cfg(feature = "radix")]
+fn parse_alphabet_sign<'a, T>(bytes: &'a [u8], alphabet: &DigitAlphabet)
+    -> (Sign, &'a [u8])
+    where T: Integer
+{
+    match bytes.first() {
+        Some(&c) if alphabet.to_digit(c).is_some()  => (Sign::Positive, bytes),
+        Some(&b'+')                                 => (Sign::Positive, &index!(bytes[1..])),
+        Some(&b'-') if T::IS_SIGNED                 => (Sign::Negative, &index!(bytes[1..])),
+        _                                           => (Sign::Positive, bytes),
+    }
+}}
+
+// Standalone atoi processor for digits in an alphabet.
+perftools_inline!{
+#[cfg(feature = "radix")]
+pub(crate) fn standalone_alphabet<T>(bytes: &[u8], alphabet: &DigitAlphabet, mode: OverflowMode)
+    -> ParseResult<(T, *const u8)>
+    where T: Integer
+{
+    if bytes.is_empty() {
+        return Err((ErrorCode::Empty, bytes.as_ptr()));
+    }
+    let (sign, digits) = parse_alphabet_sign::<T>(bytes, alphabet);
+    if digits.is_empty() {
+        return Err((ErrorCode::Empty, digits.as_ptr()));
+    }
+
+    let mut value = T::ZERO;
+    match (mode, sign) {
+        (OverflowMode::Checked, Sign::Positive)     => { parse_alphabet_digits!(value, digits, alphabet, checked_add, Overflow); },
+        (OverflowMode::Checked, Sign::Negative)     => { parse_alphabet_digits!(value, digits, alphabet, checked_sub, Underflow); },
+        (OverflowMode::Wrapping, Sign::Positive)    => { parse_alphabet_digits_overflow!(value, digits, alphabet, wrapping_mul, wrapping_add); },
+        (OverflowMode::Wrapping, Sign::Negative)    => { parse_alphabet_digits_overflow!(value, digits, alphabet, wrapping_mul, wrapping_sub); },
+        (OverflowMode::Saturating, Sign::Positive)  => { parse_alphabet_digits_overflow!(value, digits, alphabet, saturating_mul, saturating_add); },
+        (OverflowMode::Saturating, Sign::Negative)  => { parse_alphabet_digits_overflow!(value, digits, alphabet, saturating_mul, saturating_sub); },
+    }
+    Ok((value, last_ptr(digits)))
+}}
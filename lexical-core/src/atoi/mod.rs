@@ -5,10 +5,13 @@
 mod shared;
 
 mod api;
+mod big;
 mod exponent;
 mod generic;
 mod mantissa;
 
 // Re-exports
+pub(crate) use self::api::atoi_expected;
+pub(crate) use self::big::*;
 pub(crate) use self::mantissa::*;
 pub(crate) use self::exponent::*;
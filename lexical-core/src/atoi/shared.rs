@@ -60,6 +60,31 @@ pub(super) fn is_not_digit_char(c: u8, radix: u32) -> bool {
     to_digit!(c, radix).is_none()
 }}
 
+// Parse 8 decimal digits at once, using SWAR (SIMD within a register).
+// Returns `None` if any of the bytes is not a decimal digit.
+perftools_inline_always!{
+pub(super) fn parse_8digits(bytes: &[u8]) -> Option<u32> {
+    debug_assert!(bytes.len() == 8);
+    let mut buffer = [0u8; 8];
+    buffer.copy_from_slice(bytes);
+    let value = u64::from_le_bytes(buffer);
+
+    // Every byte must be in the range `[0x30, 0x39]`: the high nibble
+    // must be 3, and adding 6 must not change the high nibble.
+    const MASK: u64 = 0xF0F0_F0F0_F0F0_F0F0;
+    let carry = value.wrapping_add(0x0606_0606_0606_0606);
+    if (value & MASK) | ((carry & MASK) >> 4) != 0x3333_3333_3333_3333 {
+        return None;
+    }
+
+    // Combine adjacent digits, then pairs of 2 digits, then pairs of 4 digits.
+    let value = value - 0x3030_3030_3030_3030;
+    let value = value.wrapping_mul(10) + (value >> 8);
+    let lo = (value & 0x0000_00FF_0000_00FF).wrapping_mul(100 + (1_000_000 << 32));
+    let hi = ((value >> 16) & 0x0000_00FF_0000_00FF).wrapping_mul(1 + (10_000 << 32));
+    Some((lo.wrapping_add(hi) >> 32) as u32)
+}}
+
 // Add digit to mantissa.
 perftools_inline_always!{
 #[cfg(feature = "correct")]
@@ -296,38 +296,7 @@ pub(crate) fn internal_rounding(kind: RoundingKind, sign: Sign)
     }
 
     #[cfg(feature = "rounding")] {
-        match sign {
-            Sign::Positive => {
-                match kind {
-                    RoundingKind::TowardPositiveInfinity => RoundingKind::Upward,
-                    RoundingKind::TowardNegativeInfinity => RoundingKind::Downward,
-                    RoundingKind::TowardZero             => RoundingKind::Downward,
-                    _                                    => kind,
-                }
-            },
-            Sign::Negative => {
-                match kind {
-                    RoundingKind::TowardPositiveInfinity => RoundingKind::Downward,
-                    RoundingKind::TowardNegativeInfinity => RoundingKind::Upward,
-                    RoundingKind::TowardZero             => RoundingKind::Downward,
-                    _                                    => kind,
-                }
-            },
-        }
-    }
-}}
-
-// Get the global, default rounding scheme.
-perftools_inline!{
-#[cfg(feature = "correct")]
-#[allow(unused_variables)]
-pub(crate) fn global_rounding(sign: Sign) -> RoundingKind {
-    #[cfg(not(feature = "rounding"))] {
-        RoundingKind::NearestTieEven
-    }
-
-    #[cfg(feature = "rounding")] {
-        internal_rounding(get_float_rounding(), sign)
+        magnitude_rounding(kind, sign)
     }
 }}
 
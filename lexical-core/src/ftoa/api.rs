@@ -51,6 +51,17 @@
 //  plt.show()
 
 use crate::util::*;
+use super::notation::{format_size, ftoa_notation, notation_size, write_nan};
+
+#[cfg(feature = "f16")]
+use crate::atof::Bigint;
+#[cfg(feature = "f16")]
+use super::exact::shortest_digits;
+#[cfg(feature = "f16")]
+use super::notation::ftoa_notation_shortest;
+
+#[cfg(feature = "radix")]
+use super::pow2::{double_pow2, float_pow2};
 
 #[cfg(feature = "radix")]
 use super::radix::{double_radix, float_radix};
@@ -69,15 +80,33 @@ if #[cfg(feature = "grisu3")] {
 
 /// Trait to define serialization of a float to string.
 pub(crate) trait FloatToString: Float {
+    /// Maximum number of digits in the shortest representation.
+    const SHORTEST_DIGITS: usize;
+
+    /// Maximum number of integer digits in positional notation.
+    const MAX_INTEGER_DIGITS: usize;
+
+    /// Maximum number of fraction digits of the shortest representation
+    /// in positional notation.
+    const MAX_FRACTION_DIGITS: usize;
+
     /// Export float to decimal string with optimized algorithm.
     fn decimal<'a>(self, bytes: &'a mut [u8]) -> usize;
 
     /// Export float to radix string with slow algorithm.
     #[cfg(feature = "radix")]
-    fn radix<'a>(self, radix: u32, bytes: &'a mut [u8]) -> usize;
+    fn radix<'a>(self, radix: u32, exponent_char: u8, bytes: &'a mut [u8]) -> usize;
+
+    /// Export float to exact string with a power-of-two exponent base.
+    #[cfg(feature = "radix")]
+    fn pow2<'a>(self, options: &WriteFloatOptions, bytes: &'a mut [u8]) -> usize;
 }
 
 impl FloatToString for f32 {
+    const SHORTEST_DIGITS: usize = 9;
+    const MAX_INTEGER_DIGITS: usize = 39;
+    const MAX_FRACTION_DIGITS: usize = 54;
+
     perftools_inline!{
     fn decimal<'a>(self, bytes: &'a mut [u8]) -> usize {
         float_decimal(self, bytes)
@@ -85,12 +114,22 @@ impl FloatToString for f32 {
 
     perftools_inline!{
     #[cfg(feature = "radix")]
-    fn radix<'a>(self, radix: u32, bytes: &'a mut [u8]) -> usize {
-        float_radix(self, radix, bytes)
+    fn radix<'a>(self, radix: u32, exponent_char: u8, bytes: &'a mut [u8]) -> usize {
+        float_radix(self, radix, exponent_char, bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn pow2<'a>(self, options: &WriteFloatOptions, bytes: &'a mut [u8]) -> usize {
+        float_pow2(self, options, bytes)
     }}
 }
 
 impl FloatToString for f64 {
+    const SHORTEST_DIGITS: usize = 17;
+    const MAX_INTEGER_DIGITS: usize = 309;
+    const MAX_FRACTION_DIGITS: usize = 341;
+
     perftools_inline!{
     fn decimal<'a>(self, bytes: &'a mut [u8]) -> usize {
         double_decimal(self, bytes)
@@ -98,40 +137,64 @@ impl FloatToString for f64 {
 
     perftools_inline!{
     #[cfg(feature = "radix")]
-    fn radix<'a>(self, radix: u32, bytes: &'a mut [u8]) -> usize {
-        double_radix(self, radix, bytes)
+    fn radix<'a>(self, radix: u32, exponent_char: u8, bytes: &'a mut [u8]) -> usize {
+        double_radix(self, radix, exponent_char, bytes)
+    }}
+
+    perftools_inline!{
+    #[cfg(feature = "radix")]
+    fn pow2<'a>(self, options: &WriteFloatOptions, bytes: &'a mut [u8]) -> usize {
+        double_pow2(self, options, bytes)
     }}
 }
 
 // FTOA
 
+// Export float to decimal string, replacing the exponent character.
+//
+// The decimal backends always write `e` as the exponent character,
+// so any other character is patched in after the fact.
+perftools_inline!{
+fn decimal<'a, F: FloatToString>(value: F, exponent_char: u8, bytes: &'a mut [u8])
+    -> usize
+{
+    let len = value.decimal(bytes);
+    if exponent_char != b'e' {
+        if let Some(c) = index_mut!(bytes[..len]).iter_mut().find(|c| **c == b'e') {
+            *c = exponent_char;
+        }
+    }
+    len
+}}
+
 // Forward the correct arguments the ideal encoder.
 perftools_inline!{
-fn forward<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8])
+fn forward<'a, F: FloatToString>(value: F, bytes: &'a mut [u8], options: &WriteFloatOptions)
     -> usize
 {
+    let radix = options.radix_u32();
+    let exponent_char = options.exponent_char();
     debug_assert_radix!(radix);
 
     #[cfg(not(feature = "radix"))] {
-        value.decimal(bytes)
+        decimal(value, exponent_char, bytes)
     }
 
     #[cfg(feature = "radix")] {
         match radix {
-            10 => value.decimal(bytes),
-            _  => value.radix(radix, bytes),
+            10 => decimal(value, exponent_char, bytes),
+            _  => value.radix(radix, exponent_char, bytes),
         }
     }
 }}
 
 // Convert float-to-string and handle special (positive) floats.
 perftools_inline!{
-fn filter_special<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8])
+fn filter_special<'a, F: FloatToString>(value: F, bytes: &'a mut [u8], options: &WriteFloatOptions)
     -> usize
 {
     // Logic errors, disable in release builds.
     debug_assert!(value.is_sign_positive(), "Value cannot be negative.");
-    debug_assert_radix!(radix);
 
     // We already check for 0 in `filter_sign` if value.is_zero().
     #[cfg(not(feature = "trim_floats"))] {
@@ -144,26 +207,25 @@ fn filter_special<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8
 
     if value.is_nan() {
         // This is safe, because we confirmed the buffer is >= F::FORMATTED_SIZE.
-        // We have up to `F::FORMATTED_SIZE - 1` bytes from `get_nan_string()`,
-        // and up to 1 byte from the sign.
-        copy_to_dst(bytes, get_nan_string())
+        // We have up to `F::FORMATTED_SIZE - 1` bytes from `nan_string()`,
+        // and up to 1 byte from the sign, and the buffer size includes
+        // the payload if it is written.
+        write_nan(value, bytes, options)
     } else if value.is_special() {
         // This is safe, because we confirmed the buffer is >= F::FORMATTED_SIZE.
-        // We have up to `F::FORMATTED_SIZE - 1` bytes from `get_inf_string()`,
+        // We have up to `F::FORMATTED_SIZE - 1` bytes from `inf_string()`,
         // and up to 1 byte from the sign.
-        copy_to_dst(bytes, get_inf_string())
+        copy_to_dst(bytes, options.inf_string())
     } else {
-        forward(value, radix, bytes)
+        forward(value, bytes, options)
     }
 }}
 
 // Handle +/- values.
 perftools_inline!{
-fn filter_sign<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8])
+fn filter_sign<'a, F: FloatToString>(value: F, bytes: &'a mut [u8], options: &WriteFloatOptions)
     -> usize
 {
-    debug_assert_radix!(radix);
-
     // Export "-0.0" and "0.0" as "0" with trimmed floats.
     #[cfg(feature = "trim_floats")] {
         if value.is_zero() {
@@ -180,20 +242,65 @@ fn filter_sign<'a, F: FloatToString>(value: F, radix: u32, bytes: &'a mut [u8])
         // We know this is safe, because we confirmed the buffer is >= 1.
         index_mut!(bytes[0] = b'-');
         let bytes = &mut index_mut!(bytes[1..]);
-        filter_special(value, radix, bytes) + 1
+        filter_special(value, bytes, options) + 1
     } else {
-        filter_special(value, radix, bytes)
+        filter_special(value, bytes, options)
     }
 }}
 
-// Write float to string..
+// Write float to string with custom options.
+perftools_inline!{
+fn ftoa_options<F: FloatToString>(value: F, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+{
+    // Grouped digits are written from the digits, with the separators,
+    // and power-of-two exponent bases are written from the exact digits.
+    let is_grouped = options.grouping() != DigitGrouping::None;
+    match options.is_shortest() && !options.is_formatted() && !is_grouped && !options.is_pow2() {
+        true  => {
+            let len = filter_sign(value, bytes, options);
+            trim(&mut index_mut!(bytes[..len]))
+        },
+        false => ftoa_notation(value, bytes, options),
+    }
+}}
+
+// Write float to string with a custom numerical format.
+//
+// Returns `None` if the float cannot be represented in the format.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn ftoa_format<F: FloatToString>(value: F, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> Option<usize>
+{
+    let format = options.number_format();
+    let is_valid = !(value.is_special() && format.no_special())
+        && !(format.no_exponent_notation() && options.radix_u32() != 10);
+    match is_valid {
+        true  => Some(ftoa_options(value, bytes, options)),
+        false => None,
+    }
+}}
+
+// Get the number of bytes required to write any float with custom options.
+perftools_inline!{
+fn ftoa_buffer_size<F: FloatToString>(options: &WriteFloatOptions)
+    -> usize
+{
+    let size = formatted_size::<F>(options.radix_u32()).max(notation_size::<F>(options));
+    let size = size + group_separators_size(size, options.grouping());
+    // The signaling prefix, parentheses, the "0x" prefix and up to
+    // 16 hex digits for a NaN payload.
+    let size = size + if options.nan_payload() { 21 } else { 0 };
+    size + format_size(options)
+}}
+
+// Write float to string.
 perftools_inline!{
 fn ftoa<F: FloatToString>(value: F, radix: u32, bytes: &mut [u8])
     -> usize
 {
-    let len = filter_sign(value, radix, bytes);
-    let bytes = &mut index_mut!(bytes[..len]);
-    trim(bytes)
+    ftoa_options(value, bytes, &WriteFloatOptions::from_config(radix, NumberFormat::standard().unwrap()))
 }}
 
 // Trim a trailing ".0" from a float.
@@ -209,10 +316,95 @@ fn trim<'a>(bytes: &'a mut [u8])
     }
 }}
 
+// HALF
+
+// Generate the shortest digits of a half-precision float that round-trip.
+//
+// The float must be finite, positive, and non-zero. The shortest
+// representation of the float as an `f32` is usually much longer,
+// so the digits are generated from the half-precision float.
+// Returns the number of digits and the exponent of the first digit.
+#[cfg(feature = "f16")]
+fn half_shortest_digits<H: Half>(value: H, digits: &mut [u8]) -> (usize, i32) {
+    // The float is `mant * 2^exp`.
+    let bits = value.to_half_bits() & 0x7FFF;
+    let biased = (bits >> H::MANTISSA_SIZE) as i32;
+    let fraction = (bits & ((1 << H::MANTISSA_SIZE) - 1)) as u128;
+    let min_exp = 1 - H::EXPONENT_BIAS - H::MANTISSA_SIZE;
+    let (mant, exp) = match biased {
+        0 => (fraction, min_exp),
+        _ => (fraction | (1 << H::MANTISSA_SIZE), biased + min_exp - 1),
+    };
+    shortest_digits::<Bigint>(mant, exp, H::MANTISSA_SIZE + 1, min_exp, digits)
+}
+
+// Write half-precision float to string with custom options.
+//
+// The float is written as an `f32`, which is exact, with the shortest
+// digits of the half-precision float for decimal floats.
+#[cfg(feature = "f16")]
+fn htoa_options<H: Half>(value: H, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+{
+    let float = value.as_f32();
+    match options.radix_u32() == 10 && !float.is_special() && !float.is_zero() {
+        true  => {
+            let mut digits: [u8; 8] = [b'0'; 8];
+            let (count, exp10) = half_shortest_digits(value, &mut digits);
+            ftoa_notation_shortest(float, Some((&digits[..count], exp10)), bytes, options)
+        },
+        false => ftoa_options(float, bytes, options),
+    }
+}
+
+// Write half-precision float to string with a custom numerical format.
+#[cfg(all(feature = "f16", feature = "format"))]
+fn htoa_format<H: Half>(value: H, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> Option<usize>
+{
+    let format = options.number_format();
+    let float = value.as_f32();
+    let is_valid = !(float.is_special() && format.no_special())
+        && !(format.no_exponent_notation() && options.radix_u32() != 10);
+    match is_valid {
+        true  => Some(htoa_options(value, bytes, options)),
+        false => None,
+    }
+}
+
+// Write half-precision float to string.
+#[cfg(feature = "f16")]
+fn htoa<H: Half>(value: H, radix: u32, bytes: &mut [u8])
+    -> usize
+{
+    htoa_options(value, bytes, &WriteFloatOptions::from_config(radix, NumberFormat::standard().unwrap()))
+}
+
 // TO LEXICAL
 
 to_lexical!(ftoa, f32);
 to_lexical!(ftoa, f64);
+to_lexical_options!(ftoa_options, ftoa_buffer_size::<f32>, WriteFloatOptions, f32);
+to_lexical_options!(ftoa_options, ftoa_buffer_size::<f64>, WriteFloatOptions, f64);
+
+#[cfg(feature = "format")]
+to_lexical_format!(ftoa_format, WriteFloatOptions, f32);
+#[cfg(feature = "format")]
+to_lexical_format!(ftoa_format, WriteFloatOptions, f64);
+
+cfg_if! {
+if #[cfg(feature = "f16")] {
+    to_lexical!(htoa, f16);
+    to_lexical!(htoa, bf16);
+    to_lexical_options!(htoa_options, ftoa_buffer_size::<f32>, WriteFloatOptions, f16);
+    to_lexical_options!(htoa_options, ftoa_buffer_size::<f32>, WriteFloatOptions, bf16);
+}}  // cfg_if
+
+cfg_if! {
+if #[cfg(all(feature = "f16", feature = "format"))] {
+    to_lexical_format!(htoa_format, WriteFloatOptions, f16);
+    to_lexical_format!(htoa_format, WriteFloatOptions, bf16);
+}}  // cfg_if
 
 // TESTS
 // -----
@@ -222,6 +414,12 @@ mod tests {
     use crate::util::*;
     use crate::util::test::*;
 
+    #[cfg(feature = "f16")]
+    use super::half_shortest_digits;
+
+    #[cfg(feature = "correct")]
+    use quickcheck::TestResult;
+
     // Test data for roundtrips.
     const F32_DATA : [f32; 31] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e-8, 1.2345e-11, 1.2345e-38];
     const F64_DATA: [f64; 33] = [0., 0.1, 1., 1.1, 12., 12.1, 123., 123.1, 1234., 1234.1, 12345., 12345.1, 123456., 123456.1, 1234567., 1234567.1, 12345678., 12345678.1, 123456789., 123456789.1, 123456789.12, 123456789.123, 123456789.1234, 123456789.12345, 1.2345678912345e8, 1.2345e+8, 1.2345e+11, 1.2345e+38, 1.2345e+308, 1.2345e-8, 1.2345e-11, 1.2345e-38, 1.2345e-299];
@@ -415,16 +613,457 @@ mod tests {
         }
     }
 
+    #[test]
+    fn f64_options_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::new();
+        assert_eq!(as_slice(b"1.5e-300"), 1.5e-300f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+
+        let options = WriteFloatOptions::builder()
+            .exponent_default_char(b'E')
+            .nan_string(b"nan")
+            .inf_string(b"Infinity")
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"1.5E-300"), 1.5e-300f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.5E-300"), (-1.5e-300f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"nan"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-Infinity"), f64::NEG_INFINITY.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_options_radix_test() {
+        let mut buffer = new_buffer();
+        let options = WriteFloatOptions::builder()
+            .radix(16)
+            .exponent_backup_char(b'#')
+            .build()
+            .unwrap();
+        let s = 1.5e300f64.to_lexical_with_options(&mut buffer, &options);
+        assert!(s.contains(&b'#'));
+        assert_eq!(1.5e300f64.to_lexical_radix(16, &mut [0u8; 256]).len(), s.len());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn f64_exponent_base_test() {
+        let mut buffer = [b'\0'; 1024];
+        let options = WriteFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"1.8p3"), 12.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.999999999999Ap-4"), (-0.1f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"1.99999Ap-4"), 0.1f32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+
+        #[cfg(feature = "trim_floats")] {
+            assert_eq!(as_slice(b"0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+            assert_eq!(as_slice(b"1p0"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+        }
+
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(as_slice(b"0.0"), 0.0f64.to_lexical_with_options(&mut buffer, &options));
+            assert_eq!(as_slice(b"1.0p0"), 1.0f64.to_lexical_with_options(&mut buffer, &options));
+
+            let size = f64::buffer_size(&options);
+            let bytes = 5e-324f64.to_lexical_with_options(&mut buffer[..size], &options);
+            assert_eq!(bytes, b"1.0p-1074");
+        }
+
+        // The integer digits are grouped.
+        let options = WriteFloatOptions::builder()
+            .radix(2)
+            .exponent_base(16)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .digit_separator(b'_')
+            .grouping(DigitGrouping::Uniform(3))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"1_100.1p1"), 200.0f64.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[cfg(all(feature = "radix", feature = "format"))]
+    #[test]
+    fn f64_exponent_base_format_test() {
+        let mut buffer = [b'\0'; 1024];
+        let format = NumberFormat::REQUIRED_MANTISSA_SIGN | NumberFormat::REQUIRED_EXPONENT_SIGN;
+        let options = WriteFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .format(format)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"+1.8p+3"), 12.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.8p-1"), (-0.75f64).to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[cfg(all(feature = "radix", feature = "correct"))]
+    #[test]
+    fn f64_exponent_base_roundtrip_test() {
+        let mut buffer = new_buffer();
+        let write_options = WriteFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        let parse_options = ParseFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        for &f in &[1.0, 0.1, 1.5e300, 5e-324, 2.2250738585072014e-308, f64::MAX, 123456.789] {
+            let bytes = f.to_lexical_with_options(&mut buffer, &write_options);
+            assert_eq!(Ok(f), f64::from_lexical_with_options(bytes, &parse_options));
+        }
+    }
+
+    #[test]
+    fn f64_grouping_test() {
+        let mut buffer = [b'\0'; 1024];
+        let options = WriteFloatOptions::builder()
+            .digit_separator(b',')
+            .grouping(DigitGrouping::Uniform(3))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"1,234,567.125"), 1234567.125f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.2345e-300"), (-1.2345e-300f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+
+        let options = WriteFloatOptions::builder()
+            .digit_separator(b' ')
+            .grouping(DigitGrouping::Uniform(3))
+            .group_fraction(true)
+            .notation(FloatNotation::Fixed)
+            .precision(Some(7))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"-1 234 567.125 000 0"), (-1234567.125f64).to_lexical_with_options(&mut buffer, &options));
+
+        // Ensure the buffer size is sufficient for the worst case.
+        let options = WriteFloatOptions::builder()
+            .grouping(DigitGrouping::Uniform(1))
+            .group_fraction(true)
+            .notation(FloatNotation::Fixed)
+            .build()
+            .unwrap();
+        let size = f64::buffer_size(&options);
+        assert!(size <= buffer.len());
+        let bytes = f64::MAX.to_lexical_with_options(&mut buffer[..size], &options);
+        assert_eq!(bytes.iter().filter(|&&c| c == b'_').count(), 308);
+        let bytes = (-5e-324f64).to_lexical_with_options(&mut buffer[..size], &options);
+        assert!(bytes.starts_with(b"-0.0_0_0"));
+
+        assert!(WriteFloatOptions::builder().digit_separator(b'e').build().is_none());
+        assert!(WriteFloatOptions::builder().digit_separator(b'^').build().is_none());
+    }
+
+    #[test]
+    fn f64_nan_payload_test() {
+        let mut buffer = [b'\0'; 1024];
+        let options = WriteFloatOptions::builder()
+            .nan_payload(true)
+            .build()
+            .unwrap();
+        let payload = f64::from_bits(0x7FF80000000007FF);
+        assert_eq!(as_slice(b"NaN(0x7ff)"), payload.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-NaN(0x7ff)"), (-payload).to_lexical_with_options(&mut buffer, &options));
+        let signaling = f64::from_bits(0x7FF0000000000001);
+        assert_eq!(as_slice(b"sNaN(0x1)"), signaling.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-sNaN(0x1)"), (-signaling).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NaN"), signaling.to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"NaN"), f64::NAN.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-NaN"), (-f64::NAN).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"NaN"), payload.to_lexical(&mut buffer));
+
+        // Ensure the buffer size is sufficient for the worst case.
+        let largest = -f64::from_bits(0x7FF7FFFFFFFFFFFF);
+        let size = f64::buffer_size(&options);
+        assert_eq!(as_slice(b"-sNaN(0x7ffffffffffff)"), largest.to_lexical_with_options(&mut buffer[..size], &options));
+
+        let options = WriteFloatOptions::builder()
+            .nan_payload(true)
+            .notation(FloatNotation::Scientific)
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"NaN(0x7ff)"), payload.to_lexical_with_options(&mut buffer, &options));
+
+        // Round-trip the payload, the sign, and signaling NaNs.
+        let parse_options = ParseFloatOptions::builder()
+            .nan_payload(true)
+            .build()
+            .unwrap();
+        let options = WriteFloatOptions::builder()
+            .nan_payload(true)
+            .build()
+            .unwrap();
+        for &bits in [0x7FF8000000000001u64, 0xFFF8000000000000, 0xFFFABCDEF0123456, 0x7FF0000000000001, 0xFFF7FFFFFFFFFFFF, 0x7FF4000000000000].iter() {
+            let bytes = f64::from_bits(bits).to_lexical_with_options(&mut buffer, &options);
+            assert_eq!(Ok(bits), f64::from_lexical_with_options(bytes, &parse_options).map(f64::to_bits));
+        }
+        let bytes = f32::from_bits(0xFFC0ABCD).to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(as_slice(b"-NaN(0xabcd)"), bytes);
+        assert_eq!(Ok(0xFFC0ABCD), f32::from_lexical_with_options(bytes, &parse_options).map(f32::to_bits));
+        let bytes = f32::from_bits(0x7F80ABCD).to_lexical_with_options(&mut buffer, &options);
+        assert_eq!(as_slice(b"sNaN(0xabcd)"), bytes);
+        assert_eq!(Ok(0x7F80ABCD), f32::from_lexical_with_options(bytes, &parse_options).map(f32::to_bits));
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn half_shortest_digits_test() {
+        let check = |value: f16, expected: &[u8], exp10: i32| {
+            let mut digits = [b'0'; 8];
+            let (count, exp) = half_shortest_digits(value, &mut digits);
+            assert_eq!((&digits[..count], exp), (expected, exp10));
+        };
+        check(f16::from_bits(0x0001), b"6", -8);
+        check(f16::from_bits(0x03FF), b"61", -5);
+        check(f16::from_bits(0x0400), b"6104", -5);
+        check(f16::from_bits(0x0800), b"1221", -4);
+        check(f16::from_bits(0x2E66), b"1", -1);
+        check(f16::from_bits(0x3C00), b"1", 0);
+        check(f16::from_bits(0x3C01), b"1001", 0);
+        check(f16::from_bits(0x7BFF), b"655", 4);
+    }
+
+    #[cfg(all(feature = "f16", feature = "correct"))]
+    #[test]
+    fn f16_test() {
+        let mut buffer = new_buffer();
+        #[cfg(not(feature = "trim_floats"))] {
+            assert_eq!(as_slice(b"1.0"), f16::from_f32(1.0).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"-0.0"), f16::from_f32(-0.0).to_lexical(&mut buffer));
+            assert_eq!(as_slice(b"65500.0"), f16::from_f32(65504.0).to_lexical(&mut buffer));
+        }
+        assert_eq!(as_slice(b"0.1"), f16::from_f32(0.1).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"-3.14"), f16::from_f32(-3.14).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"0.0001"), f16::from_f32(0.0001).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"6e-8"), f16::from_bits(0x0001).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"inf"), f16::from_bits(0x7C00).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"NaN"), f16::from_bits(0x7E00).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"3.14"), bf16::from_f32(3.14).to_lexical(&mut buffer));
+        assert_eq!(as_slice(b"3.39e38"), bf16::from_bits(0x7F7F).to_lexical(&mut buffer));
+
+        let options = WriteFloatOptions::builder()
+            .notation(FloatNotation::Scientific)
+            .precision(Some(10))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"9.9975585938e-2"), f16::from_f32(0.1).to_lexical_with_options(&mut buffer, &options));
+        let options = WriteFloatOptions::builder()
+            .precision(Some(2))
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"3.1"), f16::from_f32(3.14).to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[cfg(all(feature = "f16", feature = "correct"))]
+    #[test]
+    fn f16_roundtrip_test() {
+        let mut buffer = new_buffer();
+        for bits in 0..0x7C00u16 {
+            let value = f16::from_bits(bits);
+            let bytes = value.to_lexical(&mut buffer);
+            assert_eq!(f16::from_lexical(bytes).map(f16::to_bits), Ok(bits));
+        }
+        for bits in (0..0x7F80u16).step_by(7) {
+            let value = bf16::from_bits(bits);
+            let bytes = value.to_lexical(&mut buffer);
+            assert_eq!(bf16::from_lexical(bytes).map(bf16::to_bits), Ok(bits));
+        }
+    }
+
+    #[cfg(feature = "format")]
+    fn write_format(f: f64, format: NumberFormat, buffer: &mut [u8]) -> Option<&[u8]> {
+        f.to_lexical_format(format, buffer).map(|x| &*x)
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_format_test() {
+        let mut buffer = [b'\0'; 1024];
+        let format = NumberFormat::REQUIRED_MANTISSA_SIGN | NumberFormat::REQUIRED_EXPONENT_SIGN;
+        assert_eq!(Some(as_slice(b"+1.5e-300")), write_format(1.5e-300f64, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"-1.5e-300")), write_format(-1.5e-300f64, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"+1.5e+300")), write_format(1.5e300f64, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"NaN")), write_format(f64::NAN, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"-inf")), write_format(f64::NEG_INFINITY, format, &mut buffer));
+
+        let format = NumberFormat::NO_POSITIVE_EXPONENT_SIGN | NumberFormat::NO_EXPONENT_WITHOUT_FRACTION;
+        assert_eq!(Some(as_slice(b"1.0e300")), write_format(1e300f64, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"-1.0e-300")), write_format(-1e-300f64, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"NaN")), write_format(f64::NAN, format, &mut buffer));
+
+        let format = NumberFormat::REQUIRED_DIGITS;
+        assert_eq!(Some(as_slice(b"0.5")), write_format(0.5f64, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"1e300")), write_format(1e300f64, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"1.5e-300")), write_format(1.5e-300f64, format, &mut buffer));
+
+        let format = NumberFormat::NO_EXPONENT_NOTATION;
+        assert_eq!(Some(as_slice(b"0.000000015")), write_format(1.5e-8f64, format, &mut buffer));
+        let s = write_format(-1e20f64, format, &mut buffer).unwrap();
+        assert!(s.starts_with(b"-100000000000000000000") && !s.contains(&b'e'));
+        let s = write_format(5e-324f64, format, &mut buffer).unwrap();
+        assert_eq!(s.len(), 326);
+        assert!(s.starts_with(b"0.000") && s.ends_with(b"0005"));
+
+        let format = NumberFormat::NO_SPECIAL;
+        assert_eq!(None, write_format(f64::NAN, format, &mut buffer));
+        assert_eq!(None, write_format(f64::INFINITY, format, &mut buffer));
+        assert_eq!(Some(as_slice(b"1.5")), write_format(1.5f64, format, &mut buffer));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn f64_options_format_test() {
+        let mut buffer = [b'\0'; 1024];
+        let options = WriteFloatOptions::builder()
+            .format(NumberFormat::NO_EXPONENT_NOTATION)
+            .notation(FloatNotation::General)
+            .precision(Some(3))
+            .build()
+            .unwrap();
+        assert!(f64::buffer_size(&options) <= buffer.len());
+        assert_eq!(as_slice(b"123000"), 123456.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"0.00000123"), 1.23456e-6f64.to_lexical_with_options(&mut buffer, &options));
+
+        let format = NumberFormat::REQUIRED_MANTISSA_SIGN
+            | NumberFormat::REQUIRED_EXPONENT_SIGN
+            | NumberFormat::NO_EXPONENT_WITHOUT_FRACTION;
+        let options = WriteFloatOptions::builder()
+            .format(format)
+            .exponent_default_char(b'E')
+            .build()
+            .unwrap();
+        assert_eq!(as_slice(b"+1.0E+300"), 1e300f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"-1.5E-300"), (-1.5e-300f64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(as_slice(b"inf"), f64::INFINITY.to_lexical_with_options(&mut buffer, &options));
+        let size = f64::buffer_size(&options);
+        let s = (-5e-324f64).to_lexical_with_options(&mut buffer[..size], &options);
+        assert_eq!(s, b"-5.0E-324");
+
+        let builder = WriteFloatOptions::builder().format(NumberFormat::NO_EXPONENT_NOTATION);
+        assert!(builder.notation(FloatNotation::Scientific).build().is_none());
+        #[cfg(feature = "radix")]
+        assert!(builder.radix(2).build().is_none());
+    }
+
+    #[cfg(all(feature = "format", feature = "radix"))]
+    #[test]
+    fn f64_format_radix_test() {
+        let mut buffer = [b'\0'; 1024];
+        let format = NumberFormat::REQUIRED_EXPONENT_SIGN | NumberFormat::NO_EXPONENT_WITHOUT_FRACTION;
+        let s = 1e300f64.to_lexical_format_radix(2, format, &mut buffer).unwrap();
+        let index = s.iter().position(|&c| c == b'e').unwrap();
+        assert!(s[..index].contains(&b'.') && s[index+1] == b'+');
+        assert!(1.5f64.to_lexical_format_radix(2, NumberFormat::NO_EXPONENT_NOTATION, &mut buffer).is_none());
+
+        // A decimal point is never written without fraction digits.
+        let format = NumberFormat::REQUIRED_DIGITS;
+        let s = 1.2676506002282294e30f64.to_lexical_format_radix(2, format, &mut buffer).unwrap();
+        assert_eq!(s, b"1e1100100");
+        let s = 1e300f64.to_lexical_format_radix(2, format, &mut buffer).unwrap();
+        assert!(!s.windows(2).any(|w| w == b".e"));
+
+        // Hexadecimal float literals.
+        let options = WriteFloatOptions::builder()
+            .format(NumberFormat::C_LITERAL)
+            .radix(16)
+            .exponent_base(2)
+            .exponent_radix(10)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        assert_eq!(b"0x1.8p3", 12.0f64.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-0x1.8p-1", (-0.75f64).to_lexical_with_options(&mut buffer, &options));
+        let size = f64::buffer_size(&options);
+        #[cfg(feature = "trim_floats")]
+        assert_eq!(b"-0x1p-1074", (-5e-324f64).to_lexical_with_options(&mut buffer[..size], &options));
+        #[cfg(not(feature = "trim_floats"))]
+        assert_eq!(b"-0x1.0p-1074", (-5e-324f64).to_lexical_with_options(&mut buffer[..size], &options));
+    }
+
+    #[cfg(all(feature = "format", feature = "correct"))]
+    fn roundtrip_format(f: f64, format: NumberFormat) -> bool {
+        let mut buffer = [b'\0'; 1024];
+        match f.to_lexical_format(format, &mut buffer) {
+            Some(bytes) => {
+                let roundtrip = f64::from_lexical_format(bytes, format).unwrap();
+                (f.is_nan() && roundtrip.is_nan()) || f == roundtrip
+            },
+            None        => f.is_special() && format.no_special(),
+        }
+    }
+
+    #[cfg(all(feature = "format", feature = "correct"))]
+    #[test]
+    fn f64_format_roundtrip_test() {
+        let formats = [
+            NumberFormat::JSON,
+            NumberFormat::TOML,
+            NumberFormat::RUST_LITERAL,
+            NumberFormat::PYTHON3_LITERAL,
+            NumberFormat::CSHARP7_LITERAL,
+            NumberFormat::C_LITERAL,
+            NumberFormat::FORTRAN_LITERAL,
+            NumberFormat::REQUIRED_MANTISSA_SIGN | NumberFormat::REQUIRED_EXPONENT_SIGN,
+            NumberFormat::NO_EXPONENT_NOTATION,
+            NumberFormat::REQUIRED_DIGITS | NumberFormat::NO_EXPONENT_WITHOUT_FRACTION,
+        ];
+        for &format in formats.iter() {
+            for &f in F64_DATA.iter() {
+                assert!(roundtrip_format(f, format));
+                assert!(roundtrip_format(-f, format));
+            }
+            assert!(roundtrip_format(1e300, format));
+            assert!(roundtrip_format(5e-324, format));
+            assert!(roundtrip_format(f64::NAN, format));
+            assert!(roundtrip_format(f64::INFINITY, format));
+        }
+    }
+
+    #[cfg(all(feature = "format", feature = "correct"))]
+    quickcheck! {
+        fn f64_json_quickcheck(f: f64) -> bool {
+            roundtrip_format(f, NumberFormat::JSON)
+        }
+
+        fn f64_rust_literal_quickcheck(f: f64) -> bool {
+            roundtrip_format(f, NumberFormat::RUST_LITERAL)
+        }
+    }
+
     #[cfg(feature = "correct")]
     quickcheck! {
-        fn f32_quickcheck(f: f32) -> bool {
+        fn f32_quickcheck(f: f32) -> TestResult {
+            // NaN is never equal to itself, and is tested separately.
+            if f.is_nan() {
+                return TestResult::discard();
+            }
             let mut buffer = new_buffer();
-            f == f32::from_lexical(f.to_lexical(&mut buffer)).unwrap()
+            TestResult::from_bool(f == f32::from_lexical(f.to_lexical(&mut buffer)).unwrap())
         }
 
-        fn f64_quickcheck(f: f64) -> bool {
+        fn f64_quickcheck(f: f64) -> TestResult {
+            // NaN is never equal to itself, and is tested separately.
+            if f.is_nan() {
+                return TestResult::discard();
+            }
             let mut buffer = new_buffer();
-            f == f64::from_lexical(f.to_lexical(&mut buffer)).unwrap()
+            TestResult::from_bool(f == f64::from_lexical(f.to_lexical(&mut buffer)).unwrap())
         }
     }
 
@@ -456,4 +1095,14 @@ mod tests {
         let mut buffer = [b'0'; f64::FORMATTED_SIZE_DECIMAL-1];
         1.2345f64.to_lexical(&mut buffer);
     }
+
+    #[test]
+    fn try_write_test() {
+        let mut buffer = [b'0'; 4];
+        assert_eq!(crate::formatted_len(1.5f32), 3);
+        assert_eq!(crate::try_write(1.5f32, &mut buffer).map(|x| &*x), Ok(&b"1.5"[..]));
+        assert_eq!(crate::try_write(-1.25f64, &mut buffer), Err(WriteError { required: 5 }));
+        let length = crate::formatted_len(f64::MIN_POSITIVE);
+        assert_eq!(crate::try_write(f64::MIN_POSITIVE, &mut buffer), Err(WriteError { required: length }));
+    }
 }
@@ -0,0 +1,486 @@
+//! Exact digit generation for floats.
+//!
+//! Generates correctly rounded digits in any radix from the exact value
+//! of a float, using the big-integer arithmetic from the correct parser.
+//! This is the digit-generation half of Dragon4, and is used when a fixed
+//! number of digits is requested, or the exact value of the float is
+//! written. The shortest digits are only generated with Dragon4 for the
+//! formats without a shortest backend.
+
+use crate::atof::{integral_binary_factor, Bigint, LargeOps, Limb, SharedOps, SmallOps};
+use crate::lib::cmp;
+use crate::util::*;
+use super::notation::write_fixed;
+
+// DIGITS
+
+/// Position at which to stop generating digits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Cutoff {
+    /// Total number of significant digits.
+    Significant(usize),
+    /// Number of digits after the decimal point.
+    Fraction(usize),
+}
+
+/// Maximum number of significant digits in the exact representation
+/// of an `f64` (denormal floats have up to 767 significant digits).
+pub(crate) const MAX_DIGITS: usize = 768;
+
+/// Maximum number of significant digits in the exact representation
+/// of an `f64` in any radix with a terminating representation.
+///
+/// Radix 34 requires the most digits, with `~870` significant digits
+/// for denormal floats.
+pub(crate) const MAX_RADIX_DIGITS: usize = 1100;
+
+/// Estimate `floor(log10(2^exp))`, exactly for `exp` in `[-1100, 1100]`.
+#[inline]
+fn floor_log10_pow2(exp: i32) -> i32 {
+    (exp * 78913) >> 18
+}
+
+/// Estimate `floor(log(2^exp, radix))`, within 1 of the exact value.
+#[inline]
+fn floor_log_pow2(exp: i32, radix: u32) -> i32 {
+    // `floor(2^20 / log2(radix))` for each radix in `[2, 36]`.
+    const LOG_2: [i32; 35] = [
+        1048576, 661577, 524288, 451597, 405644, 373510, 349525, 330788,
+        315652, 303106, 292492, 283365, 275407, 268391, 262144, 256534,
+        251461, 246844, 242617, 238729, 235136, 231803, 228698, 225798,
+        223080, 220525, 218119, 215846, 213694, 211654, 209715, 207869,
+        206109, 204429, 202822,
+    ];
+    match radix {
+        10 => floor_log10_pow2(exp),
+        _  => ((exp as i64 * LOG_2[radix as usize - 2] as i64) >> 20) as i32,
+    }
+}
+
+/// Write the correctly rounded digits of a float.
+///
+/// The float must be finite, positive, and non-zero. The digits are
+/// written as ASCII characters, without trailing zeros, and rounded
+/// at the cutoff with the rounding kind, which must be `NearestTieEven`,
+/// `NearestTieAwayZero`, `Upward` or `Downward`. At most `digits.len()`
+/// digits are written, so `digits` must have at least `MAX_DIGITS`
+/// elements for decimal, or `MAX_RADIX_DIGITS` elements for any other
+/// radix, to write the exact value.
+///
+/// Returns the number of digits written and the exponent of the first
+/// digit, so the value is `0.d1d2d3... * radix^(exp+1)`. If the value
+/// rounds to zero, no digits are written.
+pub(crate) fn exact_digits<F: Float>(value: F, radix: u32, cutoff: Cutoff, kind: RoundingKind, digits: &mut [u8])
+    -> (usize, i32)
+{
+    debug_assert!(!value.is_special() && value > F::ZERO);
+    debug_assert!(radix >= 2 && radix <= 36);
+    debug_assert!(!digits.is_empty());
+
+    // The float is `mant * 2^exp2`, with `mant < 2^53`.
+    let mant = value.mantissa().as_u64();
+    let exp2 = value.exponent();
+    let bitlen = 64 - mant.leading_zeros() as i32;
+
+    // Scale the float so `num / den` is in the range `[1, radix)`,
+    // and the float is `num / den * radix^exp`.
+    let mut exp = floor_log_pow2(exp2 + bitlen - 1, radix);
+    let small_radix: Limb = as_cast(radix);
+    let mut num = Bigint::from_u64(mant);
+    let mut den = Bigint::from_u64(1);
+    if exp2 > 0 {
+        num.ishl(exp2 as usize);
+    } else {
+        den.ishl(-exp2 as usize);
+    }
+    if exp >= 0 {
+        den.imul_power(radix, exp as u32);
+    } else {
+        num.imul_power(radix, -exp as u32);
+    }
+    // The estimate may be 1 too small or too large.
+    let den_radix = den.mul_small(small_radix);
+    if num.greater_equal(&den_radix) {
+        exp += 1;
+        den = den_radix;
+    } else if num.less(&den) {
+        exp -= 1;
+        num.imul_small(small_radix);
+    }
+
+    // Scale the denominator so it has the number of bits in the radix
+    // as the number of leading zeros, so `quorem` produces a single digit.
+    let bits = <Limb as Integer>::BITS;
+    let wlz = integral_binary_factor(radix).as_usize();
+    let shift = (den.leading_zeros() + bits - wlz) % bits;
+    num.ishl(shift);
+    den.ishl(shift);
+
+    // Determine the number of digits to generate.
+    let count = match cutoff {
+        Cutoff::Significant(n) => n as i64,
+        Cutoff::Fraction(n)    => exp as i64 + 1 + n as i64,
+    };
+    if count <= 0 {
+        // Round `num / den * radix^(exp + count)`, which is in the
+        // range `(0, 1)`, to 0 or 1 at the cutoff.
+        let round_up = match (kind, count) {
+            (RoundingKind::Upward, _)   => true,
+            (RoundingKind::Downward, _) => false,
+            (_, 0)                      => {
+                // Round `num / (radix * den)`, which is in the range `[1/radix, 1)`.
+                num.ishl(1);
+                den.imul_small(small_radix);
+                match num.compare(&den) {
+                    cmp::Ordering::Greater => true,
+                    cmp::Ordering::Equal   => kind == RoundingKind::NearestTieAwayZero,
+                    cmp::Ordering::Less    => false,
+                }
+            },
+            _                           => false,
+        };
+        return match round_up {
+            true  => {
+                digits[0] = b'1';
+                (1, exp - count as i32 + 1)
+            },
+            false => (0, exp),
+        };
+    }
+    let count = (count as usize).min(digits.len());
+
+    // Generate the digits, stopping early if the value is exact.
+    let mut length = 0;
+    loop {
+        digits[length] = num.quorem(&den) as u8;
+        length += 1;
+        if length == count || num.is_zero() {
+            break;
+        }
+        num.imul_small(small_radix);
+    }
+
+    // Round using the remainder.
+    let round_up = match kind {
+        _ if num.is_zero()          => false,
+        RoundingKind::Upward        => true,
+        RoundingKind::Downward      => false,
+        _                           => {
+            num.ishl(1);
+            match num.compare(&den) {
+                cmp::Ordering::Greater => true,
+                cmp::Ordering::Equal   => kind == RoundingKind::NearestTieAwayZero || digits[length - 1] % 2 == 1,
+                cmp::Ordering::Less    => false,
+            }
+        },
+    };
+    if round_up {
+        let max_digit = radix as u8 - 1;
+        match digits[..length].iter().rposition(|&c| c != max_digit) {
+            Some(index) => {
+                digits[index] += 1;
+                length = index + 1;
+            },
+            None => {
+                // All digits were the maximum digit, carry to the next power of the radix.
+                digits[0] = 1;
+                length = 1;
+                exp += 1;
+            },
+        }
+    }
+
+    // Trim any trailing zeros, and convert the digits to characters.
+    while length > 1 && digits[length - 1] == 0 {
+        length -= 1;
+    }
+    for digit in digits[..length].iter_mut() {
+        *digit = digit_to_char(*digit);
+    }
+
+    (length, exp)
+}
+
+// SHORTEST
+
+// Get the shortest digits that round-trip, rounded to nearest, with
+// ties to even.
+//
+// Uses the free-format algorithm of Steele & White, as described by
+// Burger & Dybvig, with exact big-integer arithmetic. The float is
+// `mantissa * 2^exponent`, in a format with `precision` bits, where
+// the exponent of the last bit of denormal floats is `min_exp`. The
+// float must be finite, positive, and non-zero. Returns the number of
+// digits written and the decimal exponent of the first digit.
+#[cfg(any(feature = "f16", feature = "f128"))]
+pub(crate) fn shortest_digits<B: LargeOps>(mantissa: u128, exponent: i32, precision: i32, min_exp: i32, digits: &mut [u8])
+    -> (usize, i32)
+{
+    // Normalize the float, so the gaps to the adjacent floats are exact.
+    let bits = 128 - mantissa.leading_zeros() as i32;
+    debug_assert!(mantissa != 0 && bits <= precision);
+    let shift = cmp::min(precision - bits, exponent - min_exp).max(0);
+    let mant = mantissa << shift;
+    let exp = exponent - shift;
+    let bits = bits + shift;
+
+    // The value is `r / s`, and the gaps to the adjacent floats are
+    // `m_minus / s` and `m_plus / s`. The lower gap is half the upper
+    // gap at a power of 2.
+    let is_boundary = (mant == 1 << (precision - 1) && exp > min_exp) as usize;
+    let is_even = mant & 1 == 0;
+    let mut r = B::from_u128(mant);
+    let mut s = B::from_u128(1);
+    let mut m_plus = B::from_u128(1);
+    let mut m_minus = B::from_u128(1);
+    if exp >= 0 {
+        r.ishl(exp as usize + 1 + is_boundary);
+        s.ishl(1 + is_boundary);
+        m_plus.ishl(exp as usize + is_boundary);
+        m_minus.ishl(exp as usize);
+    } else {
+        r.ishl(1 + is_boundary);
+        s.ishl((1 - exp) as usize + is_boundary);
+        m_plus.ishl(is_boundary);
+    }
+
+    // Estimate the decimal exponent, which may be at most 2 too small,
+    // and scale the value so `r / s < 1`.
+    let mut k = ((exp + bits - 1) as i64 * 30103 / 100000) as i32;
+    if k >= 0 {
+        s.imul_pow10(k as u32);
+    } else {
+        r.imul_pow10(-k as u32);
+        m_plus.imul_pow10(-k as u32);
+        m_minus.imul_pow10(-k as u32);
+    }
+    let is_high = |r: &B, m_plus: &B, s: &B| {
+        let mut high = r.clone();
+        high.iadd_large(m_plus);
+        match high.compare(s) {
+            cmp::Ordering::Greater => true,
+            cmp::Ordering::Equal   => is_even,
+            cmp::Ordering::Less    => false,
+        }
+    };
+    while is_high(&r, &m_plus, &s) {
+        s.imul_small(10);
+        k += 1;
+    }
+
+    // Generate digits until the value is within the gaps.
+    let mut length = 0;
+    loop {
+        r.imul_small(10);
+        m_plus.imul_small(10);
+        m_minus.imul_small(10);
+        let mut digit = 0;
+        while r.greater_equal(&s) {
+            r.isub_large(&s);
+            digit += 1;
+        }
+        let is_low = match r.compare(&m_minus) {
+            cmp::Ordering::Less    => true,
+            cmp::Ordering::Equal   => is_even,
+            cmp::Ordering::Greater => false,
+        };
+        let is_high = is_high(&r, &m_plus, &s);
+        let digit = match (is_low, is_high) {
+            (false, false) => {
+                digits[length] = digit + b'0';
+                length += 1;
+                continue;
+            },
+            (true, false)  => digit,
+            (false, true)  => digit + 1,
+            (true, true)   => {
+                r.ishl(1);
+                match r.compare(&s) {
+                    cmp::Ordering::Less     => digit,
+                    cmp::Ordering::Equal    => digit + digit % 2,
+                    cmp::Ordering::Greater  => digit + 1,
+                }
+            },
+        };
+        debug_assert!(digit < 10);
+        digits[length] = digit + b'0';
+        return (length + 1, k - 1);
+    }
+}
+
+// WRITER
+
+// Write the exact value of a float in positional notation.
+//
+// If `max_digits` is provided, the value is rounded to that many
+// significant digits with the rounding kind, otherwise, the exact value
+// is written, and non-terminating representations are rounded to
+// nearest, tie-even, at `MAX_RADIX_DIGITS` significant digits.
+// Special values are written with the strings from the options.
+// `bytes` must have at least `EXACT_BUFFER_SIZE` elements.
+perftools_inline!{
+pub(crate) fn ftoa_exact<F: Float>(value: F, radix: u32, max_digits: Option<usize>, kind: RoundingKind, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    // Minimum number of fraction digits, as for the default writer.
+    let min_fraction = if cfg!(feature = "trim_floats") { 0 } else { 1 };
+
+    if value.is_nan() {
+        return copy_to_dst(bytes, options.nan_string());
+    }
+    let (value, sign, mut index) = match value.is_sign_negative() {
+        true if cfg!(feature = "trim_floats") && value.is_zero() => (F::ZERO, Sign::Positive, 0),
+        true  => {
+            index_mut!(bytes[0] = b'-');
+            (-value, Sign::Negative, 1)
+        },
+        false => (value, Sign::Positive, 0),
+    };
+    let bytes = &mut index_mut!(bytes[index..]);
+
+    index += if value.is_special() {
+        copy_to_dst(bytes, options.inf_string())
+    } else if value.is_zero() {
+        write_fixed(b"0", 0, min_fraction, options, bytes)
+    } else {
+        let mut digits: [u8; MAX_RADIX_DIGITS] = [b'0'; MAX_RADIX_DIGITS];
+        let (cutoff, kind) = match max_digits {
+            Some(n) => (Cutoff::Significant(n.max(1)), magnitude_rounding(kind, sign)),
+            None    => (Cutoff::Significant(MAX_RADIX_DIGITS), RoundingKind::NearestTieEven),
+        };
+        let (count, exp) = exact_digits(value, radix, cutoff, kind, &mut digits);
+        write_fixed(&digits[..count], exp, min_fraction, options, bytes)
+    };
+    index
+}}
+
+// TESTS
+// -----
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    fn check_radix<F: Float>(value: F, radix: u32, cutoff: Cutoff, kind: RoundingKind, expected: &[u8], exp10: i32) {
+        let mut digits = [0u8; MAX_RADIX_DIGITS];
+        let (length, exp) = exact_digits(value, radix, cutoff, kind, &mut digits);
+        assert_eq!(&digits[..length], expected);
+        assert_eq!(exp, exp10);
+    }
+
+    fn check<F: Float>(value: F, cutoff: Cutoff, expected: &[u8], exp10: i32) {
+        check_radix(value, 10, cutoff, RoundingKind::NearestTieEven, expected, exp10);
+    }
+
+    fn write(value: f64, radix: u32, max_digits: Option<usize>, kind: RoundingKind) -> String {
+        let mut buffer = [0u8; EXACT_BUFFER_SIZE];
+        let length = ftoa_exact(value, radix, max_digits, kind, &WriteFloatOptions::new(), &mut buffer);
+        String::from_utf8(buffer[..length].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn exact_digits_test() {
+        check(1.0f64, Cutoff::Significant(5), b"1", 0);
+        check(0.1f64, Cutoff::Significant(20), b"10000000000000000555", -1);
+        check(0.1f64, Cutoff::Significant(100), b"1000000000000000055511151231257827021181583404541015625", -1);
+        check(0.1f32, Cutoff::Significant(10), b"1000000015", -1);
+        check(123.456f64, Cutoff::Fraction(1), b"1235", 2);
+        check(9.99f64, Cutoff::Fraction(1), b"1", 1);
+        check(1e300f64, Cutoff::Significant(3), b"1", 300);
+        check(5e-324f64, Cutoff::Significant(3), b"494", -324);
+        check(1.7976931348623157e308f64, Cutoff::Significant(17), b"17976931348623157", 308);
+    }
+
+    #[test]
+    fn exact_digits_round_test() {
+        // Ties are exact in binary and round to even.
+        check(0.5f64, Cutoff::Fraction(0), b"", -1);
+        check(1.5f64, Cutoff::Fraction(0), b"2", 0);
+        check(2.5f64, Cutoff::Fraction(0), b"2", 0);
+        check(0.125f64, Cutoff::Fraction(2), b"12", -1);
+        check(0.375f64, Cutoff::Fraction(2), b"38", -1);
+        // Not a tie, since 2.675 is below the halfway point.
+        check(2.675f64, Cutoff::Fraction(2), b"267", 0);
+        // Rounds to the next power of 10, or to zero.
+        check(0.006f64, Cutoff::Fraction(2), b"1", -2);
+        check(0.004f64, Cutoff::Fraction(2), b"", -3);
+        check(0.0004f64, Cutoff::Fraction(2), b"", -4);
+    }
+
+    #[test]
+    fn exact_digits_rounding_test() {
+        use RoundingKind::*;
+        check_radix(0.5f64, 10, Cutoff::Fraction(0), NearestTieAwayZero, b"1", 0);
+        check_radix(2.5f64, 10, Cutoff::Fraction(0), NearestTieAwayZero, b"3", 0);
+        check_radix(2.1f64, 10, Cutoff::Fraction(0), Upward, b"3", 0);
+        check_radix(2.9f64, 10, Cutoff::Fraction(0), Downward, b"2", 0);
+        check_radix(2.0f64, 10, Cutoff::Fraction(0), Upward, b"2", 0);
+        check_radix(0.1f64, 10, Cutoff::Significant(17), Downward, b"1", -1);
+        check_radix(0.1f64, 10, Cutoff::Significant(17), Upward, b"10000000000000001", -1);
+        check_radix(9.5f64, 10, Cutoff::Significant(1), Upward, b"1", 1);
+        // Rounds to the first digit after the cutoff, or to zero.
+        check_radix(0.0004f64, 10, Cutoff::Fraction(2), Upward, b"1", -2);
+        check_radix(0.004f64, 10, Cutoff::Fraction(2), Upward, b"1", -2);
+        check_radix(0.005f64, 10, Cutoff::Fraction(2), NearestTieAwayZero, b"1", -2);
+        check_radix(0.0004f64, 10, Cutoff::Fraction(2), Downward, b"", -4);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn exact_digits_radix_test() {
+        use RoundingKind::*;
+        check_radix(0.5f64, 2, Cutoff::Significant(10), NearestTieEven, b"1", -1);
+        check_radix(0.1f32, 2, Cutoff::Significant(100), NearestTieEven, b"110011001100110011001101", -4);
+        check_radix(255.0f64, 16, Cutoff::Significant(10), NearestTieEven, b"FF", 1);
+        check_radix(35.0f64, 36, Cutoff::Significant(10), NearestTieEven, b"Z", 0);
+        // The remainder is exactly halfway, since `0.5` is `0.111...` in radix 3.
+        check_radix(0.5f64, 3, Cutoff::Significant(5), NearestTieEven, b"11112", -1);
+        check_radix(0.5f64, 3, Cutoff::Significant(5), Downward, b"11111", -1);
+        check_radix(5e-324f64, 2, Cutoff::Significant(10), NearestTieEven, b"1", -1074);
+        check_radix(1.7976931348623157e308f64, 2, Cutoff::Significant(100), NearestTieEven, &[b'1'; 53], 1023);
+    }
+
+    #[cfg(not(feature = "trim_floats"))]
+    #[test]
+    fn ftoa_exact_test() {
+        use RoundingKind::*;
+        assert_eq!(write(0.1, 10, None, NearestTieEven), "0.1000000000000000055511151231257827021181583404541015625");
+        assert_eq!(write(-0.1, 10, Some(3), NearestTieEven), "-0.1");
+        assert_eq!(write(1e21, 10, None, NearestTieEven), "1000000000000000000000.0");
+        assert_eq!(write(123.456, 10, Some(2), TowardPositiveInfinity), "130.0");
+        assert_eq!(write(-123.456, 10, Some(2), TowardPositiveInfinity), "-120.0");
+        assert_eq!(write(-123.456, 10, Some(2), TowardNegativeInfinity), "-130.0");
+        assert_eq!(write(-123.456, 10, Some(0), TowardZero), "-100.0");
+        assert_eq!(write(f64::NAN, 10, None, NearestTieEven), "NaN");
+        assert_eq!(write(f64::NEG_INFINITY, 10, None, NearestTieEven), "-inf");
+
+        let mut buffer = [0u8; EXACT_BUFFER_SIZE];
+        let length = ftoa_exact(5e-324f64, 10, None, NearestTieEven, &WriteFloatOptions::new(), &mut buffer);
+        assert_eq!(length, 2 + 323 + 751);
+        assert!(buffer[2..325].iter().all(|&c| c == b'0'));
+        assert!(buffer[325..].starts_with(b"49406564584124654417656879286822137236505980"));
+        #[cfg(feature = "radix")] {
+            assert_eq!(write(0.75, 2, None, NearestTieEven), "0.11");
+            let length = ftoa_exact(5e-324f64, 3, None, NearestTieEven, &WriteFloatOptions::new(), &mut buffer);
+            assert!(length <= EXACT_BUFFER_SIZE);
+        }
+
+        assert_eq!(write(0.0, 10, None, RoundingKind::NearestTieEven), "0.0");
+        assert_eq!(write(-0.0, 10, None, RoundingKind::NearestTieEven), "-0.0");
+    }
+
+    #[test]
+    fn ftoa_exact_special_test() {
+        use RoundingKind::*;
+        let options = WriteFloatOptions::builder()
+            .nan_string(b"nan")
+            .inf_string(b"Infinity")
+            .build()
+            .unwrap();
+        let mut buffer = [0u8; EXACT_BUFFER_SIZE];
+        let length = ftoa_exact(f64::NAN, 10, None, NearestTieEven, &options, &mut buffer);
+        assert_eq!(&buffer[..length], b"nan");
+        let length = ftoa_exact(f64::NEG_INFINITY, 10, None, NearestTieEven, &options, &mut buffer);
+        assert_eq!(&buffer[..length], b"-Infinity");
+    }
+}
@@ -0,0 +1,155 @@
+//! Shortest float-to-string conversion for extended-precision floats.
+//!
+//! Generates the shortest digits that round-trip with the free-format
+//! algorithm of Steele & White, as described by Burger & Dybvig, using
+//! exact big-integer arithmetic.
+
+use crate::atof::ExtendedBigint;
+use crate::util::*;
+use super::exact::shortest_digits;
+use super::notation::{write_fixed, write_scientific};
+
+/// Maximum number of digits in the shortest representation of any format.
+const MAX_DIGITS: usize = 40;
+
+/// Write an extended-precision float with the shortest representation.
+///
+/// The buffer must have at least `EXTENDED_FORMATTED_SIZE_DECIMAL` bytes.
+/// Returns the number of bytes written.
+pub(crate) fn etoa<T: ExtendedPrecision>(value: T, bytes: &mut [u8])
+    -> usize
+{
+    // Minimum number of fraction digits for positional floats.
+    let min_fraction = if cfg!(feature = "trim_floats") { 0 } else { 1 };
+    let (is_negative, mantissa, exponent) = match value.to_parts() {
+        FloatParts::Finite { is_negative, mantissa, exponent } => (is_negative, mantissa, exponent),
+        FloatParts::Infinite { is_negative } => {
+            let index = is_negative as usize;
+            index_mut!(bytes[0] = b'-');
+            return index + copy_to_dst(&mut index_mut!(bytes[index..]), get_inf_string());
+        },
+        FloatParts::Nan => return copy_to_dst(bytes, get_nan_string()),
+    };
+
+    let index = is_negative as usize;
+    index_mut!(bytes[0] = b'-');
+    let bytes = &mut index_mut!(bytes[index..]);
+    let options = WriteFloatOptions::new();
+    if mantissa == 0 {
+        return index + write_fixed(&[], 0, min_fraction, &options, bytes);
+    }
+
+    // Use scientific notation for the same exponents as the decimal backend.
+    let mut digits = [0u8; MAX_DIGITS];
+    let precision = T::MANTISSA_DIGITS as i32;
+    let min_exp = T::MIN_EXP - precision;
+    let (count, exp10) = shortest_digits::<ExtendedBigint>(mantissa, exponent, precision, min_exp, &mut digits);
+    index + match exp10 < -5 || exp10 >= 16 {
+        true  => write_scientific(&digits[..count], exp10, 0, &options, bytes),
+        false => write_fixed(&digits[..count], exp10, min_fraction, &options, bytes),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_partial_extended;
+    use super::*;
+
+    fn write<T: ExtendedPrecision>(value: T) -> Vec<u8> {
+        let mut buffer = [0u8; EXTENDED_FORMATTED_SIZE_DECIMAL];
+        let length = etoa(value, &mut buffer);
+        buffer[..length].to_vec()
+    }
+
+    // Compare to the shortest representation from the decimal backend.
+    #[test]
+    #[cfg(all(feature = "ryu", not(feature = "grisu3"), not(feature = "trim_floats")))]
+    fn f64_test() {
+        let check = |value: f64| {
+            let mut buffer = [0u8; 64];
+            assert_eq!(write(value), value.to_lexical(&mut buffer).to_vec());
+        };
+        check(0.0);
+        check(-0.0);
+        check(1.0);
+        check(-1.5);
+        check(0.1);
+        check(1e15);
+        check(1e16);
+        check(1.2345678901234567e-5);
+        check(1.2345678901234567e-6);
+        check(5e-324);
+        check(2.2250738585072014e-308);
+        check(1.7976931348623157e308);
+        check(9007199254740993.0);
+        check(f64::INFINITY);
+        check(f64::NEG_INFINITY);
+        check(f64::NAN);
+    }
+
+    #[test]
+    fn f128_test() {
+        let one = if cfg!(feature = "trim_floats") { &b"1"[..] } else { &b"1.0"[..] };
+        assert_eq!(write(f128::from_bits(0x3FFF << 112)), one);
+        assert_eq!(write(f128::from_bits(0x3FFB_9999_9999_9999_9999_9999_9999_999A)), b"0.1");
+        assert_eq!(write(f128::from_bits(0x7FFE_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF)), &b"1.189731495357231765085759326628007e4932"[..]);
+        assert_eq!(write(f128::from_bits(1)), b"6e-4966");
+        assert_eq!(write(f128::from_bits(1 << 112)), &b"3.3621031431120935062626778173217526e-4932"[..]);
+        assert_eq!(write(f128::from_bits((0x3FFF << 112) | 1)), b"1.0000000000000000000000000000000002");
+        assert_eq!(write(f128::from_f64(0.1)), b"0.1000000000000000055511151231257827");
+        assert_eq!(write(f128::from_bits(0xFFFF << 112)), b"-inf");
+        assert_eq!(write(f128::from_bits(0x7FFF_8000 << 96)), b"NaN");
+    }
+
+    #[test]
+    fn f80_test() {
+        assert_eq!(write(f80::from_bits(0x3FFB_CCCC_CCCC_CCCC_CCCD)), b"0.1");
+        assert_eq!(write(f80::from_bits(0x7FFE_FFFF_FFFF_FFFF_FFFF)), b"1.189731495357231765e4932");
+        assert_eq!(write(f80::from_bits(1)), b"4e-4951");
+        assert_eq!(write(f80::from_bits(0xBFFF_C000_0000_0000_0000)), &b"-1.5"[..]);
+        assert_eq!(write(f80::from_bits(0x7FFF_8000_0000_0000_0000)), b"inf");
+        assert_eq!(write(f80::from_bits(0x7FFF_C000_0000_0000_0000)), b"NaN");
+    }
+
+    #[test]
+    fn f80_special_roundtrip_test() {
+        for &string in [&b"inf"[..], b"-inf"].iter() {
+            let (parsed, _) = parse_partial_extended::<f80>(string).unwrap();
+            assert_eq!(write(parsed), string);
+        }
+        let (parsed, _) = parse_partial_extended::<f80>(b"NaN").unwrap();
+        assert_eq!(write(parsed), b"NaN");
+    }
+
+    #[cfg(all(feature = "std", feature = "ryu", not(feature = "grisu3"), not(feature = "trim_floats")))]
+    proptest! {
+        #[test]
+        fn f64_proptest(bits in 0u64..0x7FF0_0000_0000_0000) {
+            let value = f64::from_bits(bits);
+            let mut buffer = [0u8; 64];
+            prop_assert_eq!(write(value), value.to_lexical(&mut buffer).to_vec());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn f128_roundtrip_proptest(hi in 0u64..0x7FFF_0000_0000_0000, lo: u64) {
+            let value = f128::from_bits(((hi as u128) << 64) | lo as u128);
+            let bytes = write(value);
+            let (parsed, _) = parse_partial_extended::<f128>(&bytes).unwrap();
+            prop_assert_eq!(parsed.to_bits(), value.to_bits());
+        }
+
+        #[test]
+        fn f80_roundtrip_proptest(hi in 1u16..0x7FFF, lo: u64) {
+            let value = f80::from_bits(((hi as u128) << 64) | (lo | 1 << 63) as u128);
+            let bytes = write(value);
+            let (parsed, _) = parse_partial_extended::<f80>(&bytes).unwrap();
+            prop_assert_eq!(parsed.to_bits(), value.to_bits());
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Fast lexical float-to-string conversion routines.
 
 // Hide implementation details.
+#[cfg(feature = "radix")]
+mod pow2;
+
 #[cfg(feature = "radix")]
 mod radix;
 
@@ -14,3 +17,13 @@ if #[cfg(feature = "grisu3")] {
 }}  // cfg_if
 
 mod api;
+mod exact;
+mod notation;
+
+#[cfg(feature = "f128")]
+mod extended;
+
+pub(crate) use self::exact::ftoa_exact;
+
+#[cfg(feature = "f128")]
+pub(crate) use self::extended::etoa;
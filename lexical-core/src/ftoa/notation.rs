@@ -0,0 +1,568 @@
+//! Float formatting with a custom notation and precision.
+//!
+//! The shortest digits are extracted from the output of the decimal
+//! backend, while digits for a fixed precision are generated from the
+//! exact value of the float, so the digit string is correctly rounded.
+
+use crate::itoa;
+use crate::lib::iter;
+use crate::util::*;
+use super::api::FloatToString;
+use super::exact::{exact_digits, Cutoff, MAX_DIGITS};
+
+// DIGITS
+
+// Parse the exponent written by the backend, in the exponent radix.
+perftools_inline!{
+fn parse_exponent(bytes: &[u8], radix: u32) -> i32 {
+    let (is_negative, digits) = match bytes.first() {
+        Some(&b'-') => (true, &bytes[1..]),
+        Some(&b'+') => (false, &bytes[1..]),
+        _           => (false, bytes),
+    };
+    let exp = digits.iter().fold(0i32, |exp, &c| {
+        let digit = (c as char).to_digit(radix).unwrap_or(0);
+        exp * radix as i32 + digit as i32
+    });
+    match is_negative {
+        true  => -exp,
+        false => exp,
+    }
+}}
+
+// Write the shortest representation of the float with the backend.
+//
+// Returns the number of bytes written and the exponent character.
+perftools_inline!{
+fn write_shortest<F: FloatToString>(value: F, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> (usize, u8)
+{
+    #[cfg(not(feature = "radix"))] {
+        let _ = options;
+        (value.decimal(bytes), b'e')
+    }
+
+    #[cfg(feature = "radix")] {
+        let radix = options.radix_u32();
+        let exponent_char = options.exponent_char();
+        if options.is_pow2() {
+            return (value.pow2(options, bytes), exponent_char);
+        }
+        // The decimal backends always write `e` as the exponent character.
+        match radix {
+            10    => (value.decimal(bytes), b'e'),
+            radix => (value.radix(radix, exponent_char, bytes), exponent_char),
+        }
+    }
+}}
+
+// Get the shortest digits that round-trip from the backend.
+//
+// The float must be finite, positive, and non-zero. Returns the number
+// of digits, the exponent of the first digit in the exponent base, and
+// if the backend used scientific notation.
+perftools_inline!{
+fn shortest_digits<F: FloatToString>(value: F, options: &WriteFloatOptions, digits: &mut [u8])
+    -> (usize, i32, bool)
+{
+    let mut buffer: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
+    let (len, exponent_char) = write_shortest(value, options, &mut buffer);
+    let buffer = &buffer[..len];
+
+    #[cfg(not(feature = "radix"))]
+    let exponent_radix = 10;
+    #[cfg(feature = "radix")]
+    let exponent_radix = options.exponent_radix_u32();
+    let (mantissa, exp, is_scientific) = match buffer.iter().position(|&c| c == exponent_char) {
+        Some(index) => (&buffer[..index], parse_exponent(&buffer[index+1..], exponent_radix), true),
+        None        => (buffer, 0, false),
+    };
+    let point = mantissa.iter().position(|&c| c == b'.').unwrap_or(mantissa.len());
+
+    // Copy the significant digits, skipping the leading zeros.
+    let mut count = 0;
+    let mut leading = 0;
+    for &c in mantissa.iter().filter(|&&c| c != b'.') {
+        if count == 0 && c == b'0' {
+            leading += 1;
+        } else {
+            digits[count] = c;
+            count += 1;
+        }
+    }
+    while count > 1 && digits[count - 1] == b'0' {
+        count -= 1;
+    }
+
+    (count, exp + point as i32 - leading - 1, is_scientific)
+}}
+
+// WRITER
+
+// Write the fraction digits, padding with trailing zeros.
+//
+// Fraction digits are grouped with the digit separator, if enabled.
+perftools_inline!{
+fn write_fraction(bytes: &mut [u8], zeros: usize, digits: &[u8], min_digits: usize, options: &WriteFloatOptions)
+    -> usize
+{
+    let length = zeros + digits.len();
+    if length == 0 && min_digits == 0 {
+        return 0;
+    }
+
+    index_mut!(bytes[0] = b'.');
+    let grouping = match options.group_fraction() {
+        true  => options.grouping(),
+        false => DigitGrouping::None,
+    };
+    let digits = iter::repeat(b'0').take(zeros)
+        .chain(digits.iter().cloned())
+        .chain(iter::repeat(b'0').take(min_digits.saturating_sub(length)));
+    1 + write_fraction_digits(&mut index_mut!(bytes[1..]), digits, options.digit_separator(), grouping)
+}}
+
+// Write the integer digits, grouped with the digit separator.
+//
+// The integer has `count` digits, padded with trailing zeros.
+perftools_inline!{
+fn write_integer(bytes: &mut [u8], count: usize, digits: &[u8], options: &WriteFloatOptions)
+    -> usize
+{
+    let digits = digits.iter().cloned().chain(iter::repeat(b'0'));
+    write_integer_digits(bytes, count, digits, options.digit_separator(), options.grouping())
+}}
+
+// Write digits in scientific notation.
+//
+// The exponent character, the exponent radix, and the exponent sign
+// and fraction digits required by the format are taken from the options.
+perftools_inline!{
+pub(crate) fn write_scientific(digits: &[u8], exp: i32, min_fraction: usize, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    debug_assert!(!digits.is_empty());
+
+    #[cfg(not(feature = "radix"))]
+    let exponent_radix = 10;
+    #[cfg(feature = "radix")]
+    let exponent_radix = options.exponent_radix_u32();
+
+    let min_fraction = match options.no_exponent_without_fraction() {
+        true  => min_fraction.max(1),
+        false => min_fraction,
+    };
+    index_mut!(bytes[0] = digits[0]);
+    let mut index = 1;
+    index += write_fraction(&mut index_mut!(bytes[index..]), 0, &digits[1..], min_fraction, options);
+    index + write_exponent(exp, exponent_radix, options, &mut index_mut!(bytes[index..]))
+}}
+
+// Write the exponent character, sign and digits.
+perftools_inline!{
+fn write_exponent(exp: i32, exponent_radix: u32, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    index_mut!(bytes[0] = options.exponent_char());
+    let mut index = 1;
+    if exp < 0 {
+        index_mut!(bytes[index] = b'-');
+        index += 1;
+    } else if options.required_exponent_sign() {
+        index_mut!(bytes[index] = b'+');
+        index += 1;
+    }
+    let exp = (exp as i64).abs() as u32;
+    index + itoa::itoa_positive(exp, exponent_radix, &mut index_mut!(bytes[index..]))
+}}
+
+// Write a float exactly with a power-of-two radix and exponent base.
+//
+// The float must be finite, positive, and non-zero. The backend may
+// write more than one integer digit, if the exponent base is larger
+// than the radix, so the integer and fraction digits are written as-is.
+perftools_inline!{
+#[cfg(feature = "radix")]
+fn write_pow2<F: FloatToString>(value: F, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    let mut buffer: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
+    let len = value.pow2(options, &mut buffer);
+    let buffer = &buffer[..len];
+
+    let exponent_radix = options.exponent_radix_u32();
+    let (mantissa, exp) = match buffer.iter().position(|&c| c == options.exponent_char()) {
+        Some(index) => (&buffer[..index], parse_exponent(&buffer[index+1..], exponent_radix)),
+        None        => (buffer, 0),
+    };
+    let (integer, fraction) = match mantissa.iter().position(|&c| c == b'.') {
+        Some(index) => (&mantissa[..index], &mantissa[index+1..]),
+        None        => (mantissa, &[][..]),
+    };
+    let min_fraction = options.no_exponent_without_fraction() as usize;
+
+    let mut index = write_integer(bytes, integer.len(), integer, options);
+    index += write_fraction(&mut index_mut!(bytes[index..]), 0, fraction, min_fraction, options);
+    index + write_exponent(exp, exponent_radix, options, &mut index_mut!(bytes[index..]))
+}}
+
+// Write digits in positional notation.
+//
+// If `digits` is empty, the value was rounded to zero.
+perftools_inline!{
+pub(crate) fn write_fixed(digits: &[u8], exp: i32, min_fraction: usize, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    if digits.is_empty() {
+        index_mut!(bytes[0] = b'0');
+        1 + write_fraction(&mut index_mut!(bytes[1..]), 0, &[], min_fraction, options)
+    } else if exp < 0 {
+        let zeros = (-exp - 1) as usize;
+        index_mut!(bytes[0] = b'0');
+        1 + write_fraction(&mut index_mut!(bytes[1..]), zeros, digits, min_fraction, options)
+    } else {
+        let integer_length = exp as usize + 1;
+        let (integer, fraction) = match digits.len() > integer_length {
+            true  => digits.split_at(integer_length),
+            false => (digits, &[][..]),
+        };
+        let index = write_integer(bytes, integer_length, integer, options);
+        index + write_fraction(&mut index_mut!(bytes[index..]), 0, fraction, min_fraction, options)
+    }
+}}
+
+// Write a finite, positive float with the notation and precision.
+//
+// Uses the shortest digits and exponent, if provided, rather than
+// the shortest digits of the float from the backend.
+perftools_inline!{
+fn write_positive<F: FloatToString>(value: F, shortest: Option<(&[u8], i32)>, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+{
+    // Minimum number of fraction digits for positional floats without a precision.
+    let min_fraction = if cfg!(feature = "trim_floats") { 0 } else { 1 };
+    let precision = options.precision();
+    let positional = options.no_exponent_notation();
+
+    #[cfg(feature = "radix")] {
+        if options.is_pow2() && !value.is_zero() {
+            return write_pow2(value, options, bytes);
+        }
+    }
+
+    let mut digits: [u8; MAX_DIGITS] = [b'0'; MAX_DIGITS];
+    let (count, exp10, is_scientific) = match value.is_zero() {
+        true  => (1, 0, false),
+        false => shortest_digits(value, options, &mut digits),
+    };
+    let (count, exp10) = match shortest {
+        Some((shortest, exp10)) => (copy_to_dst(&mut digits, shortest), exp10),
+        None                    => (count, exp10),
+    };
+    // Generate the exactly rounded digits for the cutoff, if required.
+    let exact = | cutoff, digits: &mut [u8] | match value.is_zero() {
+        true  => (1, 0),
+        false => exact_digits(value, 10, cutoff, RoundingKind::NearestTieEven, digits),
+    };
+
+    match options.notation() {
+        FloatNotation::Shortest => {
+            let max_digits = precision.unwrap_or(MAX_DIGITS).max(1);
+            let (count, exp10) = match count > max_digits {
+                true  => exact(Cutoff::Significant(max_digits), &mut digits),
+                false => (count, exp10),
+            };
+            match is_scientific && !positional {
+                true  => write_scientific(&digits[..count], exp10, 0, options, bytes),
+                false => write_fixed(&digits[..count], exp10, min_fraction, options, bytes),
+            }
+        },
+        FloatNotation::Scientific => {
+            let (count, exp10) = match precision {
+                Some(p) => exact(Cutoff::Significant(p + 1), &mut digits),
+                None    => (count, exp10),
+            };
+            let min_fraction = precision.unwrap_or(0);
+            write_scientific(&digits[..count], exp10, min_fraction, options, bytes)
+        },
+        FloatNotation::Fixed => {
+            let (count, exp10, min_fraction) = match precision {
+                Some(p) => {
+                    let (count, exp10) = exact(Cutoff::Fraction(p), &mut digits);
+                    (count, exp10, p)
+                },
+                None    => (count, exp10, min_fraction),
+            };
+            write_fixed(&digits[..count], exp10, min_fraction, options, bytes)
+        },
+        FloatNotation::General => {
+            let max_digits = precision.unwrap_or(F::SHORTEST_DIGITS).max(1);
+            let (count, exp10) = match precision {
+                Some(_) => exact(Cutoff::Significant(max_digits), &mut digits),
+                None    => (count, exp10),
+            };
+            match (exp10 < -4 || exp10 >= max_digits as i32) && !positional {
+                true  => write_scientific(&digits[..count], exp10, 0, options, bytes),
+                false => write_fixed(&digits[..count], exp10, 0, options, bytes),
+            }
+        },
+    }
+}}
+
+// Write float to string with the notation, precision and format.
+//
+// The positive sign and the base prefix required by the format are
+// only written before finite floats.
+perftools_inline!{
+pub(crate) fn ftoa_notation<F: FloatToString>(value: F, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+{
+    ftoa_notation_shortest(value, None, bytes, options)
+}}
+
+// Write float to string with the notation, precision, format, and
+// the shortest digits and exponent of the float, if provided.
+perftools_inline!{
+pub(crate) fn ftoa_notation_shortest<F: FloatToString>(value: F, shortest: Option<(&[u8], i32)>, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+{
+    // Export "-0.0" as "0" with trimmed floats, like the backends.
+    let value = match cfg!(feature = "trim_floats") && value.is_zero() && options.is_shortest() {
+        true  => F::ZERO,
+        false => value,
+    };
+    let (value, mut index) = match value.is_sign_negative() {
+        true  => {
+            index_mut!(bytes[0] = b'-');
+            (-value, 1)
+        },
+        false if options.required_mantissa_sign() && !value.is_special() => {
+            index_mut!(bytes[0] = b'+');
+            (value, 1)
+        },
+        false => (value, 0),
+    };
+    if let Some(prefix) = options.base_prefix() {
+        if !value.is_special() {
+            index += copy_to_dst(&mut index_mut!(bytes[index..]), prefix);
+        }
+    }
+    index + write_special(value, shortest, &mut index_mut!(bytes[index..]), options)
+}}
+
+// Write special or finite float.
+perftools_inline!{
+fn write_special<F: FloatToString>(value: F, shortest: Option<(&[u8], i32)>, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+{
+    if value.is_nan() {
+        write_nan(value, bytes, options)
+    } else if value.is_special() {
+        copy_to_dst(bytes, options.inf_string())
+    } else {
+        write_positive(value, shortest, bytes, options)
+    }
+}}
+
+// Write NaN, with the payload in parentheses if requested.
+perftools_inline!{
+pub(crate) fn write_nan<F: FloatToString>(value: F, bytes: &mut [u8], options: &WriteFloatOptions)
+    -> usize
+{
+    const HEX_DIGITS: &[u8] = b"0123456789abcdef";
+    // The payload is the mantissa bits below the quiet bit, and NaNs
+    // without the quiet bit are signaling NaNs, written with an `s`
+    // before the NaN string. Nothing is written for the default NaN.
+    let quiet = F::HIDDEN_BIT_MASK >> 1;
+    let bits = value.to_bits();
+    let mut len = 0;
+    if options.nan_payload() && (bits & quiet).is_zero() {
+        index_mut!(bytes[0] = b's');
+        len += 1;
+    }
+    len += copy_to_dst(&mut index_mut!(bytes[len..]), options.nan_string());
+    let payload: u64 = as_cast(bits & (quiet - F::Unsigned::ONE));
+    if !options.nan_payload() || payload == 0 {
+        return len;
+    }
+
+    let digits = payload.bit_length().ceil_div(4) as usize;
+    let bytes = &mut index_mut!(bytes[len..len + digits + 4]);
+    copy_to_dst(bytes, b"(0x");
+    for i in 0..digits {
+        let nibble = (payload >> (4 * (digits - i - 1))) & 0xF;
+        index_mut!(bytes[i + 3] = index!(HEX_DIGITS[nibble as usize]));
+    }
+    index_mut!(bytes[digits + 3] = b')');
+
+    len + digits + 4
+}}
+
+// Get the number of bytes required to write any float with the notation.
+perftools_inline!{
+pub(crate) fn notation_size<F: FloatToString>(options: &WriteFloatOptions)
+    -> usize
+{
+    // Bytes for the sign, the decimal point, and the exponent.
+    const SCIENTIFIC_OVERHEAD: usize = 8;
+    let precision = options.precision();
+    let positional = options.no_exponent_notation();
+    let fixed = F::MAX_INTEGER_DIGITS.max(F::MAX_FRACTION_DIGITS) + 3;
+    match options.notation() {
+        FloatNotation::Shortest if positional => fixed,
+        // Exact digits for the precision may extend past the shortest digits.
+        FloatNotation::General if positional => match precision {
+            Some(p) => F::MAX_INTEGER_DIGITS.max(F::MAX_FRACTION_DIGITS + p) + 3,
+            None    => fixed,
+        },
+        FloatNotation::Shortest     => 0,
+        FloatNotation::Scientific   => precision.unwrap_or(F::SHORTEST_DIGITS) + SCIENTIFIC_OVERHEAD,
+        FloatNotation::General      => precision.unwrap_or(F::SHORTEST_DIGITS).max(1) + SCIENTIFIC_OVERHEAD,
+        FloatNotation::Fixed        => match precision {
+            Some(p) => F::MAX_INTEGER_DIGITS + p + 2,
+            None    => fixed,
+        },
+    }
+}}
+
+// Get the number of extra bytes required to write any float with the format.
+perftools_inline!{
+pub(crate) fn format_size(options: &WriteFloatOptions)
+    -> usize
+{
+    // Bytes for the mantissa and exponent signs, a ".0" fraction
+    // before the exponent, and the base prefix.
+    let mut size = options.required_mantissa_sign() as usize;
+    size += options.required_exponent_sign() as usize;
+    if options.no_exponent_without_fraction() {
+        size += 2;
+    }
+    size + options.base_prefix().map_or(0, |prefix| prefix.len())
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write<F: FloatToString>(value: F, notation: FloatNotation, precision: Option<usize>, buffer: &mut [u8])
+        -> usize
+    {
+        let options = WriteFloatOptions::builder()
+            .notation(notation)
+            .precision(precision)
+            .build()
+            .unwrap();
+        assert!(buffer.len() >= notation_size::<F>(&options));
+        ftoa_notation(value, buffer, &options)
+    }
+
+    fn check<F: FloatToString>(value: F, notation: FloatNotation, precision: Option<usize>, expected: &[u8]) {
+        let mut buffer = [0u8; 512];
+        let len = write(value, notation, precision, &mut buffer);
+        assert_eq!(&buffer[..len], expected);
+    }
+
+    #[test]
+    fn shortest_digits_test() {
+        let options = WriteFloatOptions::new();
+        let mut digits = [0u8; MAX_DIGITS];
+        let (count, exp10, _) = shortest_digits(123.456f64, &options, &mut digits);
+        assert_eq!(&digits[..count], b"123456");
+        assert_eq!(exp10, 2);
+
+        let (count, exp10, is_scientific) = shortest_digits(1.5e-300f64, &options, &mut digits);
+        assert_eq!(&digits[..count], b"15");
+        assert_eq!(exp10, -300);
+        assert!(is_scientific);
+
+        let (count, exp10, _) = shortest_digits(0.001f64, &options, &mut digits);
+        assert_eq!(&digits[..count], b"1");
+        assert_eq!(exp10, -3);
+
+        let (count, exp10, _) = shortest_digits(100.0f32, &options, &mut digits);
+        assert_eq!(&digits[..count], b"1");
+        assert_eq!(exp10, 2);
+    }
+
+    #[test]
+    fn fixed_test() {
+        check(3.14159f64, FloatNotation::Fixed, Some(3), b"3.142");
+        check(-3.14159f64, FloatNotation::Fixed, Some(0), b"-3");
+        check(0.5f64, FloatNotation::Fixed, Some(0), b"0");
+        check(1.5f64, FloatNotation::Fixed, Some(0), b"2");
+        check(2.675f64, FloatNotation::Fixed, Some(2), b"2.67");
+        check(9.999f64, FloatNotation::Fixed, Some(2), b"10.00");
+        check(0.0004f64, FloatNotation::Fixed, Some(3), b"0.000");
+        check(0.0f64, FloatNotation::Fixed, Some(2), b"0.00");
+        check(-0.0f64, FloatNotation::Fixed, Some(2), b"-0.00");
+        check(1e21f64, FloatNotation::Fixed, Some(1), b"1000000000000000000000.0");
+        check(0.1f64, FloatNotation::Fixed, Some(20), b"0.10000000000000000555");
+        check(0.1f32, FloatNotation::Fixed, Some(10), b"0.1000000015");
+        check(1e-7f64, FloatNotation::Fixed, None, b"0.0000001");
+        #[cfg(not(feature = "trim_floats"))]
+        assert_eq!(write(1.5e300f64, FloatNotation::Fixed, None, &mut [0u8; 512]), 303);
+        check(f64::NAN, FloatNotation::Fixed, Some(2), b"NaN");
+        check(f64::NEG_INFINITY, FloatNotation::Fixed, Some(2), b"-inf");
+        #[cfg(not(feature = "trim_floats"))]
+        check(150.0f64, FloatNotation::Fixed, None, b"150.0");
+        #[cfg(feature = "trim_floats")]
+        check(150.0f64, FloatNotation::Fixed, None, b"150");
+    }
+
+    #[test]
+    fn scientific_test() {
+        check(123456.0f64, FloatNotation::Scientific, Some(2), b"1.23e5");
+        check(123456.0f64, FloatNotation::Scientific, Some(0), b"1e5");
+        check(0.000123456f64, FloatNotation::Scientific, Some(3), b"1.235e-4");
+        check(9.9999f64, FloatNotation::Scientific, Some(2), b"1.00e1");
+        check(1.0f64, FloatNotation::Scientific, Some(6), b"1.000000e0");
+        check(0.0f64, FloatNotation::Scientific, Some(2), b"0.00e0");
+        check(150.0f64, FloatNotation::Scientific, None, b"1.5e2");
+        check(1e300f64, FloatNotation::Scientific, None, b"1e300");
+        check(-5e-324f64, FloatNotation::Scientific, None, b"-5e-324");
+        check(5e-324f64, FloatNotation::Scientific, Some(3), b"4.941e-324");
+    }
+
+    #[test]
+    fn general_test() {
+        check(123456.0f64, FloatNotation::General, Some(6), b"123456");
+        check(1234567.0f64, FloatNotation::General, Some(6), b"1.23457e6");
+        check(0.0001f64, FloatNotation::General, Some(6), b"0.0001");
+        check(0.00001f64, FloatNotation::General, Some(6), b"1e-5");
+        check(100.0f64, FloatNotation::General, Some(0), b"1e2");
+        check(3.5f64, FloatNotation::General, Some(3), b"3.5");
+        check(999999.5f64, FloatNotation::General, Some(6), b"1e6");
+        check(0.0f64, FloatNotation::General, Some(6), b"0");
+        check(1e16f64, FloatNotation::General, None, b"10000000000000000");
+        check(1e17f64, FloatNotation::General, None, b"1e17");
+    }
+
+    #[test]
+    fn shortest_precision_test() {
+        check(3.14159f64, FloatNotation::Shortest, Some(3), b"3.14");
+        check(3.5f64, FloatNotation::Shortest, Some(3), b"3.5");
+        #[cfg(not(feature = "trim_floats"))]
+        check(999.9f64, FloatNotation::Shortest, Some(3), b"1000.0");
+        check(1.23456e-300f64, FloatNotation::Shortest, Some(2), b"1.2e-300");
+    }
+
+    #[cfg(feature = "std")]
+    quickcheck! {
+        fn f64_fixed_quickcheck(f: f64, precision: u8) -> bool {
+            let precision = (precision % 40) as usize;
+            let mut buffer = [0u8; 512];
+            let len = write(f, FloatNotation::Fixed, Some(precision), &mut buffer);
+            f.is_special() || &buffer[..len] == format!("{:.*}", precision, f).as_bytes()
+        }
+
+        fn f64_scientific_quickcheck(f: f64, precision: u8) -> bool {
+            let precision = (precision % 40) as usize;
+            let mut buffer = [0u8; 512];
+            let len = write(f, FloatNotation::Scientific, Some(precision), &mut buffer);
+            f.is_special() || &buffer[..len] == format!("{:.*e}", precision, f).as_bytes()
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Exact float-to-string conversions with a power-of-two exponent base.
+//!
+//! Since the radix and the exponent base are both powers of two,
+//! every float has an exact, finite representation, which is written
+//! like C's `%a` format, such as `1.8p3` for `12.0` in hexadecimal.
+
+use crate::itoa;
+use crate::util::*;
+
+// Floor division of a (possibly negative) exponent by a positive divisor.
+perftools_inline!{
+fn floor_div(exponent: i32, divisor: i32) -> i32 {
+    match exponent >= 0 {
+        true  => exponent / divisor,
+        false => -((-exponent + divisor - 1) / divisor),
+    }
+}}
+
+/// Write a float with a power-of-two radix and exponent base.
+///
+/// The float is written exactly, as the integer digits, the fraction
+/// digits without trailing zeros, and the exponent, where the integer
+/// digits are less than the exponent base. Like the other backends, an
+/// integer is written with a trailing `.0`, unless floats are trimmed.
+///
+/// `value` must be non-special (NaN or infinite), non-negative,
+/// and non-zero.
+fn ftoa_pow2(value: f64, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    let radix = options.radix_u32();
+    let exponent_base = options.exponent_base_u32();
+    let exponent_radix = options.exponent_radix_u32();
+    debug_assert!(radix.is_power_of_two(), "Radix must be a power of two.");
+    debug_assert!(exponent_base.is_power_of_two(), "Exponent base must be a power of two.");
+
+    // Assert no special cases remain, no non-zero values,
+    // and no negative numbers.
+    debug_assert!(!value.is_special());
+    debug_assert!(value != 0.0);
+    debug_assert!(value > 0.0);
+
+    // Normalize the mantissa so the highest bit is set, which gives
+    // the binary exponent of the highest bit.
+    let mut mantissa = value.mantissa();
+    let shift = mantissa.leading_zeros();
+    mantissa <<= shift;
+    let binary_exp = value.exponent() - shift as i32 + 63;
+
+    // Split the highest bits into the integer, so the remaining binary
+    // exponent is a multiple of the bits in the exponent base.
+    let pow2_exp = radix.trailing_zeros();
+    let exponent_pow2 = exponent_base.trailing_zeros() as i32;
+    let exponent = floor_div(binary_exp, exponent_pow2);
+    let integer_bits = (binary_exp - exponent * exponent_pow2 + 1) as u32;
+    let integer = mantissa >> (64 - integer_bits);
+    let mut fraction = mantissa << integer_bits;
+
+    // Write the integer and fraction digits.
+    let mut count = itoa::itoa_positive(integer, radix, bytes);
+    if fraction == 0 && !cfg!(feature = "trim_floats") {
+        count += copy_to_dst(&mut index_mut!(bytes[count..]), b".0");
+    } else if fraction != 0 {
+        index_mut!(bytes[count] = b'.');
+        count += 1;
+    }
+    while fraction != 0 {
+        let digit = fraction >> (64 - pow2_exp);
+        index_mut!(bytes[count] = digit_to_char(digit));
+        count += 1;
+        fraction <<= pow2_exp;
+    }
+
+    // Write the exponent.
+    index_mut!(bytes[count] = options.exponent_char());
+    count += 1;
+    if exponent < 0 {
+        index_mut!(bytes[count] = b'-');
+        count += 1;
+    }
+    let exp = exponent.wrapping_abs() as u32;
+    count + itoa::itoa_positive(exp, exponent_radix, &mut index_mut!(bytes[count..]))
+}
+
+// F32
+
+// Forward to double_pow2.
+//
+// `f` must be non-special (NaN or infinite), non-negative,
+// and non-zero.
+perftools_inline!{
+pub(crate) fn float_pow2(f: f32, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    double_pow2(f as f64, options, bytes)
+}}
+
+// F64
+
+// Algorithm for string representations with a power-of-two exponent base.
+//
+// `d` must be non-special (NaN or infinite), non-negative,
+// and non-zero.
+perftools_inline!{
+pub(crate) fn double_pow2(value: f64, options: &WriteFloatOptions, bytes: &mut [u8])
+    -> usize
+{
+    ftoa_pow2(value, options, bytes)
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(radix: u8, exponent_base: u8, exponent_radix: u8) -> WriteFloatOptions {
+        WriteFloatOptions::builder()
+            .radix(radix)
+            .exponent_base(exponent_base)
+            .exponent_radix(exponent_radix)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap()
+    }
+
+    fn write(value: f64, radix: u8, exponent_base: u8, exponent_radix: u8) -> String {
+        let mut buffer = [b'\0'; 128];
+        let count = double_pow2(value, &options(radix, exponent_base, exponent_radix), &mut buffer);
+        String::from_utf8(buffer[..count].to_vec()).unwrap()
+    }
+
+    // Get the integer with the trailing `.0` written by the backend.
+    fn integer(integer: &str) -> String {
+        match cfg!(feature = "trim_floats") {
+            true  => integer.to_string(),
+            false => format!("{}.0", integer),
+        }
+    }
+
+    #[test]
+    fn double_pow2_test() {
+        assert_eq!(write(1.0, 16, 2, 10), integer("1") + "p0");
+        assert_eq!(write(12.0, 16, 2, 10), "1.8p3");
+        assert_eq!(write(0.1, 16, 2, 10), "1.999999999999Ap-4");
+        assert_eq!(write(0.75, 16, 2, 10), "1.8p-1");
+        assert_eq!(write(f64::MAX, 16, 2, 10), "1.FFFFFFFFFFFFFp1023");
+        assert_eq!(write(f64::MIN_POSITIVE, 16, 2, 10), integer("1") + "p-1022");
+        assert_eq!(write(5e-324, 16, 2, 10), integer("1") + "p-1074");
+        assert_eq!(write(12.0, 16, 2, 16), "1.8p3");
+        assert_eq!(write(1024.0, 16, 2, 16), integer("1") + "pA");
+        assert_eq!(write(12.0, 16, 16, 10), integer("C") + "^0");
+        assert_eq!(write(12.0, 8, 4, 10), integer("3") + "p1");
+        assert_eq!(write(12.0, 2, 2, 10), "1.1p3");
+        assert_eq!(write(12.0, 2, 16, 10), integer("1100") + "p0");
+    }
+
+    #[test]
+    fn float_pow2_test() {
+        let mut buffer = [b'\0'; 128];
+        let count = float_pow2(0.1, &options(16, 2, 10), &mut buffer);
+        assert_eq!(&buffer[..count], b"1.99999Ap-4");
+    }
+}
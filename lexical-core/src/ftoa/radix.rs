@@ -33,7 +33,7 @@ pub(crate) fn naive_exponent(d: f64, radix: u32) -> i32
 /// and non-zero.
 ///
 /// Adapted from the V8 implementation.
-fn ftoa_naive<'a>(value: f64, radix: u32, bytes: &'a mut [u8])
+fn ftoa_naive<'a>(value: f64, radix: u32, exponent_char: u8, bytes: &'a mut [u8])
     -> usize
 {
     debug_assert_radix!(radix);
@@ -176,7 +176,7 @@ fn ftoa_naive<'a>(value: f64, radix: u32, bytes: &'a mut [u8])
         let bytes = &mut bytes[count+2..];
 
         // write the exponent component
-        bytes[0] = exponent_notation_char(radix);
+        bytes[0] = exponent_char;
         // Handle negative exponents.
         let exp: u32;
         if exponent < 0 {
@@ -221,10 +221,10 @@ fn ftoa_naive<'a>(value: f64, radix: u32, bytes: &'a mut [u8])
 // `f` must be non-special (NaN or infinite), non-negative,
 // and non-zero.
 perftools_inline!{
-pub(crate) fn float_radix<'a>(f: f32, radix: u32, bytes: &'a mut [u8])
+pub(crate) fn float_radix<'a>(f: f32, radix: u32, exponent_char: u8, bytes: &'a mut [u8])
     -> usize
 {
-    double_radix(f as f64, radix, bytes)
+    double_radix(f as f64, radix, exponent_char, bytes)
 }}
 
 // F64
@@ -234,8 +234,8 @@ pub(crate) fn float_radix<'a>(f: f32, radix: u32, bytes: &'a mut [u8])
 // `d` must be non-special (NaN or infinite), non-negative,
 // and non-zero.
 perftools_inline!{
-pub(crate) fn double_radix<'a>(value: f64, radix: u32, bytes: &'a mut [u8])
+pub(crate) fn double_radix<'a>(value: f64, radix: u32, exponent_char: u8, bytes: &'a mut [u8])
     -> usize
 {
-    ftoa_naive(value, radix, bytes)
+    ftoa_naive(value, radix, exponent_char, bytes)
 }}
@@ -0,0 +1,66 @@
+//! Integer-to-string conversion with a digit alphabet.
+
+use crate::util::*;
+
+// Write the digits of the value in the alphabet to the end of the buffer.
+//
+// Returns the index of the first digit.
+// Precondition: `value` must be non-negative and mutable.
+perftools_inline!{
+fn alphabet<T>(mut value: T, alphabet: &DigitAlphabet, buffer: &mut [u8])
+    -> usize
+    where T: UnsignedInteger
+{
+    let radix: T = as_cast(alphabet.radix());
+    let mut index = buffer.len();
+    loop {
+        let r = (value % radix).as_usize();
+        value /= radix;
+
+        // This is always safe, since r must be [0, radix).
+        index -= 1;
+        index_mut!(buffer[index] = alphabet.to_char(r));
+        if value.is_zero() {
+            break;
+        }
+    }
+    index
+}}
+
+// Export an unsigned integer to string with a digit alphabet.
+//  Preconditions: `value` must be non-negative and unsigned.
+perftools_inline!{
+pub(crate) fn itoa_alphabet<T>(value: T, digits: &DigitAlphabet, buffer: &mut [u8])
+    -> usize
+    where T: UnsignedInteger
+{
+    // Write backwards to a temporary buffer, and copy to the output.
+    let mut tmp: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
+    let tmp = &mut tmp[..T::FORMATTED_SIZE];
+    let offset = alphabet(value, digits, tmp);
+    copy_to_dst(buffer, &index!(tmp[offset..]))
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write<T: UnsignedInteger>(value: T, digits: &DigitAlphabet) -> Vec<u8> {
+        let mut buffer = [b'\0'; 256];
+        let count = itoa_alphabet(value, digits, &mut buffer);
+        buffer[..count].to_vec()
+    }
+
+    #[test]
+    fn itoa_alphabet_test() {
+        assert_eq!(write(0u32, &DigitAlphabet::BASE58), b"1");
+        assert_eq!(write(57u32, &DigitAlphabet::BASE58), b"z");
+        assert_eq!(write(58u32, &DigitAlphabet::BASE58), b"21");
+        assert_eq!(write(255u8, &DigitAlphabet::BASE64), b"D/");
+        assert_eq!(write(u64::max_value(), &DigitAlphabet::BASE62), b"LygHa16AHYF");
+        assert_eq!(write(u128::max_value(), &DigitAlphabet::BASE32).len(), 26);
+    }
+}
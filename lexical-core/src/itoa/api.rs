@@ -15,6 +15,9 @@ use super::generic::Generic;
 #[cfg(not(feature = "table"))]
 use super::naive::Naive;
 
+#[cfg(feature = "radix")]
+use super::alphabet::itoa_alphabet;
+
 // HELPERS
 
 // Wrapper to facilitate calling a backend that writes iteratively to
@@ -114,9 +117,95 @@ fn unsigned<Narrow, Wide>(value: Narrow, radix: u32, buffer: &mut [u8])
     itoa_positive(value, radix, buffer)
 }}
 
+// Forward itoa arguments with options to the alphabet, or an optimized backend.
+//  Preconditions: `value` must be non-negative and unsigned.
+perftools_inline!{
+fn itoa_options<T>(value: T, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> usize
+    where T: Itoa
+{
+    #[cfg(feature = "radix")] {
+        if let Some(alphabet) = options.alphabet() {
+            return itoa_alphabet(value, alphabet, buffer);
+        }
+    }
+
+    itoa_positive(value, options.radix_u32(), buffer)
+}}
+
+// Write a positive sign before the number, if required by the options.
+perftools_inline!{
+fn positive_sign(buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> usize
+{
+    match options.required_mantissa_sign() {
+        true  => {
+            unchecked_index_mut!(buffer[0] = b'+');
+            1
+        },
+        false => 0,
+    }
+}}
+
+// Write the integer with options, emitting the digit separators
+// between the groups of digits.
+perftools_inline!{
+fn itoa_grouped<T>(value: T, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> usize
+    where T: Itoa
+{
+    match options.grouping() {
+        DigitGrouping::None => itoa_options(value, buffer, options),
+        grouping            => {
+            let mut digits: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
+            let count = itoa_options(value, &mut digits, options);
+            let digits = digits[..count].iter().cloned();
+            write_integer_digits(buffer, count, digits, options.digit_separator(), grouping)
+        },
+    }
+}}
+
+// Callback for unsigned integer formatter with options.
+perftools_inline!{
+fn unsigned_options<Narrow, Wide>(value: Narrow, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> usize
+    where Narrow: UnsignedInteger,
+          Wide: Itoa
+{
+    let index = positive_sign(buffer, options);
+    let buffer = &mut unchecked_index_mut!(buffer[index..]);
+    let value: Wide = as_cast(value);
+    itoa_grouped(value, buffer, options) + index
+}}
+
+// Callback for unsigned integer formatter with a custom format.
+//
+// Every integer can be represented in any format.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn unsigned_format<Narrow, Wide>(value: Narrow, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> Option<usize>
+    where Narrow: UnsignedInteger,
+          Wide: Itoa
+{
+    Some(unsigned_options::<Narrow, Wide>(value, buffer, options))
+}}
+
+// Get the number of bytes required to write any integer with custom options.
+perftools_inline!{
+fn itoa_buffer_size<T: Integer>(options: &WriteIntegerOptions)
+    -> usize
+{
+    let size = formatted_size::<T>(options.radix_u32());
+    size + options.required_mantissa_sign() as usize + group_separators_size(size, options.grouping())
+}}
+
 macro_rules! unsigned_to_lexical {
     ($narrow:ty, $wide:ty) => (
         to_lexical!(unsigned::<$narrow, $wide>, $narrow);
+        to_lexical_options!(unsigned_options::<$narrow, $wide>, itoa_buffer_size::<$narrow>, WriteIntegerOptions, $narrow);
+        #[cfg(feature = "format")]
+        to_lexical_format!(unsigned_format::<$narrow, $wide>, WriteIntegerOptions, $narrow);
     );
 }
 
@@ -151,9 +240,51 @@ fn signed<Narrow, Wide, Unsigned>(value: Narrow, radix: u32, buffer: &mut [u8])
     }
 }}
 
+// Callback for signed integer formatter with options.
+//
+// Panics if the value is negative and the minus sign is a digit in the
+// alphabet, since the sign would be parsed as a digit.
+perftools_inline!{
+fn signed_options<Narrow, Wide, Unsigned>(value: Narrow, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> usize
+    where Narrow: SignedInteger,
+          Wide: SignedInteger,
+          Unsigned: Itoa
+{
+    let index = match value < Narrow::ZERO {
+        true  => {
+            assert!(options.is_negative_valid(), "Cannot write a negative number if `-` is a digit in the alphabet.");
+            unchecked_index_mut!(buffer[0] = b'-');
+            1
+        },
+        false => positive_sign(buffer, options),
+    };
+    let buffer = &mut unchecked_index_mut!(buffer[index..]);
+    let value: Wide = as_cast(value);
+    let value: Unsigned = as_cast(value.wrapping_abs());
+    itoa_grouped(value, buffer, options) + index
+}}
+
+// Callback for signed integer formatter with a custom format.
+//
+// Every integer can be represented in any format.
+perftools_inline!{
+#[cfg(feature = "format")]
+fn signed_format<Narrow, Wide, Unsigned>(value: Narrow, buffer: &mut [u8], options: &WriteIntegerOptions)
+    -> Option<usize>
+    where Narrow: SignedInteger,
+          Wide: SignedInteger,
+          Unsigned: Itoa
+{
+    Some(signed_options::<Narrow, Wide, Unsigned>(value, buffer, options))
+}}
+
 macro_rules! signed_to_lexical {
     ($narrow:ty, $wide:ty, $unsigned:ty) => (
         to_lexical!(signed::<$narrow, $wide, $unsigned>, $narrow);
+        to_lexical_options!(signed_options::<$narrow, $wide, $unsigned>, itoa_buffer_size::<$narrow>, WriteIntegerOptions, $narrow);
+        #[cfg(feature = "format")]
+        to_lexical_format!(signed_format::<$narrow, $wide, $unsigned>, WriteIntegerOptions, $narrow);
     );
 }
 
@@ -347,6 +478,189 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "format")]
+    #[test]
+    fn format_test() {
+        let mut buffer = new_buffer();
+        let format = NumberFormat::REQUIRED_MANTISSA_SIGN;
+        assert_eq!(Some(&b"+0"[..]), 0u8.to_lexical_format(format, &mut buffer).map(|x| &*x));
+        assert_eq!(Some(&b"+127"[..]), 127i8.to_lexical_format(format, &mut buffer).map(|x| &*x));
+        assert_eq!(Some(&b"-128"[..]), (-128i8).to_lexical_format(format, &mut buffer).map(|x| &*x));
+        assert_eq!(i128::format_buffer_size(10, format), i128::FORMATTED_SIZE_DECIMAL + 1);
+        let mut buffer = [b'\0'; u128::FORMATTED_SIZE_DECIMAL + 1];
+        let bytes = u128::max_value().to_lexical_format(format, &mut buffer).unwrap();
+        assert_eq!(u128::from_lexical_format(bytes, format), Ok(u128::max_value()));
+
+        let format = NumberFormat::JSON;
+        assert_eq!(Some(&b"-5"[..]), (-5i32).to_lexical_format(format, &mut buffer).map(|x| &*x));
+    }
+
+    #[test]
+    fn grouping_test() {
+        let options = WriteIntegerOptions::builder()
+            .digit_separator(b',')
+            .grouping(DigitGrouping::Uniform(3))
+            .build()
+            .unwrap();
+        let mut buffer = [b'\0'; 64];
+        assert_eq!(b"0", 0u8.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"999", 999u16.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"1,000", 1000u16.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-1,234,567", (-1234567i32).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(&b"-170,141,183,460,469,231,731,687,303,715,884,105,728"[..], i128::min_value().to_lexical_with_options(&mut buffer, &options));
+
+        let options = WriteIntegerOptions::builder()
+            .digit_separator(b',')
+            .grouping(DigitGrouping::Indian)
+            .build()
+            .unwrap();
+        assert_eq!(b"12,34,567", 1234567u32.to_lexical_with_options(&mut buffer, &options));
+
+        // Ensure the buffer size is sufficient for the worst case.
+        let options = WriteIntegerOptions::builder()
+            .grouping(DigitGrouping::Uniform(1))
+            .build()
+            .unwrap();
+        let mut buffer = [b'\0'; 80];
+        let size = i128::buffer_size(&options);
+        let bytes = i128::min_value().to_lexical_with_options(&mut buffer[..size], &options);
+        assert_eq!(bytes.len(), 1 + 39 + 38);
+
+        assert!(WriteIntegerOptions::builder().grouping(DigitGrouping::Uniform(0)).build().is_none());
+        assert!(WriteIntegerOptions::builder().digit_separator(b'0').build().is_none());
+        assert!(WriteIntegerOptions::builder().digit_separator(b'-').build().is_none());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn grouping_radix_test() {
+        let options = WriteIntegerOptions::builder()
+            .radix(16)
+            .grouping(DigitGrouping::Uniform(4))
+            .build()
+            .unwrap();
+        let mut buffer = new_buffer();
+        assert_eq!(b"DEAD_BEEF", 0xDEADBEEFu32.to_lexical_with_options(&mut buffer, &options));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn alphabet_test() {
+        let mut buffer = new_buffer();
+        let options = WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE58))
+            .build()
+            .unwrap();
+        assert_eq!(b"1", 0u8.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"21", 58u32.to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-21", (-58i64).to_lexical_with_options(&mut buffer, &options));
+        assert_eq!(b"-3D", i8::min_value().to_lexical_with_options(&mut buffer, &options));
+
+        let options = WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE64_URL))
+            .build()
+            .unwrap();
+        assert_eq!(b"-_", (62u32 * 64 + 63).to_lexical_with_options(&mut buffer, &options));
+
+        let options = WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE32_HEX))
+            .digit_separator(b' ')
+            .grouping(DigitGrouping::Uniform(2))
+            .build()
+            .unwrap();
+        assert_eq!(b"-V VV", (-32767i16).to_lexical_with_options(&mut buffer, &options));
+
+        let hex = DigitAlphabet::new(b"0123456789abcdef", false).unwrap();
+        let options = WriteIntegerOptions::builder()
+            .alphabet(Some(hex))
+            .build()
+            .unwrap();
+        assert_eq!(b"deadbeef", 0xDEADBEEFu32.to_lexical_with_options(&mut buffer, &options));
+
+        // Roundtrip with the parser, with the largest buffer required.
+        let write_options = WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE62))
+            .build()
+            .unwrap();
+        let parse_options = ParseIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE62))
+            .build()
+            .unwrap();
+        let size = i128::buffer_size(&write_options);
+        for &value in &[0, 1, 61, 62, i128::max_value(), i128::min_value()] {
+            let bytes = value.to_lexical_with_options(&mut buffer[..size], &write_options);
+            assert_eq!(Ok(value), i128::from_lexical_with_options(bytes, &parse_options));
+        }
+
+        // Signed roundtrip for every built-in alphabet. Negative numbers
+        // cannot be written if `-` is a digit, as with BASE64_URL.
+        let alphabets = [
+            DigitAlphabet::BASE32,
+            DigitAlphabet::BASE32_HEX,
+            DigitAlphabet::BASE58,
+            DigitAlphabet::BASE62,
+            DigitAlphabet::BASE64,
+            DigitAlphabet::BASE64_URL,
+        ];
+        for &alphabet in alphabets.iter() {
+            let write_options = WriteIntegerOptions::builder()
+                .alphabet(Some(alphabet))
+                .build()
+                .unwrap();
+            let parse_options = ParseIntegerOptions::builder()
+                .alphabet(Some(alphabet))
+                .build()
+                .unwrap();
+            let has_minus = alphabet.to_digit(b'-').is_some();
+            for &value in &[i32::min_value(), -5, -1, 0, 1, 5, 62, i32::max_value()] {
+                if value < 0 && has_minus {
+                    continue;
+                }
+                let bytes = value.to_lexical_with_options(&mut buffer, &write_options);
+                assert_eq!(Ok(value), i32::from_lexical_with_options(bytes, &parse_options));
+            }
+        }
+
+        assert!(WriteIntegerOptions::builder().radix(64).build().is_none());
+        assert!(WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE64_URL))
+            .grouping(DigitGrouping::Uniform(3))
+            .build()
+            .is_none());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    #[should_panic]
+    fn alphabet_negative_minus_digit_test() {
+        let mut buffer = new_buffer();
+        let options = WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE64_URL))
+            .build()
+            .unwrap();
+        (-5i32).to_lexical_with_options(&mut buffer, &options);
+    }
+
+    #[cfg(all(feature = "radix", feature = "format"))]
+    #[test]
+    fn alphabet_format_test() {
+        // A required `+` sign would be parsed as a digit.
+        let format = NumberFormat::REQUIRED_MANTISSA_SIGN;
+        assert!(WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE64))
+            .format(format)
+            .build()
+            .is_none());
+
+        let mut buffer = new_buffer();
+        let options = WriteIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE64_URL))
+            .format(format)
+            .build()
+            .unwrap();
+        assert_eq!(b"+F", 5i32.to_lexical_with_options(&mut buffer, &options));
+    }
+
     // Extensive tests
 
     #[test]
@@ -510,73 +824,73 @@ mod tests {
         #[test]
         fn u8_proptest(i in u8::min_value()..u8::max_value()) {
             let mut buffer = new_buffer();
-            i == u8::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u8::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i8_proptest(i in i8::min_value()..i8::max_value()) {
             let mut buffer = new_buffer();
-            i == i8::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i8::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u16_proptest(i in u16::min_value()..u16::max_value()) {
             let mut buffer = new_buffer();
-            i == u16::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u16::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i16_proptest(i in i16::min_value()..i16::max_value()) {
             let mut buffer = new_buffer();
-            i == i16::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i16::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u32_proptest(i in u32::min_value()..u32::max_value()) {
             let mut buffer = new_buffer();
-            i == u32::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u32::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i32_proptest(i in i32::min_value()..i32::max_value()) {
             let mut buffer = new_buffer();
-            i == i32::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i32::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u64_proptest(i in u64::min_value()..u64::max_value()) {
             let mut buffer = new_buffer();
-            i == u64::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u64::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i64_proptest(i in i64::min_value()..i64::max_value()) {
             let mut buffer = new_buffer();
-            i == i64::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i64::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn u128_proptest(i in u128::min_value()..u128::max_value()) {
             let mut buffer = new_buffer();
-            i == u128::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, u128::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn i128_proptest(i in i128::min_value()..i128::max_value()) {
             let mut buffer = new_buffer();
-            i == i128::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, i128::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn usize_proptest(i in usize::min_value()..usize::max_value()) {
             let mut buffer = new_buffer();
-            i == usize::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, usize::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
 
         #[test]
         fn isize_proptest(i in isize::min_value()..isize::max_value()) {
             let mut buffer = new_buffer();
-            i == isize::from_lexical(i.to_lexical(&mut buffer)).unwrap()
+            prop_assert_eq!(i, isize::from_lexical(i.to_lexical(&mut buffer)).unwrap());
         }
     }
 
@@ -665,4 +979,24 @@ mod tests {
         let mut buffer = [b'0'; usize::FORMATTED_SIZE_DECIMAL-1];
         12usize.to_lexical(&mut buffer);
     }
+
+    #[test]
+    fn try_write_test() {
+        let mut buffer = [b'0'; 3];
+        assert_eq!(crate::formatted_len(12i8), 2);
+        assert_eq!(crate::formatted_len(u64::max_value()), 20);
+        assert_eq!(crate::try_write(12i8, &mut buffer).map(|x| &*x), Ok(&b"12"[..]));
+        assert_eq!(crate::try_write(-12i64, &mut buffer).map(|x| &*x), Ok(&b"-12"[..]));
+        assert_eq!(crate::try_write(-123i64, &mut buffer), Err(WriteError { required: 4 }));
+        assert_eq!(crate::try_write(u128::max_value(), &mut []), Err(WriteError { required: 39 }));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn try_write_radix_test() {
+        let mut buffer = [b'0'; 4];
+        assert_eq!(crate::formatted_len_radix(255u8, 2), 8);
+        assert_eq!(crate::try_write_radix(255u8, 16, &mut buffer).map(|x| &*x), Ok(&b"FF"[..]));
+        assert_eq!(crate::try_write_radix(255u8, 2, &mut buffer), Err(WriteError { required: 8 }));
+    }
 }
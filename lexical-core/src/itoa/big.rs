@@ -0,0 +1,96 @@
+//! Integer-to-string conversions for arbitrary-precision integers.
+//!
+//! The magnitude is repeatedly divided by the largest power of the
+//! radix that fits in a 32-bit integer, and the digits of each chunk
+//! are written right-to-left, so the number of big-integer divisions
+//! is minimized.
+
+use crate::lib::result::Result as StdResult;
+use crate::util::*;
+
+// ITOBIG
+
+// Write a big integer to the buffer.
+//
+// Digits are written to the end of the buffer and then moved to the
+// front, so the required size is only known once every digit is
+// generated. If the buffer is too small, the remaining digits are
+// counted but not written.
+perftools_inline!{
+pub(crate) fn itobig<'a, T>(value: &T, radix: u32, bytes: &'a mut [u8])
+    -> StdResult<&'a mut [u8], WriteError>
+    where T: BigInteger
+{
+    debug_assert_radix!(radix);
+    let (step, power) = big_integer_step(radix);
+    let sign = value.is_negative() as usize;
+    let len = bytes.len();
+    let mut value = value.clone();
+    let mut count = 0;
+    loop {
+        let mut chunk = value.divrem_small(power);
+        let is_last = value.is_zero();
+        let mut index = 0;
+        // Only pad the chunk with zeros if more significant digits follow.
+        while index < step && (!is_last || chunk != 0 || count == 0) {
+            count += 1;
+            index += 1;
+            if let Some(c) = len.checked_sub(count).and_then(|i| bytes.get_mut(i)) {
+                *c = digit_to_char(chunk % radix);
+            }
+            chunk /= radix;
+        }
+        if is_last {
+            break;
+        }
+    }
+
+    let required = sign + count;
+    if required > len {
+        return Err(WriteError { required });
+    }
+    bytes.copy_within(len - count.., sign);
+    if sign == 1 {
+        bytes[0] = b'-';
+    }
+    Ok(&mut bytes[..required])
+}}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write<T: BigInteger>(value: &T, radix: u32, expected: &[u8]) {
+        let mut buffer = [b'x'; 128];
+        assert_eq!(itobig(value, radix, &mut buffer).map(|x| &*x), Ok(expected));
+    }
+
+    #[test]
+    fn itobig_test() {
+        write(&[0u64, 0], 10, b"0");
+        write(&[12345u64, 0], 10, b"12345");
+        write(&[1_000_000_000u64, 0], 10, b"1000000000");
+        write(&[0u64, 1], 10, b"18446744073709551616");
+        write(&[u64::max_value(); 2], 10, b"340282366920938463463374607431768211455");
+        write(&[0u64, 0, 1], 10, b"340282366920938463463374607431768211456");
+    }
+
+    #[test]
+    fn itobig_short_buffer_test() {
+        let mut buffer = [0u8; 19];
+        assert_eq!(itobig(&[0u64, 1], 10, &mut buffer), Err(WriteError { required: 20 }));
+        let mut buffer = [0u8; 20];
+        assert_eq!(itobig(&[0u64, 1], 10, &mut buffer).map(|x| &*x), Ok(&b"18446744073709551616"[..]));
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn itobig_radix_test() {
+        write(&[15u64, 1], 16, b"1000000000000000F");
+        write(&[0u64, 1], 2, b"10000000000000000000000000000000000000000000000000000000000000000");
+        write(&[35u64, 0], 36, b"Z");
+    }
+}
@@ -10,7 +10,11 @@ mod generic;
 #[cfg(not(feature = "table"))]
 mod naive;
 
+#[cfg(feature = "radix")]
+mod alphabet;
+
 mod api;
+mod big;
 
-#[cfg(feature = "radix")]
 pub(crate) use self::api::itoa_positive;
+pub(crate) use self::big::*;
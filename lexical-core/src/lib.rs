@@ -56,6 +56,22 @@
 //! **To String**
 //! - [`write`]
 #![cfg_attr(feature = "radix", doc = " - [`write_radix`]")]
+#![cfg_attr(feature = "format", doc = " - [`write_format`]")]
+#![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`write_format_radix`]")]
+//! - [`write_with_options`]
+//! - [`write_float_with_options`]
+#![cfg_attr(feature = "f128", doc = " - [`write_extended`]")]
+//! - [`try_write`]
+#![cfg_attr(feature = "radix", doc = " - [`try_write_radix`]")]
+//! - [`write_big_integer`]
+#![cfg_attr(feature = "radix", doc = " - [`write_big_integer_radix`]")]
+//! - [`write_locale`]
+//! - [`formatted_len`]
+#![cfg_attr(feature = "radix", doc = " - [`formatted_len_radix`]")]
+//! - [`write_exact`]
+//! - [`write_exact_digits`]
+#![cfg_attr(feature = "radix", doc = " - [`write_exact_radix`]")]
+#![cfg_attr(feature = "radix", doc = " - [`write_exact_digits_radix`]")]
 //!
 //! **From String**
 //! - [`parse`]
@@ -63,8 +79,18 @@
 #![cfg_attr(feature = "format", doc = " - [`parse_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_format_radix`]")]
 //! - [`parse_partial`]
+//! - [`parse_with_context`]
+//! - [`parse_bounded`]
+//! - [`parse_partial_bounded`]
+//! - [`parse_bounded_with_options`]
+//! - [`parse_value`]
+//! - [`parse_partial_value`]
+//! - [`parse_value_with_options`]
+//! - [`parse_partial_value_with_options`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_format`]")]
+#![cfg_attr(feature = "format", doc = " - [`parse_format_suffix`]")]
+#![cfg_attr(feature = "format", doc = " - [`parse_partial_format_suffix`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_format_radix`]")]
 //! - [`parse_lossy`]
 #![cfg_attr(feature = "radix", doc = " - [`parse_lossy_radix`]")]
@@ -74,9 +100,68 @@
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_lossy_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_lossy_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_lossy_format_radix`]")]
+//! - [`parse_with_options`]
+//! - [`parse_partial_with_options`]
+#![cfg_attr(feature = "f128", doc = " - [`parse_extended`]")]
+#![cfg_attr(feature = "f128", doc = " - [`parse_partial_extended`]")]
+#![cfg_attr(feature = "f128", doc = " - [`parse_extended_with_options`]")]
+#![cfg_attr(feature = "f128", doc = " - [`parse_partial_extended_with_options`]")]
+//! - [`NumberParser`]
+//! - [`parse_many`]
+#![cfg_attr(feature = "std", doc = " - [`parse_many_vec`]")]
+#![cfg_attr(feature = "std", doc = " - [`parse_decimal`]")]
+#![cfg_attr(feature = "std", doc = " - [`parse_partial_decimal`]")]
+#![cfg_attr(all(feature = "std", feature = "format"), doc = " - [`parse_decimal_format`]")]
+#![cfg_attr(all(feature = "std", feature = "format"), doc = " - [`parse_partial_decimal_format`]")]
+//! - [`parse_components`]
+//! - [`parse_partial_components`]
+#![cfg_attr(feature = "format", doc = " - [`parse_components_format`]")]
+#![cfg_attr(feature = "format", doc = " - [`parse_partial_components_format`]")]
+//! - [`parse_big_integer`]
+//! - [`parse_partial_big_integer`]
+//! - [`parse_big_integer_with_options`]
+//! - [`parse_partial_big_integer_with_options`]
+//! - [`parse_locale`]
+//! - [`parse_partial_locale`]
+//! - [`parse_units`]
+//! - [`parse_partial_units`]
+//! - [`parse_units_with_options`]
+//! - [`parse_partial_units_with_options`]
+//!
+//! # Options API
+//!
+//! The options API customizes parsing and writing on a per-call basis,
+//! without modifying any global state, and is therefore thread-safe.
+//! Options are created with the default values via `new`, or
+//! customized via `builder`, which validates the options on `build`.
+//!
+//! ```rust
+//! extern crate lexical_core;
+//!
+//! use lexical_core::{Number, ParseFloatOptions, WriteFloatOptions};
+//!
+//! let options = ParseFloatOptions::builder()
+//!     .nan_string(b"nan")
+//!     .exponent_default_char(b'p')
+//!     .build()
+//!     .unwrap();
+//! let f: f64 = lexical_core::parse_with_options(b"1.5p2", &options).unwrap();
+//! assert_eq!(f, 150.0);
+//!
+//! let options = WriteFloatOptions::builder()
+//!     .exponent_default_char(b'E')
+//!     .build()
+//!     .unwrap();
+//! let mut buf = [b'0'; f64::FORMATTED_SIZE];
+//! let slc = lexical_core::write_with_options::<f64>(1.5e-300, &mut buf, &options);
+//! assert_eq!(slc, b"1.5E-300");
+//! ```
 //!
 //! # Configuration Settings
 //!
+//! The global configuration settings only affect the functions that
+//! do not take options, and are not thread-safe. Prefer the options API.
+//!
 //! **Get Configuration**
 //! - [`get_exponent_default_char`]
 #![cfg_attr(feature = "radix", doc = " - [`get_exponent_backup_char`]")]
@@ -95,11 +180,36 @@
 //!
 //! [`write`]: fn.write.html
 #![cfg_attr(feature = "radix", doc = " [`write_radix`]: fn.write_radix.html")]
+#![cfg_attr(feature = "format", doc = " [`write_format`]: fn.write_format.html")]
+#![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`write_format_radix`]: fn.write_format_radix.html")]
+//! [`write_with_options`]: fn.write_with_options.html
+//! [`write_float_with_options`]: fn.write_float_with_options.html
+//! [`try_write`]: fn.try_write.html
+#![cfg_attr(feature = "radix", doc = " [`try_write_radix`]: fn.try_write_radix.html")]
+//! [`write_big_integer`]: fn.write_big_integer.html
+#![cfg_attr(feature = "radix", doc = " [`write_big_integer_radix`]: fn.write_big_integer_radix.html")]
+//! [`write_locale`]: fn.write_locale.html
+//! [`formatted_len`]: fn.formatted_len.html
+#![cfg_attr(feature = "radix", doc = " [`formatted_len_radix`]: fn.formatted_len_radix.html")]
+//! [`write_exact`]: fn.write_exact.html
+//! [`write_exact_digits`]: fn.write_exact_digits.html
+#![cfg_attr(feature = "radix", doc = " [`write_exact_radix`]: fn.write_exact_radix.html")]
+#![cfg_attr(feature = "radix", doc = " [`write_exact_digits_radix`]: fn.write_exact_digits_radix.html")]
 //! [`parse`]: fn.parse.html
 #![cfg_attr(feature = "radix", doc = " [`parse_radix`]: fn.parse_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_format`]: fn.parse_format.html")]
+#![cfg_attr(feature = "format", doc = " [`parse_format_suffix`]: fn.parse_format_suffix.html")]
+#![cfg_attr(feature = "format", doc = " [`parse_partial_format_suffix`]: fn.parse_partial_format_suffix.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_format_radix`]: fn.parse_format_radix.html")]
 //! [`parse_partial`]: fn.parse_partial.html
+//! [`parse_with_context`]: fn.parse_with_context.html
+//! [`parse_bounded`]: fn.parse_bounded.html
+//! [`parse_partial_bounded`]: fn.parse_partial_bounded.html
+//! [`parse_bounded_with_options`]: fn.parse_bounded_with_options.html
+//! [`parse_value`]: fn.parse_value.html
+//! [`parse_partial_value`]: fn.parse_partial_value.html
+//! [`parse_value_with_options`]: fn.parse_value_with_options.html
+//! [`parse_partial_value_with_options`]: fn.parse_partial_value_with_options.html
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_radix`]: fn.parse_partial_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_format`]: fn.parse_partial_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_format_radix`]: fn.parse_partial_format_radix.html")]
@@ -111,6 +221,34 @@
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_lossy_radix`]: fn.parse_partial_lossy_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_lossy_format`]: fn.parse_partial_lossy_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_lossy_format_radix`]: fn.parse_partial_lossy_format_radix.html")]
+//! [`parse_with_options`]: fn.parse_with_options.html
+//! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+#![cfg_attr(feature = "f128", doc = " [`parse_extended`]: fn.parse_extended.html")]
+#![cfg_attr(feature = "f128", doc = " [`parse_partial_extended`]: fn.parse_partial_extended.html")]
+#![cfg_attr(feature = "f128", doc = " [`parse_extended_with_options`]: fn.parse_extended_with_options.html")]
+#![cfg_attr(feature = "f128", doc = " [`parse_partial_extended_with_options`]: fn.parse_partial_extended_with_options.html")]
+#![cfg_attr(feature = "f128", doc = " [`write_extended`]: fn.write_extended.html")]
+//! [`NumberParser`]: struct.NumberParser.html
+//! [`parse_many`]: fn.parse_many.html
+#![cfg_attr(feature = "std", doc = " [`parse_many_vec`]: fn.parse_many_vec.html")]
+#![cfg_attr(feature = "std", doc = " [`parse_decimal`]: fn.parse_decimal.html")]
+#![cfg_attr(feature = "std", doc = " [`parse_partial_decimal`]: fn.parse_partial_decimal.html")]
+#![cfg_attr(all(feature = "std", feature = "format"), doc = " [`parse_decimal_format`]: fn.parse_decimal_format.html")]
+#![cfg_attr(all(feature = "std", feature = "format"), doc = " [`parse_partial_decimal_format`]: fn.parse_partial_decimal_format.html")]
+//! [`parse_components`]: fn.parse_components.html
+//! [`parse_partial_components`]: fn.parse_partial_components.html
+#![cfg_attr(feature = "format", doc = " [`parse_components_format`]: fn.parse_components_format.html")]
+#![cfg_attr(feature = "format", doc = " [`parse_partial_components_format`]: fn.parse_partial_components_format.html")]
+//! [`parse_big_integer`]: fn.parse_big_integer.html
+//! [`parse_partial_big_integer`]: fn.parse_partial_big_integer.html
+//! [`parse_big_integer_with_options`]: fn.parse_big_integer_with_options.html
+//! [`parse_partial_big_integer_with_options`]: fn.parse_partial_big_integer_with_options.html
+//! [`parse_locale`]: fn.parse_locale.html
+//! [`parse_partial_locale`]: fn.parse_partial_locale.html
+//! [`parse_units`]: fn.parse_units.html
+//! [`parse_partial_units`]: fn.parse_partial_units.html
+//! [`parse_units_with_options`]: fn.parse_units_with_options.html
+//! [`parse_partial_units_with_options`]: fn.parse_partial_units_with_options.html
 //!
 //! [`get_exponent_default_char`]: fn.get_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`get_exponent_backup_char`]: fn.get_exponent_backup_char.html")]
@@ -160,15 +298,11 @@ extern crate quickcheck;
 extern crate proptest;
 
 // Use vec if there is a system allocator, which we require only if
-// we're using the correct and radix features.
-#[cfg(all(not(feature = "std"), feature = "correct", feature = "radix"))]
+// we're using the radix feature.
+#[cfg(all(not(feature = "std"), feature = "radix"))]
 #[cfg_attr(test, macro_use)]
 extern crate alloc;
 
-// Use arrayvec for atof.
-#[cfg(feature = "correct")]
-extern crate arrayvec;
-
 // Ensure only one back-end is enabled.
 #[cfg(all(feature = "grisu3", feature = "ryu"))]
 compile_error!("Lexical only accepts one of the following backends: `grisu3` or `ryu`.");
@@ -190,7 +324,7 @@ pub(crate) use std::*;
 pub(crate) use core::*;
 
 cfg_if! {
-if #[cfg(all(feature = "correct", feature = "radix"))] {
+if #[cfg(feature = "radix")] {
     #[cfg(feature = "std")]
     pub(crate) use std::vec::Vec;
 
@@ -263,306 +397,1855 @@ pub fn write_radix<'a, N: ToLexical>(n: N, radix: u8, bytes: &'a mut [u8])
     n.to_lexical_radix(radix, bytes)
 }
 
-/// Parse number from string.
+/// Get the exact number of bytes required to write a number to string.
 ///
-/// This method parses the entire string, returning an error if
-/// any invalid digits are found during parsing.
+/// * `value`   - Number to serialize.
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::formatted_len(12345u32), 5);
+/// assert_eq!(lexical_core::formatted_len(-1i8), 2);
+/// # }
+/// ```
 #[inline]
-pub fn parse<N: FromLexical>(bytes: &[u8])
-    -> Result<N>
+pub fn formatted_len<N: ToLexical>(n: N)
+    -> usize
 {
-    N::from_lexical(bytes)
+    let mut buffer = [0u8; BUFFER_SIZE];
+    n.to_lexical(&mut buffer).len()
 }
 
-/// Parse number from string.
+/// Get the exact number of bytes required to write a number to string with a custom radix.
 ///
-/// This method parses until an invalid digit is found (or the end
-/// of the string), returning the number of processed digits
-/// and the parsed value until that point.
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
 #[inline]
-pub fn parse_partial<N: FromLexical>(bytes: &[u8])
-    -> Result<(N, usize)>
+#[cfg(feature = "radix")]
+pub fn formatted_len_radix<N: ToLexical>(n: N, radix: u8)
+    -> usize
 {
-    N::from_lexical_partial(bytes)
+    let mut buffer = [0u8; BUFFER_SIZE];
+    n.to_lexical_radix(radix, &mut buffer).len()
 }
 
-/// Lossily parse number from string.
-///
-/// This method parses the entire string, returning an error if
-/// any invalid digits are found during parsing. This parser is
-/// lossy, so numerical rounding may occur during parsing.
-///
-/// * `bytes`   - Byte slice containing a numeric string.
+// Copy the written number to the buffer, if it fits.
 #[inline]
-pub fn parse_lossy<N: FromLexicalLossy>(bytes: &[u8])
-    -> Result<N>
+fn copy_written<'a>(src: &[u8], bytes: &'a mut [u8])
+    -> lib::result::Result<&'a mut [u8], WriteError>
 {
-    N::from_lexical_lossy(bytes)
+    match bytes.get_mut(..src.len()) {
+        Some(dst)   => {
+            dst.copy_from_slice(src);
+            Ok(dst)
+        },
+        None        => Err(WriteError { required: src.len() }),
+    }
 }
 
-/// Lossily parse number from string.
+/// Write number to string, without panicking on a short buffer.
 ///
-/// This method parses until an invalid digit is found (or the end
-/// of the string), returning the number of processed digits
-/// and the parsed value until that point. This parser is
-/// lossy, so numerical rounding may occur during parsing.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or an
+/// error containing the required size if the buffer is too small for
+/// the serialized number. The buffer only needs to fit the written
+/// number, rather than [`FORMATTED_SIZE_DECIMAL`] elements.
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::WriteError;
+/// # pub fn main() {
+/// let mut buffer = [0u8; 3];
+/// assert_eq!(lexical_core::try_write(123u64, &mut buffer).map(|x| &*x), Ok(&b"123"[..]));
+/// assert_eq!(lexical_core::try_write(1234u64, &mut buffer), Err(WriteError { required: 4 }));
+/// # }
+/// ```
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
 #[inline]
-pub fn parse_partial_lossy<N: FromLexicalLossy>(bytes: &[u8])
-    -> Result<(N, usize)>
+pub fn try_write<'a, N: ToLexical>(n: N, bytes: &'a mut [u8])
+    -> lib::result::Result<&'a mut [u8], WriteError>
 {
-    N::from_lexical_partial_lossy(bytes)
+    if bytes.len() >= N::FORMATTED_SIZE_DECIMAL {
+        return Ok(n.to_lexical(bytes));
+    }
+    let mut buffer = [0u8; BUFFER_SIZE];
+    copy_written(n.to_lexical(&mut buffer), bytes)
 }
 
-/// Parse number from string with a custom radix.
+/// Write number to string with a custom radix, without panicking on a short buffer.
 ///
-/// This method parses the entire string, returning an error if
-/// any invalid digits are found during parsing.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or an
+/// error containing the required size if the buffer is too small for
+/// the serialized number.
 ///
-/// * `radix`   - Radix for number decoding.
-/// * `bytes`   - Byte slice containing a numeric string.
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `bytes`   - Slice containing a numeric string.
 ///
 /// # Panics
 ///
 /// Panics if the radix is not in the range `[2, 36]`.
 #[inline]
 #[cfg(feature = "radix")]
-pub fn parse_radix<N: FromLexical>(bytes: &[u8], radix: u8)
-    -> Result<N>
+pub fn try_write_radix<'a, N: ToLexical>(n: N, radix: u8, bytes: &'a mut [u8])
+    -> lib::result::Result<&'a mut [u8], WriteError>
 {
-    N::from_lexical_radix(bytes, radix)
+    if bytes.len() >= N::FORMATTED_SIZE {
+        return Ok(n.to_lexical_radix(radix, bytes));
+    }
+    let mut buffer = [0u8; BUFFER_SIZE];
+    copy_written(n.to_lexical_radix(radix, &mut buffer), bytes)
 }
 
-/// Parse number from string with a custom radix.
+/// Write arbitrary-precision integer to string.
 ///
-/// This method parses until an invalid digit is found (or the end
-/// of the string), returning the number of processed digits
-/// and the parsed value until that point.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or an
+/// error containing the required size if the buffer is too small for
+/// the serialized number.
 ///
-/// * `radix`   - Radix for number decoding.
-/// * `bytes`   - Byte slice containing a numeric string.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Slice containing a numeric string.
 ///
-/// # Panics
+/// # Examples
 ///
-/// Panics if the radix is not in the range `[2, 36]`.
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::WriteError;
+/// # pub fn main() {
+/// let value: [u64; 4] = [0, 0, 1, 0];
+/// let mut buffer = [0u8; 64];
+/// let slc = lexical_core::write_big_integer(&value, &mut buffer).unwrap();
+/// assert_eq!(slc, b"340282366920938463463374607431768211456");
+///
+/// let mut buffer = [0u8; 16];
+/// let result = lexical_core::write_big_integer(&value, &mut buffer);
+/// assert_eq!(result, Err(WriteError { required: 39 }));
+/// # }
+/// ```
 #[inline]
-#[cfg(feature = "radix")]
-pub fn parse_partial_radix<N: FromLexical>(bytes: &[u8], radix: u8)
-    -> Result<(N, usize)>
+pub fn write_big_integer<'a, T: BigInteger>(value: &T, bytes: &'a mut [u8])
+    -> lib::result::Result<&'a mut [u8], WriteError>
 {
-    N::from_lexical_partial_radix(bytes, radix)
+    itoa::itobig(value, 10, bytes)
 }
 
-/// Lossily parse number from string with a custom radix.
+/// Write arbitrary-precision integer to string with a custom radix.
 ///
-/// This method parses the entire string, returning an error if
-/// any invalid digits are found during parsing. This parser is
-/// lossy, so numerical rounding may occur during parsing.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or an
+/// error containing the required size if the buffer is too small for
+/// the serialized number.
 ///
-/// * `radix`   - Radix for number decoding.
-/// * `bytes`   - Byte slice containing a numeric string.
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `bytes`   - Slice containing a numeric string.
 ///
 /// # Panics
 ///
 /// Panics if the radix is not in the range `[2, 36]`.
 #[inline]
 #[cfg(feature = "radix")]
-pub fn parse_lossy_radix<N: FromLexicalLossy>(bytes: &[u8], radix: u8)
-    -> Result<N>
+pub fn write_big_integer_radix<'a, T: BigInteger>(value: &T, radix: u8, bytes: &'a mut [u8])
+    -> lib::result::Result<&'a mut [u8], WriteError>
 {
-    N::from_lexical_lossy_radix(bytes, radix)
+    assert_radix!(radix);
+    itoa::itobig(value, radix as u32, bytes)
 }
 
-/// Lossily parse number from string with a custom radix.
+/// Write the exact value of a float to string.
 ///
-/// This method parses until an invalid digit is found (or the end
-/// of the string), returning the number of processed digits
-/// and the parsed value until that point. This parser is
-/// lossy, so numerical rounding may occur during parsing.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// float is written in positional notation with every significant digit
+/// of the exact value, rather than the shortest representation that
+/// round-trips.
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
-/// * `radix`   - Radix for number decoding.
+/// * `value`   - Float to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::EXACT_BUFFER_SIZE;
+/// # pub fn main() {
+/// let mut buf = [b'0'; EXACT_BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_exact(0.1f64, &mut buf), &b"0.1000000000000000055511151231257827021181583404541015625"[..]);
+/// assert_eq!(lexical_core::write_exact(0.1f32, &mut buf), &b"0.100000001490116119384765625"[..]);
+/// # }
+/// ```
 ///
 /// # Panics
 ///
-/// Panics if the radix is not in the range `[2, 36]`.
+/// Panics if the buffer is not large enough to hold the exact value of
+/// any float. In order to ensure the function will not panic, provide
+/// a buffer with at least [`EXACT_BUFFER_SIZE`] elements.
+///
+/// [`EXACT_BUFFER_SIZE`]: constant.EXACT_BUFFER_SIZE.html
 #[inline]
-#[cfg(feature = "radix")]
-pub fn parse_partial_lossy_radix<N: FromLexicalLossy>(bytes: &[u8], radix: u8)
-    -> Result<(N, usize)>
+pub fn write_exact<'a, F: Float>(value: F, bytes: &'a mut [u8])
+    -> &'a mut [u8]
 {
-    N::from_lexical_partial_lossy_radix(bytes, radix)
+    assert!(bytes.len() >= EXACT_BUFFER_SIZE);
+    let options = WriteFloatOptions::from_config(10, NumberFormat::standard().unwrap());
+    let len = ftoa::ftoa_exact(value, 10, None, RoundingKind::NearestTieEven, &options, bytes);
+    &mut index_mut!(bytes[..len])
 }
 
-/// Parse number from string with a custom numerical format.
+/// Write a float to string, rounded to a number of significant digits.
 ///
-/// This method parses the entire string, returning an error if
-/// any invalid digits are found during parsing. The numerical format
-/// is specified by the format bitflags, which customize the required
-/// components, digit separators, and other parameters of the number.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// float is written in positional notation, with the exact value rounded
+/// to at most `digits` significant digits using the rounding kind. Trailing
+/// zeros are not written, and a digit count of 0 is treated as 1.
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
-/// * `format`  - Numerical format.
+/// * `value`       - Float to serialize.
+/// * `digits`      - Maximum number of significant digits.
+/// * `rounding`    - Rounding kind for the last digit.
+/// * `bytes`       - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{RoundingKind, EXACT_BUFFER_SIZE};
+/// # pub fn main() {
+/// let mut buf = [b'0'; EXACT_BUFFER_SIZE];
+/// let slc = lexical_core::write_exact_digits(0.1f64, 20, RoundingKind::NearestTieEven, &mut buf);
+/// assert_eq!(slc, &b"0.10000000000000000555"[..]);
+/// let slc = lexical_core::write_exact_digits(-2.25f64, 2, RoundingKind::TowardNegativeInfinity, &mut buf);
+/// assert_eq!(slc, &b"-2.3"[..]);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the exact value of
+/// any float. In order to ensure the function will not panic, provide
+/// a buffer with at least [`EXACT_BUFFER_SIZE`] elements.
+///
+/// [`EXACT_BUFFER_SIZE`]: constant.EXACT_BUFFER_SIZE.html
 #[inline]
-#[cfg(feature = "format")]
-pub fn parse_format<N: FromLexicalFormat>(bytes: &[u8], format: NumberFormat)
-    -> Result<N>
+pub fn write_exact_digits<'a, F: Float>(value: F, digits: usize, rounding: RoundingKind, bytes: &'a mut [u8])
+    -> &'a mut [u8]
 {
-    N::from_lexical_format(bytes, format)
+    assert!(bytes.len() >= EXACT_BUFFER_SIZE);
+    let options = WriteFloatOptions::from_config(10, NumberFormat::standard().unwrap());
+    let len = ftoa::ftoa_exact(value, 10, Some(digits), rounding, &options, bytes);
+    &mut index_mut!(bytes[..len])
 }
 
-/// Parse number from string with a custom numerical format.
+/// Write the exact value of a float to string with a custom radix.
 ///
-/// This method parses until an invalid digit is found (or the end
-/// of the string), returning the number of processed digits
-/// and the parsed value until that point. The numerical format
-/// is specified by the format bitflags, which customize the required
-/// components, digit separators, and other parameters of the number.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// float is written in positional notation with every significant digit
+/// of the exact value. Since the value does not terminate for odd radixes,
+/// it is rounded to nearest, tie-even, at 1100 significant digits.
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
-/// * `format`  - Numerical format.
+/// * `value`   - Float to serialize.
+/// * `radix`   - Radix for number encoding.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::EXACT_BUFFER_SIZE;
+/// # pub fn main() {
+/// let mut buf = [b'0'; EXACT_BUFFER_SIZE];
+/// assert_eq!(lexical_core::write_exact_radix(0.1f32, 2, &mut buf), &b"0.000110011001100110011001101"[..]);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// Also panics if the buffer is not large enough to hold the exact value
+/// of any float. In order to ensure the function will not panic, provide
+/// a buffer with at least [`EXACT_BUFFER_SIZE`] elements.
+///
+/// [`EXACT_BUFFER_SIZE`]: constant.EXACT_BUFFER_SIZE.html
 #[inline]
-#[cfg(feature = "format")]
-pub fn parse_partial_format<N: FromLexicalFormat>(bytes: &[u8], format: NumberFormat)
-    -> Result<(N, usize)>
+#[cfg(feature = "radix")]
+pub fn write_exact_radix<'a, F: Float>(value: F, radix: u8, bytes: &'a mut [u8])
+    -> &'a mut [u8]
 {
-    N::from_lexical_partial_format(bytes, format)
+    assert_radix!(radix);
+    assert!(bytes.len() >= EXACT_BUFFER_SIZE);
+    let options = WriteFloatOptions::from_config(radix as u32, NumberFormat::standard().unwrap());
+    let len = ftoa::ftoa_exact(value, radix as u32, None, RoundingKind::NearestTieEven, &options, bytes);
+    &mut index_mut!(bytes[..len])
 }
 
-/// Lossily parse number from string with a custom numerical format.
+/// Write a float to string with a custom radix, rounded to a number of significant digits.
 ///
-/// This method parses the entire string, returning an error if
-/// any invalid digits are found during parsing. This parser is
-/// lossy, so numerical rounding may occur during parsing. The
-/// numerical format is specified by the format bitflags, which
-/// customize the required components, digit separators, and other
-/// parameters of the number.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// float is written in positional notation, with the exact value rounded
+/// to at most `digits` significant digits using the rounding kind. Trailing
+/// zeros are not written, and a digit count of 0 is treated as 1.
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
-/// * `format`  - Numerical format.
+/// * `value`       - Float to serialize.
+/// * `radix`       - Radix for number encoding.
+/// * `digits`      - Maximum number of significant digits.
+/// * `rounding`    - Rounding kind for the last digit.
+/// * `bytes`       - Slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+///
+/// Also panics if the buffer is not large enough to hold the exact value
+/// of any float. In order to ensure the function will not panic, provide
+/// a buffer with at least [`EXACT_BUFFER_SIZE`] elements.
+///
+/// [`EXACT_BUFFER_SIZE`]: constant.EXACT_BUFFER_SIZE.html
 #[inline]
-#[cfg(feature = "format")]
-pub fn parse_lossy_format<N: FromLexicalLossyFormat>(bytes: &[u8], format: NumberFormat)
-    -> Result<N>
+#[cfg(feature = "radix")]
+pub fn write_exact_digits_radix<'a, F: Float>(value: F, radix: u8, digits: usize, rounding: RoundingKind, bytes: &'a mut [u8])
+    -> &'a mut [u8]
 {
-    N::from_lexical_lossy_format(bytes, format)
+    assert_radix!(radix);
+    assert!(bytes.len() >= EXACT_BUFFER_SIZE);
+    let options = WriteFloatOptions::from_config(radix as u32, NumberFormat::standard().unwrap());
+    let len = ftoa::ftoa_exact(value, radix as u32, Some(digits), rounding, &options, bytes);
+    &mut index_mut!(bytes[..len])
 }
 
-/// Lossily parse number from string with a custom numerical format.
+/// Write number to string with a custom numerical format.
 ///
-/// This method parses until an invalid digit is found (or the end
-/// of the string), returning the number of processed digits
-/// and the parsed value until that point. This parser is
-/// lossy, so numerical rounding may occur during parsing. The
-/// numerical format is specified by the format bitflags, which
-/// customize the required components, digit separators, and other
-/// parameters of the number.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or
+/// `None` if the number cannot be represented in the format. The
+/// written number is valid under the same format, so it may be parsed
+/// back with [`parse_format`].
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
+/// * `value`   - Number to serialize.
 /// * `format`  - Numerical format.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{NumberFormat, ToLexicalFormat};
+/// # pub fn main() {
+/// let format = NumberFormat::REQUIRED_EXPONENT_SIGN | NumberFormat::NO_SPECIAL;
+/// let mut buf = vec![b'0'; f64::format_buffer_size(10, format)];
+/// let slc = lexical_core::write_format(1.5e-300f64, format, &mut buf);
+/// assert_eq!(slc.map(|x| &*x), Some(&b"1.5e-300"[..]));
+/// assert!(lexical_core::write_format(f64::NAN, format, &mut buf).is_none());
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`format_buffer_size`] elements.
+///
+/// [`parse_format`]: fn.parse_format.html
+/// [`format_buffer_size`]: trait.ToLexicalFormat.html#tymethod.format_buffer_size
 #[inline]
 #[cfg(feature = "format")]
-pub fn parse_partial_lossy_format<N: FromLexicalLossyFormat>(bytes: &[u8], format: NumberFormat)
-    -> Result<(N, usize)>
+pub fn write_format<'a, N: ToLexicalFormat>(n: N, format: NumberFormat, bytes: &'a mut [u8])
+    -> Option<&'a mut [u8]>
 {
-    N::from_lexical_partial_lossy_format(bytes, format)
+    n.to_lexical_format(format, bytes)
 }
 
-/// Parse number from string with a custom radix and numerical format.
+/// Write number to string with a custom radix and numerical format.
 ///
-/// This method parses the entire string, returning an error if
-/// any invalid digits are found during parsing. The numerical format
-/// is specified by the format bitflags, which customize the required
-/// components, digit separators, and other parameters of the number.
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or
+/// `None` if the number cannot be represented in the format. The
+/// written number is valid under the same format, so it may be parsed
+/// back with [`parse_format_radix`].
 ///
-/// * `bytes`   - Byte slice containing a numeric string.
-/// * `radix`   - Radix for number decoding.
+/// * `value`   - Number to serialize.
+/// * `radix`   - Radix for number encoding.
 /// * `format`  - Numerical format.
+/// * `bytes`   - Slice containing a numeric string.
 ///
 /// # Panics
 ///
 /// Panics if the radix is not in the range `[2, 36]`.
+///
+/// Also panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`format_buffer_size`] elements.
+///
+/// [`parse_format_radix`]: fn.parse_format_radix.html
+/// [`format_buffer_size`]: trait.ToLexicalFormat.html#tymethod.format_buffer_size
 #[inline]
 #[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_format_radix<N: FromLexicalFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+pub fn write_format_radix<'a, N: ToLexicalFormat>(n: N, radix: u8, format: NumberFormat, bytes: &'a mut [u8])
+    -> Option<&'a mut [u8]>
+{
+    n.to_lexical_format_radix(radix, format, bytes)
+}
+
+/// Parse number from string.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+pub fn parse<N: FromLexical>(bytes: &[u8])
     -> Result<N>
 {
-    N::from_lexical_format_radix(bytes, radix, format)
+    N::from_lexical(bytes)
 }
 
-/// Parse number from string with a custom radix and numerical format.
+/// Parse number from string.
 ///
 /// This method parses until an invalid digit is found (or the end
 /// of the string), returning the number of processed digits
-/// and the parsed value until that point. The numerical format
-/// is specified by the format bitflags, which customize the required
-/// components, digit separators, and other parameters of the number.
+/// and the parsed value until that point.
 ///
 /// * `bytes`   - Byte slice containing a numeric string.
-/// * `radix`   - Radix for number decoding.
-/// * `format`  - Numerical format.
-///
-/// # Panics
-///
-/// Panics if the radix is not in the range `[2, 36]`.
 #[inline]
-#[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_partial_format_radix<N: FromLexicalFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+pub fn parse_partial<N: FromLexical>(bytes: &[u8])
     -> Result<(N, usize)>
 {
-    N::from_lexical_partial_format_radix(bytes, radix, format)
+    N::from_lexical_partial(bytes)
 }
 
-/// Lossily parse number from string with a custom radix and numerical format.
+/// Parse number from string, with context for any error.
 ///
-/// This method parses the entire string, returning an error if
+/// This method parses the entire string, returning an error with
+/// the tokens expected at the error if any invalid digits are
+/// found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+pub fn parse_with_context<N: FromLexical>(bytes: &[u8])
+    -> lib::result::Result<N, ErrorContext>
+{
+    N::from_lexical(bytes).map_err(|error| ErrorContext::new::<N>(bytes, error))
+}
+
+/// Check a parsed value is within a range of values.
+///
+/// This method validates values parsed by other means, such as
+/// a custom parser for a unit type, with the same errors as
+/// [`parse_bounded`]. Out-of-range values are reported with
+/// [`BelowMinimum`] or [`AboveMaximum`], at `start`, which should
+/// be the index of the start of the number. Unordered values, such
+/// as NaN, are not within any bounded range, and are reported with
+/// [`InvalidNan`] instead.
+///
+/// * `value`   - Parsed value.
+/// * `range`   - Range of valid values.
+/// * `start`   - Index of the start of the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// assert!(lexical_core::check_bounds(&5u8, &(1..=10), 0).is_ok());
+///
+/// let error = lexical_core::check_bounds(&0u8, &(1..=10), 2).err().unwrap();
+/// assert_eq!(error.code, ErrorCode::BelowMinimum);
+/// assert_eq!(error.index, 2);
+/// # }
+/// ```
+///
+/// [`parse_bounded`]: fn.parse_bounded.html
+/// [`BelowMinimum`]: enum.ErrorCode.html#variant.BelowMinimum
+/// [`AboveMaximum`]: enum.ErrorCode.html#variant.AboveMaximum
+/// [`InvalidNan`]: enum.ErrorCode.html#variant.InvalidNan
+#[inline]
+pub fn check_bounds<N, R>(value: &N, range: &R, start: usize)
+    -> Result<()>
+    where N: PartialOrd,
+          R: lib::ops::RangeBounds<N>
+{
+    use lib::cmp::Ordering;
+    use lib::ops::Bound;
+
+    // Check if the value is past the bound, in the direction of the
+    // ordering, or `None` if the value is unordered to the bound.
+    let is_past = |bound: Bound<&N>, ordering: Ordering| match bound {
+        Bound::Included(x)  => value.partial_cmp(x).map(|x| x == ordering),
+        Bound::Excluded(x)  => value.partial_cmp(x).map(|x| x != ordering.reverse()),
+        Bound::Unbounded    => Some(false),
+    };
+
+    let is_below = is_past(range.start_bound(), Ordering::Less);
+    let is_above = is_past(range.end_bound(), Ordering::Greater);
+    match (is_below, is_above) {
+        (None, _) | (_, None)   => Err((ErrorCode::InvalidNan, start).into()),
+        (Some(true), _)         => Err((ErrorCode::BelowMinimum, start).into()),
+        (_, Some(true))         => Err((ErrorCode::AboveMaximum, start).into()),
+        _                       => Ok(()),
+    }
+}
+
+/// Get the index of the start of the number with a custom numerical format.
+///
+/// Skips any leading whitespace and opening quote allowed by the
+/// format, as the format parsers do, to report errors for the
+/// parsed value, such as with [`check_bounds`], at the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::NumberFormat;
+/// # pub fn main() {
+/// let format = NumberFormat::LEADING_WHITESPACE | NumberFormat::SURROUNDING_QUOTES;
+/// assert_eq!(lexical_core::format_number_start(b"42", format), 0);
+/// assert_eq!(lexical_core::format_number_start(b" \"42\"", format), 2);
+/// assert_eq!(lexical_core::format_number_start(b" 42", NumberFormat::STANDARD), 0);
+/// # }
+/// ```
+///
+/// [`check_bounds`]: fn.check_bounds.html
+#[inline]
+#[cfg(feature = "format")]
+pub fn format_number_start(bytes: &[u8], format: NumberFormat) -> usize {
+    util::number_start(bytes, format, ASCII_WHITESPACE)
+}
+
+/// Parse number from string, within a range of values.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing, or if the value is
+/// outside of the range. Out-of-range values are reported with
+/// [`BelowMinimum`] or [`AboveMaximum`], at the index of the start
+/// of the number. NaN is not within any bounded range, and is
+/// reported with [`InvalidNan`].
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `range`   - Range of valid values.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_bounded::<u16, _>(b"8080", 1..=65535), Ok(8080));
+///
+/// let error = lexical_core::parse_bounded::<u16, _>(b"0", 1..=65535).err().unwrap();
+/// assert_eq!(error.code, ErrorCode::BelowMinimum);
+/// let error = lexical_core::parse_bounded::<u32, _>(b"128", 1..=64).err().unwrap();
+/// assert_eq!(error.code, ErrorCode::AboveMaximum);
+/// assert!(lexical_core::parse_bounded::<f64, _>(b"0.5", 0.0..1.0).is_ok());
+/// assert!(lexical_core::parse_bounded::<f64, _>(b"1.0", 0.0..1.0).is_err());
+///
+/// let error = lexical_core::parse_bounded::<f64, _>(b"NaN", 0.0..1.0).err().unwrap();
+/// assert_eq!(error.code, ErrorCode::InvalidNan);
+/// assert!(lexical_core::parse_bounded::<f64, _>(b"NaN", ..).is_ok());
+/// # }
+/// ```
+///
+/// [`BelowMinimum`]: enum.ErrorCode.html#variant.BelowMinimum
+/// [`AboveMaximum`]: enum.ErrorCode.html#variant.AboveMaximum
+/// [`InvalidNan`]: enum.ErrorCode.html#variant.InvalidNan
+#[inline]
+pub fn parse_bounded<N, R>(bytes: &[u8], range: R)
+    -> Result<N>
+    where N: FromLexical + PartialOrd,
+          R: lib::ops::RangeBounds<N>
+{
+    let value = N::from_lexical(bytes)?;
+    check_bounds(&value, &range, 0)?;
+    Ok(value)
+}
+
+/// Parse number from string, within a range of values.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point, or an error if the
+/// value is outside of the range.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `range`   - Range of valid values.
+#[inline]
+pub fn parse_partial_bounded<N, R>(bytes: &[u8], range: R)
+    -> Result<(N, usize)>
+    where N: FromLexical + PartialOrd,
+          R: lib::ops::RangeBounds<N>
+{
+    let (value, count) = N::from_lexical_partial(bytes)?;
+    check_bounds(&value, &range, 0)?;
+    Ok((value, count))
+}
+
+/// Parse number from string with custom options, within a range of values.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing, or if the value is
+/// outside of the range.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `range`   - Range of valid values.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_bounded_with_options<N, R>(bytes: &[u8], range: R, options: &N::Options)
+    -> Result<N>
+    where N: FromLexicalOptions + PartialOrd,
+          R: lib::ops::RangeBounds<N>
+{
+    let value = N::from_lexical_with_options(bytes, options)?;
+    check_bounds(&value, &range, N::number_start(bytes, options))?;
+    Ok(value)
+}
+
+/// Parse value from string.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing, or if the value
+/// is rejected. Unlike [`parse`], the value may be any type parsed
+/// through a number, such as a non-zero integer.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # use std::num::NonZeroU32;
+/// # pub fn main() {
+/// assert_eq!(lexical_core::parse_value::<NonZeroU32>(b"8"), Ok(NonZeroU32::new(8).unwrap()));
+///
+/// let error = lexical_core::parse_value::<NonZeroU32>(b"0").err().unwrap();
+/// assert_eq!(error.code, ErrorCode::InvalidZero);
+/// # }
+/// ```
+///
+/// [`parse`]: fn.parse.html
+#[inline]
+pub fn parse_value<N: FromLexicalValue>(bytes: &[u8])
+    -> Result<N>
+{
+    N::from_lexical(bytes)
+}
+
+/// Parse value from string.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point, or an error if the
+/// value is rejected.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+pub fn parse_partial_value<N: FromLexicalValue>(bytes: &[u8])
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial(bytes)
+}
+
+/// Parse value from string with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing, or if the value
+/// is rejected. The radix and numerical format are set by the
+/// options.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseIntegerOptions;
+/// # use std::num::NonZeroU32;
+/// # pub fn main() {
+/// let options = ParseIntegerOptions::new();
+/// let value = lexical_core::parse_value_with_options::<NonZeroU32>(b"8", &options);
+/// assert_eq!(value, Ok(NonZeroU32::new(8).unwrap()));
+/// # }
+/// ```
+#[inline]
+pub fn parse_value_with_options<N: FromLexicalValueOptions>(bytes: &[u8], options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes, options)
+}
+
+/// Parse value from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point, or an error if the
+/// value is rejected. The radix and numerical format are set by
+/// the options.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_partial_value_with_options<N: FromLexicalValueOptions>(bytes: &[u8], options: &N::Options)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_with_options(bytes, options)
+}
+
+/// Lossily parse number from string.
+///
+/// This method parses the entire string, returning an error if
 /// any invalid digits are found during parsing. This parser is
-/// lossy, so numerical rounding may occur during parsing. The
-/// numerical format is specified by the format bitflags, which
-/// customize the required components, digit separators, and other
-/// parameters of the number.
+/// lossy, so numerical rounding may occur during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+pub fn parse_lossy<N: FromLexicalLossy>(bytes: &[u8])
+    -> Result<N>
+{
+    N::from_lexical_lossy(bytes)
+}
+
+/// Lossily parse number from string.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. This parser is
+/// lossy, so numerical rounding may occur during parsing.
 ///
 /// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+pub fn parse_partial_lossy<N: FromLexicalLossy>(bytes: &[u8])
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy(bytes)
+}
+
+/// Parse number from string with a custom radix.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
 /// * `radix`   - Radix for number decoding.
-/// * `format`  - Numerical format.
+/// * `bytes`   - Byte slice containing a numeric string.
 ///
 /// # Panics
 ///
 /// Panics if the radix is not in the range `[2, 36]`.
 #[inline]
-#[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_lossy_format_radix<N: FromLexicalLossyFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+#[cfg(feature = "radix")]
+pub fn parse_radix<N: FromLexical>(bytes: &[u8], radix: u8)
     -> Result<N>
 {
-    N::from_lexical_lossy_format_radix(bytes, radix, format)
+    N::from_lexical_radix(bytes, radix)
 }
 
-/// Lossily parse number from string with a custom radix and numerical format.
+/// Parse number from string with a custom radix.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `radix`   - Radix for number decoding.
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_partial_radix<N: FromLexical>(bytes: &[u8], radix: u8)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_radix(bytes, radix)
+}
+
+/// Lossily parse number from string with a custom radix.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. This parser is
+/// lossy, so numerical rounding may occur during parsing.
+///
+/// * `radix`   - Radix for number decoding.
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(feature = "radix")]
+pub fn parse_lossy_radix<N: FromLexicalLossy>(bytes: &[u8], radix: u8)
+    -> Result<N>
+{
+    N::from_lexical_lossy_radix(bytes, radix)
+}
+
+/// Lossily parse number from string with a custom radix.
 ///
 /// This method parses until an invalid digit is found (or the end
 /// of the string), returning the number of processed digits
 /// and the parsed value until that point. This parser is
-/// lossy, so numerical rounding may occur during parsing. The
-/// numerical format is specified by the format bitflags, which
-/// customize the required components, digit separators, and other
-/// parameters of the number.
+/// lossy, so numerical rounding may occur during parsing.
 ///
 /// * `bytes`   - Byte slice containing a numeric string.
 /// * `radix`   - Radix for number decoding.
-/// * `format`  - Numerical format.
 ///
 /// # Panics
 ///
 /// Panics if the radix is not in the range `[2, 36]`.
 #[inline]
-#[cfg(all(feature = "radix", feature = "format"))]
-pub fn parse_partial_lossy_format_radix<N: FromLexicalLossyFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+#[cfg(feature = "radix")]
+pub fn parse_partial_lossy_radix<N: FromLexicalLossy>(bytes: &[u8], radix: u8)
     -> Result<(N, usize)>
 {
-    N::from_lexical_partial_lossy_format_radix(bytes, radix, format)
+    N::from_lexical_partial_lossy_radix(bytes, radix)
+}
+
+/// Parse number from string with a custom numerical format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+/// Base prefixes allowed by the format set the radix, and type
+/// suffixes allowed by the format are consumed after the number.
+/// Whitespace and surrounding quotes allowed by the format are
+/// consumed around the number, and error indexes are relative to
+/// the start of the bytes.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ErrorCode, NumberFormat};
+/// # pub fn main() {
+/// let format = NumberFormat::CXX17_LITERAL;
+/// assert_eq!(lexical_core::parse_format::<u64>(b"0x1'0000UL", format), Ok(65536));
+/// assert_eq!(lexical_core::parse_format::<f32>(b"1.5f", format), Ok(1.5));
+///
+/// let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+/// assert_eq!(lexical_core::parse_format::<i32>(b" 42\n", format), Ok(42));
+/// assert_eq!(lexical_core::parse_format::<f64>(b"\"1.5\"", format), Ok(1.5));
+/// let error = lexical_core::parse_format::<i32>(b" \"4x\"", format).unwrap_err();
+/// assert_eq!((error.code, error.index), (ErrorCode::InvalidDigit, 3));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_format<N: FromLexicalFormat>(bytes: &[u8], format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_format(bytes, format)
+}
+
+/// Parse number from string with a custom numerical format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_partial_format<N: FromLexicalFormat>(bytes: &[u8], format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_format(bytes, format)
+}
+
+/// Parse number and type suffix from string with a custom numerical format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. Any base prefix
+/// allowed by the format sets the radix, and the type suffix allowed
+/// by the format is returned with the value, or an empty slice if
+/// the number has no suffix.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::NumberFormat;
+/// # pub fn main() {
+/// let format = NumberFormat::RUST_LITERAL;
+/// assert_eq!(lexical_core::parse_format_suffix::<u32>(b"0xFFu8", format), Ok((255, &b"u8"[..])));
+/// assert_eq!(lexical_core::parse_format_suffix::<u32>(b"0b1010", format), Ok((10, &b""[..])));
+/// assert_eq!(lexical_core::parse_format_suffix::<f32>(b"1.5f32", format), Ok((1.5, &b"f32"[..])));
+///
+/// let format = NumberFormat::JAVA_LITERAL;
+/// assert_eq!(lexical_core::parse_format_suffix::<i64>(b"10L", format), Ok((10, &b"L"[..])));
+///
+/// let format = NumberFormat::RUST_LITERAL | NumberFormat::SURROUNDING_QUOTES;
+/// assert_eq!(lexical_core::parse_format_suffix::<u8>(b"\"10u8\"", format), Ok((10, &b"u8"[..])));
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_format_suffix<N: FromLexicalFormat>(bytes: &[u8], format: NumberFormat)
+    -> Result<(N, &[u8])>
+{
+    match parse_partial_format_suffix::<N>(bytes, format) {
+        Ok((value, suffix, count)) if count == bytes.len() => Ok((value, suffix)),
+        Ok((_, _, count)) => Err((ErrorCode::InvalidDigit, count).into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse number and type suffix from string with a custom numerical format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the parsed value, the type suffix
+/// (or an empty slice), and the number of processed bytes including
+/// the suffix. Any base prefix allowed by the format sets the radix,
+/// and the whitespace and quotes allowed by the format surround the
+/// number and its suffix.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_partial_format_suffix<N: FromLexicalFormat>(bytes: &[u8], format: NumberFormat)
+    -> Result<(N, &[u8], usize)>
+{
+    let number_format = format
+        - NumberFormat::TYPE_SUFFIX_FLAG_MASK
+        - NumberFormat::WHITESPACE_FLAG_MASK
+        - NumberFormat::SURROUNDING_QUOTES;
+    let ((value, suffix), count) = parse_trimmed(bytes, format, ASCII_WHITESPACE, |bytes| {
+        let (value, index) = N::from_lexical_partial_format(bytes, number_format)?;
+        let rest = &bytes[index..];
+        let suffix = &rest[..type_suffix_len(rest, format, N::IS_FLOAT)];
+        Ok(((value, suffix), index + suffix.len()))
+    })?;
+    Ok((value, suffix, count))
+}
+
+/// Lossily parse number from string with a custom numerical format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. This parser is
+/// lossy, so numerical rounding may occur during parsing. The
+/// numerical format is specified by the format bitflags, which
+/// customize the required components, digit separators, and other
+/// parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_lossy_format<N: FromLexicalLossyFormat>(bytes: &[u8], format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_lossy_format(bytes, format)
+}
+
+/// Lossily parse number from string with a custom numerical format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. This parser is
+/// lossy, so numerical rounding may occur during parsing. The
+/// numerical format is specified by the format bitflags, which
+/// customize the required components, digit separators, and other
+/// parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_partial_lossy_format<N: FromLexicalLossyFormat>(bytes: &[u8], format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy_format(bytes, format)
+}
+
+/// Parse number from string with a custom radix and numerical format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+/// * `format`  - Numerical format.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_format_radix<N: FromLexicalFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_format_radix(bytes, radix, format)
+}
+
+/// Parse number from string with a custom radix and numerical format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+/// * `format`  - Numerical format.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_partial_format_radix<N: FromLexicalFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_format_radix(bytes, radix, format)
+}
+
+/// Lossily parse number from string with a custom radix and numerical format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. This parser is
+/// lossy, so numerical rounding may occur during parsing. The
+/// numerical format is specified by the format bitflags, which
+/// customize the required components, digit separators, and other
+/// parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+/// * `format`  - Numerical format.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_lossy_format_radix<N: FromLexicalLossyFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+    -> Result<N>
+{
+    N::from_lexical_lossy_format_radix(bytes, radix, format)
+}
+
+/// Lossily parse number from string with a custom radix and numerical format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. This parser is
+/// lossy, so numerical rounding may occur during parsing. The
+/// numerical format is specified by the format bitflags, which
+/// customize the required components, digit separators, and other
+/// parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `radix`   - Radix for number decoding.
+/// * `format`  - Numerical format.
+///
+/// # Panics
+///
+/// Panics if the radix is not in the range `[2, 36]`.
+#[inline]
+#[cfg(all(feature = "radix", feature = "format"))]
+pub fn parse_partial_lossy_format_radix<N: FromLexicalLossyFormat>(bytes: &[u8], radix: u8, format: NumberFormat)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_lossy_format_radix(bytes, radix, format)
+}
+
+/// Write number to string with custom options.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to customize number serialization.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`buffer_size`] elements.
+///
+/// Also panics if the number is negative, and `-` is a digit in the
+/// alphabet of the options, such as [`DigitAlphabet::BASE64_URL`].
+///
+/// [`buffer_size`]: trait.ToLexicalOptions.html#tymethod.buffer_size
+/// [`DigitAlphabet::BASE64_URL`]: struct.DigitAlphabet.html#associatedconstant.BASE64_URL
+#[inline]
+pub fn write_with_options<'a, N: ToLexicalOptions>(n: N, bytes: &'a mut [u8], options: &N::Options)
+    -> &'a mut [u8]
+{
+    n.to_lexical_with_options(bytes, options)
+}
+
+/// Write float to string with a custom notation and precision.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// digits are correctly rounded, to nearest with ties to even, from
+/// the exact value of the float.
+///
+/// * `value`   - Float to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+/// * `options` - Options to customize float serialization.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{FloatNotation, ToLexicalOptions, WriteFloatOptions};
+/// # pub fn main() {
+/// let options = WriteFloatOptions::builder()
+///     .notation(FloatNotation::Fixed)
+///     .precision(Some(3))
+///     .build()
+///     .unwrap();
+/// let mut buf = vec![b'0'; f64::buffer_size(&options)];
+/// let slc = lexical_core::write_float_with_options(3.14159f64, &mut buf, &options);
+/// assert_eq!(slc, b"3.142");
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`buffer_size`] elements.
+///
+/// [`buffer_size`]: trait.ToLexicalOptions.html#tymethod.buffer_size
+#[inline]
+pub fn write_float_with_options<'a, F>(value: F, bytes: &'a mut [u8], options: &WriteFloatOptions)
+    -> &'a mut [u8]
+    where F: Float + ToLexicalOptions<Options = WriteFloatOptions>
+{
+    value.to_lexical_with_options(bytes, options)
+}
+
+/// Parse number from string with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_with_options<N: FromLexicalOptions>(bytes: &[u8], options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes, options)
+}
+
+/// Parse number from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_partial_with_options<N: FromLexicalOptions>(bytes: &[u8], options: &N::Options)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_with_options(bytes, options)
+}
+
+/// Parse many delimited numbers from string into a slice.
+///
+/// Parses fields until the slice is full or the string is exhausted,
+/// returning the number of parsed values and the number of processed
+/// bytes, or the first error with the index of the failing field.
+/// See [`ParseManyIter`] for the syntax of the delimited fields.
+///
+/// * `bytes`   - Byte slice containing delimited numeric strings.
+/// * `dst`     - Slice to store the parsed values.
+/// * `options` - Options for the delimited fields.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ParseManyOptions;
+/// # pub fn main() {
+/// let options = ParseManyOptions::new();
+/// let mut values = [0u32; 4];
+/// assert_eq!(lexical_core::parse_many(b"1,2\n3,4\n5,6", &mut values, &options), Ok((4, 8)));
+/// assert_eq!(values, [1, 2, 3, 4]);
+///
+/// let error = lexical_core::parse_many(b"1,x", &mut values, &options).err().unwrap();
+/// assert_eq!(error.field, 1);
+/// assert_eq!(error.error.index, 2);
+/// # }
+/// ```
+///
+/// [`ParseManyIter`]: struct.ParseManyIter.html
+#[inline]
+pub fn parse_many<N: FromLexical>(bytes: &[u8], dst: &mut [N], options: &ParseManyOptions)
+    -> lib::result::Result<(usize, usize), FieldError>
+{
+    let mut iter = ParseManyIter::new(bytes, options);
+    let mut count = 0;
+    for slot in dst.iter_mut() {
+        match iter.next() {
+            Some(value) => *slot = value?,
+            None        => break,
+        }
+        count += 1;
+    }
+    Ok((count, iter.processed()))
+}
+
+/// Parse many delimited numbers from string into a vector.
+///
+/// Returns the parsed values, or the first error with the index of the
+/// failing field. See [`ParseManyIter`] for the syntax of the delimited
+/// fields.
+///
+/// * `bytes`   - Byte slice containing delimited numeric strings.
+/// * `options` - Options for the delimited fields.
+///
+/// [`ParseManyIter`]: struct.ParseManyIter.html
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_many_vec<N: FromLexical>(bytes: &[u8], options: &ParseManyOptions)
+    -> lib::result::Result<lib::vec::Vec<N>, FieldError>
+{
+    ParseManyIter::new(bytes, options).collect()
+}
+
+/// Write extended-precision float to string.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The
+/// float is written with the shortest representation that round-trips.
+///
+/// * `value`   - Float to serialize.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{f128, EXTENDED_FORMATTED_SIZE_DECIMAL};
+/// # pub fn main() {
+/// let mut buf = [b'0'; EXTENDED_FORMATTED_SIZE_DECIMAL];
+/// let value = f128::from_bits(0x3FFB_9999_9999_9999_9999_9999_9999_999A);
+/// assert_eq!(lexical_core::write_extended(value, &mut buf), b"0.1");
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the
+/// serialized number. In order to ensure the function will not panic,
+/// provide a buffer with at least [`EXTENDED_FORMATTED_SIZE_DECIMAL`] elements.
+///
+/// [`EXTENDED_FORMATTED_SIZE_DECIMAL`]: constant.EXTENDED_FORMATTED_SIZE_DECIMAL.html
+#[inline]
+#[cfg(feature = "f128")]
+pub fn write_extended<'a, F: ExtendedPrecision>(value: F, bytes: &'a mut [u8])
+    -> &'a mut [u8]
+{
+    let len = ftoa::etoa(value, bytes);
+    &mut index_mut!(bytes[..len])
+}
+
+/// Parse extended-precision float from string.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The float is
+/// correctly rounded, to nearest with ties to even, from the exact
+/// decimal value.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::f80;
+/// # pub fn main() {
+/// let value: f80 = lexical_core::parse_extended(b"0.1").unwrap();
+/// assert_eq!(value.to_bits(), 0x3FFB_CCCC_CCCC_CCCC_CCCD);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "f128")]
+pub fn parse_extended<F: ExtendedPrecision>(bytes: &[u8])
+    -> Result<F>
+{
+    parse_extended_with_options(bytes, &ParseFloatOptions::new())
+}
+
+/// Parse extended-precision float from string.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+#[cfg(feature = "f128")]
+pub fn parse_partial_extended<F: ExtendedPrecision>(bytes: &[u8])
+    -> Result<(F, usize)>
+{
+    parse_partial_extended_with_options(bytes, &ParseFloatOptions::new())
+}
+
+/// Parse extended-precision float from string with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{f128, ParseFloatOptions};
+/// # pub fn main() {
+/// let options = ParseFloatOptions::builder()
+///     .exponent_default_char(b'^')
+///     .build()
+///     .unwrap();
+/// let value: f128 = lexical_core::parse_extended_with_options(b"1^2", &options).unwrap();
+/// assert_eq!(value.to_bits(), 0x4005_9000_0000_0000_0000_0000_0000_0000);
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "f128")]
+pub fn parse_extended_with_options<F: ExtendedPrecision>(bytes: &[u8], options: &ParseFloatOptions)
+    -> Result<F>
+{
+    match atof::atoe_options(bytes, options) {
+        Ok((value, count)) if count == bytes.len() => Ok(value),
+        Ok((_, count)) => Err((ErrorCode::InvalidDigit, count).into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse extended-precision float from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+#[cfg(feature = "f128")]
+pub fn parse_partial_extended_with_options<F: ExtendedPrecision>(bytes: &[u8], options: &ParseFloatOptions)
+    -> Result<(F, usize)>
+{
+    atof::atoe_options(bytes, options)
+}
+
+/// Parse exact decimal number from string.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. Every digit is kept,
+/// so no rounding occurs. Special values cannot be represented by
+/// a decimal number, and are invalid.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::Decimal;
+/// # pub fn main() {
+/// let decimal = lexical_core::parse_decimal(b"-12345.678900000000000000000001").unwrap();
+/// assert_eq!(decimal.digits, b"12345678900000000000000000001".to_vec());
+/// assert_eq!(decimal.exponent, -24);
+/// assert!(decimal.is_negative);
+/// assert_eq!(lexical_core::parse_decimal(b"1.5e3"), Ok(Decimal::new(false, b"15", 2)));
+///
+/// let mut buf = vec![b'0'; decimal.formatted_len()];
+/// assert_eq!(decimal.write(&mut buf), b"-12345.678900000000000000000001");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_decimal(bytes: &[u8])
+    -> Result<Decimal>
+{
+    match atof::atodecimal(bytes) {
+        Ok((value, count)) if count == bytes.len() => Ok(value),
+        Ok((_, count)) => Err((ErrorCode::InvalidDigit, count).into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse exact decimal number from string.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_partial_decimal(bytes: &[u8])
+    -> Result<(Decimal, usize)>
+{
+    atof::atodecimal(bytes)
+}
+
+/// Parse exact decimal number from string with a custom numerical format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Decimal, NumberFormat};
+/// # pub fn main() {
+/// let format = NumberFormat::ignore(b'_').unwrap();
+/// let decimal = lexical_core::parse_decimal_format(b"1_000_000.25", format).unwrap();
+/// assert_eq!(decimal, Decimal::new(false, b"100000025", -2));
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", feature = "format"))]
+pub fn parse_decimal_format(bytes: &[u8], format: NumberFormat)
+    -> Result<Decimal>
+{
+    match atof::atodecimal_format(bytes, format) {
+        Ok((value, count)) if count == bytes.len() => Ok(value),
+        Ok((_, count)) => Err((ErrorCode::InvalidDigit, count).into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse exact decimal number from string with a custom numerical format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+#[inline]
+#[cfg(all(feature = "std", feature = "format"))]
+pub fn parse_partial_decimal_format(bytes: &[u8], format: NumberFormat)
+    -> Result<(Decimal, usize)>
+{
+    atof::atodecimal_format(bytes, format)
+}
+
+/// Parse the components of a number from string.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The number is
+/// validated as a decimal float, and the integer and fraction
+/// digits are returned as subslices of the input, which allows
+/// other parsers to reuse the syntax validation.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # pub fn main() {
+/// let components = lexical_core::parse_components(b"-0012.500e-3").unwrap();
+/// assert!(components.is_negative);
+/// assert_eq!(components.integer, b"0012");
+/// assert_eq!(components.fraction, b"500");
+/// assert_eq!(components.exponent, -3);
+/// assert!(!components.is_special);
+///
+/// assert!(lexical_core::parse_components(b"NaN").unwrap().is_special);
+/// assert!(lexical_core::parse_components(b"1.5x").is_err());
+/// # }
+/// ```
+#[inline]
+pub fn parse_components(bytes: &[u8])
+    -> Result<NumberComponents>
+{
+    match atof::atocomponents(bytes) {
+        Ok((value, count)) if count == bytes.len() => Ok(value),
+        Ok((_, count)) => Err((ErrorCode::InvalidDigit, count).into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse the components of a number from string.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the components until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+pub fn parse_partial_components(bytes: &[u8])
+    -> Result<(NumberComponents, usize)>
+{
+    atof::atocomponents(bytes)
+}
+
+/// Parse the components of a number from string with a custom numerical format.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+/// The integer and fraction digits include any digit separators.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::NumberFormat;
+/// # pub fn main() {
+/// let format = NumberFormat::ignore(b'_').unwrap();
+/// let components = lexical_core::parse_components_format(b"1_000.2_5", format).unwrap();
+/// assert_eq!(components.integer, b"1_000");
+/// assert_eq!(components.fraction, b"2_5");
+///
+/// let format = NumberFormat::JSON;
+/// assert!(lexical_core::parse_components_format(b"01", format).is_err());
+/// assert!(lexical_core::parse_components_format(b"NaN", format).is_err());
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_components_format(bytes: &[u8], format: NumberFormat)
+    -> Result<NumberComponents>
+{
+    match atof::atocomponents_format(bytes, format) {
+        Ok((value, count)) if count == bytes.len() => Ok(value),
+        Ok((_, count)) => Err((ErrorCode::InvalidDigit, count).into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse the components of a number from string with a custom numerical format.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the components until that point. The numerical format
+/// is specified by the format bitflags, which customize the required
+/// components, digit separators, and other parameters of the number.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `format`  - Numerical format.
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_partial_components_format(bytes: &[u8], format: NumberFormat)
+    -> Result<(NumberComponents, usize)>
+{
+    atof::atocomponents_format(bytes, format)
+}
+
+/// Parse arbitrary-precision integer from string.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. Digits are added to
+/// the integer in chunks, so any type implementing [`BigInteger`]
+/// may be parsed, such as `[u64; 4]` for 256-bit unsigned integers.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::ErrorCode;
+/// # pub fn main() {
+/// let value: [u64; 4] = lexical_core::parse_big_integer(b"340282366920938463463374607431768211456").unwrap();
+/// assert_eq!(value, [0, 0, 1, 0]);
+///
+/// let result = lexical_core::parse_big_integer::<[u64; 2]>(b"340282366920938463463374607431768211456");
+/// assert_eq!(result.err().unwrap().code, ErrorCode::Overflow);
+/// # }
+/// ```
+///
+/// [`BigInteger`]: trait.BigInteger.html
+#[inline]
+pub fn parse_big_integer<T: BigInteger>(bytes: &[u8])
+    -> Result<T>
+{
+    parse_big_integer_with_options(bytes, &ParseIntegerOptions::new())
+}
+
+/// Parse arbitrary-precision integer from string.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+#[inline]
+pub fn parse_partial_big_integer<T: BigInteger>(bytes: &[u8])
+    -> Result<(T, usize)>
+{
+    parse_partial_big_integer_with_options(bytes, &ParseIntegerOptions::new())
+}
+
+/// Parse arbitrary-precision integer from string with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. The options
+/// customize the radix and the numerical format, including
+/// digit separators.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_big_integer_with_options<T: BigInteger>(bytes: &[u8], options: &ParseIntegerOptions)
+    -> Result<T>
+{
+    match atoi::atobig_options(bytes, options) {
+        Ok((value, count)) if count == bytes.len() => Ok(value),
+        Ok((_, count)) => Err((ErrorCode::InvalidDigit, count).into()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Parse arbitrary-precision integer from string with custom options.
+///
+/// This method parses until an invalid digit is found (or the end
+/// of the string), returning the number of processed digits
+/// and the parsed value until that point.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_partial_big_integer_with_options<T: BigInteger>(bytes: &[u8], options: &ParseIntegerOptions)
+    -> Result<(T, usize)>
+{
+    atoi::atobig_options(bytes, options)
+}
+
+/// Parse number from a localized string.
+///
+/// The number is parsed with the decimal point, group separator, signs
+/// and special strings of the locale. Group separators are optional,
+/// but if the integer digits have any group separators, they must match
+/// the grouping of the locale. Misplaced group separators are invalid
+/// digits.
+///
+/// * `bytes`   - Byte slice containing a localized numeric string.
+/// * `locale`  - Locale-specific symbols for the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::Locale;
+/// # pub fn main() {
+/// let value: i32 = lexical_core::parse_locale(b"-1.234.567", &Locale::DE_DE).unwrap();
+/// assert_eq!(value, -1234567);
+///
+/// let value: f64 = lexical_core::parse_locale("\u{2212}1\u{A0}234,5".as_bytes(), &Locale::SV_SE).unwrap();
+/// assert_eq!(value, -1234.5);
+/// # }
+/// ```
+#[inline]
+pub fn parse_locale<N>(bytes: &[u8], locale: &Locale)
+    -> Result<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    util::parse_localized(bytes, locale)
+}
+
+/// Parse number from a localized string until an invalid digit is found.
+///
+/// Returns the parsed value and the number of processed bytes. See
+/// [`parse_locale`] for the syntax of localized numbers.
+///
+/// * `bytes`   - Byte slice containing a localized numeric string.
+/// * `locale`  - Locale-specific symbols for the number.
+///
+/// [`parse_locale`]: fn.parse_locale.html
+#[inline]
+pub fn parse_partial_locale<N>(bytes: &[u8], locale: &Locale)
+    -> Result<(N, usize)>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    util::parse_partial_localized(bytes, locale)
+}
+
+/// Write number to a localized string.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice, or an
+/// error containing the required size if the buffer is too small for
+/// the serialized number. The number is written with the shortest
+/// representation, with the decimal point, grouping, signs and special
+/// strings of the locale.
+///
+/// * `n`       - Number to serialize.
+/// * `locale`  - Locale-specific symbols for the number.
+/// * `bytes`   - Slice containing a numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{Locale, WriteError};
+/// # pub fn main() {
+/// let mut buffer = [0u8; 64];
+/// let slc = lexical_core::write_locale(1234567.5f64, &Locale::EN_IN, &mut buffer).unwrap();
+/// assert_eq!(slc, b"12,34,567.5");
+///
+/// let mut buffer = [0u8; 4];
+/// let result = lexical_core::write_locale(-12345i32, &Locale::DE_DE, &mut buffer);
+/// assert_eq!(result, Err(WriteError { required: 7 }));
+/// # }
+/// ```
+#[inline]
+pub fn write_locale<'a, N>(n: N, locale: &Locale, bytes: &'a mut [u8])
+    -> lib::result::Result<&'a mut [u8], WriteError>
+    where N: ToLexicalOptions,
+          N::Options: Default
+{
+    util::write_localized(n, locale, bytes)
+}
+
+/// Parse number from code units.
+///
+/// The code units are UTF-8 (`u8`) or UTF-16 (`u16`), and the digit
+/// script controls which characters are accepted: only ASCII
+/// characters, or Unicode decimal digits and separators mapped to
+/// ASCII. Error indexes are reported in code units.
+///
+/// * `units`   - Slice of code units containing a numeric string.
+/// * `script`  - Characters accepted in the numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{DigitScript, Error, ErrorCode};
+/// # pub fn main() {
+/// let units: Vec<u16> = "-1.5".encode_utf16().collect();
+/// assert_eq!(lexical_core::parse_units::<f64, u16>(&units, DigitScript::Ascii), Ok(-1.5));
+///
+/// let units: Vec<u16> = "\u{FF11}\u{FF12}\u{FF13}".encode_utf16().collect();
+/// assert_eq!(lexical_core::parse_units::<i32, u16>(&units, DigitScript::Unicode), Ok(123));
+///
+/// let bytes = "\u{0663}\u{066B}\u{0661}\u{0664}".as_bytes();
+/// assert_eq!(lexical_core::parse_units::<f64, u8>(bytes, DigitScript::Unicode), Ok(3.14));
+///
+/// let bytes = "\u{0661}\u{0662}x".as_bytes();
+/// let error = Error { code: ErrorCode::InvalidDigit, index: 4 };
+/// assert_eq!(lexical_core::parse_units::<i32, u8>(bytes, DigitScript::Unicode), Err(error));
+/// # }
+/// ```
+#[inline]
+pub fn parse_units<N, U>(units: &[U], script: DigitScript)
+    -> Result<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit
+{
+    util::parse_transcoded(units, script, &N::Options::default())
+}
+
+/// Parse number from code units until an invalid digit is found.
+///
+/// Returns the parsed value and the number of processed code units.
+/// See [`parse_units`] for the accepted characters.
+///
+/// * `units`   - Slice of code units containing a numeric string.
+/// * `script`  - Characters accepted in the numeric string.
+///
+/// [`parse_units`]: fn.parse_units.html
+#[inline]
+pub fn parse_partial_units<N, U>(units: &[U], script: DigitScript)
+    -> Result<(N, usize)>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit
+{
+    util::parse_partial_transcoded(units, script, &N::Options::default())
+}
+
+/// Parse number from code units with custom options.
+///
+/// This method parses the entire string, returning an error if
+/// any invalid digits are found during parsing. See [`parse_units`]
+/// for the accepted characters.
+///
+/// * `units`   - Slice of code units containing a numeric string.
+/// * `script`  - Characters accepted in the numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// [`parse_units`]: fn.parse_units.html
+#[inline]
+pub fn parse_units_with_options<N, U>(units: &[U], script: DigitScript, options: &N::Options)
+    -> Result<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit
+{
+    util::parse_transcoded(units, script, options)
+}
+
+/// Parse number from code units with custom options until an invalid digit is found.
+///
+/// Returns the parsed value and the number of processed code units.
+/// See [`parse_units`] for the accepted characters.
+///
+/// * `units`   - Slice of code units containing a numeric string.
+/// * `script`  - Characters accepted in the numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// [`parse_units`]: fn.parse_units.html
+#[inline]
+pub fn parse_partial_units_with_options<N, U>(units: &[U], script: DigitScript, options: &N::Options)
+    -> Result<(N, usize)>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit
+{
+    util::parse_partial_transcoded(units, script, options)
 }
@@ -0,0 +1,257 @@
+//! Digit alphabets for integers with a radix up to 64.
+//!
+//! Each alphabet has precalculated tables from digits to characters,
+//! and from characters to digits, so both conversions are a single
+//! lookup. The built-in alphabets are constant tables, and custom
+//! alphabets are created at runtime.
+
+use crate::lib::fmt;
+
+// Marker for a character that is not a digit in the alphabet.
+const INVALID: u8 = 0xFF;
+
+// ALPHABET
+
+/// Set of characters for the digits of integers.
+///
+/// The alphabet maps each digit in `[0, radix)` to a character, and
+/// each character back to its digit, for radixes from 2 to 64. Use one
+/// of the built-in alphabets, or create a custom alphabet with
+/// [`DigitAlphabet::new`].
+///
+/// The digits are a positional number, most-significant digit first,
+/// so `base64` is an integer in radix 64, not an encoding of bytes,
+/// and padding is not supported. A leading `+` or `-` is only a sign
+/// if it is not a digit in the alphabet, so a `+` sign cannot be
+/// parsed or written with the [`BASE64`] alphabet, and negative
+/// numbers cannot be parsed or written with the [`BASE64_URL`]
+/// alphabet. Floats
+/// always use the default digits, since the float algorithms are
+/// limited to radixes up to 36.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{DigitAlphabet, ParseIntegerOptions};
+/// # pub fn main() {
+/// let options = ParseIntegerOptions::builder()
+///     .alphabet(Some(DigitAlphabet::BASE58))
+///     .build()
+///     .unwrap();
+/// let value: u64 = lexical_core::parse_with_options(b"5Q", &options).unwrap();
+/// assert_eq!(value, 4 * 58 + 23);
+/// # }
+/// ```
+///
+/// [`DigitAlphabet::new`]: #method.new
+/// [`BASE64`]: #associatedconstant.BASE64
+/// [`BASE64_URL`]: #associatedconstant.BASE64_URL
+#[derive(Copy, Clone)]
+pub struct DigitAlphabet {
+    /// Number of digits in the alphabet.
+    radix: u8,
+    /// Character for each digit.
+    digits: [u8; 64],
+    /// Digit for each character, or `INVALID`.
+    values: [u8; 256],
+}
+
+impl DigitAlphabet {
+    /// RFC 4648 base32 alphabet, `A-Z2-7`, case-insensitive.
+    pub const BASE32: DigitAlphabet = DigitAlphabet { radix: 32, digits: BASE32_DIGITS, values: BASE32_VALUES };
+
+    /// RFC 4648 extended hex base32 alphabet, `0-9A-V`, case-insensitive.
+    pub const BASE32_HEX: DigitAlphabet = DigitAlphabet { radix: 32, digits: BASE32_HEX_DIGITS, values: BASE32_HEX_VALUES };
+
+    /// Bitcoin base58 alphabet, which excludes `0`, `I`, `O` and `l`.
+    pub const BASE58: DigitAlphabet = DigitAlphabet { radix: 58, digits: BASE58_DIGITS, values: BASE58_VALUES };
+
+    /// Base62 alphabet, `0-9A-Za-z`.
+    pub const BASE62: DigitAlphabet = DigitAlphabet { radix: 62, digits: BASE62_DIGITS, values: BASE62_VALUES };
+
+    /// RFC 4648 base64 alphabet, `A-Za-z0-9+/`.
+    pub const BASE64: DigitAlphabet = DigitAlphabet { radix: 64, digits: BASE64_DIGITS, values: BASE64_VALUES };
+
+    /// RFC 4648 URL and filename safe base64 alphabet, `A-Za-z0-9-_`.
+    pub const BASE64_URL: DigitAlphabet = DigitAlphabet { radix: 64, digits: BASE64_URL_DIGITS, values: BASE64_URL_VALUES };
+
+    /// Create a custom alphabet from the character for each digit.
+    ///
+    /// Returns `None` if there are fewer than 2 or more than 64 digits,
+    /// any digit is not a printable ASCII character, or any digit is
+    /// repeated. If the alphabet is case-insensitive, uppercase and
+    /// lowercase letters are parsed as the same digit, and are written
+    /// as given, so `0-9a-f` writes lowercase hexadecimal digits.
+    pub fn new(digits: &[u8], case_sensitive: bool) -> Option<DigitAlphabet> {
+        if digits.len() < 2 || digits.len() > 64 {
+            return None;
+        }
+
+        let mut alphabet = DigitAlphabet {
+            radix: digits.len() as u8,
+            digits: [0; 64],
+            values: [INVALID; 256],
+        };
+        for (digit, &c) in digits.iter().enumerate() {
+            if !c.is_ascii_graphic() {
+                return None;
+            }
+            alphabet.digits[digit] = c;
+            alphabet.insert(c, digit as u8)?;
+            if !case_sensitive && c.is_ascii_alphabetic() {
+                let other = match c.is_ascii_uppercase() {
+                    true  => c.to_ascii_lowercase(),
+                    false => c.to_ascii_uppercase(),
+                };
+                alphabet.insert(other, digit as u8)?;
+            }
+        }
+        Some(alphabet)
+    }
+
+    // Set the digit for a character, if the character is not a digit.
+    #[inline]
+    fn insert(&mut self, c: u8, digit: u8) -> Option<()> {
+        let value = &mut self.values[c as usize];
+        match *value {
+            INVALID => {
+                *value = digit;
+                Some(())
+            },
+            _       => None,
+        }
+    }
+
+    /// Get the radix, or the number of digits.
+    #[inline]
+    pub fn radix(&self) -> u8 {
+        self.radix
+    }
+
+    /// Get the character for each digit.
+    #[inline]
+    pub fn digits(&self) -> &[u8] {
+        &index!(self.digits[..self.radix as usize])
+    }
+
+    /// Get the digit for a character, if it is a digit.
+    #[inline]
+    pub fn to_digit(&self, c: u8) -> Option<u32> {
+        match index!(self.values[c as usize]) {
+            INVALID => None,
+            digit   => Some(digit as u32),
+        }
+    }
+
+    /// Get the character for a digit.
+    #[inline]
+    pub(crate) fn to_char(&self, digit: usize) -> u8 {
+        debug_assert!(digit < self.radix as usize, "to_char() invalid digit.");
+        index!(self.digits[digit])
+    }
+}
+
+impl fmt::Debug for DigitAlphabet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DigitAlphabet")
+            .field("radix", &self.radix)
+            .field("digits", &self.digits())
+            .finish()
+    }
+}
+
+impl PartialEq for DigitAlphabet {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.digits() == other.digits() && self.values[..] == other.values[..]
+    }
+}
+
+impl Eq for DigitAlphabet {}
+
+// TABLES
+
+/// Digits for the RFC 4648 base32 alphabet, `A-Z2-7`.
+const BASE32_DIGITS: [u8; 64] = [b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X', b'Y', b'Z', b'2', b'3', b'4', b'5', b'6', b'7', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00'];
+
+/// Digit values for the RFC 4648 base32 alphabet, `A-Z2-7`.
+const BASE32_VALUES: [u8; 256] = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 26, 27, 28, 29, 30, 31, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 255, 255, 255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255];
+
+/// Digits for the RFC 4648 base32 alphabet with the extended hex digits, `0-9A-V`.
+const BASE32_HEX_DIGITS: [u8; 64] = [b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00'];
+
+/// Digit values for the RFC 4648 base32 alphabet with the extended hex digits, `0-9A-V`.
+const BASE32_HEX_VALUES: [u8; 256] = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255, 255, 255, 255, 255, 255, 255, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255];
+
+/// Digits for the Bitcoin base58 alphabet, which excludes `0`, `I`, `O` and `l`.
+const BASE58_DIGITS: [u8; 64] = [b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'J', b'K', b'L', b'M', b'N', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X', b'Y', b'Z', b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j', b'k', b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x', b'y', b'z', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00', b'\x00'];
+
+/// Digit values for the Bitcoin base58 alphabet, which excludes `0`, `I`, `O` and `l`.
+const BASE58_VALUES: [u8; 256] = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 255, 255, 255, 255, 255, 255, 255, 9, 10, 11, 12, 13, 14, 15, 16, 255, 17, 18, 19, 20, 21, 255, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 255, 255, 255, 255, 255, 255, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 255, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255];
+
+/// Digits for the Base62 alphabet, `0-9A-Za-z`.
+const BASE62_DIGITS: [u8; 64] = [b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X', b'Y', b'Z', b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j', b'k', b'l', b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x', b'y', b'z', b'\x00', b'\x00'];
+
+/// Digit values for the Base62 alphabet, `0-9A-Za-z`.
+const BASE62_VALUES: [u8; 256] = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 255, 255, 255, 255, 255, 255, 255, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 255, 255, 255, 255, 255, 255, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255];
+
+/// Digits for the RFC 4648 base64 alphabet, `A-Za-z0-9+/`.
+const BASE64_DIGITS: [u8; 64] = [b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X', b'Y', b'Z', b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j', b'k', b'l', b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x', b'y', b'z', b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'+', b'/'];
+
+/// Digit values for the RFC 4648 base64 alphabet, `A-Za-z0-9+/`.
+const BASE64_VALUES: [u8; 256] = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 62, 255, 255, 255, 63, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 255, 255, 255, 255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 255, 255, 255, 255, 255, 255, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255];
+
+/// Digits for the RFC 4648 base64 alphabet with the URL and filename safe digits, `A-Za-z0-9-_`.
+const BASE64_URL_DIGITS: [u8; 64] = [b'A', b'B', b'C', b'D', b'E', b'F', b'G', b'H', b'I', b'J', b'K', b'L', b'M', b'N', b'O', b'P', b'Q', b'R', b'S', b'T', b'U', b'V', b'W', b'X', b'Y', b'Z', b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i', b'j', b'k', b'l', b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x', b'y', b'z', b'0', b'1', b'2', b'3', b'4', b'5', b'6', b'7', b'8', b'9', b'-', b'_'];
+
+/// Digit values for the RFC 4648 base64 alphabet with the URL and filename safe digits, `A-Za-z0-9-_`.
+const BASE64_URL_VALUES: [u8; 256] = [255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 62, 255, 255, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 255, 255, 255, 255, 255, 255, 255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 255, 255, 255, 255, 63, 255, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255];
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        let alphabet = DigitAlphabet::new(b"0123456789abcdef", false).unwrap();
+        assert_eq!(alphabet.radix(), 16);
+        assert_eq!(alphabet.to_digit(b'a'), Some(10));
+        assert_eq!(alphabet.to_digit(b'F'), Some(15));
+        assert_eq!(alphabet.to_digit(b'g'), None);
+        assert_eq!(alphabet.to_char(10), b'a');
+
+        let alphabet = DigitAlphabet::new(b"01ab", true).unwrap();
+        assert_eq!(alphabet.to_digit(b'b'), Some(3));
+        assert_eq!(alphabet.to_digit(b'B'), None);
+
+        assert!(DigitAlphabet::new(b"0", true).is_none());
+        assert!(DigitAlphabet::new(b"010", true).is_none());
+        assert!(DigitAlphabet::new(b"0aA", true).is_some());
+        assert!(DigitAlphabet::new(b"0aA", false).is_none());
+        assert!(DigitAlphabet::new(b"0 1", true).is_none());
+        assert!(DigitAlphabet::new(&[b'0'; 65], true).is_none());
+    }
+
+    #[test]
+    fn builtin_test() {
+        let builtin = [
+            (DigitAlphabet::BASE32, &b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567"[..], false),
+            (DigitAlphabet::BASE32_HEX, &b"0123456789ABCDEFGHIJKLMNOPQRSTUV"[..], false),
+            (DigitAlphabet::BASE58, &b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"[..], true),
+            (DigitAlphabet::BASE62, &b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"[..], true),
+            (DigitAlphabet::BASE64, &b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"[..], true),
+            (DigitAlphabet::BASE64_URL, &b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"[..], true),
+        ];
+        for &(alphabet, digits, case_sensitive) in builtin.iter() {
+            assert_eq!(alphabet.digits(), digits);
+            assert_eq!(Some(alphabet), DigitAlphabet::new(digits, case_sensitive));
+        }
+        assert_eq!(DigitAlphabet::BASE32.to_digit(b'b'), Some(1));
+        assert_eq!(DigitAlphabet::BASE58.to_digit(b'0'), None);
+        assert_eq!(DigitAlphabet::BASE64.to_digit(b'/'), Some(63));
+    }
+}
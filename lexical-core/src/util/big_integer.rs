@@ -0,0 +1,140 @@
+//! Pluggable arbitrary-precision integers.
+//!
+//! Integers wider than 128 bits are parsed and written through a
+//! minimal trait, so any big-integer type can reuse the digit scanning,
+//! radix support, and format validation of the native integer parsers.
+
+// BIG INTEGER
+
+/// Arbitrary-precision integer, parsed and written one chunk of digits at a time.
+///
+/// Parsing multiplies the value by a power of the radix and adds (or,
+/// for negative numbers, subtracts) the next chunk of digits, and
+/// writing repeatedly divides the magnitude by a power of the radix,
+/// so only arithmetic with 32-bit values is required.
+///
+/// Little-endian arrays of `u64` limbs, such as `[u64; 4]` for 256-bit
+/// integers, implement the trait as unsigned integers.
+pub trait BigInteger: Clone {
+    /// If the integer may be negative.
+    const IS_SIGNED: bool;
+
+    /// Create the integer with a value of zero.
+    fn zero() -> Self;
+
+    /// Check if the value is zero.
+    fn is_zero(&self) -> bool;
+
+    /// Check if the value is negative.
+    #[inline]
+    fn is_negative(&self) -> bool {
+        false
+    }
+
+    /// Multiply the value by `multiplier`, then add `addend`, in-place.
+    ///
+    /// Returns false if the result overflows.
+    fn mul_add_small(&mut self, multiplier: u32, addend: u32) -> bool;
+
+    /// Multiply the value by `multiplier`, then subtract `subtrahend`, in-place.
+    ///
+    /// Returns false if the result underflows. This is only called
+    /// for signed integers.
+    #[inline]
+    fn mul_sub_small(&mut self, multiplier: u32, subtrahend: u32) -> bool {
+        let _ = (multiplier, subtrahend);
+        false
+    }
+
+    /// Divide the magnitude of the value by `divisor` in-place, rounding
+    /// toward zero, and return the remainder of the magnitude.
+    fn divrem_small(&mut self, divisor: u32) -> u32;
+}
+
+macro_rules! big_integer_array_impl {
+    ($($n:expr)*) => ($(
+        impl BigInteger for [u64; $n] {
+            const IS_SIGNED: bool = false;
+
+            #[inline]
+            fn zero() -> Self {
+                [0; $n]
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.iter().all(|&x| x == 0)
+            }
+
+            #[inline]
+            fn mul_add_small(&mut self, multiplier: u32, addend: u32) -> bool {
+                let mut carry = addend as u128;
+                for limb in self.iter_mut() {
+                    let z = *limb as u128 * multiplier as u128 + carry;
+                    *limb = z as u64;
+                    carry = z >> 64;
+                }
+                carry == 0
+            }
+
+            #[inline]
+            fn divrem_small(&mut self, divisor: u32) -> u32 {
+                let mut rem = 0u128;
+                for limb in self.iter_mut().rev() {
+                    let z = (rem << 64) | *limb as u128;
+                    *limb = (z / divisor as u128) as u64;
+                    rem = z % divisor as u128;
+                }
+                rem as u32
+            }
+        }
+    )*);
+}
+
+big_integer_array_impl! { 2 3 4 5 6 7 8 16 }
+
+/// Get the number of digits and the power of the radix for each chunk.
+///
+/// The power is the largest power of the radix that fits in 32 bits.
+#[inline]
+pub(crate) fn big_integer_step(radix: u32) -> (usize, u32) {
+    let mut step = 1;
+    let mut power = radix;
+    while let Some(next) = power.checked_mul(radix) {
+        step += 1;
+        power = next;
+    }
+    (step, power)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_test() {
+        assert_eq!(big_integer_step(2), (31, 1 << 31));
+        assert_eq!(big_integer_step(10), (9, 1_000_000_000));
+        assert_eq!(big_integer_step(16), (7, 1 << 28));
+        assert_eq!(big_integer_step(36), (6, 2_176_782_336));
+    }
+
+    #[test]
+    fn array_test() {
+        let mut x = <[u64; 2]>::zero();
+        assert!(x.is_zero());
+        assert!(x.mul_add_small(10, 5));
+        assert_eq!(x, [5, 0]);
+        x = [u64::max_value(), 0];
+        assert!(x.mul_add_small(2, 3));
+        assert_eq!(x, [1, 2]);
+        assert_eq!(x.divrem_small(2), 1);
+        assert_eq!(x, [0, 1]);
+        x = [0, 1 << 63];
+        assert!(!x.mul_add_small(2, 0));
+        assert!(!x.is_negative());
+    }
+}
@@ -0,0 +1,28 @@
+//! Decomposed components of a number string.
+
+// COMPONENTS
+
+/// Components of a validated number string.
+///
+/// The integer and fraction are subslices of the parsed string, so
+/// they keep any leading or trailing zeros, and any digit separators
+/// allowed by the numerical format. The value of a finite number is
+/// `integer.fraction * 10^exponent`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct NumberComponents<'a> {
+    /// If the number is negative.
+    pub is_negative: bool,
+    /// Integer digits, before the decimal point.
+    pub integer: &'a [u8],
+    /// Fraction digits, after the decimal point.
+    pub fraction: &'a [u8],
+    /// Value of the exponent.
+    ///
+    /// Exponents that do not fit in an `i64` are rejected with
+    /// `ErrorCode::Overflow` when parsing.
+    pub exponent: i64,
+    /// If the number is a special (non-finite) value, such as NaN or Infinity.
+    ///
+    /// Special values do not have any digits, and an exponent of 0.
+    pub is_special: bool,
+}
@@ -8,7 +8,7 @@ use super::rounding::RoundingKind;
 
 /// Determine if the character is a control character for integers or floats.
 /// Control characters include digits, `.`, `+`, and `-`.
-fn is_control_character(ch: u8, is_default: bool) -> bool {
+pub(crate) fn is_control_character(ch: u8, is_default: bool) -> bool {
     if is_default {
         // Default character handles radixes < 15 (where 'e'/'E' is a
         // a valid exponent character).
@@ -34,7 +34,7 @@ fn is_control_character(ch: u8, is_default: bool) -> bool {
 
 // Check if byte array starts with case-insensitive N.
 #[inline]
-fn starts_with_n(bytes: &[u8]) -> bool {
+pub(crate) fn starts_with_n(bytes: &[u8]) -> bool {
     match bytes.get(0) {
         Some(&b'N') => true,
         Some(&b'n') => true,
@@ -44,7 +44,7 @@ fn starts_with_n(bytes: &[u8]) -> bool {
 
 // Check if byte array starts with case-insensitive I.
 #[inline]
-fn starts_with_i(bytes: &[u8]) -> bool {
+pub(crate) fn starts_with_i(bytes: &[u8]) -> bool {
     match bytes.get(0) {
         Some(&b'I') => true,
         Some(&b'i') => true,
@@ -410,24 +410,20 @@ if #[cfg(target_pointer_width = "16")] {
 /// Maximum number of bytes required to serialize any number to string.
 pub const BUFFER_SIZE: usize = F64_FORMATTED_SIZE;
 
-// FUNCTIONS
-
-/// Get the exponent notation character.
-#[inline]
-#[allow(unused_variables)]
-pub(crate) fn exponent_notation_char(radix: u32) -> u8 {
-    #[cfg(not(feature ="radix"))] {
-        get_exponent_default_char()
-    }
-
-    #[cfg(feature ="radix")] {
-        if radix >= 15 {
-            get_exponent_backup_char()
-        } else {
-            get_exponent_default_char()
-        }
-    }
-}
+cfg_if! {
+if #[cfg(feature = "radix")] {
+    /// Maximum number of bytes required to serialize the exact value of any float to string.
+    ///
+    /// Radix 3 requires the most bytes, with a sign, `"0."`, 677 leading
+    /// zeros, and 1100 significant digits for the smallest denormal float.
+    pub const EXACT_BUFFER_SIZE: usize = 1800;
+} else {
+    /// Maximum number of bytes required to serialize the exact value of any float to string.
+    ///
+    /// The smallest denormal float requires the most bytes, with a sign,
+    /// `"0."`, 323 leading zeros, and 767 significant digits.
+    pub const EXACT_BUFFER_SIZE: usize = 1100;
+}}  // cfg_if
 
 // TEST
 // ----
@@ -443,6 +439,9 @@ mod tests {
     fn exponent_notation_char_test() {
         let default = get_exponent_default_char();
         let backup = get_exponent_backup_char();
+        let exponent_notation_char = | radix | {
+            WriteFloatOptions::from_config(radix, NumberFormat::standard().unwrap()).exponent_char()
+        };
         assert_eq!(exponent_notation_char(2), default);
         assert_eq!(exponent_notation_char(8), default);
         assert_eq!(exponent_notation_char(10), default);
@@ -0,0 +1,186 @@
+//! Context for parse errors, describing the tokens expected at the error.
+
+use crate::lib::fmt;
+use super::error::{Error, ErrorCode};
+use super::num::Number;
+use super::options::ParseFloatOptions;
+
+// EXPECTED
+
+bitflags! {
+    /// Tokens that would have been valid at the position of an error.
+    #[derive(Default)]
+    pub struct Expected: u8 {
+        /// A digit.
+        const DIGIT = 0b00001;
+        /// A sign symbol, `+` or `-`.
+        const SIGN = 0b00010;
+        /// An exponent symbol, such as `e`.
+        const EXPONENT = 0b00100;
+        /// A separator, such as the decimal point.
+        const SEPARATOR = 0b01000;
+        /// The end of the number.
+        const END = 0b10000;
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const NAMES: [(Expected, &str); 5] = [
+            (Expected::DIGIT, "a digit"),
+            (Expected::SIGN, "a sign"),
+            (Expected::EXPONENT, "an exponent"),
+            (Expected::SEPARATOR, "a decimal point"),
+            (Expected::END, "the end of the number"),
+        ];
+        let count = self.bits().count_ones() as usize;
+        let names = NAMES.iter().filter(|x| self.contains(x.0)).map(|x| x.1);
+        for (index, name) in names.enumerate() {
+            match (index, count) {
+                (0, _)                  => (),
+                (1, 2)                  => f.write_str(" or ")?,
+                (i, n) if i + 1 == n    => f.write_str(", or ")?,
+                _                       => f.write_str(", ")?,
+            }
+            f.write_str(name)?;
+        }
+        Ok(())
+    }
+}
+
+// CONTEXT
+
+/// Parse error with the tokens that were expected at the error.
+///
+/// The expected tokens are derived from the error code, and from the
+/// bytes the parser accepted before the error, using the default options,
+/// so the position of the error may be shown with a caret diagnostic.
+/// Empty exponents are reported after any exponent sign.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ErrorContext, Expected};
+/// # pub fn main() {
+/// let bytes = b"1.5x";
+/// let error = lexical_core::parse_with_context::<f64>(bytes).err().unwrap();
+/// assert_eq!(error.error.index, 3);
+/// assert_eq!(error.expected, Expected::DIGIT | Expected::EXPONENT | Expected::END);
+/// assert_eq!(error.to_string(), "invalid digit found at index 3, expected a digit, an exponent, or the end of the number");
+///
+/// let caret = format!("{}^", " ".repeat(error.error.index));
+/// assert_eq!(caret, "   ^");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ErrorContext {
+    /// Error from parsing the number.
+    pub error: Error,
+    /// Tokens that would have been valid at the index of the error.
+    pub expected: Expected,
+}
+
+impl ErrorContext {
+    /// Create the context for an error from parsing the bytes as `N`.
+    pub fn new<N: Number>(bytes: &[u8], mut error: Error) -> ErrorContext {
+        let expected = match error.code {
+            ErrorCode::Overflow                     |
+            ErrorCode::Underflow                    |
+            ErrorCode::InvalidExponent              => Expected::END,
+            ErrorCode::MissingMantissaSign          |
+            ErrorCode::MissingExponentSign          => Expected::SIGN,
+            ErrorCode::InvalidPositiveMantissaSign  |
+            ErrorCode::InvalidPositiveExponentSign  |
+            ErrorCode::InvalidLeadingZeros          => Expected::DIGIT,
+            ErrorCode::ExponentWithoutFraction      => Expected::SEPARATOR,
+            // The parser reports empty exponents at the exponent sign.
+            ErrorCode::EmptyExponent                => match bytes.get(error.index) {
+                Some(&b'+') | Some(&b'-')   => {
+                    error.index += 1;
+                    Expected::DIGIT
+                },
+                _                           => Expected::DIGIT | Expected::SIGN,
+            },
+            ErrorCode::InvalidDigit                 |
+            ErrorCode::Empty                        |
+            ErrorCode::EmptyMantissa                |
+            ErrorCode::EmptyInteger                 |
+            ErrorCode::EmptyFraction                => match N::IS_FLOAT {
+                true  => crate::atof::atof_expected(bytes, error.code, error.index, &ParseFloatOptions::default()),
+                false => crate::atoi::atoi_expected::<N>(bytes, error.index),
+            },
+            ErrorCode::BelowMinimum                 |
+            ErrorCode::AboveMaximum                 |
+            ErrorCode::InvalidZero                  |
+            ErrorCode::InvalidNan                   |
+            ErrorCode::__Nonexhaustive              => Expected::empty(),
+        };
+        ErrorContext { error, expected }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.expected.is_empty() {
+            true  => write!(f, "{}", self.error),
+            false => write!(f, "{}, expected {}", self.error, self.expected),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ErrorContext {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    fn context<N: FromLexical>(bytes: &[u8]) -> ErrorContext {
+        ErrorContext::new::<N>(bytes, N::from_lexical(bytes).err().unwrap())
+    }
+
+    #[test]
+    fn expected_display_test() {
+        assert_eq!(Expected::empty().to_string(), "");
+        assert_eq!(Expected::DIGIT.to_string(), "a digit");
+        assert_eq!((Expected::DIGIT | Expected::END).to_string(), "a digit or the end of the number");
+        assert_eq!((Expected::DIGIT | Expected::SIGN | Expected::SEPARATOR).to_string(), "a digit, a sign, or a decimal point");
+    }
+
+    #[test]
+    fn integer_test() {
+        assert_eq!(context::<u32>(b"").expected, Expected::DIGIT | Expected::SIGN);
+        assert_eq!(context::<i32>(b"-").expected, Expected::DIGIT);
+        assert_eq!(context::<i32>(b"12a").expected, Expected::DIGIT | Expected::END);
+        assert_eq!(context::<i32>(b"1.0").expected, Expected::DIGIT | Expected::END);
+        assert_eq!(context::<u8>(b"256").expected, Expected::END);
+        assert_eq!(context::<u8>(b"256").to_string(), "numeric overflow occurred at index 2, expected the end of the number");
+    }
+
+    #[test]
+    fn float_test() {
+        assert_eq!(context::<f64>(b"").expected, Expected::DIGIT | Expected::SIGN | Expected::SEPARATOR);
+        assert_eq!(context::<f64>(b"12a").expected, Expected::DIGIT | Expected::SEPARATOR | Expected::EXPONENT | Expected::END);
+        assert_eq!(context::<f64>(b"1.5a").expected, Expected::DIGIT | Expected::EXPONENT | Expected::END);
+        assert_eq!(context::<f64>(b"1.5e").expected, Expected::DIGIT | Expected::SIGN);
+        assert_eq!(context::<f64>(b"1.5E-").expected, Expected::DIGIT);
+        assert_eq!(context::<f64>(b"1.5E-").error.index, 5);
+        assert_eq!(context::<f64>(b"1e5.").expected, Expected::DIGIT | Expected::END);
+        assert_eq!(context::<f64>(b"inf0").expected, Expected::END);
+        assert_eq!(context::<f64>(b"nan(1").expected, Expected::END);
+        assert_eq!(context::<f64>(b"+.").expected, Expected::DIGIT | Expected::SEPARATOR);
+        assert_eq!(context::<f64>(b"1e+").expected, Expected::DIGIT);
+        assert_eq!(context::<f64>(b"1e+").to_string(), "empty exponent found at index 3, expected a digit");
+        assert_eq!(context::<f64>(b"1e").error.index, 2);
+        assert_eq!(context::<f64>(b"1e+5x").expected, Expected::DIGIT | Expected::END);
+    }
+}
@@ -0,0 +1,235 @@
+//! Arbitrary-precision decimal numbers.
+//!
+//! Decimal numbers keep every parsed digit, so they may be converted
+//! to fixed-point or decimal types without rounding to a binary float.
+
+use crate::lib::{fmt, iter};
+use crate::lib::vec::Vec;
+
+// DECIMAL
+
+/// Exact decimal number, with the value `digits * 10^exponent`.
+///
+/// Decimals created by the parser or by [`new`] are normalized: the
+/// digits do not have leading or trailing zeros, so zero has no
+/// digits and an exponent of 0, and two decimals are equal if and only
+/// if they have the same value and sign.
+///
+/// [`new`]: #method.new
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// If the number is negative.
+    pub is_negative: bool,
+    /// Significant digits of the number, as ASCII decimal digits.
+    pub digits: Vec<u8>,
+    /// Decimal exponent of the least-significant digit.
+    pub exponent: i64,
+}
+
+impl Decimal {
+    /// Maximum number of padding zeros written in positional notation.
+    ///
+    /// Decimals with larger exponents are written in scientific notation,
+    /// so the formatted length does not grow with the exponent.
+    pub const MAX_POSITIONAL_ZEROS: usize = 64;
+
+    /// Create a normalized decimal from the sign, digits, and exponent.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any digit is not an ASCII decimal digit.
+    pub fn new(is_negative: bool, digits: &[u8], exponent: i64) -> Decimal {
+        assert!(digits.iter().all(u8::is_ascii_digit));
+        let leading = digits.iter().take_while(|&&c| c == b'0').count();
+        let digits = &digits[leading..];
+        let trailing = digits.iter().rev().take_while(|&&c| c == b'0').count();
+        let digits = &digits[..digits.len() - trailing];
+        let exponent = match digits.is_empty() {
+            true  => 0,
+            false => exponent + trailing as i64,
+        };
+        Decimal { is_negative, digits: digits.to_vec(), exponent }
+    }
+
+    /// Check if the value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.significant_digits().is_empty()
+    }
+
+    /// Get the number of bytes required to write the decimal to string.
+    ///
+    /// The decimal is written in positional notation, unless it would
+    /// require more than [`MAX_POSITIONAL_ZEROS`] padding zeros, in which
+    /// case it is written in scientific notation.
+    ///
+    /// [`MAX_POSITIONAL_ZEROS`]: #associatedconstant.MAX_POSITIONAL_ZEROS
+    pub fn formatted_len(&self) -> usize {
+        self.bytes().count()
+    }
+
+    // Get the digits, without any leading zeros.
+    #[inline]
+    fn significant_digits(&self) -> &[u8] {
+        let leading = self.digits.iter().take_while(|&&c| c == b'0').count();
+        &self.digits[leading..]
+    }
+
+    // Iterate over the bytes of the decimal.
+    fn bytes(&self) -> impl Iterator<Item=u8> + '_ {
+        let digits = self.significant_digits();
+        let sign = match self.is_negative {
+            true  => Some(b'-'),
+            false => None,
+        };
+        // Use a wider type, since the point may not fit in an i64 for
+        // decimals that were not created by the parser.
+        let point = digits.len() as i128 + self.exponent as i128;
+        let max_zeros = Self::MAX_POSITIONAL_ZEROS as i128;
+        let is_scientific = !digits.is_empty() && (self.exponent as i128 > max_zeros || -point > max_zeros);
+        let (leading, integer, fraction) = if digits.is_empty() {
+            (&b"0"[..], &[][..], &[][..])
+        } else if is_scientific {
+            let (leading, fraction) = digits.split_at(1);
+            (leading, &[][..], fraction)
+        } else if self.exponent >= 0 {
+            (&[][..], digits, &[][..])
+        } else if point > 0 {
+            let (integer, fraction) = digits.split_at(point as usize);
+            (&[][..], integer, fraction)
+        } else {
+            (&b"0"[..], &[][..], digits)
+        };
+        let trailing = match self.exponent > 0 && !digits.is_empty() && !is_scientific {
+            true  => self.exponent as usize,
+            false => 0,
+        };
+        let zeros = match point < 0 && !digits.is_empty() && !is_scientific {
+            true  => (-point) as usize,
+            false => 0,
+        };
+        let decimal_point = match fraction.is_empty() {
+            true  => None,
+            false => Some(b'.'),
+        };
+        let exponent = match is_scientific {
+            true  => Some(point - 1),
+            false => None,
+        };
+        sign.into_iter()
+            .chain(leading.iter().cloned())
+            .chain(integer.iter().cloned())
+            .chain(iter::repeat(b'0').take(trailing))
+            .chain(decimal_point)
+            .chain(iter::repeat(b'0').take(zeros))
+            .chain(fraction.iter().cloned())
+            .chain(exponent.into_iter().flat_map(exponent_bytes))
+    }
+
+    /// Write the decimal to string.
+    ///
+    /// Returns a subslice of the input buffer containing the written
+    /// bytes, starting from the same address in memory as the input slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is smaller than [`formatted_len`] elements.
+    ///
+    /// [`formatted_len`]: #method.formatted_len
+    pub fn write<'a>(&self, bytes: &'a mut [u8]) -> &'a mut [u8] {
+        let len = self.formatted_len();
+        let dst = &mut bytes[..len];
+        for (d, s) in dst.iter_mut().zip(self.bytes()) {
+            *d = s;
+        }
+        dst
+    }
+}
+
+// Iterate over the bytes of a scientific exponent, with the exponent character.
+fn exponent_bytes(exponent: i128) -> impl Iterator<Item=u8> {
+    // The largest exponent, 2^64 in magnitude, requires 20 digits.
+    let mut buffer = [0u8; 20];
+    let mut value = exponent.abs() as u128;
+    let mut index = buffer.len();
+    loop {
+        index -= 1;
+        buffer[index] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    let sign = match exponent < 0 {
+        true  => Some(b'-'),
+        false => None,
+    };
+    iter::once(b'e')
+        .chain(sign)
+        .chain((index..buffer.len()).map(move |i| buffer[i]))
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.bytes().try_for_each(|c| fmt::Write::write_char(f, c as char))
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(decimal: &Decimal) -> String {
+        let mut buffer = vec![0u8; decimal.formatted_len()];
+        let slc = decimal.write(&mut buffer);
+        assert_eq!(slc.len(), decimal.formatted_len());
+        let string = String::from_utf8(slc.to_vec()).unwrap();
+        assert_eq!(string, decimal.to_string());
+        string
+    }
+
+    #[test]
+    fn new_test() {
+        let decimal = Decimal::new(false, b"0012300", -4);
+        assert_eq!(decimal.digits, b"123");
+        assert_eq!(decimal.exponent, -2);
+        assert_eq!(Decimal::new(true, b"000", 5), Decimal { is_negative: true, digits: vec![], exponent: 0 });
+        assert!(Decimal::new(false, b"", 0).is_zero());
+        assert_eq!(Decimal::new(false, b"1200", 0), Decimal::new(false, b"12", 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_invalid_test() {
+        Decimal::new(false, b"1a", 0);
+    }
+
+    #[test]
+    fn write_test() {
+        assert_eq!(write(&Decimal::new(false, b"", 0)), "0");
+        assert_eq!(write(&Decimal::new(true, b"", 0)), "-0");
+        assert_eq!(write(&Decimal::new(false, b"123", 0)), "123");
+        assert_eq!(write(&Decimal::new(false, b"123", 2)), "12300");
+        assert_eq!(write(&Decimal::new(true, b"12345", -2)), "-123.45");
+        assert_eq!(write(&Decimal::new(false, b"12345", -5)), "0.12345");
+        assert_eq!(write(&Decimal::new(false, b"12345", -8)), "0.00012345");
+
+        // Large exponents are written in scientific notation.
+        assert_eq!(write(&Decimal::new(false, b"1", 64)), "1".to_string() + &"0".repeat(64));
+        assert_eq!(write(&Decimal::new(false, b"1", 65)), "1e65");
+        assert_eq!(write(&Decimal::new(true, b"125", 100)), "-1.25e102");
+        assert_eq!(write(&Decimal::new(false, b"1", -65)), "0.".to_string() + &"0".repeat(64) + "1");
+        assert_eq!(write(&Decimal::new(false, b"15", -67)), "1.5e-66");
+        assert_eq!(write(&Decimal::new(false, b"1", i64::max_value())), "1e9223372036854775807");
+        assert_eq!(write(&Decimal::new(false, b"12", i64::min_value())), "1.2e-9223372036854775807");
+
+        // Non-normalized digits.
+        let decimal = Decimal { is_negative: false, digits: b"00150".to_vec(), exponent: -2 };
+        assert_eq!(write(&decimal), "1.50");
+        let decimal = Decimal { is_negative: false, digits: b"000".to_vec(), exponent: -2 };
+        assert_eq!(write(&decimal), "0");
+    }
+}
@@ -1,5 +1,7 @@
 //! C-compatible error type.
 
+use crate::lib::fmt;
+
 /// Error code, indicating failure type.
 ///
 /// Error messages are designating by an error code of less than 0.
@@ -61,6 +63,14 @@ pub enum ErrorCode {
     ExponentWithoutFraction = -14,
     /// Integer had invalid leading zeros.
     InvalidLeadingZeros = -15,
+    /// Parsed value was below the minimum allowed value.
+    BelowMinimum = -16,
+    /// Parsed value was above the maximum allowed value.
+    AboveMaximum = -17,
+    /// Parsed value was zero, but zero was not allowed.
+    InvalidZero = -18,
+    /// Parsed value was NaN, but NaN was not allowed.
+    InvalidNan = -19,
 
     // We may add additional variants later, so ensure that client matching
     // does not depend on exhaustive matching.
@@ -68,6 +78,40 @@ pub enum ErrorCode {
     __Nonexhaustive = -200,
 }
 
+impl ErrorCode {
+    /// Get a short, human-readable description of the error.
+    pub fn description(&self) -> &'static str {
+        match self {
+            ErrorCode::Overflow                     => "numeric overflow occurred",
+            ErrorCode::Underflow                    => "numeric underflow occurred",
+            ErrorCode::InvalidDigit                 => "invalid digit found",
+            ErrorCode::Empty                        => "empty input found",
+            ErrorCode::EmptyMantissa                => "empty mantissa found",
+            ErrorCode::EmptyExponent                => "empty exponent found",
+            ErrorCode::EmptyInteger                 => "empty integer found",
+            ErrorCode::EmptyFraction                => "empty fraction found",
+            ErrorCode::InvalidPositiveMantissaSign  => "invalid positive mantissa sign found",
+            ErrorCode::MissingMantissaSign          => "missing required mantissa sign",
+            ErrorCode::InvalidExponent              => "exponent found but not allowed",
+            ErrorCode::InvalidPositiveExponentSign  => "invalid positive exponent sign found",
+            ErrorCode::MissingExponentSign          => "missing required exponent sign",
+            ErrorCode::ExponentWithoutFraction      => "exponent found without fraction",
+            ErrorCode::InvalidLeadingZeros          => "invalid leading zeros found",
+            ErrorCode::BelowMinimum                 => "value below the minimum found",
+            ErrorCode::AboveMaximum                 => "value above the maximum found",
+            ErrorCode::InvalidZero                  => "zero found but not allowed",
+            ErrorCode::InvalidNan                   => "NaN found but not allowed",
+            ErrorCode::__Nonexhaustive              => "unknown error",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
 /// Error type for lexical parsing.
 ///
 /// This error is FFI-compatible for interfacing with C code.
@@ -93,3 +137,49 @@ impl From<(ErrorCode, usize)> for Error {
         Error { code: error.0, index: error.1 }
     }
 }
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at index {}", self.code, self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for Error {
+}
+
+/// Error type for writing a number to a buffer that is too small.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct WriteError {
+    /// Number of bytes required to write the number.
+    pub required: usize,
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "buffer too small, {} bytes required", self.required)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for WriteError {
+}
+
+// TESTS
+// -----
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_test() {
+        assert_eq!(ErrorCode::InvalidDigit.to_string(), "invalid digit found");
+        assert_eq!(ErrorCode::EmptyExponent.description(), "empty exponent found");
+        let error: Error = (ErrorCode::Overflow, 3).into();
+        assert_eq!(error.to_string(), "numeric overflow occurred at index 3");
+        let error: &dyn ::std::error::Error = &error;
+        assert!(error.source().is_none());
+        assert_eq!(WriteError { required: 5 }.to_string(), "buffer too small, 5 bytes required");
+    }
+}
@@ -0,0 +1,402 @@
+//! Extended-precision float types.
+//!
+//! Formats wider than `f64` have no hardware support, so the types are
+//! storage-only, and formats describe their layout with decomposed
+//! parts through `ExtendedPrecision`. The parser and writer use exact
+//! big-integer arithmetic for any format implementing the trait.
+
+use crate::lib::{cmp, fmt, str};
+
+// PARTS
+
+/// Decomposed value of a binary float.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FloatParts {
+    /// Finite float, with the value `mantissa * 2^exponent`.
+    Finite {
+        /// If the float is negative.
+        is_negative: bool,
+        /// Significant bits of the float.
+        mantissa: u128,
+        /// Binary exponent of the least-significant bit.
+        exponent: i32,
+    },
+    /// Infinite float.
+    Infinite {
+        /// If the float is negative.
+        is_negative: bool,
+    },
+    /// Not a number.
+    Nan,
+}
+
+// EXTENDED
+
+/// Maximum number of bytes required to write any extended-precision float.
+pub const EXTENDED_FORMATTED_SIZE_DECIMAL: usize = 64;
+
+/// Binary float format for extended-precision parsing and writing.
+///
+/// Formats describe their precision and exponent range with the same
+/// conventions as `f64::MANTISSA_DIGITS`, `f64::MAX_EXP` and
+/// `f64::MIN_EXP`, and convert to and from decomposed parts, so the
+/// parser and writer do not depend on the bit layout. Formats may have
+/// at most 113 significant bits, and an exponent range no larger than
+/// the IEEE754 quadruple-precision format.
+pub trait ExtendedPrecision: Copy {
+    /// Number of significant bits, including any hidden bit.
+    const MANTISSA_DIGITS: u32;
+    /// One greater than the maximum binary exponent.
+    const MAX_EXP: i32;
+    /// One greater than the minimum normal binary exponent.
+    const MIN_EXP: i32;
+
+    /// Create the float from decomposed parts.
+    ///
+    /// Finite parts are normalized: the mantissa has exactly
+    /// `MANTISSA_DIGITS` bits, or fewer bits for denormal floats and
+    /// zero, where the exponent is `MIN_EXP - MANTISSA_DIGITS`.
+    fn from_parts(parts: FloatParts) -> Self;
+
+    /// Decompose the float into parts.
+    ///
+    /// Finite parts must be exact, but do not need to be normalized.
+    fn to_parts(self) -> FloatParts;
+}
+
+// Round a positive value to the nearest float, with ties to even.
+//
+// The value is `mantissa * 2^exponent`, and if `is_truncated`, is
+// slightly larger than that, in which case the mantissa must have at
+// least `MANTISSA_DIGITS + 2` bits. The mantissa must have fewer than
+// 128 bits.
+pub(crate) fn round_extended<T: ExtendedPrecision>(is_negative: bool, mantissa: u128, exponent: i32, is_truncated: bool)
+    -> FloatParts
+{
+    let precision = T::MANTISSA_DIGITS as i32;
+    let min_exp = T::MIN_EXP - precision;
+    let max_exp = T::MAX_EXP - precision;
+    if mantissa == 0 {
+        return FloatParts::Finite { is_negative, mantissa: 0, exponent: min_exp };
+    }
+
+    // Find the exponent of the last bit kept, which is fixed for denormals.
+    let bits = 128 - mantissa.leading_zeros() as i32;
+    debug_assert!(bits < 128);
+    let exp = cmp::max(exponent + bits - precision, min_exp);
+    let shift = exp - exponent;
+    let mut mant = if shift <= 0 {
+        debug_assert!(!is_truncated);
+        mantissa << -shift
+    } else if shift > bits {
+        // Below half the last bit kept.
+        0
+    } else {
+        let halfway = 1u128 << (shift - 1);
+        let truncated = mantissa & ((halfway << 1) - 1);
+        let mant = mantissa >> shift;
+        let is_above = truncated > halfway || (truncated == halfway && is_truncated);
+        let is_odd = mant & 1 == 1;
+        mant + (is_above || (truncated == halfway && is_odd)) as u128
+    };
+    let mut exp = exp;
+    if mant >> precision != 0 {
+        mant >>= 1;
+        exp += 1;
+    }
+
+    match exp > max_exp {
+        true  => FloatParts::Infinite { is_negative },
+        false => FloatParts::Finite { is_negative, mantissa: mant, exponent: exp },
+    }
+}
+
+// Create the bits of an IEEE754 float with a hidden bit from parts.
+#[inline]
+fn ieee_from_parts<T: ExtendedPrecision>(parts: FloatParts, size: u32) -> u128 {
+    let fraction_bits = T::MANTISSA_DIGITS - 1;
+    let sign = 1u128 << (size - 1);
+    let exponent_mask = (sign - 1) >> fraction_bits;
+    match parts {
+        FloatParts::Finite { is_negative, mantissa, exponent } => {
+            let biased = match mantissa >> fraction_bits {
+                0 => 0,
+                _ => (exponent - (T::MIN_EXP - T::MANTISSA_DIGITS as i32) + 1) as u128,
+            };
+            let fraction = mantissa & ((1 << fraction_bits) - 1);
+            (is_negative as u128 * sign) | (biased << fraction_bits) | fraction
+        },
+        FloatParts::Infinite { is_negative } => (is_negative as u128 * sign) | (exponent_mask << fraction_bits),
+        FloatParts::Nan => (exponent_mask << fraction_bits) | (1 << (fraction_bits - 1)),
+    }
+}
+
+// Decompose the bits of an IEEE754 float with a hidden bit into parts.
+#[inline]
+fn ieee_to_parts<T: ExtendedPrecision>(bits: u128, size: u32) -> FloatParts {
+    let fraction_bits = T::MANTISSA_DIGITS - 1;
+    let sign = 1u128 << (size - 1);
+    let exponent_mask = (sign - 1) >> fraction_bits;
+    let is_negative = bits & sign != 0;
+    let biased = (bits >> fraction_bits) & exponent_mask;
+    let fraction = bits & ((1 << fraction_bits) - 1);
+    let min_exp = T::MIN_EXP - T::MANTISSA_DIGITS as i32;
+    match biased {
+        0 => FloatParts::Finite { is_negative, mantissa: fraction, exponent: min_exp },
+        _ if biased == exponent_mask && fraction == 0 => FloatParts::Infinite { is_negative },
+        _ if biased == exponent_mask => FloatParts::Nan,
+        _ => {
+            let mantissa = fraction | (1 << fraction_bits);
+            FloatParts::Finite { is_negative, mantissa, exponent: biased as i32 - 1 + min_exp }
+        },
+    }
+}
+
+// Convert a float to another format, rounding to nearest, with ties to even.
+#[inline]
+fn convert<T: ExtendedPrecision, U: ExtendedPrecision>(value: T) -> U {
+    U::from_parts(match value.to_parts() {
+        FloatParts::Finite { is_negative, mantissa, exponent } => {
+            // Ensure the value has enough bits to use the truncated bits.
+            let shift = mantissa.leading_zeros().saturating_sub(8);
+            round_extended::<U>(is_negative, mantissa << shift, exponent - shift as i32, false)
+        },
+        parts => parts,
+    })
+}
+
+// Write the float to a buffer for display.
+#[inline]
+fn display<T: ExtendedPrecision>(value: T, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut buffer = [0u8; EXTENDED_FORMATTED_SIZE_DECIMAL];
+    let length = crate::ftoa::etoa(value, &mut buffer);
+    // Safe, since the buffer only contains ASCII characters.
+    f.write_str(unsafe { str::from_utf8_unchecked(&buffer[..length]) })
+}
+
+macro_rules! primitive_extended_impl {
+    ($t:ty, $bits:ty, $size:expr) => (
+        impl ExtendedPrecision for $t {
+            const MANTISSA_DIGITS: u32 = <$t>::MANTISSA_DIGITS;
+            const MAX_EXP: i32 = <$t>::MAX_EXP;
+            const MIN_EXP: i32 = <$t>::MIN_EXP;
+
+            #[inline]
+            fn from_parts(parts: FloatParts) -> $t {
+                <$t>::from_bits(ieee_from_parts::<$t>(parts, $size) as $bits)
+            }
+
+            #[inline]
+            fn to_parts(self) -> FloatParts {
+                ieee_to_parts::<$t>(self.to_bits() as u128, $size)
+            }
+        }
+    );
+}
+
+primitive_extended_impl!(f32, u32, 32);
+primitive_extended_impl!(f64, u64, 64);
+
+macro_rules! extended_impl {
+    ($(#[$meta:meta])* $t:ident) => (
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default)]
+        pub struct $t(u128);
+
+        impl $t {
+            /// Create the float from the raw bits.
+            #[inline]
+            pub const fn from_bits(bits: u128) -> $t {
+                $t(bits)
+            }
+
+            /// Get the raw bits of the float.
+            #[inline]
+            pub const fn to_bits(self) -> u128 {
+                self.0
+            }
+
+            /// Create the float from a double-precision float, exactly.
+            #[inline]
+            pub fn from_f64(value: f64) -> $t {
+                convert(value)
+            }
+
+            /// Convert the float to a double-precision float, rounding
+            /// to nearest, with ties to even.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                convert(self)
+            }
+
+            /// Get if the float is NaN.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.to_parts() == FloatParts::Nan
+            }
+        }
+
+        impl fmt::Display for $t {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                display(*self, f)
+            }
+        }
+
+        impl fmt::Debug for $t {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                display(*self, f)
+            }
+        }
+    );
+}
+
+extended_impl!(
+    /// IEEE754 quadruple-precision float.
+    ///
+    /// The float has 113 significant bits, including the hidden bit,
+    /// and a 15-bit exponent.
+    f128
+);
+
+extended_impl!(
+    /// x87 80-bit extended-precision float.
+    ///
+    /// The float has 64 significant bits, with an explicit integer bit,
+    /// and a 15-bit exponent. The bits are stored in the lower 80 bits.
+    f80
+);
+
+impl ExtendedPrecision for f128 {
+    const MANTISSA_DIGITS: u32 = 113;
+    const MAX_EXP: i32 = 16384;
+    const MIN_EXP: i32 = -16381;
+
+    #[inline]
+    fn from_parts(parts: FloatParts) -> f128 {
+        f128(ieee_from_parts::<f128>(parts, 128))
+    }
+
+    #[inline]
+    fn to_parts(self) -> FloatParts {
+        ieee_to_parts::<f128>(self.0, 128)
+    }
+}
+
+impl ExtendedPrecision for f80 {
+    const MANTISSA_DIGITS: u32 = 64;
+    const MAX_EXP: i32 = 16384;
+    const MIN_EXP: i32 = -16381;
+
+    #[inline]
+    fn from_parts(parts: FloatParts) -> f80 {
+        const SIGN: u128 = 1 << 79;
+        const EXPONENT_MASK: u128 = 0x7FFF;
+        const INTEGER_BIT: u128 = 1 << 63;
+        f80(match parts {
+            FloatParts::Finite { is_negative, mantissa, exponent } => {
+                let biased = match mantissa & INTEGER_BIT {
+                    0 => 0,
+                    _ => (exponent - (Self::MIN_EXP - 64) + 1) as u128,
+                };
+                (is_negative as u128 * SIGN) | (biased << 64) | mantissa
+            },
+            FloatParts::Infinite { is_negative } => (is_negative as u128 * SIGN) | (EXPONENT_MASK << 64) | INTEGER_BIT,
+            FloatParts::Nan => (EXPONENT_MASK << 64) | INTEGER_BIT | (INTEGER_BIT >> 1),
+        })
+    }
+
+    #[inline]
+    fn to_parts(self) -> FloatParts {
+        const EXPONENT_MASK: u128 = 0x7FFF;
+        let is_negative = self.0 & (1 << 79) != 0;
+        let biased = ((self.0 >> 64) & EXPONENT_MASK) as i32;
+        let mantissa = self.0 & 0xFFFF_FFFF_FFFF_FFFF;
+        // Denormals, including pseudo-denormals, use the minimum exponent,
+        // and unnormals are still exact.
+        match biased {
+            0x7FFF if (mantissa << 1) as u64 == 0 => FloatParts::Infinite { is_negative },
+            0x7FFF => FloatParts::Nan,
+            _ => {
+                let exponent = cmp::max(biased, 1) - 1 + Self::MIN_EXP - 64;
+                FloatParts::Finite { is_negative, mantissa, exponent }
+            },
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f64_parts_test() {
+        assert_eq!(1.0f64.to_parts(), FloatParts::Finite { is_negative: false, mantissa: 1 << 52, exponent: -52 });
+        assert_eq!((-5e-324f64).to_parts(), FloatParts::Finite { is_negative: true, mantissa: 1, exponent: -1074 });
+        assert_eq!(f64::INFINITY.to_parts(), FloatParts::Infinite { is_negative: false });
+        assert_eq!(f64::NAN.to_parts(), FloatParts::Nan);
+        for &value in [0.0, -0.0, 1.0, 0.1, 5e-324, 2.2250738585072014e-308, 1.7976931348623157e308, f64::NEG_INFINITY].iter() {
+            assert_eq!(f64::from_parts(value.to_parts()).to_bits(), value.to_bits());
+        }
+        assert!(f64::from_parts(FloatParts::Nan).is_nan());
+    }
+
+    #[test]
+    fn f128_test() {
+        assert_eq!(f128::from_f64(1.0).to_bits(), 0x3FFF << 112);
+        assert_eq!(f128::from_f64(-2.0).to_bits(), (1 << 127) | (0x4000 << 112));
+        assert_eq!(f128::from_f64(5e-324).to_bits(), (0x3FFF - 1074) << 112);
+        assert_eq!(f128::from_f64(f64::INFINITY).to_bits(), 0x7FFF << 112);
+        assert!(f128::from_f64(f64::NAN).is_nan());
+        for &value in [0.0, 1.0, 0.1, -3.5, 5e-324, 1.7976931348623157e308].iter() {
+            assert_eq!(f128::from_f64(value).to_f64(), value);
+        }
+
+        // Rounding to double precision.
+        assert_eq!(f128::from_bits(0x3FFF << 112 | 1).to_f64(), 1.0);
+        assert_eq!(f128::from_bits(0x3FFF << 112 | 1 << 59).to_f64(), 1.0);
+        assert_eq!(f128::from_bits(0x3FFF << 112 | 1 << 59 | 1).to_f64(), 1.0000000000000002);
+        assert_eq!(f128::from_bits(0x7FFE << 112).to_f64(), f64::INFINITY);
+        assert_eq!(f128::from_bits(1).to_f64(), 0.0);
+        assert_eq!(f128::from_bits(1).to_parts(), FloatParts::Finite { is_negative: false, mantissa: 1, exponent: -16494 });
+    }
+
+    #[test]
+    fn f80_test() {
+        assert_eq!(f80::from_f64(1.0).to_bits(), 0x3FFF << 64 | 1 << 63);
+        assert_eq!(f80::from_f64(-0.0).to_bits(), 1 << 79);
+        assert_eq!(f80::from_f64(f64::NEG_INFINITY).to_bits(), 0xFFFF << 64 | 1 << 63);
+        assert!(f80::from_f64(f64::NAN).is_nan());
+        assert!(!f80::from_f64(f64::INFINITY).is_nan());
+        assert_eq!(f80::from_bits(0x7FFF << 64 | 1 << 63).to_parts(), FloatParts::Infinite { is_negative: false });
+        assert_eq!(f80::from_bits(0x7FFF << 64 | 1 << 63 | 1).to_parts(), FloatParts::Nan);
+        for &value in [0.0, 1.0, 0.1, -3.5, 5e-324, 1.7976931348623157e308, f64::INFINITY, f64::NEG_INFINITY].iter() {
+            assert_eq!(f80::from_f64(value).to_f64(), value);
+        }
+
+        // Denormals and unnormals.
+        assert_eq!(f80::from_bits(1).to_parts(), FloatParts::Finite { is_negative: false, mantissa: 1, exponent: -16445 });
+        assert_eq!(f80::from_bits(1 << 63).to_parts(), FloatParts::Finite { is_negative: false, mantissa: 1 << 63, exponent: -16445 });
+        assert_eq!(f80::from_bits(0x3FFF << 64 | 1 << 62).to_f64(), 0.5);
+    }
+
+    #[test]
+    fn round_extended_test() {
+        let round = |mantissa, exponent, is_truncated| f64::from_parts(round_extended::<f64>(false, mantissa, exponent, is_truncated));
+        assert_eq!(round(1, 0, false), 1.0);
+        assert_eq!(round((1 << 54) | 2, -54, false), 1.0);
+        assert_eq!(round((1 << 54) | 2, -54, true), 1.0000000000000002);
+        assert_eq!(round((1 << 54) | 6, -54, false), 1.0000000000000004);
+        assert_eq!(round(1, -1075, false), 0.0);
+        assert_eq!(round(3, -1076, false), 5e-324);
+        assert_eq!(round(1, 1024, false), f64::INFINITY);
+        assert_eq!(round((1 << 55) - 1, 971, false), f64::INFINITY);
+        assert_eq!(round((1 << 55) - 3, 969, false), 1.7976931348623157e308);
+    }
+}
@@ -775,12 +775,12 @@ if #[cfg(not(feature = "format"))] {
         /// ```text
         ///  0   1   2   3   4   5   6   7   8   9   0   1   2   3   4   5
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-        /// |I/R|F/R|E/R|+/M|R/M|e/e|+/E|R/E|e/F|S/S|S/C|     RESERVED      |
+        /// |I/R|F/R|E/R|+/M|R/M|e/e|+/E|R/E|e/F|S/S|S/C|N/I|N/F|x/P|o/P|b/P|
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
         ///
         ///  16  17  18  19  20  21  22  23  24  25  26  27  28  29  30  31
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-        /// |                           RESERVED                            |
+        /// |P/C|R/S|C/I|C/F|J/I|J/F|L/W|T/W|Q/S|         RESERVED          |
         /// +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
         ///
         ///  32  33  34  35  36  37  38  39  40  41  42  43  44  45  46  47
@@ -805,6 +805,20 @@ if #[cfg(not(feature = "format"))] {
         ///     e/F = No exponent without fraction.
         ///     S/S = No special (non-finite) values.
         ///     S/C = Case-sensitive special (non-finite) values.
+        ///     N/I = No integer leading zeros.
+        ///     N/F = No float leading zeros.
+        ///     x/P = Hexadecimal base prefix.
+        ///     o/P = Octal base prefix.
+        ///     b/P = Binary base prefix.
+        ///     P/C = Case-sensitive base prefix.
+        ///     R/S = Rust type suffix.
+        ///     C/I = C integer suffix.
+        ///     C/F = C float suffix.
+        ///     J/I = Java integer suffix.
+        ///     J/F = Java float suffix.
+        ///     L/W = Leading whitespace.
+        ///     T/W = Trailing whitespace.
+        ///     Q/S = Surrounding quotes.
         ///     I/I = Integer internal digit separator.
         ///     F/I = Fraction internal digit separator.
         ///     E/I = Exponent internal digit separator.
@@ -934,6 +948,11 @@ if #[cfg(not(feature = "format"))] {
                 | Self::CASE_SENSITIVE_SPECIAL.bits
                 | Self::NO_INTEGER_LEADING_ZEROS.bits
                 | Self::NO_FLOAT_LEADING_ZEROS.bits
+                | Self::BASE_PREFIX_FLAG_MASK.bits
+                | Self::CASE_SENSITIVE_BASE_PREFIX.bits
+                | Self::TYPE_SUFFIX_FLAG_MASK.bits
+                | Self::WHITESPACE_FLAG_MASK.bits
+                | Self::SURROUNDING_QUOTES.bits
                 | Self::INTERNAL_DIGIT_SEPARATOR.bits
                 | Self::LEADING_DIGIT_SEPARATOR.bits
                 | Self::TRAILING_DIGIT_SEPARATOR.bits
@@ -997,6 +1016,31 @@ if #[cfg(not(feature = "format"))] {
                 | Self::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR.bits
             );
 
+            /// Mask to extract base prefix flags.
+            #[doc(hidden)]
+            const BASE_PREFIX_FLAG_MASK                 = (
+                Self::HEXADECIMAL_PREFIX.bits
+                | Self::OCTAL_PREFIX.bits
+                | Self::BINARY_PREFIX.bits
+            );
+
+            /// Mask to extract type suffix flags.
+            #[doc(hidden)]
+            const TYPE_SUFFIX_FLAG_MASK                 = (
+                Self::RUST_TYPE_SUFFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
+                | Self::JAVA_INTEGER_SUFFIX.bits
+                | Self::JAVA_FLOAT_SUFFIX.bits
+            );
+
+            /// Mask to extract whitespace flags.
+            #[doc(hidden)]
+            const WHITESPACE_FLAG_MASK                  = (
+                Self::LEADING_WHITESPACE.bits
+                | Self::TRAILING_WHITESPACE.bits
+            );
+
             /// Mask to extract exponent flags.
             #[doc(hidden)]
             const EXPONENT_FLAG_MASK                    = (
@@ -1104,6 +1148,73 @@ if #[cfg(not(feature = "format"))] {
             #[doc(hidden)]
             const NO_FLOAT_LEADING_ZEROS                = 0b0000000000000000000000000000000000000000000000000001000000000000;
 
+            /// A `0x` prefix before integer digits sets the radix to 16.
+            ///
+            /// The prefix follows any sign, and must be followed by a digit.
+            /// Base prefixes take precedence over the radix passed to the
+            /// parser, and only apply to integers.
+            #[doc(hidden)]
+            const HEXADECIMAL_PREFIX                    = 0b0000000000000000000000000000000000000000000000000010000000000000;
+
+            /// A `0o` prefix before integer digits sets the radix to 8.
+            #[doc(hidden)]
+            const OCTAL_PREFIX                          = 0b0000000000000000000000000000000000000000000000000100000000000000;
+
+            /// A `0b` prefix before integer digits sets the radix to 2.
+            #[doc(hidden)]
+            const BINARY_PREFIX                         = 0b0000000000000000000000000000000000000000000000001000000000000000;
+
+            /// Base prefixes are case-sensitive, so `0X` is not a prefix.
+            #[doc(hidden)]
+            const CASE_SENSITIVE_BASE_PREFIX            = 0b0000000000000000000000000000000000000000000000010000000000000000;
+
+            /// Rust type suffixes are allowed after the number.
+            ///
+            /// Integers accept `i8`, `i16`, `i32`, `i64`, `i128`, `isize`,
+            /// `u8`, `u16`, `u32`, `u64`, `u128` and `usize`, and floats
+            /// accept `f32` and `f64`.
+            #[doc(hidden)]
+            const RUST_TYPE_SUFFIX                      = 0b0000000000000000000000000000000000000000000000100000000000000000;
+
+            /// C integer suffixes are allowed after the number.
+            ///
+            /// These are an optional `u`, and an optional `l` or `ll`, in
+            /// either order and any case, such as `10UL` or `10llu`.
+            #[doc(hidden)]
+            const C_INTEGER_SUFFIX                      = 0b0000000000000000000000000000000000000000000001000000000000000000;
+
+            /// C float suffixes (`f` or `l`, in any case) are allowed after the number.
+            #[doc(hidden)]
+            const C_FLOAT_SUFFIX                        = 0b0000000000000000000000000000000000000000000010000000000000000000;
+
+            /// Java integer suffixes (`l` or `L`) are allowed after the number.
+            #[doc(hidden)]
+            const JAVA_INTEGER_SUFFIX                   = 0b0000000000000000000000000000000000000000000100000000000000000000;
+
+            /// Java float suffixes (`f` or `d`, in any case) are allowed after the number.
+            #[doc(hidden)]
+            const JAVA_FLOAT_SUFFIX                     = 0b0000000000000000000000000000000000000000001000000000000000000000;
+
+            /// Whitespace is allowed before the number.
+            ///
+            /// By default, whitespace is the ASCII whitespace characters
+            /// (space, `\t`, `\n`, `\x0B`, `\x0C` and `\r`), and
+            /// the whitespace characters may be changed in the options.
+            #[doc(hidden)]
+            const LEADING_WHITESPACE                    = 0b0000000000000000000000000000000000000000010000000000000000000000;
+
+            /// Whitespace is allowed after the number.
+            #[doc(hidden)]
+            const TRAILING_WHITESPACE                   = 0b0000000000000000000000000000000000000000100000000000000000000000;
+
+            /// The number may be surrounded by double quotes, such as `"1.5"`.
+            ///
+            /// The quotes are inside any leading or trailing whitespace,
+            /// and the closing quote is required if the opening quote
+            /// is present.
+            #[doc(hidden)]
+            const SURROUNDING_QUOTES                    = 0b0000000000000000000000000000000000000001000000000000000000000000;
+
             // DIGIT SEPARATOR FLAGS & MASKS
 
             /// Digit separators are allowed between integer digits.
@@ -1211,6 +1322,9 @@ if #[cfg(not(feature = "format"))] {
                 | Self::INTERNAL_DIGIT_SEPARATOR.bits
                 | Self::TRAILING_DIGIT_SEPARATOR.bits
                 | Self::CONSECUTIVE_DIGIT_SEPARATOR.bits
+                | Self::BASE_PREFIX_FLAG_MASK.bits
+                | Self::CASE_SENSITIVE_BASE_PREFIX.bits
+                | Self::RUST_TYPE_SUFFIX.bits
             );
 
             // RUST STRING [0134567MN]
@@ -1236,6 +1350,7 @@ if #[cfg(not(feature = "format"))] {
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::NO_SPECIAL.bits
                 | Self::NO_INTEGER_LEADING_ZEROS.bits
+                | Self::BASE_PREFIX_FLAG_MASK.bits
             );
 
             // PYTHON3 STRING [0134567MN]
@@ -1266,6 +1381,10 @@ if #[cfg(not(feature = "format"))] {
                 | Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::CASE_SENSITIVE_SPECIAL.bits
                 | Self::INTERNAL_DIGIT_SEPARATOR.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::BINARY_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C++17 STRING [013456MN]
@@ -1278,6 +1397,10 @@ if #[cfg(not(feature = "format"))] {
                 | Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::CASE_SENSITIVE_SPECIAL.bits
                 | Self::INTERNAL_DIGIT_SEPARATOR.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::BINARY_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C++14 STRING [013456MN]
@@ -1289,6 +1412,9 @@ if #[cfg(not(feature = "format"))] {
             const CXX11_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::CASE_SENSITIVE_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C++11 STRING [013456MN]
@@ -1300,6 +1426,9 @@ if #[cfg(not(feature = "format"))] {
             const CXX03_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::NO_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C++03 STRING [013456MN]
@@ -1311,6 +1440,9 @@ if #[cfg(not(feature = "format"))] {
             const CXX98_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::NO_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C++98 STRING [013456MN]
@@ -1328,6 +1460,9 @@ if #[cfg(not(feature = "format"))] {
             const C18_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::CASE_SENSITIVE_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C18 STRING [013456MN]
@@ -1339,6 +1474,9 @@ if #[cfg(not(feature = "format"))] {
             const C11_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::CASE_SENSITIVE_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C11 STRING [013456MN]
@@ -1350,6 +1488,9 @@ if #[cfg(not(feature = "format"))] {
             const C99_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::CASE_SENSITIVE_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C99 STRING [013456MN]
@@ -1361,6 +1502,9 @@ if #[cfg(not(feature = "format"))] {
             const C90_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::NO_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C90 STRING [013456MN]
@@ -1372,6 +1516,9 @@ if #[cfg(not(feature = "format"))] {
             const C89_LITERAL = (
                 Self::REQUIRED_EXPONENT_DIGITS.bits
                 | Self::NO_SPECIAL.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::C_INTEGER_SUFFIX.bits
+                | Self::C_FLOAT_SUFFIX.bits
             );
 
             // C89 STRING [013456MN]
@@ -1487,6 +1634,10 @@ if #[cfg(not(feature = "format"))] {
                 | Self::NO_SPECIAL.bits
                 | Self::INTERNAL_DIGIT_SEPARATOR.bits
                 | Self::CONSECUTIVE_DIGIT_SEPARATOR.bits
+                | Self::HEXADECIMAL_PREFIX.bits
+                | Self::BINARY_PREFIX.bits
+                | Self::JAVA_INTEGER_SUFFIX.bits
+                | Self::JAVA_FLOAT_SUFFIX.bits
             );
 
             // JAVA STRING [01345678MN]
@@ -2160,7 +2311,7 @@ if #[cfg(not(feature = "format"))] {
         /// * `required_fraction_digits`                - If digits are required after the decimal point.
         /// * `required_exponent_digits`                - If digits are required after the exponent character.
         /// * `no_positive_mantissa_sign`               - If positive sign before the mantissa is not allowed.
-        /// * `required_mantissa_sign`                  - If positive sign before the mantissa is required.
+        /// * `required_mantissa_sign`                  - If positive sign before the mantissa is required, except for special values.
         /// * `no_exponent_notation`                    - If exponent notation is not allowed.
         /// * `no_positive_exponent_sign`               - If positive sign before the exponent is not allowed.
         /// * `required_exponent_sign`                  - If sign before the exponent is required.
@@ -2377,7 +2528,7 @@ if #[cfg(not(feature = "format"))] {
             self.intersects(NumberFormat::NO_POSITIVE_MANTISSA_SIGN)
         }
 
-        /// Get if positive sign before the mantissa is required.
+        /// Get if positive sign before the mantissa is required, except for special values.
         #[inline]
         pub fn required_mantissa_sign(self) -> bool {
             self.intersects(NumberFormat::REQUIRED_MANTISSA_SIGN)
@@ -2431,6 +2582,96 @@ if #[cfg(not(feature = "format"))] {
             self.intersects(NumberFormat::NO_FLOAT_LEADING_ZEROS)
         }
 
+        /// Get if a `0x` prefix sets the radix to 16.
+        #[inline]
+        pub fn hexadecimal_prefix(self) -> bool {
+            self.intersects(NumberFormat::HEXADECIMAL_PREFIX)
+        }
+
+        /// Get if a `0o` prefix sets the radix to 8.
+        #[inline]
+        pub fn octal_prefix(self) -> bool {
+            self.intersects(NumberFormat::OCTAL_PREFIX)
+        }
+
+        /// Get if a `0b` prefix sets the radix to 2.
+        #[inline]
+        pub fn binary_prefix(self) -> bool {
+            self.intersects(NumberFormat::BINARY_PREFIX)
+        }
+
+        /// Get if any base prefix is allowed.
+        #[inline]
+        pub fn base_prefix(self) -> bool {
+            self.intersects(NumberFormat::BASE_PREFIX_FLAG_MASK)
+        }
+
+        /// Get if base prefixes are case-sensitive.
+        #[inline]
+        pub fn case_sensitive_base_prefix(self) -> bool {
+            self.intersects(NumberFormat::CASE_SENSITIVE_BASE_PREFIX)
+        }
+
+        /// Get if Rust type suffixes are allowed.
+        #[inline]
+        pub fn rust_type_suffix(self) -> bool {
+            self.intersects(NumberFormat::RUST_TYPE_SUFFIX)
+        }
+
+        /// Get if C integer suffixes are allowed.
+        #[inline]
+        pub fn c_integer_suffix(self) -> bool {
+            self.intersects(NumberFormat::C_INTEGER_SUFFIX)
+        }
+
+        /// Get if C float suffixes are allowed.
+        #[inline]
+        pub fn c_float_suffix(self) -> bool {
+            self.intersects(NumberFormat::C_FLOAT_SUFFIX)
+        }
+
+        /// Get if Java integer suffixes are allowed.
+        #[inline]
+        pub fn java_integer_suffix(self) -> bool {
+            self.intersects(NumberFormat::JAVA_INTEGER_SUFFIX)
+        }
+
+        /// Get if Java float suffixes are allowed.
+        #[inline]
+        pub fn java_float_suffix(self) -> bool {
+            self.intersects(NumberFormat::JAVA_FLOAT_SUFFIX)
+        }
+
+        /// Get if any type suffix is allowed.
+        #[inline]
+        pub fn type_suffix(self) -> bool {
+            self.intersects(NumberFormat::TYPE_SUFFIX_FLAG_MASK)
+        }
+
+        /// Get if whitespace is allowed before the number.
+        #[inline]
+        pub fn leading_whitespace(self) -> bool {
+            self.intersects(NumberFormat::LEADING_WHITESPACE)
+        }
+
+        /// Get if whitespace is allowed after the number.
+        #[inline]
+        pub fn trailing_whitespace(self) -> bool {
+            self.intersects(NumberFormat::TRAILING_WHITESPACE)
+        }
+
+        /// Get if any whitespace is allowed.
+        #[inline]
+        pub fn whitespace(self) -> bool {
+            self.intersects(NumberFormat::WHITESPACE_FLAG_MASK)
+        }
+
+        /// Get if the number may be surrounded by double quotes.
+        #[inline]
+        pub fn surrounding_quotes(self) -> bool {
+            self.intersects(NumberFormat::SURROUNDING_QUOTES)
+        }
+
         /// Get if digit separators are allowed between integer digits.
         #[inline]
         pub fn integer_internal_digit_separator(self) -> bool {
@@ -2582,6 +2823,11 @@ if #[cfg(not(feature = "format"))] {
             assert_eq!(flags.case_sensitive_special(), false);
             assert_eq!(flags.no_integer_leading_zeros(), false);
             assert_eq!(flags.no_float_leading_zeros(), false);
+            assert_eq!(flags.base_prefix(), false);
+            assert_eq!(flags.case_sensitive_base_prefix(), false);
+            assert_eq!(flags.type_suffix(), false);
+            assert_eq!(flags.whitespace(), false);
+            assert_eq!(flags.surrounding_quotes(), false);
             assert_eq!(flags.integer_internal_digit_separator(), true);
             assert_eq!(flags.fraction_internal_digit_separator(), true);
             assert_eq!(flags.exponent_internal_digit_separator(), true);
@@ -2617,6 +2863,18 @@ if #[cfg(not(feature = "format"))] {
                 NumberFormat::CASE_SENSITIVE_SPECIAL,
                 NumberFormat::NO_INTEGER_LEADING_ZEROS,
                 NumberFormat::NO_FLOAT_LEADING_ZEROS,
+                NumberFormat::HEXADECIMAL_PREFIX,
+                NumberFormat::OCTAL_PREFIX,
+                NumberFormat::BINARY_PREFIX,
+                NumberFormat::CASE_SENSITIVE_BASE_PREFIX,
+                NumberFormat::RUST_TYPE_SUFFIX,
+                NumberFormat::C_INTEGER_SUFFIX,
+                NumberFormat::C_FLOAT_SUFFIX,
+                NumberFormat::JAVA_INTEGER_SUFFIX,
+                NumberFormat::JAVA_FLOAT_SUFFIX,
+                NumberFormat::LEADING_WHITESPACE,
+                NumberFormat::TRAILING_WHITESPACE,
+                NumberFormat::SURROUNDING_QUOTES,
                 NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR,
                 NumberFormat::FRACTION_INTERNAL_DIGIT_SEPARATOR,
                 NumberFormat::EXPONENT_INTERNAL_DIGIT_SEPARATOR,
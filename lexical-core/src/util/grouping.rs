@@ -0,0 +1,196 @@
+//! Digit grouping for serialized numbers.
+//!
+//! Separators are emitted by the writers, between the groups of
+//! digits, as the digits are written.
+
+// GROUPING
+
+/// Grouping of digits with separators, for writing numbers.
+///
+/// Integer digits are grouped from the decimal point to the left,
+/// and fraction digits, if enabled, from the decimal point to the right.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DigitGrouping {
+    /// Do not separate digits.
+    None,
+    /// Groups with the same number of digits, such as `1,234,567`
+    /// with groups of 3 digits, or `dead_beef` with groups of 4.
+    Uniform(u8),
+    /// Indian grouping, such as `12,34,567`, where the first group
+    /// has 3 digits and every other group has 2 digits.
+    Indian,
+}
+
+impl DigitGrouping {
+    /// Get if the grouping is valid.
+    #[inline]
+    pub(crate) fn is_valid(self) -> bool {
+        self != DigitGrouping::Uniform(0)
+    }
+
+    /// Get the number of digits in the first group.
+    #[inline]
+    fn first_size(self) -> usize {
+        match self {
+            DigitGrouping::None         => 0,
+            DigitGrouping::Uniform(n)   => n as usize,
+            DigitGrouping::Indian       => 3,
+        }
+    }
+
+    /// Get the number of digits in the smallest group.
+    #[inline]
+    fn min_size(self) -> usize {
+        match self {
+            DigitGrouping::None         => 0,
+            DigitGrouping::Uniform(n)   => n as usize,
+            DigitGrouping::Indian       => 2,
+        }
+    }
+
+    /// Get if a separator goes before the integer digit at `index`,
+    /// counting from the last digit.
+    #[inline]
+    pub(super) fn is_integer_boundary(self, index: usize) -> bool {
+        match self {
+            DigitGrouping::None         => false,
+            DigitGrouping::Uniform(n)   => index % n as usize == 0,
+            DigitGrouping::Indian       => index == 3 || (index > 3 && (index - 3) % 2 == 0),
+        }
+    }
+
+}
+
+// Get if the separator can be used to group digits.
+//
+// The separator must be ASCII, and not a digit, a letter, or a
+// character used to write numbers.
+#[inline]
+pub(crate) fn is_valid_group_separator(ch: u8) -> bool {
+    match ch {
+        b'.' | b'+' | b'-'  => false,
+        _                   => ch.is_ascii_punctuation() || ch == b' ',
+    }
+}
+
+// Get the maximum number of separators inserted into `size` bytes.
+#[inline]
+pub(crate) fn group_separators_size(size: usize, grouping: DigitGrouping) -> usize {
+    match grouping.min_size() {
+        0 => 0,
+        n => size / n,
+    }
+}
+
+// Write integer digits, emitting a separator before each group.
+//
+// The first `count` digits are taken from `digits`, and the buffer
+// must have enough room for the separators. Returns the number of
+// bytes written.
+pub(crate) fn write_integer_digits<Iter>(bytes: &mut [u8], count: usize, digits: Iter, separator: u8, grouping: DigitGrouping)
+    -> usize
+    where Iter: Iterator<Item=u8>
+{
+    let mut index = 0;
+    for (position, digit) in digits.take(count).enumerate() {
+        if position != 0 && grouping.is_integer_boundary(count - position) {
+            index_mut!(bytes[index] = separator);
+            index += 1;
+        }
+        index_mut!(bytes[index] = digit);
+        index += 1;
+    }
+    index
+}
+
+// Write fraction digits, emitting a separator before each group.
+//
+// Fraction digits are grouped from the decimal point, using the
+// size of the first integer group. The buffer must have enough room
+// for the separators. Returns the number of bytes written.
+pub(crate) fn write_fraction_digits<Iter>(bytes: &mut [u8], digits: Iter, separator: u8, grouping: DigitGrouping)
+    -> usize
+    where Iter: Iterator<Item=u8>
+{
+    let size = grouping.first_size();
+    let mut index = 0;
+    for (position, digit) in digits.enumerate() {
+        if position != 0 && size != 0 && position % size == 0 {
+            index_mut!(bytes[index] = separator);
+            index += 1;
+        }
+        index_mut!(bytes[index] = digit);
+        index += 1;
+    }
+    index
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integer(input: &[u8], grouping: DigitGrouping, buffer: &mut [u8]) -> usize {
+        write_integer_digits(buffer, input.len(), input.iter().cloned(), b',', grouping)
+    }
+
+    fn fraction(input: &[u8], grouping: DigitGrouping, buffer: &mut [u8]) -> usize {
+        write_fraction_digits(buffer, input.iter().cloned(), b',', grouping)
+    }
+
+    #[test]
+    fn write_integer_digits_test() {
+        let mut buffer = [b'\0'; 64];
+        let len = integer(b"1234567", DigitGrouping::Uniform(3), &mut buffer);
+        assert_eq!(&buffer[..len], b"1,234,567");
+        let len = integer(b"123456", DigitGrouping::Uniform(3), &mut buffer);
+        assert_eq!(&buffer[..len], b"123,456");
+        let len = integer(b"123", DigitGrouping::Uniform(3), &mut buffer);
+        assert_eq!(&buffer[..len], b"123");
+        let len = integer(b"0", DigitGrouping::Uniform(1), &mut buffer);
+        assert_eq!(&buffer[..len], b"0");
+        let len = integer(b"dabbad00", DigitGrouping::Uniform(4), &mut buffer);
+        assert_eq!(&buffer[..len], b"dabb,ad00");
+        let len = integer(b"1234567", DigitGrouping::Indian, &mut buffer);
+        assert_eq!(&buffer[..len], b"12,34,567");
+        let len = integer(b"123456789", DigitGrouping::Indian, &mut buffer);
+        assert_eq!(&buffer[..len], b"12,34,56,789");
+        let len = integer(b"1234567", DigitGrouping::None, &mut buffer);
+        assert_eq!(&buffer[..len], b"1234567");
+
+        // Only the first `count` digits are written.
+        let digits = b"12".iter().cloned().chain(crate::lib::iter::repeat(b'0'));
+        let len = write_integer_digits(&mut buffer, 5, digits, b'_', DigitGrouping::Uniform(2));
+        assert_eq!(&buffer[..len], b"1_20_00");
+    }
+
+    #[test]
+    fn write_fraction_digits_test() {
+        let mut buffer = [b'\0'; 64];
+        let len = fraction(b"1234567", DigitGrouping::Uniform(3), &mut buffer);
+        assert_eq!(&buffer[..len], b"123,456,7");
+        let len = fraction(b"5678", DigitGrouping::Uniform(2), &mut buffer);
+        assert_eq!(&buffer[..len], b"56,78");
+        let len = fraction(b"0123456", DigitGrouping::Indian, &mut buffer);
+        assert_eq!(&buffer[..len], b"012,345,6");
+        let len = fraction(b"1234567", DigitGrouping::None, &mut buffer);
+        assert_eq!(&buffer[..len], b"1234567");
+        let len = fraction(b"", DigitGrouping::Uniform(1), &mut buffer);
+        assert_eq!(len, 0);
+    }
+
+    #[test]
+    fn group_separators_size_test() {
+        assert_eq!(group_separators_size(40, DigitGrouping::None), 0);
+        assert_eq!(group_separators_size(40, DigitGrouping::Uniform(3)), 13);
+        assert_eq!(group_separators_size(40, DigitGrouping::Indian), 20);
+        assert!(!DigitGrouping::Uniform(0).is_valid());
+        assert!(is_valid_group_separator(b'_'));
+        assert!(is_valid_group_separator(b' '));
+        assert!(!is_valid_group_separator(b'.'));
+        assert!(!is_valid_group_separator(b'a'));
+        assert!(!is_valid_group_separator(0xE2));
+    }
+}
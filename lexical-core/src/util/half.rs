@@ -0,0 +1,476 @@
+//! Half-precision float types.
+//!
+//! The types are storage-only: arithmetic is done in single-precision,
+//! which is exact before the final rounding, since `f32` has more than
+//! twice the precision of either type.
+
+use crate::lib::{cmp, fmt, iter, ops, str};
+use super::cast::{AsCast, TryCast};
+use super::config::*;
+use super::num::Number;
+use super::primitive::{AsPrimitive, Primitive, TryPrimitive};
+use super::rounding::RoundingKind;
+use super::options::ParseFloatOptions;
+use super::traits::{FromLexicalOptions, ToLexical};
+
+// HALF
+
+/// Half-precision float format.
+pub(crate) trait Half: FromLexicalOptions<Options=ParseFloatOptions> {
+    /// Number of explicit bits in the mantissa.
+    const MANTISSA_SIZE: i32;
+    /// Exponent bias of the format.
+    const EXPONENT_BIAS: i32;
+
+    /// Create the float from the raw bits.
+    fn from_half_bits(bits: u16) -> Self;
+
+    /// Get the raw bits of the float.
+    fn to_half_bits(self) -> u16;
+
+    /// Round a double-precision float to the format.
+    ///
+    /// `ordering` is the exact, parsed value compared to the magnitude
+    /// of `value`, and is only used to break ties when rounding to nearest.
+    #[inline]
+    fn from_f64_rounded(value: f64, kind: RoundingKind, ordering: cmp::Ordering) -> Self {
+        Self::from_half_bits(narrow(value, Self::MANTISSA_SIZE, Self::EXPONENT_BIAS, kind, ordering))
+    }
+
+    /// Get if the double-precision float is exactly halfway between two values.
+    #[inline]
+    fn is_halfway(value: f64) -> bool {
+        match value.is_finite() && value != 0.0 {
+            true  => {
+                let (_, truncated, halfway) = split(value, Self::MANTISSA_SIZE, Self::EXPONENT_BIAS);
+                truncated == halfway
+            },
+            false => false,
+        }
+    }
+}
+
+// Split a finite, non-zero double at the precision of a half-precision format.
+//
+// Returns the bits of the value truncated to the format, without the
+// sign, the truncated bits, and the truncated bits for a value exactly
+// halfway to the next representation. The bits may exceed the largest
+// finite value in the format.
+#[inline]
+fn split(value: f64, mantissa_size: i32, exponent_bias: i32) -> (u64, u64, u64) {
+    // The float is `mant * 2^exp`.
+    let bits = value.to_bits();
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & 0x000F_FFFF_FFFF_FFFF;
+    let (mant, exp) = match biased {
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), biased - 1075),
+    };
+
+    // Find the exponent of the last bit kept in the format, which is
+    // fixed for denormal values.
+    let min_exp = 1 - exponent_bias - mantissa_size;
+    let top = exp + 63 - mant.leading_zeros() as i32;
+    let last = (top - mantissa_size).max(min_exp);
+    let shift = last - exp;
+    if shift > 54 {
+        // Below half the smallest denormal value.
+        return (0, 1, 2);
+    }
+
+    let truncated = mant & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    let mant = mant >> shift;
+    // The hidden bit of normal values carries into the exponent.
+    let bits = (((last - min_exp) as u64) << mantissa_size) + mant;
+    (bits, truncated, halfway)
+}
+
+// Round a double to the raw bits of a half-precision format.
+fn narrow(value: f64, mantissa_size: i32, exponent_bias: i32, kind: RoundingKind, ordering: cmp::Ordering)
+    -> u16
+{
+    let sign = ((value.to_bits() >> 63) as u16) << 15;
+    let infinity = ((1u16 << (15 - mantissa_size)) - 1) << mantissa_size;
+    if value.is_nan() {
+        // Keep the most significant bits of the payload, and quiet the NaN.
+        let mask = (1u16 << mantissa_size) - 1;
+        let payload = (value.to_bits() >> (52 - mantissa_size)) as u16 & mask;
+        return sign | infinity | payload | (1 << (mantissa_size - 1));
+    } else if value.is_infinite() {
+        return sign | infinity;
+    } else if value == 0.0 {
+        return sign;
+    }
+
+    let is_negative = sign != 0;
+    let is_toward_zero = match kind {
+        RoundingKind::TowardPositiveInfinity => is_negative,
+        RoundingKind::TowardNegativeInfinity => !is_negative,
+        RoundingKind::TowardZero             => true,
+        RoundingKind::Downward               => true,
+        _                                    => false,
+    };
+    let (bits, truncated, halfway) = split(value, mantissa_size, exponent_bias);
+    let is_odd = bits & 1 == 1;
+    let is_above = truncated > halfway;
+    let is_halfway = truncated == halfway;
+    let round_up = match kind {
+        RoundingKind::NearestTieEven     => is_above || (is_halfway && (ordering == cmp::Ordering::Greater || (ordering == cmp::Ordering::Equal && is_odd))),
+        RoundingKind::NearestTieAwayZero => is_above || (is_halfway && ordering != cmp::Ordering::Less),
+        _                                => truncated != 0 && !is_toward_zero,
+    };
+    let bits = bits + round_up as u64;
+
+    // Values above the largest finite value only become infinite
+    // when they do not round toward zero.
+    match bits >= infinity as u64 {
+        true if is_toward_zero => sign | (infinity - 1),
+        true                   => sign | infinity,
+        false                  => sign | bits as u16,
+    }
+}
+
+// TYPES
+
+macro_rules! half_impl {
+    (
+        $(#[$meta:meta])*
+        struct $t:ident,
+        mantissa_size => $mantissa_size:expr,
+        exponent_bias => $exponent_bias:expr
+    ) => (
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default)]
+        pub struct $t(u16);
+
+        impl $t {
+            /// Create the float from the raw bits.
+            #[inline]
+            pub const fn from_bits(bits: u16) -> Self {
+                $t(bits)
+            }
+
+            /// Get the raw bits of the float.
+            #[inline]
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Create the float from a single-precision float, rounding to nearest, tie-even.
+            #[inline]
+            pub fn from_f32(value: f32) -> Self {
+                Self::from_f64(value as f64)
+            }
+
+            /// Create the float from a double-precision float, rounding to nearest, tie-even.
+            #[inline]
+            pub fn from_f64(value: f64) -> Self {
+                <Self as Half>::from_f64_rounded(value, RoundingKind::NearestTieEven, cmp::Ordering::Equal)
+            }
+
+            /// Convert the float to a single-precision float, which is exact.
+            #[inline]
+            pub fn to_f32(self) -> f32 {
+                let shift = 23 - $mantissa_size;
+                let sign = ((self.0 & 0x8000) as u32) << 16;
+                let bits = (self.0 & 0x7FFF) as u32;
+                let infinity = ((1u32 << (15 - $mantissa_size)) - 1) << $mantissa_size;
+                let mantissa_mask = (1u32 << $mantissa_size) - 1;
+                let bits = if bits >= infinity {
+                    // Special values keep the payload.
+                    0x7F80_0000 | ((bits & mantissa_mask) << shift)
+                } else if bits > mantissa_mask {
+                    // Normal values only need to be re-biased.
+                    (bits << shift) + (((127 - $exponent_bias) as u32) << 23)
+                } else if bits != 0 {
+                    // Denormal values are normal in single-precision.
+                    let ulp = f64::from_bits(((1023 + 1 - $exponent_bias - $mantissa_size) as u64) << 52);
+                    ((bits as f64 * ulp) as f32).to_bits()
+                } else {
+                    0
+                };
+                f32::from_bits(sign | bits)
+            }
+
+            /// Convert the float to a double-precision float, which is exact.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.to_f32() as f64
+            }
+
+            /// Get if the float is NaN.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.to_f32().is_nan()
+            }
+        }
+
+        impl Half for $t {
+            const MANTISSA_SIZE: i32 = $mantissa_size;
+            const EXPONENT_BIAS: i32 = $exponent_bias;
+
+            #[inline]
+            fn from_half_bits(bits: u16) -> Self {
+                $t(bits)
+            }
+
+            #[inline]
+            fn to_half_bits(self) -> u16 {
+                self.0
+            }
+        }
+
+        impl From<$t> for f32 {
+            #[inline]
+            fn from(value: $t) -> f32 {
+                value.to_f32()
+            }
+        }
+
+        impl From<$t> for f64 {
+            #[inline]
+            fn from(value: $t) -> f64 {
+                value.to_f64()
+            }
+        }
+
+        impl PartialEq for $t {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $t {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl fmt::Display for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut buffer = [b'0'; F32_FORMATTED_SIZE_DECIMAL];
+                let bytes = self.to_lexical(&mut buffer);
+                // The serialized float is always ASCII.
+                f.write_str(unsafe { str::from_utf8_unchecked(bytes) })
+            }
+        }
+
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        half_impl!(@op $t, Add, add, AddAssign, add_assign, +);
+        half_impl!(@op $t, Sub, sub, SubAssign, sub_assign, -);
+        half_impl!(@op $t, Mul, mul, MulAssign, mul_assign, *);
+        half_impl!(@op $t, Div, div, DivAssign, div_assign, /);
+        half_impl!(@op $t, Rem, rem, RemAssign, rem_assign, %);
+
+        impl ops::Neg for $t {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                $t(self.0 ^ 0x8000)
+            }
+        }
+
+        impl iter::Sum for $t {
+            #[inline]
+            fn sum<Iter: Iterator<Item=Self>>(iter: Iter) -> Self {
+                iter.fold($t(0), |sum, value| sum + value)
+            }
+        }
+
+        impl iter::Product for $t {
+            #[inline]
+            fn product<Iter: Iterator<Item=Self>>(iter: Iter) -> Self {
+                iter.fold(Self::from_f32(1.0), |product, value| product * value)
+            }
+        }
+
+        impl AsPrimitive for $t {
+            half_impl!(@as $t, as_u8 u8, as_u16 u16, as_u32 u32, as_u64 u64,
+                as_u128 u128, as_usize usize, as_i8 i8, as_i16 i16, as_i32 i32,
+                as_i64 i64, as_i128 i128, as_isize isize, as_f32 f32, as_f64 f64);
+        }
+
+        impl AsCast for $t {
+            #[inline]
+            fn as_cast<N: AsPrimitive>(n: N) -> Self {
+                Self::from_f64(n.as_f64())
+            }
+        }
+
+        half_impl!(@try $t, u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+        impl TryPrimitive for $t {}
+        impl Primitive for $t {}
+
+        impl Number for $t {
+            const FORMATTED_SIZE: usize = F32_FORMATTED_SIZE;
+            const FORMATTED_SIZE_DECIMAL: usize = F32_FORMATTED_SIZE_DECIMAL;
+            const IS_SIGNED: bool = true;
+            const IS_FLOAT: bool = true;
+        }
+    );
+
+    // Arithmetic is exact in single-precision before rounding.
+    (@op $t:ident, $op:ident, $meth:ident, $op_assign:ident, $meth_assign:ident, $symbol:tt) => (
+        impl ops::$op for $t {
+            type Output = Self;
+
+            #[inline]
+            fn $meth(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() $symbol rhs.to_f32())
+            }
+        }
+
+        impl ops::$op_assign for $t {
+            #[inline]
+            fn $meth_assign(&mut self, rhs: Self) {
+                *self = *self $symbol rhs;
+            }
+        }
+    );
+
+    (@as $t:ident, $($meth:ident $dst:ty),*) => ($(
+        #[inline]
+        fn $meth(self) -> $dst {
+            self.to_f32() as $dst
+        }
+    )*);
+
+    (@try $t:ident, $($dst:ty)*) => ($(
+        impl TryCast<$dst> for $t {
+            #[inline]
+            fn try_cast(self) -> Option<$dst> {
+                self.to_f32().try_cast()
+            }
+        }
+    )*);
+}
+
+half_impl! {
+    /// IEEE754 half-precision float (binary16).
+    ///
+    /// Has 11 bits of precision and 5 exponent bits, for a largest
+    /// finite value of `65504`.
+    struct f16,
+    mantissa_size => 10,
+    exponent_bias => 15
+}
+
+half_impl! {
+    /// Brain floating-point format (bfloat16).
+    ///
+    /// Has 8 bits of precision and the 8 exponent bits of `f32`, so
+    /// it has the same range as `f32`.
+    struct bf16,
+    mantissa_size => 7,
+    exponent_bias => 127
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(value: f64, kind: RoundingKind) -> u16 {
+        f16::from_f64_rounded(value, kind, cmp::Ordering::Equal).to_bits()
+    }
+
+    #[test]
+    fn f16_conversion_test() {
+        assert_eq!(f16::from_f32(1.0).to_bits(), 0x3C00);
+        assert_eq!(f16::from_f32(-2.0).to_bits(), 0xC000);
+        assert_eq!(f16::from_f32(65504.0).to_bits(), 0x7BFF);
+        assert_eq!(f16::from_f32(65520.0).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f32(f32::INFINITY).to_bits(), 0x7C00);
+        assert_eq!(f16::from_f32(5.960464477539063e-08).to_bits(), 0x0001);
+        assert_eq!(f16::from_f32(2.98023223876953125e-08).to_bits(), 0x0000);
+        assert_eq!(f16::from_f64(2f64.powi(-25) * (1.0 + f64::EPSILON)).to_bits(), 0x0001);
+        assert_eq!(f16::from_f32(-0.0).to_bits(), 0x8000);
+        assert!(f16::from_f32(f32::NAN).is_nan());
+
+        assert_eq!(f16::from_bits(0x3C00).to_f32(), 1.0);
+        assert_eq!(f16::from_bits(0x7BFF).to_f32(), 65504.0);
+        assert_eq!(f16::from_bits(0x0001).to_f32(), 5.960464477539063e-08);
+        assert_eq!(f16::from_bits(0x03FF).to_f32(), 6.097555160522461e-05);
+        assert_eq!(f16::from_bits(0xFC00).to_f32(), f32::NEG_INFINITY);
+        assert!(f16::from_bits(0x7E00).to_f32().is_nan());
+
+        for bits in 0..0x7C00u16 {
+            let value = f16::from_bits(bits);
+            assert_eq!(f16::from_f32(value.to_f32()).to_bits(), bits);
+            assert_eq!(f16::from_f32(-value.to_f32()).to_bits(), bits | 0x8000);
+        }
+    }
+
+    #[test]
+    fn bf16_conversion_test() {
+        assert_eq!(bf16::from_f32(1.0).to_bits(), 0x3F80);
+        assert_eq!(bf16::from_f32(f32::MAX).to_bits(), 0x7F80);
+        assert_eq!(bf16::from_f32(3.3895314e38).to_bits(), 0x7F7F);
+        assert_eq!(bf16::from_f32(1.00390625).to_bits(), 0x3F80);
+        assert_eq!(bf16::from_f32(1.01171875).to_bits(), 0x3F82);
+        assert_eq!(bf16::from_bits(0x0001).to_f32(), f32::from_bits(0x0001_0000));
+
+        for bits in 0..0x7F80u16 {
+            let value = bf16::from_bits(bits);
+            assert_eq!(value.to_f32().to_bits(), (bits as u32) << 16);
+            assert_eq!(bf16::from_f32(value.to_f32()).to_bits(), bits);
+        }
+    }
+
+    #[test]
+    fn rounding_test() {
+        // Halfway between 1.0 and the next value.
+        let halfway = 1.0 + 2f64.powi(-11);
+        assert!(f16::is_halfway(halfway));
+        assert!(!f16::is_halfway(1.0));
+        assert_eq!(round(halfway, RoundingKind::NearestTieEven), 0x3C00);
+        assert_eq!(round(halfway, RoundingKind::NearestTieAwayZero), 0x3C01);
+        assert_eq!(round(-halfway, RoundingKind::NearestTieAwayZero), 0xBC01);
+        let above = f16::from_f64_rounded(halfway, RoundingKind::NearestTieEven, cmp::Ordering::Greater);
+        assert_eq!(above.to_bits(), 0x3C01);
+        let below = f16::from_f64_rounded(halfway, RoundingKind::NearestTieAwayZero, cmp::Ordering::Less);
+        assert_eq!(below.to_bits(), 0x3C00);
+
+        assert_eq!(round(1.0001, RoundingKind::TowardPositiveInfinity), 0x3C01);
+        assert_eq!(round(-1.0001, RoundingKind::TowardPositiveInfinity), 0xBC00);
+        assert_eq!(round(1.0001, RoundingKind::TowardNegativeInfinity), 0x3C00);
+        assert_eq!(round(-1.0001, RoundingKind::TowardNegativeInfinity), 0xBC01);
+        assert_eq!(round(-1.0001, RoundingKind::TowardZero), 0xBC00);
+        assert_eq!(round(1e10, RoundingKind::TowardZero), 0x7BFF);
+        assert_eq!(round(-1e10, RoundingKind::TowardPositiveInfinity), 0xFBFF);
+        assert_eq!(round(1e10, RoundingKind::TowardPositiveInfinity), 0x7C00);
+        assert_eq!(round(1e-300, RoundingKind::TowardPositiveInfinity), 0x0001);
+        assert_eq!(round(1e-300, RoundingKind::NearestTieEven), 0x0000);
+    }
+
+    #[test]
+    fn arithmetic_test() {
+        let one = f16::from_f32(1.0);
+        let two = f16::from_f32(2.0);
+        assert_eq!(one + two, f16::from_f32(3.0));
+        assert_eq!(one - two, f16::from_f32(-1.0));
+        assert_eq!(one / f16::from_f32(3.0), f16::from_f64(1.0 / 3.0));
+        assert_eq!(-one, f16::from_f32(-1.0));
+        assert_eq!([one, two, two].iter().cloned().sum::<f16>(), f16::from_f32(5.0));
+        assert_eq!([one, two, two].iter().cloned().product::<f16>(), f16::from_f32(4.0));
+        assert!(one < two);
+        assert_eq!(f16::from_bits(0x8000), f16::from_bits(0x0000));
+        assert!(f16::from_f32(f32::NAN) != f16::from_f32(f32::NAN));
+        assert_eq!(two.as_i32(), 2);
+        assert_eq!(<bf16 as AsCast>::as_cast(3u8), bf16::from_f32(3.0));
+    }
+}
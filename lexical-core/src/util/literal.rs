@@ -0,0 +1,273 @@
+//! Base prefixes and type suffixes for number literals.
+
+use super::format::NumberFormat;
+use super::num::Number;
+use super::sign::{parse_sign_no_separator, Sign};
+
+// PREFIX
+
+/// Find and parse a base prefix after an optional sign.
+///
+/// Returns the sign, the radix from the prefix, and the digits after
+/// the prefix. The prefix is only parsed if it is followed by a digit
+/// (or a digit separator, if leading digit separators are allowed), so
+/// `0x` is parsed as `0` with the trailing `x`.
+#[inline]
+pub(crate) fn parse_base_prefix<'a, T>(bytes: &'a [u8], format: NumberFormat)
+    -> Option<(Sign, u32, &'a [u8])>
+    where T: Number
+{
+    if !format.base_prefix() {
+        return None;
+    }
+
+    let (sign, digits) = parse_sign_no_separator::<T>(bytes, format.digit_separator());
+    if digits.len() < 3 || index!(digits[0]) != b'0' {
+        return None;
+    }
+
+    let c = index!(digits[1]);
+    if format.case_sensitive_base_prefix() && c.is_ascii_uppercase() {
+        return None;
+    }
+    let radix = match c.to_ascii_lowercase() {
+        b'x' if format.hexadecimal_prefix() => 16,
+        b'o' if format.octal_prefix()       => 8,
+        b'b' if format.binary_prefix()      => 2,
+        _                                   => return None,
+    };
+
+    let digits = &index!(digits[2..]);
+    let first = index!(digits[0]);
+    let is_separator = first == format.digit_separator() && format.integer_leading_digit_separator();
+    match (first as char).is_digit(radix) || is_separator {
+        true  => Some((sign, radix, digits)),
+        false => None,
+    }
+}
+
+/// Get the base prefix for the radix of a float, if the format has one.
+///
+/// Unlike integers, the prefix does not set the radix of a float, so
+/// it is only used if it matches the radix.
+#[inline]
+pub(crate) fn float_base_prefix(radix: u32, format: NumberFormat)
+    -> Option<&'static [u8]>
+{
+    match radix {
+        16 if format.hexadecimal_prefix()   => Some(b"0x"),
+        8 if format.octal_prefix()          => Some(b"0o"),
+        2 if format.binary_prefix()         => Some(b"0b"),
+        _                                   => None,
+    }
+}
+
+/// Skip a base prefix before the digits of a float.
+///
+/// The prefix is only skipped if it matches the radix, and is followed
+/// by a digit or a decimal point, such as `0x1.8p3` with a radix of 16.
+#[inline]
+pub(crate) fn skip_float_base_prefix(bytes: &[u8], radix: u32, format: NumberFormat)
+    -> &[u8]
+{
+    let prefix = match float_base_prefix(radix, format) {
+        Some(prefix) => index!(prefix[1]),
+        None         => return bytes,
+    };
+    if bytes.len() < 3 || index!(bytes[0]) != b'0' {
+        return bytes;
+    }
+
+    let c = index!(bytes[1]);
+    let is_prefix = match format.case_sensitive_base_prefix() {
+        true  => c == prefix,
+        false => c.to_ascii_lowercase() == prefix,
+    };
+    let first = index!(bytes[2]);
+    match is_prefix && ((first as char).is_digit(radix) || first == b'.') {
+        true  => &index!(bytes[2..]),
+        false => bytes,
+    }
+}
+
+// SUFFIX
+
+/// Rust integer type suffixes.
+const RUST_INTEGER_SUFFIXES: [&[u8]; 12] = [
+    b"i8", b"i16", b"i32", b"i64", b"i128", b"isize",
+    b"u8", b"u16", b"u32", b"u64", b"u128", b"usize",
+];
+
+/// Rust float type suffixes.
+const RUST_FLOAT_SUFFIXES: [&[u8]; 2] = [b"f32", b"f64"];
+
+// Get the length of the Rust type suffix at the start of the bytes.
+#[inline]
+fn rust_suffix_len(bytes: &[u8], is_float: bool) -> usize {
+    let suffixes: &[&[u8]] = match is_float {
+        true  => &RUST_FLOAT_SUFFIXES,
+        false => &RUST_INTEGER_SUFFIXES,
+    };
+    suffixes.iter()
+        .find(|suffix| bytes.starts_with(suffix))
+        .map_or(0, |suffix| suffix.len())
+}
+
+// Get the length of the C integer suffix at the start of the bytes.
+//
+// This is an optional `u`, and an optional `l` or `ll`, in either
+// order. The case of each character is ignored, except that both
+// characters in `ll` must have the same case.
+#[inline]
+fn c_integer_suffix_len(bytes: &[u8]) -> usize {
+    let is_unsigned = |index: usize| match bytes.get(index) {
+        Some(&b'u') | Some(&b'U') => 1,
+        _                         => 0,
+    };
+    let long_len = |index: usize| match bytes.get(index) {
+        Some(&c) if c == b'l' || c == b'L' => match bytes.get(index + 1) == Some(&c) {
+            true  => 2,
+            false => 1,
+        },
+        _ => 0,
+    };
+
+    let unsigned = is_unsigned(0);
+    if unsigned == 1 {
+        unsigned + long_len(1)
+    } else {
+        let long = long_len(0);
+        match long {
+            0 => 0,
+            _ => long + is_unsigned(long),
+        }
+    }
+}
+
+// Get the length of a single-character suffix at the start of the bytes.
+#[inline]
+fn char_suffix_len(bytes: &[u8], suffixes: &[u8]) -> usize {
+    match bytes.first() {
+        Some(c) if suffixes.contains(&c.to_ascii_lowercase()) => 1,
+        _                                                     => 0,
+    }
+}
+
+/// Get the length of the type suffix at the start of the bytes.
+///
+/// Floats only accept float suffixes, and integers only accept
+/// integer suffixes. If multiple suffix styles are allowed, the
+/// longest suffix is used.
+#[inline]
+pub(crate) fn type_suffix_len(bytes: &[u8], format: NumberFormat, is_float: bool)
+    -> usize
+{
+    if !format.type_suffix() {
+        return 0;
+    }
+
+    let mut len = 0;
+    if format.rust_type_suffix() {
+        len = len.max(rust_suffix_len(bytes, is_float));
+    }
+    if is_float {
+        if format.c_float_suffix() {
+            len = len.max(char_suffix_len(bytes, b"fl"));
+        }
+        if format.java_float_suffix() {
+            len = len.max(char_suffix_len(bytes, b"fd"));
+        }
+    } else {
+        if format.c_integer_suffix() {
+            len = len.max(c_integer_suffix_len(bytes));
+        }
+        if format.java_integer_suffix() {
+            len = len.max(char_suffix_len(bytes, b"l"));
+        }
+    }
+    len
+}
+
+/// Consume the type suffix after the number ending at `ptr`.
+///
+/// Returns the pointer past the suffix, or `ptr` if there is no suffix.
+#[inline]
+pub(crate) fn consume_type_suffix(bytes: &[u8], ptr: *const u8, format: NumberFormat, is_float: bool)
+    -> *const u8
+{
+    if !format.type_suffix() {
+        return ptr;
+    }
+
+    let rest = &index!(bytes[super::algorithm::distance(bytes.as_ptr(), ptr)..]);
+    index!(rest[type_suffix_len(rest, format, is_float)..]).as_ptr()
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_base_prefix_test() {
+        let format = NumberFormat::RUST_LITERAL;
+        assert_eq!(parse_base_prefix::<i32>(b"0xFF", format), Some((Sign::Positive, 16, &b"FF"[..])));
+        assert_eq!(parse_base_prefix::<i32>(b"-0o17", format), Some((Sign::Negative, 8, &b"17"[..])));
+        assert_eq!(parse_base_prefix::<i32>(b"0b1010", format), Some((Sign::Positive, 2, &b"1010"[..])));
+        assert_eq!(parse_base_prefix::<i32>(b"0XFF", format), None);
+        assert_eq!(parse_base_prefix::<i32>(b"0x", format), None);
+        assert_eq!(parse_base_prefix::<i32>(b"0xg", format), None);
+        assert_eq!(parse_base_prefix::<i32>(b"0b2", format), None);
+        assert_eq!(parse_base_prefix::<i32>(b"10", format), None);
+
+        let format = NumberFormat::CXX17_LITERAL;
+        assert_eq!(parse_base_prefix::<i32>(b"0XFF", format), Some((Sign::Positive, 16, &b"FF"[..])));
+        assert_eq!(parse_base_prefix::<i32>(b"0o17", format), None);
+
+        assert_eq!(parse_base_prefix::<i32>(b"0xFF", NumberFormat::STANDARD), None);
+    }
+
+    #[test]
+    fn skip_float_base_prefix_test() {
+        let format = NumberFormat::C_LITERAL;
+        assert_eq!(skip_float_base_prefix(b"0x1.8p3", 16, format), b"1.8p3");
+        assert_eq!(skip_float_base_prefix(b"0X.8p1", 16, format), b".8p1");
+        assert_eq!(skip_float_base_prefix(b"0x1.8p3", 10, format), b"0x1.8p3");
+        assert_eq!(skip_float_base_prefix(b"0xp3", 16, format), b"0xp3");
+        assert_eq!(skip_float_base_prefix(b"0b1.1", 2, format), b"0b1.1");
+        assert_eq!(skip_float_base_prefix(b"0X1.8p3", 16, NumberFormat::RUST_LITERAL), b"0X1.8p3");
+        assert_eq!(skip_float_base_prefix(b"0x1.8p3", 16, NumberFormat::STANDARD), b"0x1.8p3");
+    }
+
+    #[test]
+    fn type_suffix_len_test() {
+        let format = NumberFormat::RUST_LITERAL;
+        assert_eq!(type_suffix_len(b"u8", format, false), 2);
+        assert_eq!(type_suffix_len(b"i128", format, false), 4);
+        assert_eq!(type_suffix_len(b"usize", format, false), 5);
+        assert_eq!(type_suffix_len(b"f32", format, false), 0);
+        assert_eq!(type_suffix_len(b"f32", format, true), 3);
+        assert_eq!(type_suffix_len(b"u8", format, true), 0);
+        assert_eq!(type_suffix_len(b"U8", format, false), 0);
+
+        let format = NumberFormat::C_LITERAL;
+        assert_eq!(type_suffix_len(b"u", format, false), 1);
+        assert_eq!(type_suffix_len(b"UL", format, false), 2);
+        assert_eq!(type_suffix_len(b"llu", format, false), 3);
+        assert_eq!(type_suffix_len(b"uLL", format, false), 3);
+        assert_eq!(type_suffix_len(b"lL", format, false), 1);
+        assert_eq!(type_suffix_len(b"uu", format, false), 1);
+        assert_eq!(type_suffix_len(b"f", format, false), 0);
+        assert_eq!(type_suffix_len(b"F", format, true), 1);
+        assert_eq!(type_suffix_len(b"l", format, true), 1);
+
+        let format = NumberFormat::JAVA_LITERAL;
+        assert_eq!(type_suffix_len(b"L", format, false), 1);
+        assert_eq!(type_suffix_len(b"u", format, false), 0);
+        assert_eq!(type_suffix_len(b"d", format, true), 1);
+
+        assert_eq!(type_suffix_len(b"u8", NumberFormat::STANDARD, false), 0);
+    }
+}
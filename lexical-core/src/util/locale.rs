@@ -0,0 +1,770 @@
+//! Locale-aware parsing and writing of decimal numbers.
+//!
+//! Localized numbers are converted to and from the canonical format
+//! of the default options, with an ASCII decimal point and signs, so the
+//! existing parsers and writers handle every locale. The canonical
+//! number is fed to the incremental parser as it is converted, so
+//! numbers of any length are parsed without allocating.
+
+use crate::lib::cmp;
+use crate::lib::result::Result as StdResult;
+use super::config::*;
+use super::error::{ErrorCode, WriteError};
+use super::grouping::DigitGrouping;
+use super::options::{ParseFloatOptions, WriteFloatOptions};
+use super::result::Result;
+use super::stream::{NumberParser, ParseStatus, StreamOptions};
+use super::traits::{FromLexicalOptions, ToLexicalOptions};
+
+// LOCALE
+
+/// Locale-specific symbols for decimal numbers.
+///
+/// Describes the decimal separator, the group separator and grouping,
+/// the signs, and the special float strings, each of which may be a
+/// multi-byte UTF-8 sequence. Create the POSIX locale with
+/// [`Locale::new`], use one of the built-in locales, or customize a
+/// locale with [`Locale::builder`].
+///
+/// When parsing, group separators are optional, but if the integer digits
+/// have any group separators, they must match the grouping. The special
+/// strings are case-insensitive. When writing, the integer digits are
+/// grouped according to the grouping.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::Locale;
+/// # pub fn main() {
+/// let value: f64 = lexical_core::parse_locale(b"-1.234.567,89", &Locale::DE_DE).unwrap();
+/// assert_eq!(value, -1234567.89);
+///
+/// let mut buffer = [0u8; 64];
+/// let slc = lexical_core::write_locale(-1234567.89f64, &Locale::DE_DE, &mut buffer).unwrap();
+/// assert_eq!(slc, b"-1.234.567,89");
+/// # }
+/// ```
+///
+/// [`Locale::new`]: #method.new
+/// [`Locale::builder`]: #method.builder
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// Separator between the integer and fraction digits.
+    decimal_point: &'static [u8],
+    /// Separator between groups of integer digits.
+    group_separator: &'static [u8],
+    /// Grouping of the integer digits.
+    grouping: DigitGrouping,
+    /// Sign for negative numbers and exponents.
+    minus_sign: &'static [u8],
+    /// Sign for positive numbers and exponents.
+    plus_sign: &'static [u8],
+    /// String representation of Not a Number.
+    nan_string: &'static [u8],
+    /// Short string representation of Infinity.
+    inf_string: &'static [u8],
+    /// Long string representation of Infinity.
+    infinity_string: &'static [u8],
+}
+
+impl Locale {
+    /// POSIX locale, such as `-1234567.89`.
+    pub const POSIX: Locale = Locale {
+        decimal_point: b".",
+        group_separator: b"",
+        grouping: DigitGrouping::None,
+        minus_sign: b"-",
+        plus_sign: b"+",
+        nan_string: b"NaN",
+        inf_string: b"inf",
+        infinity_string: b"infinity",
+    };
+
+    /// English (United States) locale, such as `-1,234,567.89`.
+    pub const EN_US: Locale = Locale {
+        group_separator: b",",
+        grouping: DigitGrouping::Uniform(3),
+        ..Locale::POSIX
+    };
+
+    /// English (India) locale, such as `-12,34,567.89`.
+    pub const EN_IN: Locale = Locale {
+        group_separator: b",",
+        grouping: DigitGrouping::Indian,
+        ..Locale::POSIX
+    };
+
+    /// German (Germany) locale, such as `-1.234.567,89`.
+    pub const DE_DE: Locale = Locale {
+        decimal_point: b",",
+        group_separator: b".",
+        grouping: DigitGrouping::Uniform(3),
+        ..Locale::POSIX
+    };
+
+    /// German (Switzerland) locale, such as `-1’234’567.89`.
+    pub const DE_CH: Locale = Locale {
+        group_separator: "\u{2019}".as_bytes(),
+        grouping: DigitGrouping::Uniform(3),
+        ..Locale::POSIX
+    };
+
+    /// French (France) locale, such as `-1 234 567,89`, grouped with
+    /// a narrow no-break space.
+    pub const FR_FR: Locale = Locale {
+        decimal_point: b",",
+        group_separator: "\u{202F}".as_bytes(),
+        grouping: DigitGrouping::Uniform(3),
+        ..Locale::POSIX
+    };
+
+    /// Swedish (Sweden) locale, such as `−1 234 567,89`, grouped with
+    /// a no-break space and with a Unicode minus sign.
+    pub const SV_SE: Locale = Locale {
+        decimal_point: b",",
+        group_separator: "\u{A0}".as_bytes(),
+        grouping: DigitGrouping::Uniform(3),
+        minus_sign: "\u{2212}".as_bytes(),
+        ..Locale::POSIX
+    };
+
+    /// Create the POSIX locale.
+    #[inline]
+    pub fn new() -> Locale {
+        Locale::POSIX
+    }
+
+    /// Create a builder to customize the locale, starting from the POSIX locale.
+    #[inline]
+    pub fn builder() -> LocaleBuilder {
+        LocaleBuilder { locale: Self::new() }
+    }
+
+    /// Get the separator between the integer and fraction digits.
+    #[inline]
+    pub fn decimal_point(&self) -> &'static [u8] {
+        self.decimal_point
+    }
+
+    /// Get the separator between groups of integer digits.
+    #[inline]
+    pub fn group_separator(&self) -> &'static [u8] {
+        self.group_separator
+    }
+
+    /// Get the grouping of the integer digits.
+    #[inline]
+    pub fn grouping(&self) -> DigitGrouping {
+        self.grouping
+    }
+
+    /// Get the sign for negative numbers and exponents.
+    #[inline]
+    pub fn minus_sign(&self) -> &'static [u8] {
+        self.minus_sign
+    }
+
+    /// Get the sign for positive numbers and exponents.
+    #[inline]
+    pub fn plus_sign(&self) -> &'static [u8] {
+        self.plus_sign
+    }
+
+    /// Get the string representation of Not a Number.
+    #[inline]
+    pub fn nan_string(&self) -> &'static [u8] {
+        self.nan_string
+    }
+
+    /// Get the short string representation of Infinity.
+    #[inline]
+    pub fn inf_string(&self) -> &'static [u8] {
+        self.inf_string
+    }
+
+    /// Get the long string representation of Infinity.
+    #[inline]
+    pub fn infinity_string(&self) -> &'static [u8] {
+        self.infinity_string
+    }
+}
+
+impl Default for Locale {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Check a symbol cannot be confused with the digits or exponent.
+#[inline]
+fn is_valid_symbol(symbol: &[u8]) -> bool {
+    match symbol.first() {
+        Some(&c) => !c.is_ascii_alphanumeric(),
+        None     => false,
+    }
+}
+
+/// Builder for [`Locale`](struct.Locale.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LocaleBuilder {
+    locale: Locale,
+}
+
+impl LocaleBuilder {
+    /// Set the separator between the integer and fraction digits.
+    #[inline]
+    pub fn decimal_point(mut self, decimal_point: &'static [u8]) -> Self {
+        self.locale.decimal_point = decimal_point;
+        self
+    }
+
+    /// Set the separator between groups of integer digits.
+    #[inline]
+    pub fn group_separator(mut self, group_separator: &'static [u8]) -> Self {
+        self.locale.group_separator = group_separator;
+        self
+    }
+
+    /// Set the grouping of the integer digits.
+    #[inline]
+    pub fn grouping(mut self, grouping: DigitGrouping) -> Self {
+        self.locale.grouping = grouping;
+        self
+    }
+
+    /// Set the sign for negative numbers and exponents.
+    #[inline]
+    pub fn minus_sign(mut self, minus_sign: &'static [u8]) -> Self {
+        self.locale.minus_sign = minus_sign;
+        self
+    }
+
+    /// Set the sign for positive numbers and exponents.
+    #[inline]
+    pub fn plus_sign(mut self, plus_sign: &'static [u8]) -> Self {
+        self.locale.plus_sign = plus_sign;
+        self
+    }
+
+    /// Set the string representation of Not a Number.
+    #[inline]
+    pub fn nan_string(mut self, nan_string: &'static [u8]) -> Self {
+        self.locale.nan_string = nan_string;
+        self
+    }
+
+    /// Set the short string representation of Infinity.
+    #[inline]
+    pub fn inf_string(mut self, inf_string: &'static [u8]) -> Self {
+        self.locale.inf_string = inf_string;
+        self
+    }
+
+    /// Set the long string representation of Infinity.
+    #[inline]
+    pub fn infinity_string(mut self, infinity_string: &'static [u8]) -> Self {
+        self.locale.infinity_string = infinity_string;
+        self
+    }
+
+    /// Build the locale, returning `None` if any symbol is invalid.
+    ///
+    /// The locale is invalid if:
+    /// - the decimal point or a sign is empty, or starts with an ASCII letter or digit.
+    /// - the group separator starts with an ASCII letter or digit.
+    /// - the group separator is empty and the digits are grouped.
+    /// - the group separator is the same as the decimal point.
+    /// - the minus sign is the same as the plus sign.
+    /// - any special string is empty.
+    /// - the grouping is invalid.
+    #[inline]
+    pub fn build(self) -> Option<Locale> {
+        let locale = self.locale;
+        let is_valid_group = match locale.group_separator.is_empty() {
+            true  => locale.grouping == DigitGrouping::None,
+            false => is_valid_symbol(locale.group_separator),
+        };
+        let is_valid = is_valid_symbol(locale.decimal_point)
+            && is_valid_symbol(locale.minus_sign)
+            && is_valid_symbol(locale.plus_sign)
+            && is_valid_group
+            && locale.grouping.is_valid()
+            && locale.group_separator != locale.decimal_point
+            && locale.minus_sign != locale.plus_sign
+            && !locale.nan_string.is_empty()
+            && !locale.inf_string.is_empty()
+            && !locale.infinity_string.is_empty();
+        match is_valid {
+            true  => Some(locale),
+            false => None,
+        }
+    }
+}
+
+// TOKENS
+
+// Position within a localized number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum State {
+    Start,
+    Sign,
+    Integer,
+    Fraction,
+    Exponent,
+    ExponentSign,
+    ExponentDigits,
+    Done,
+}
+
+// Get if the bytes start with the prefix, ignoring ASCII case.
+#[inline]
+fn starts_with_ignore_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+// Iterator over the tokens of a localized number.
+//
+// Each token is the number of localized bytes and the equivalent
+// canonical bytes, which are empty for group separators. The iterator
+// stops at the first byte that cannot continue the number.
+struct Tokens<'a> {
+    bytes: &'a [u8],
+    locale: &'a Locale,
+    options: &'a ParseFloatOptions,
+    state: State,
+}
+
+impl<'a> Tokens<'a> {
+    #[inline]
+    fn new(bytes: &'a [u8], locale: &'a Locale, options: &'a ParseFloatOptions) -> Self {
+        Tokens { bytes, locale, options, state: State::Start }
+    }
+
+    // Get the canonical special string the bytes start with.
+    #[inline]
+    fn special(&self) -> Option<(usize, &'static [u8])> {
+        let locale = self.locale;
+        let options = self.options;
+        let specials = [
+            (locale.infinity_string, options.infinity_string()),
+            (locale.inf_string, options.inf_string()),
+            (locale.nan_string, options.nan_string()),
+        ];
+        specials.iter()
+            .find(|&&(string, _)| starts_with_ignore_case(self.bytes, string))
+            .map(|&(string, canonical)| (string.len(), canonical))
+    }
+
+    // Get the next token and state.
+    #[inline]
+    fn token(&self) -> Option<(usize, &'a [u8], State)> {
+        let locale = self.locale;
+        let bytes = self.bytes;
+        let state = self.state;
+        let is_mantissa = state == State::Start || state == State::Sign || state == State::Integer;
+
+        if state == State::Start || state == State::Sign {
+            if let Some((count, canonical)) = self.special() {
+                return Some((count, canonical, State::Done));
+            }
+        }
+
+        if state == State::Start || state == State::Exponent {
+            let next = match state {
+                State::Start    => State::Sign,
+                _               => State::ExponentSign,
+            };
+            if bytes.starts_with(locale.minus_sign) {
+                return Some((locale.minus_sign.len(), b"-", next));
+            } else if bytes.starts_with(locale.plus_sign) {
+                return Some((locale.plus_sign.len(), b"+", next));
+            }
+        }
+
+        if is_mantissa && bytes.starts_with(locale.decimal_point) {
+            return Some((locale.decimal_point.len(), b".", State::Fraction));
+        }
+
+        let group = locale.group_separator;
+        let is_group = !group.is_empty() && bytes.starts_with(group);
+        if state == State::Integer && is_group && bytes.get(group.len()).map_or(false, u8::is_ascii_digit) {
+            return Some((group.len(), b"", State::Integer));
+        }
+
+        let c = *bytes.first()?;
+        if c.is_ascii_digit() {
+            let next = match state {
+                State::Start | State::Sign                  => State::Integer,
+                State::Exponent | State::ExponentSign       => State::ExponentDigits,
+                _                                           => state,
+            };
+            Some((1, &bytes[..1], next))
+        } else if (state == State::Integer || state == State::Fraction) && c.eq_ignore_ascii_case(&self.options.exponent_default_char()) {
+            Some((1, &bytes[..1], State::Exponent))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = (usize, &'a [u8]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == State::Done {
+            return None;
+        }
+        match self.token() {
+            Some((count, canonical, state)) => {
+                self.bytes = &self.bytes[count..];
+                self.state = state;
+                Some((count, canonical))
+            },
+            None => {
+                self.state = State::Done;
+                None
+            },
+        }
+    }
+}
+
+// PARSE
+
+// Find the first misplaced group separator in the integer digits.
+//
+// Group separators are optional, but if the integer digits have any
+// group separators, every boundary of the grouping must have one, and
+// no other separators are allowed. Returns the index of the first
+// separator not at a boundary, or of the first digit after a boundary
+// without a separator.
+fn misplaced_group(bytes: &[u8], locale: &Locale) -> Option<usize> {
+    let group = locale.group_separator;
+    if group.is_empty() {
+        return None;
+    }
+    let start = if bytes.starts_with(locale.minus_sign) {
+        locale.minus_sign.len()
+    } else if bytes.starts_with(locale.plus_sign) {
+        locale.plus_sign.len()
+    } else {
+        0
+    };
+
+    // Get the separator at the index, only if it is followed by a digit.
+    let separator = |index: usize| {
+        let is_group = bytes[index..].starts_with(group);
+        is_group && bytes.get(index + group.len()).map_or(false, u8::is_ascii_digit)
+    };
+
+    // Count the integer digits, and check for any group separators.
+    let mut digits = 0;
+    let mut has_groups = false;
+    let mut index = start;
+    while index < bytes.len() {
+        if bytes[index].is_ascii_digit() {
+            digits += 1;
+            index += 1;
+        } else if digits != 0 && separator(index) {
+            has_groups = true;
+            index += group.len();
+        } else {
+            break;
+        }
+    }
+    if !has_groups {
+        return None;
+    }
+
+    // Check a separator is present at every boundary, and only there.
+    let mut remaining = digits;
+    let mut index = start;
+    while remaining != 0 {
+        let is_group = index != start && separator(index);
+        let is_boundary = index != start && locale.grouping.is_integer_boundary(remaining);
+        if is_group != is_boundary {
+            return Some(index);
+        } else if is_group {
+            index += group.len();
+        }
+        index += 1;
+        remaining -= 1;
+    }
+    None
+}
+
+// Get the index in the localized number from the index in the canonical number.
+#[inline]
+fn localized_index(bytes: &[u8], locale: &Locale, options: &ParseFloatOptions, index: usize) -> usize {
+    let mut src = 0;
+    let mut dst = 0;
+    for (count, canonical) in Tokens::new(bytes, locale, options) {
+        if dst + canonical.len() > index {
+            return src + cmp::min(index - dst, count);
+        }
+        src += count;
+        dst += canonical.len();
+    }
+    src
+}
+
+// Parse a localized number, returning the value and the number of processed bytes.
+//
+// The canonical number is written with the default options, and is
+// parsed incrementally with the default options.
+pub(crate) fn parse_partial_localized<N>(bytes: &[u8], locale: &Locale)
+    -> Result<(N, usize)>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    if let Some(index) = misplaced_group(bytes, locale) {
+        return Err((ErrorCode::InvalidDigit, index).into());
+    }
+
+    let options = ParseFloatOptions::new();
+    let mut parser = NumberParser::<N>::new();
+    for (_, canonical) in Tokens::new(bytes, locale, &options) {
+        match parser.feed(canonical) {
+            Ok(ParseStatus::Incomplete) => continue,
+            _                           => break,
+        }
+    }
+
+    let index = | index | localized_index(bytes, locale, &options, index);
+    match parser.finish_partial() {
+        Ok((value, count)) => Ok((value, index(count))),
+        // The number is empty, since the byte after any sign is not
+        // part of any number in the locale.
+        Err(error) if error.code == ErrorCode::Empty && index(error.index) < bytes.len() => {
+            Err((ErrorCode::InvalidDigit, index(error.index)).into())
+        },
+        Err(error)         => Err((error.code, index(error.index)).into()),
+    }
+}
+
+// Parse a complete localized number.
+pub(crate) fn parse_localized<N>(bytes: &[u8], locale: &Locale)
+    -> Result<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    let (value, count) = parse_partial_localized(bytes, locale)?;
+    match count == bytes.len() {
+        true  => Ok(value),
+        false => Err((ErrorCode::InvalidDigit, count).into()),
+    }
+}
+
+// WRITE
+
+// Writer for a localized number, which counts the required bytes
+// after the buffer is full.
+struct LocaleWriter<'a> {
+    bytes: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> LocaleWriter<'a> {
+    #[inline]
+    fn write(&mut self, value: &[u8]) {
+        let end = self.len + value.len();
+        if let Some(dst) = self.bytes.get_mut(self.len..end) {
+            dst.copy_from_slice(value);
+        }
+        self.len = end;
+    }
+}
+
+// Convert a canonical number, written with the options, to the locale.
+fn localize<'a>(canonical: &[u8], is_float: bool, locale: &Locale, options: &WriteFloatOptions, bytes: &'a mut [u8])
+    -> StdResult<&'a mut [u8], WriteError>
+{
+    let mut writer = LocaleWriter { bytes, len: 0 };
+    let mut digits = canonical;
+    if let Some((&b'-', rest)) = digits.split_first() {
+        writer.write(locale.minus_sign);
+        digits = rest;
+    }
+
+    let special = match is_float {
+        true if digits == options.nan_string()      => Some(locale.nan_string),
+        true if digits == options.inf_string()      => Some(locale.inf_string),
+        _                                           => None,
+    };
+    if let Some(special) = special {
+        writer.write(special);
+        digits = &[];
+    }
+
+    // Write the grouped integer digits.
+    let integer_count = digits.iter().take_while(|c| c.is_ascii_digit()).count();
+    for (index, digit) in digits[..integer_count].iter().enumerate() {
+        writer.write(&[*digit]);
+        let remaining = integer_count - index - 1;
+        if remaining != 0 && locale.grouping.is_integer_boundary(remaining) {
+            writer.write(locale.group_separator);
+        }
+    }
+    digits = &digits[integer_count..];
+
+    // Write the fraction digits and exponent.
+    if let Some((&b'.', rest)) = digits.split_first() {
+        writer.write(locale.decimal_point);
+        digits = rest;
+    }
+    let exponent_char = options.exponent_default_char();
+    while let Some((&c, rest)) = digits.split_first() {
+        digits = rest;
+        writer.write(&[c]);
+        if is_float && c.eq_ignore_ascii_case(&exponent_char) {
+            match digits.split_first() {
+                Some((&b'-', rest)) => { writer.write(locale.minus_sign); digits = rest; },
+                Some((&b'+', rest)) => { writer.write(locale.plus_sign); digits = rest; },
+                _                   => (),
+            }
+        }
+    }
+
+    let required = writer.len;
+    match required <= writer.bytes.len() {
+        true  => Ok(&mut writer.bytes[..required]),
+        false => Err(WriteError { required }),
+    }
+}
+
+// Write a number in the locale.
+//
+// The canonical number is written with the default options.
+pub(crate) fn write_localized<'a, N>(n: N, locale: &Locale, bytes: &'a mut [u8])
+    -> StdResult<&'a mut [u8], WriteError>
+    where N: ToLexicalOptions,
+          N::Options: Default
+{
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let canonical = n.to_lexical_with_options(&mut buffer, &N::Options::default());
+    localize(canonical, N::IS_FLOAT, locale, &WriteFloatOptions::new(), bytes)
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write<N>(n: N, locale: &Locale, expected: &[u8])
+        where N: ToLexicalOptions,
+              N::Options: Default
+    {
+        let mut buffer = [0u8; 64];
+        assert_eq!(write_localized(n, locale, &mut buffer).map(|x| &*x), Ok(expected));
+    }
+
+    #[test]
+    fn locale_builder_test() {
+        assert_eq!(Locale::builder().build(), Some(Locale::new()));
+        assert!(Locale::builder().decimal_point(b",").group_separator(b".").grouping(DigitGrouping::Uniform(3)).build().is_some());
+        assert!(Locale::builder().decimal_point(b"").build().is_none());
+        assert!(Locale::builder().decimal_point(b"e").build().is_none());
+        assert!(Locale::builder().group_separator(b".").build().is_none());
+        assert!(Locale::builder().grouping(DigitGrouping::Uniform(3)).build().is_none());
+        assert!(Locale::builder().minus_sign(b"+").build().is_none());
+        assert!(Locale::builder().nan_string(b"").build().is_none());
+        assert!(Locale::builder().minus_sign("\u{2212}".as_bytes()).build().is_some());
+    }
+
+    #[test]
+    fn parse_locale_integer_test() {
+        assert_eq!(parse_localized::<i32>(b"-1.234.567", &Locale::DE_DE), Ok(-1234567));
+        assert_eq!(parse_localized::<i32>(b"1,234,567", &Locale::EN_US), Ok(1234567));
+        assert_eq!(parse_localized::<i32>(b"12,34,567", &Locale::EN_IN), Ok(1234567));
+        assert_eq!(parse_localized::<i32>("\u{2212}1\u{A0}234".as_bytes(), &Locale::SV_SE), Ok(-1234));
+        assert_eq!(parse_localized::<i32>(b"-1234", &Locale::SV_SE), Err((ErrorCode::InvalidDigit, 0).into()));
+        assert_eq!(parse_localized::<i32>("\u{2212}".as_bytes(), &Locale::SV_SE), Err((ErrorCode::Empty, 3).into()));
+        assert_eq!(parse_localized::<i32>(b"", &Locale::SV_SE), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse_localized::<i32>(b"1,234,", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 5).into()));
+        assert_eq!(parse_localized::<u8>(b"1.000", &Locale::DE_DE), Err((ErrorCode::Overflow, 4).into()));
+        assert_eq!(parse_partial_localized::<i32>(b"1,234 apples", &Locale::EN_US), Ok((1234, 5)));
+    }
+
+    #[test]
+    fn parse_locale_grouping_test() {
+        assert_eq!(parse_localized::<i32>(b"1234567", &Locale::EN_US), Ok(1234567));
+        assert_eq!(parse_localized::<i32>(b"-1,234", &Locale::EN_US), Ok(-1234));
+        assert_eq!(parse_localized::<i32>(b"1,5", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_localized::<i32>(b"12,34", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_localized::<i32>(b"1,234567", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 5).into()));
+        assert_eq!(parse_localized::<i32>(b"1234,567", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_localized::<i32>(b"1,234,567", &Locale::EN_IN), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_partial_localized::<i32>(b"1,5", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 1).into()));
+
+        let locale = Locale::builder().group_separator(b"'").build().unwrap();
+        assert_eq!(parse_localized::<i32>(b"1'234", &locale), Err((ErrorCode::InvalidDigit, 1).into()));
+    }
+
+    #[test]
+    fn parse_locale_float_test() {
+        assert_eq!(parse_localized::<f64>(b"-1.234.567,89", &Locale::DE_DE), Ok(-1234567.89));
+        assert_eq!(parse_localized::<f64>(b"1,5e-3", &Locale::DE_DE), Ok(0.0015));
+        assert_eq!(parse_localized::<f64>(b",5", &Locale::FR_FR), Ok(0.5));
+        assert_eq!(parse_localized::<f64>("1\u{202F}234,5".as_bytes(), &Locale::FR_FR), Ok(1234.5));
+        assert_eq!(parse_localized::<f64>("1,5e\u{2212}3".as_bytes(), &Locale::SV_SE), Ok(0.0015));
+        assert_eq!(parse_localized::<f64>(b"1.5", &Locale::DE_DE), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_localized::<f64>(b"1,5", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_localized::<f64>(b"1.000,5", &Locale::DE_DE), Ok(1000.5));
+        assert_eq!(parse_localized::<f64>(b"1.5.", &Locale::EN_US), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse_partial_localized::<f64>(b"1,5.", &Locale::DE_DE), Ok((1.5, 3)));
+        assert!(parse_localized::<f64>(b"-INF", &Locale::DE_DE).unwrap().is_infinite());
+        assert!(parse_localized::<f64>(b"nan", &Locale::DE_DE).unwrap().is_nan());
+
+        let locale = Locale::builder()
+            .decimal_point(b",")
+            .nan_string("\u{975E}\u{6570}".as_bytes())
+            .inf_string("\u{221E}".as_bytes())
+            .infinity_string("\u{221E}".as_bytes())
+            .build()
+            .unwrap();
+        assert!(parse_localized::<f64>("\u{975E}\u{6570}".as_bytes(), &locale).unwrap().is_nan());
+        assert_eq!(parse_localized::<f64>("-\u{221E}".as_bytes(), &locale), Ok(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_locale_long_test() {
+        let mut bytes = b"0,".to_vec();
+        bytes.extend(crate::lib::iter::repeat(b'0').take(2000));
+        bytes.push(b'1');
+        let value = crate::lib::str::from_utf8(&bytes).unwrap();
+        let expected = value.replace(",", ".").parse::<f64>().unwrap();
+        assert_eq!(parse_localized::<f64>(value.as_bytes(), &Locale::DE_DE), Ok(expected));
+
+        bytes.extend(b" apples");
+        assert_eq!(parse_partial_localized::<f64>(&bytes, &Locale::DE_DE), Ok((expected, 2003)));
+    }
+
+    #[test]
+    fn write_locale_test() {
+        write(-1234567i32, &Locale::DE_DE, b"-1.234.567");
+        write(1234567u64, &Locale::EN_IN, b"12,34,567");
+        write(-1234i32, &Locale::SV_SE, "\u{2212}1\u{A0}234".as_bytes());
+        write(123i32, &Locale::EN_US, b"123");
+        write(0i32, &Locale::EN_US, b"0");
+        write(-1234567.89f64, &Locale::DE_DE, b"-1.234.567,89");
+        write(1234.5f64, &Locale::FR_FR, "1\u{202F}234,5".as_bytes());
+        write(1.5e-300f64, &Locale::SV_SE, "1,5e\u{2212}300".as_bytes());
+        write(1234567.89f64, &Locale::POSIX, b"1234567.89");
+
+        let locale = Locale::builder()
+            .nan_string("\u{975E}\u{6570}".as_bytes())
+            .inf_string("\u{221E}".as_bytes())
+            .build()
+            .unwrap();
+        write(f64::NAN, &locale, "\u{975E}\u{6570}".as_bytes());
+        write(f64::NEG_INFINITY, &locale, "-\u{221E}".as_bytes());
+
+        let mut buffer = [0u8; 8];
+        assert_eq!(write_localized(-1234567i32, &Locale::DE_DE, &mut buffer), Err(WriteError { required: 10 }));
+    }
+}
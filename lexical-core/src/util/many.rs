@@ -0,0 +1,235 @@
+//! Parse many delimited numbers from a single buffer.
+
+use crate::lib::fmt;
+use crate::lib::marker::PhantomData;
+use crate::lib::result::Result as StdResult;
+use super::error::{Error, ErrorCode};
+use super::options::ParseManyOptions;
+use super::traits::FromLexical;
+
+// ERROR
+
+/// Error from parsing a field of delimited numbers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FieldError {
+    /// Index of the field that failed to parse, starting from 0.
+    pub field: usize,
+    /// Error for the field, with the position within the whole buffer.
+    pub error: Error,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} in field {}", self.error, self.field)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+// ITERATOR
+
+/// Iterator over the numbers in a buffer of delimited fields.
+///
+/// Fields within a record are separated by the delimiter, and records
+/// are separated by line terminators (`\n` or `\r\n`). A single line
+/// terminator may follow the last field. Every field must contain a
+/// single number, optionally surrounded by spaces and tabs if
+/// whitespace is skipped, and empty fields are an error.
+///
+/// The iterator stops after the first error.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ParseManyIter, ParseManyOptions};
+/// # pub fn main() {
+/// let options = ParseManyOptions::new();
+/// let mut iter = ParseManyIter::<u32>::new(b"1, 2\r\n3,4\n", &options);
+/// assert_eq!(iter.next(), Some(Ok(1)));
+/// assert_eq!(iter.next(), Some(Ok(2)));
+/// assert_eq!(iter.next(), Some(Ok(3)));
+/// assert_eq!(iter.next(), Some(Ok(4)));
+/// assert_eq!(iter.next(), None);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParseManyIter<'a, N: FromLexical> {
+    /// Buffer of delimited fields.
+    bytes: &'a [u8],
+    /// Position of the next field within the buffer.
+    index: usize,
+    /// Index of the next field.
+    field: usize,
+    /// Options for the delimited fields.
+    options: ParseManyOptions,
+    /// If every field has been processed, or an error occurred.
+    is_done: bool,
+    /// Type of the parsed numbers.
+    marker: PhantomData<N>,
+}
+
+impl<'a, N: FromLexical> ParseManyIter<'a, N> {
+    /// Create new iterator over the numbers in a buffer.
+    #[inline]
+    pub fn new(bytes: &'a [u8], options: &ParseManyOptions) -> Self {
+        ParseManyIter {
+            bytes,
+            index: 0,
+            field: 0,
+            options: *options,
+            is_done: bytes.is_empty(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Get the number of bytes processed.
+    #[inline]
+    pub fn processed(&self) -> usize {
+        self.index
+    }
+
+    // Skip spaces and tabs, if enabled.
+    #[inline]
+    fn skip_whitespace(&mut self) {
+        if self.options.skip_whitespace() {
+            let delimiter = self.options.delimiter();
+            let rest = &index!(self.bytes[self.index..]);
+            self.index += rest.iter()
+                .take_while(|&&c| (c == b' ' || c == b'\t') && c != delimiter)
+                .count();
+        }
+    }
+
+    // Create an error for the current field.
+    #[inline]
+    fn error(&mut self, code: ErrorCode, index: usize) -> FieldError {
+        self.is_done = true;
+        FieldError { field: self.field, error: (code, index).into() }
+    }
+
+    // Parse the next field, and consume the following delimiter.
+    fn parse_field(&mut self) -> StdResult<N, FieldError> {
+        self.skip_whitespace();
+        let start = self.index;
+        let value = match N::from_lexical_partial(&index!(self.bytes[start..])) {
+            Ok((_, 0))          => {
+                let code = match self.is_terminator(start) {
+                    true  => ErrorCode::Empty,
+                    false => ErrorCode::InvalidDigit,
+                };
+                return Err(self.error(code, start));
+            },
+            Ok((value, count))  => {
+                self.index += count;
+                value
+            },
+            Err(error)          => return Err(self.error(error.code, start + error.index)),
+        };
+
+        self.skip_whitespace();
+        let delimiter = self.options.delimiter();
+        match self.bytes.get(self.index) {
+            None                        => self.is_done = true,
+            Some(&c) if c == delimiter  => self.index += 1,
+            Some(&b'\n')                => self.index += 1,
+            Some(&b'\r') if self.bytes.get(self.index + 1) == Some(&b'\n') => self.index += 2,
+            Some(_)                     => return Err(self.error(ErrorCode::InvalidDigit, self.index)),
+        }
+        if self.index == self.bytes.len() && self.bytes[self.index - 1] == b'\n' {
+            self.is_done = true;
+        }
+        self.field += 1;
+        Ok(value)
+    }
+
+    // Determine if the byte at the index ends the field.
+    #[inline]
+    fn is_terminator(&self, index: usize) -> bool {
+        match self.bytes.get(index) {
+            None                => true,
+            Some(&b'\n')        => true,
+            Some(&b'\r')        => true,
+            Some(&c)            => c == self.options.delimiter(),
+        }
+    }
+}
+
+impl<'a, N: FromLexical> Iterator for ParseManyIter<'a, N> {
+    type Item = StdResult<N, FieldError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.is_done {
+            true  => None,
+            false => Some(self.parse_field()),
+        }
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    fn collect<N: FromLexical>(bytes: &[u8], options: &ParseManyOptions) -> StdResult<Vec<N>, FieldError> {
+        ParseManyIter::new(bytes, options).collect()
+    }
+
+    fn error(field: usize, code: ErrorCode, index: usize) -> FieldError {
+        FieldError { field, error: (code, index).into() }
+    }
+
+    #[test]
+    fn integer_test() {
+        let options = ParseManyOptions::new();
+        assert_eq!(collect::<u64>(b"", &options), Ok(vec![]));
+        assert_eq!(collect::<u64>(b"1", &options), Ok(vec![1]));
+        assert_eq!(collect::<u64>(b"1,2,3", &options), Ok(vec![1, 2, 3]));
+        assert_eq!(collect::<i32>(b" -1 ,\t2\n3, 4\r\n", &options), Ok(vec![-1, 2, 3, 4]));
+        assert_eq!(collect::<u64>(b"12345678901234567,1", &options), Ok(vec![12345678901234567, 1]));
+        assert_eq!(collect::<u64>(b"1,,3", &options), Err(error(1, ErrorCode::Empty, 2)));
+        assert_eq!(collect::<u64>(b"1,2,", &options), Err(error(2, ErrorCode::Empty, 4)));
+        assert_eq!(collect::<u64>(b"1\n\n2", &options), Err(error(1, ErrorCode::Empty, 2)));
+        assert_eq!(collect::<u64>(b"1,2x,3", &options), Err(error(1, ErrorCode::InvalidDigit, 3)));
+        assert_eq!(collect::<u64>(b"1,x", &options), Err(error(1, ErrorCode::InvalidDigit, 2)));
+        assert_eq!(collect::<u8>(b"1, 256", &options), Err(error(1, ErrorCode::Overflow, 5)));
+        assert_eq!(collect::<u64>(b"1 2", &options), Err(error(0, ErrorCode::InvalidDigit, 2)));
+
+        let options = ParseManyOptions::builder().delimiter(b'\t').skip_whitespace(false).build().unwrap();
+        assert_eq!(collect::<u64>(b"1\t2\n3\t4", &options), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(collect::<u64>(b"1\t 2", &options), Err(error(1, ErrorCode::InvalidDigit, 2)));
+    }
+
+    #[test]
+    fn float_test() {
+        let options = ParseManyOptions::builder().delimiter(b';').build().unwrap();
+        assert_eq!(collect::<f64>(b"1.5; -2e3;inf\n0.25", &options), Ok(vec![1.5, -2e3, f64::INFINITY, 0.25]));
+        assert_eq!(collect::<f64>(b"1.5;1e", &options), Err(error(1, ErrorCode::EmptyExponent, 6)));
+        assert_eq!(collect::<f32>(b"1.5,2", &options), Err(error(0, ErrorCode::InvalidDigit, 3)));
+    }
+
+    #[test]
+    fn iterator_test() {
+        let options = ParseManyOptions::new();
+        let mut iter = ParseManyIter::<u32>::new(b"1,x,3", &options);
+        assert_eq!(iter.next(), Some(Ok(1)));
+        assert_eq!(iter.processed(), 2);
+        assert_eq!(iter.next(), Some(Err(error(1, ErrorCode::InvalidDigit, 2))));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn display_test() {
+        let error = error(2, ErrorCode::InvalidDigit, 7);
+        assert_eq!(error.to_string(), "invalid digit found at index 7 in field 2");
+    }
+}
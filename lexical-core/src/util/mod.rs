@@ -21,63 +21,123 @@ pub(crate) mod test;
 
 // Hide implementation details.
 mod algorithm;
+mod big_integer;
 mod cast;
+mod components;
 mod config;
 mod consume;
+mod context;
 mod div128;
 mod error;
 mod format;
+mod grouping;
 mod iterator;
+mod locale;
+mod many;
 mod mask;
+mod notation;
 mod num;
+mod options;
+mod overflow;
 mod primitive;
 mod pow;
 mod result;
 mod rounding;
 mod sign;
 mod table;
+mod unicode;
+
+#[cfg(feature = "radix")]
+mod alphabet;
+
+#[cfg(feature = "f16")]
+mod half;
+
+#[cfg(feature = "f128")]
+mod extended;
 
+#[cfg(feature = "format")]
+mod literal;
 #[cfg(feature = "format")]
 mod skip_value;
+#[cfg(feature = "format")]
+mod trim;
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-    #[macro_use]
-    mod sequence;
-} else {
-    mod wrapped;
-}}  // cfg_if
+#[cfg(feature = "std")]
+mod decimal;
+
+mod stream;
+
+#[macro_use]
+mod sequence;
+
+#[cfg(not(feature = "correct"))]
+mod wrapped;
 
 // Publicly export everything with crate-visibility.
 pub(crate) use self::algorithm::*;
+pub(crate) use self::big_integer::big_integer_step;
 pub(crate) use self::cast::*;
 pub(crate) use self::consume::*;
 pub(crate) use self::div128::*;
 pub(crate) use self::iterator::*;
+pub(crate) use self::locale::{parse_localized, parse_partial_localized, write_localized};
 pub(crate) use self::mask::*;
 pub(crate) use self::primitive::*;
 pub(crate) use self::pow::*;
 pub(crate) use self::rounding::*;
 pub(crate) use self::sign::*;
 pub(crate) use self::table::*;
+pub(crate) use self::unicode::{parse_partial_transcoded, parse_transcoded};
+
+#[cfg(feature = "f16")]
+pub(crate) use self::half::Half;
 
+#[cfg(feature = "f128")]
+pub(crate) use self::extended::round_extended;
+
+#[cfg(feature = "format")]
+pub(crate) use self::literal::*;
 #[cfg(feature = "format")]
 pub(crate) use self::skip_value::*;
+#[cfg(feature = "format")]
+pub(crate) use self::trim::*;
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-    pub(crate) use self::sequence::*;
-} else {
-    pub(crate) use self::wrapped::*;
-}}  // cfg_if
+pub(crate) use self::sequence::*;
+
+#[cfg(not(feature = "correct"))]
+pub(crate) use self::wrapped::*;
 
 // Publicly export config globally.
+pub use self::big_integer::BigInteger;
+pub use self::components::*;
 pub use self::config::*;
+pub use self::context::*;
 pub use self::error::*;
 pub use self::format::*;
+pub use self::grouping::*;
+pub use self::locale::{Locale, LocaleBuilder};
+pub use self::many::*;
+pub use self::notation::*;
 pub use self::num::*;
+pub use self::options::*;
+pub use self::overflow::*;
 pub use self::result::*;
 pub use self::traits::*;
+pub use self::unicode::{CodeUnit, DigitScript};
 
-#[cfg(feature = "rounding")]
 pub use self::rounding::RoundingKind;
+
+#[cfg(feature = "radix")]
+pub use self::alphabet::DigitAlphabet;
+
+#[cfg(feature = "f16")]
+pub use self::half::{bf16, f16};
+
+#[cfg(feature = "std")]
+pub use self::decimal::Decimal;
+
+pub use self::stream::{NumberParser, ParseStatus, StreamOptions};
+
+#[cfg(feature = "f128")]
+pub use self::extended::{f128, f80, ExtendedPrecision, FloatParts, EXTENDED_FORMATTED_SIZE_DECIMAL};
@@ -0,0 +1,29 @@
+//! Float notation identifiers.
+
+/// Notation for float serialization.
+///
+/// Defines how a float is formatted, and how the precision in
+/// [`WriteFloatOptions`] is interpreted.
+///
+/// [`WriteFloatOptions`]: struct.WriteFloatOptions.html
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FloatNotation {
+    /// Shortest representation that round-trips, choosing between
+    /// positional and scientific notation by the magnitude.
+    ///
+    /// The precision is the maximum number of significant digits.
+    Shortest,
+    /// Scientific notation, similar to `%e` or `{:e}`.
+    ///
+    /// The precision is the exact number of digits after the decimal point.
+    Scientific,
+    /// Positional notation, similar to `%f` or `{}`.
+    ///
+    /// The precision is the exact number of digits after the decimal point.
+    Fixed,
+    /// Positional or scientific notation depending on the exponent,
+    /// with trailing zeros removed, similar to `%g`.
+    ///
+    /// The precision is the maximum number of significant digits.
+    General,
+}
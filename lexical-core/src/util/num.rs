@@ -36,33 +36,36 @@ pub trait Number:
     const FORMATTED_SIZE_DECIMAL: usize;
     /// If the type can hold a signed (negative) value.
     const IS_SIGNED: bool;
+    /// If the type is a floating-point type.
+    const IS_FLOAT: bool;
 }
 
 macro_rules! number_impl {
-    ($($t:tt $radix_size:ident $decimal_size:ident $is_signed:expr ; )*) => ($(
+    ($($t:tt $radix_size:ident $decimal_size:ident $is_signed:tt $is_float:tt ; )*) => ($(
         impl Number for $t {
             const FORMATTED_SIZE: usize = $radix_size;
             const FORMATTED_SIZE_DECIMAL: usize = $decimal_size;
             const IS_SIGNED: bool = $is_signed;
+            const IS_FLOAT: bool = $is_float;
         }
     )*)
 }
 
 number_impl! {
-    u8 U8_FORMATTED_SIZE U8_FORMATTED_SIZE_DECIMAL false ;
-    u16 U16_FORMATTED_SIZE U16_FORMATTED_SIZE_DECIMAL false ;
-    u32 U32_FORMATTED_SIZE U32_FORMATTED_SIZE_DECIMAL false ;
-    u64 U64_FORMATTED_SIZE U64_FORMATTED_SIZE_DECIMAL false ;
-    u128 U128_FORMATTED_SIZE U128_FORMATTED_SIZE_DECIMAL false ;
-    usize USIZE_FORMATTED_SIZE USIZE_FORMATTED_SIZE_DECIMAL false ;
-    i8 I8_FORMATTED_SIZE I8_FORMATTED_SIZE_DECIMAL true ;
-    i16 I16_FORMATTED_SIZE I16_FORMATTED_SIZE_DECIMAL true ;
-    i32 I32_FORMATTED_SIZE I32_FORMATTED_SIZE_DECIMAL true ;
-    i64 I64_FORMATTED_SIZE I64_FORMATTED_SIZE_DECIMAL true ;
-    i128 I128_FORMATTED_SIZE I128_FORMATTED_SIZE_DECIMAL true ;
-    isize ISIZE_FORMATTED_SIZE ISIZE_FORMATTED_SIZE_DECIMAL true ;
-    f32 F32_FORMATTED_SIZE F32_FORMATTED_SIZE_DECIMAL true ;
-    f64 F64_FORMATTED_SIZE F64_FORMATTED_SIZE_DECIMAL true ;
+    u8 U8_FORMATTED_SIZE U8_FORMATTED_SIZE_DECIMAL false false ;
+    u16 U16_FORMATTED_SIZE U16_FORMATTED_SIZE_DECIMAL false false ;
+    u32 U32_FORMATTED_SIZE U32_FORMATTED_SIZE_DECIMAL false false ;
+    u64 U64_FORMATTED_SIZE U64_FORMATTED_SIZE_DECIMAL false false ;
+    u128 U128_FORMATTED_SIZE U128_FORMATTED_SIZE_DECIMAL false false ;
+    usize USIZE_FORMATTED_SIZE USIZE_FORMATTED_SIZE_DECIMAL false false ;
+    i8 I8_FORMATTED_SIZE I8_FORMATTED_SIZE_DECIMAL true false ;
+    i16 I16_FORMATTED_SIZE I16_FORMATTED_SIZE_DECIMAL true false ;
+    i32 I32_FORMATTED_SIZE I32_FORMATTED_SIZE_DECIMAL true false ;
+    i64 I64_FORMATTED_SIZE I64_FORMATTED_SIZE_DECIMAL true false ;
+    i128 I128_FORMATTED_SIZE I128_FORMATTED_SIZE_DECIMAL true false ;
+    isize ISIZE_FORMATTED_SIZE ISIZE_FORMATTED_SIZE_DECIMAL true false ;
+    f32 F32_FORMATTED_SIZE F32_FORMATTED_SIZE_DECIMAL true true ;
+    f64 F64_FORMATTED_SIZE F64_FORMATTED_SIZE_DECIMAL true true ;
 }
 
 // INTEGER
@@ -0,0 +1,1864 @@
+//! Per-call configuration options for parsers and writers.
+//!
+//! Unlike the global settings in `config`, options are plain values passed
+//! to each call, so independent callers (or threads) may use different
+//! settings without any synchronization.
+
+use super::config::*;
+use super::format::NumberFormat;
+use super::grouping::{is_valid_group_separator, DigitGrouping};
+use super::notation::FloatNotation;
+use super::overflow::OverflowMode;
+use super::rounding::RoundingKind;
+
+#[cfg(feature = "radix")]
+use super::alphabet::DigitAlphabet;
+
+#[cfg(feature = "format")]
+use super::literal::float_base_prefix;
+
+#[cfg(feature = "format")]
+use super::trim::ASCII_WHITESPACE;
+
+// HELPERS
+
+// Check if the radix is valid for the current feature set.
+#[inline]
+fn is_valid_radix(radix: u8) -> bool {
+    if cfg!(feature = "radix") {
+        radix >= 2 && radix <= 36
+    } else {
+        radix == 10
+    }
+}
+
+// Check the radix is the radix of the alphabet, if there is one.
+//
+// Alphabets may have up to 64 digits, while the default digits
+// are limited to radix 36.
+#[inline]
+#[cfg(feature = "radix")]
+fn is_valid_alphabet(radix: u8, alphabet: Option<&DigitAlphabet>) -> bool {
+    match alphabet {
+        Some(alphabet) => alphabet.radix() == radix,
+        None           => is_valid_radix(radix),
+    }
+}
+
+// Check the special strings are valid.
+//
+// The strings must be short enough to fit within `F32_FORMATTED_SIZE`
+// with a sign byte, the `inf` string must not be longer than the
+// `infinity` string, and they must start with an `n` or `i`, respectively.
+#[inline]
+fn is_valid_special(nan: &[u8], inf: &[u8], infinity: &[u8]) -> bool {
+    let max = F32_FORMATTED_SIZE - 1;
+    starts_with_n(nan) && nan.len() <= max
+        && starts_with_i(inf) && inf.len() <= max
+        && starts_with_i(infinity) && infinity.len() <= max
+        && inf.len() <= infinity.len()
+}
+
+// Check the exponent characters are valid.
+#[inline]
+fn is_valid_exponent(default: u8, backup: u8) -> bool {
+    !is_control_character(default, true) && !is_control_character(backup, false)
+}
+
+// Check positional notation can be written with the radix and notation.
+#[inline]
+fn is_valid_positional(radix: u8, notation: FloatNotation) -> bool {
+    radix == 10 && notation != FloatNotation::Scientific
+}
+
+// Check the delimiter cannot be part of a number or a line terminator.
+#[inline]
+fn is_valid_delimiter(delimiter: u8) -> bool {
+    match delimiter {
+        b'0' ..= b'9'   => false,
+        b'a' ..= b'z'   => false,
+        b'A' ..= b'Z'   => false,
+        b'.' | b'+' | b'-' | b'\r' | b'\n' => false,
+        _               => delimiter.is_ascii(),
+    }
+}
+
+// Check the whitespace characters cannot be part of a number.
+//
+// Whitespace must not be an ASCII letter or digit, a sign, a decimal
+// point, a double quote, or the digit separator if the format allows
+// whitespace.
+#[inline]
+#[cfg(feature = "format")]
+fn is_valid_whitespace(whitespace: &[u8], format: NumberFormat) -> bool {
+    let has_separator = format.intersects(NumberFormat::DIGIT_SEPARATOR_FLAG_MASK);
+    let separator = match format.whitespace() && has_separator {
+        true  => Some(format.digit_separator()),
+        false => None,
+    };
+    whitespace.iter().all(|&c| match c {
+        b'0' ..= b'9'   => false,
+        b'a' ..= b'z'   => false,
+        b'A' ..= b'Z'   => false,
+        b'.' | b'+' | b'-' | b'"' => false,
+        _               => Some(c) != separator,
+    })
+}
+
+// Check the exponent base and radix can be used with the radix.
+//
+// The exponent base and radix may only differ from the radix if the
+// radix and the exponent base are powers of two, so the exponent
+// can be converted exactly to a binary exponent.
+#[inline]
+fn is_valid_exponent_base(radix: u8, exponent_base: u8, exponent_radix: u8) -> bool {
+    if exponent_base == radix && exponent_radix == radix {
+        true
+    } else {
+        radix.is_power_of_two()
+            && exponent_base.is_power_of_two()
+            && is_valid_radix(exponent_base)
+            && is_valid_radix(exponent_radix)
+    }
+}
+
+// Check the exponent character is not a digit in the radix.
+#[inline]
+fn is_valid_exponent_char(radix: u8, ch: u8) -> bool {
+    !(ch as char).is_digit(radix as u32)
+}
+
+// Get the exponent base or radix, where 0 is the radix.
+#[inline]
+fn or_radix(radix: u8, value: u8) -> u8 {
+    match value {
+        0 => radix,
+        _ => value,
+    }
+}
+
+// Get the exponent character for a given radix.
+//
+// The default character is used if the exponent base differs from
+// the radix, since the exponent cannot be confused with the digits,
+// such as with `p` in hexadecimal floats.
+#[inline]
+fn exponent_char(radix: u8, exponent_base: u8, default: u8, backup: u8) -> u8 {
+    match radix >= 15 && exponent_base == radix {
+        true  => backup,
+        false => default,
+    }
+}
+
+// PARSE INTEGER
+
+/// Options to customize parsing integers.
+///
+/// Create the default options with [`ParseIntegerOptions::new`], or
+/// customize them with [`ParseIntegerOptions::builder`].
+///
+/// [`ParseIntegerOptions::new`]: #method.new
+/// [`ParseIntegerOptions::builder`]: #method.builder
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseIntegerOptions {
+    /// Radix for the number parsing.
+    radix: u8,
+    /// Numerical format for the number.
+    format: NumberFormat,
+    /// Overflow handling for the number.
+    overflow_mode: OverflowMode,
+    /// Characters for the digits, or `None` for the default digits.
+    #[cfg(feature = "radix")]
+    alphabet: Option<DigitAlphabet>,
+    /// Characters skipped as whitespace around the number.
+    #[cfg(feature = "format")]
+    whitespace: &'static [u8],
+}
+
+impl ParseIntegerOptions {
+    /// Create options with the default values.
+    #[inline]
+    pub fn new() -> ParseIntegerOptions {
+        ParseIntegerOptions {
+            radix: 10,
+            format: NumberFormat::standard().unwrap(),
+            overflow_mode: OverflowMode::Checked,
+            #[cfg(feature = "radix")]
+            alphabet: None,
+            #[cfg(feature = "format")]
+            whitespace: ASCII_WHITESPACE,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> ParseIntegerOptionsBuilder {
+        ParseIntegerOptionsBuilder { options: Self::new() }
+    }
+
+    /// Get the radix for the number parsing.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(&self) -> u8 {
+        self.radix
+    }
+
+    /// Get the numerical format for the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the overflow handling for the number.
+    #[inline]
+    pub fn overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Get the characters for the digits, or `None` for the default digits.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn alphabet(&self) -> Option<&DigitAlphabet> {
+        self.alphabet.as_ref()
+    }
+
+    /// Get the characters skipped as whitespace around the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn whitespace(&self) -> &'static [u8] {
+        self.whitespace
+    }
+
+    /// Get the radix as a 32-bit integer for internal use.
+    #[inline]
+    pub(crate) fn radix_u32(&self) -> u32 {
+        self.radix as u32
+    }
+
+    /// Get if the radix and alphabet are valid.
+    ///
+    /// Digits in an alphabet do not support digit separators,
+    /// base prefixes, type suffixes, or rejecting leading zeros,
+    /// and cannot be whitespace or quotes.
+    #[inline]
+    fn is_valid_digits(&self) -> bool {
+        #[cfg(all(feature = "radix", feature = "format"))]
+        let is_valid_format = match self.alphabet.as_ref() {
+            None           => true,
+            Some(alphabet) => !self.format.intersects(
+                NumberFormat::INTEGER_DIGIT_SEPARATOR_FLAG_MASK
+                | NumberFormat::BASE_PREFIX_FLAG_MASK
+                | NumberFormat::TYPE_SUFFIX_FLAG_MASK
+                | NumberFormat::NO_INTEGER_LEADING_ZEROS
+            ) && (!self.format.whitespace() || self.whitespace.iter().all(|&c| alphabet.to_digit(c).is_none()))
+                && (!self.format.surrounding_quotes() || alphabet.to_digit(b'"').is_none()),
+        };
+
+        #[cfg(all(feature = "radix", not(feature = "format")))]
+        let is_valid_format = true;
+
+        #[cfg(feature = "radix")]
+        return is_valid_alphabet(self.radix, self.alphabet.as_ref()) && is_valid_format;
+
+        #[cfg(not(feature = "radix"))]
+        return is_valid_radix(self.radix);
+    }
+
+    /// Get the numerical format for internal use.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub(crate) fn number_format(&self) -> NumberFormat {
+        self.format
+    }
+}
+
+impl Default for ParseIntegerOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for [`ParseIntegerOptions`](struct.ParseIntegerOptions.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseIntegerOptionsBuilder {
+    options: ParseIntegerOptions,
+}
+
+impl ParseIntegerOptionsBuilder {
+    /// Set the radix for the number parsing.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(mut self, radix: u8) -> Self {
+        self.options.radix = radix;
+        self
+    }
+
+    /// Set the numerical format for the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.options.format = format;
+        self
+    }
+
+    /// Set the overflow handling for the number.
+    #[inline]
+    pub fn overflow_mode(mut self, overflow_mode: OverflowMode) -> Self {
+        self.options.overflow_mode = overflow_mode;
+        self
+    }
+
+    /// Set the characters for the digits, or `None` for the default digits.
+    ///
+    /// An alphabet also sets the radix to the number of digits.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn alphabet(mut self, alphabet: Option<DigitAlphabet>) -> Self {
+        if let Some(ref alphabet) = alphabet {
+            self.options.radix = alphabet.radix();
+        }
+        self.options.alphabet = alphabet;
+        self
+    }
+
+    /// Set the characters skipped as whitespace around the number.
+    ///
+    /// Whitespace is only skipped if the format allows leading
+    /// or trailing whitespace.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn whitespace(mut self, whitespace: &'static [u8]) -> Self {
+        self.options.whitespace = whitespace;
+        self
+    }
+
+    /// Build the options, returning `None` if any option is invalid.
+    ///
+    /// The options are invalid if:
+    /// - the radix is not in the range `[2, 36]`, without an alphabet.
+    /// - the radix is not the radix of the alphabet.
+    /// - the format has digit separators, base prefixes, type suffixes,
+    ///     or rejects leading zeros, with an alphabet.
+    /// - any whitespace character is in the character set `[A-Za-z0-9.+\-"]`,
+    ///     or is the digit separator, if the format allows whitespace.
+    /// - any whitespace character or the quote is a digit in the alphabet,
+    ///     if the format allows them.
+    #[inline]
+    pub fn build(self) -> Option<ParseIntegerOptions> {
+        #[cfg(feature = "format")]
+        let is_valid_whitespace = is_valid_whitespace(self.options.whitespace, self.options.format);
+
+        #[cfg(not(feature = "format"))]
+        let is_valid_whitespace = true;
+
+        match self.options.is_valid_digits() && is_valid_whitespace {
+            true  => Some(self.options),
+            false => None,
+        }
+    }
+}
+
+// PARSE FLOAT
+
+/// Options to customize parsing floats.
+///
+/// Create the default options with [`ParseFloatOptions::new`], or
+/// customize them with [`ParseFloatOptions::builder`].
+///
+/// [`ParseFloatOptions::new`]: #method.new
+/// [`ParseFloatOptions::builder`]: #method.builder
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseFloatOptions {
+    /// Use the lossy, fast algorithms.
+    lossy: bool,
+    /// Radix for the number parsing.
+    radix: u8,
+    /// Base of the power the exponent is applied to, or 0 for the radix.
+    exponent_base: u8,
+    /// Radix for the exponent digits, or 0 for the radix.
+    exponent_radix: u8,
+    /// Numerical format for the number.
+    format: NumberFormat,
+    /// Character for the exponent symbol, used when the `radix < 15`,
+    /// or the exponent base differs from the radix.
+    exponent_default_char: u8,
+    /// Character for the exponent symbol, used when the `radix >= 15`.
+    exponent_backup_char: u8,
+    /// Rounding scheme for float conversions.
+    rounding: RoundingKind,
+    /// Not a Number literal.
+    nan_string: &'static [u8],
+    /// Short infinity literal.
+    inf_string: &'static [u8],
+    /// Long infinity literal.
+    infinity_string: &'static [u8],
+    /// Additional spellings of Not a Number.
+    nan_aliases: &'static [&'static [u8]],
+    /// Additional spellings of Infinity.
+    inf_aliases: &'static [&'static [u8]],
+    /// Parse a payload in parentheses after Not a Number.
+    nan_payload: bool,
+    /// Characters skipped as whitespace around the number.
+    #[cfg(feature = "format")]
+    whitespace: &'static [u8],
+}
+
+impl ParseFloatOptions {
+    /// Create options with the default values.
+    #[inline]
+    pub fn new() -> ParseFloatOptions {
+        ParseFloatOptions {
+            lossy: false,
+            radix: 10,
+            exponent_base: 0,
+            exponent_radix: 0,
+            format: NumberFormat::standard().unwrap(),
+            exponent_default_char: b'e',
+            exponent_backup_char: b'^',
+            rounding: RoundingKind::NearestTieEven,
+            nan_string: b"NaN",
+            inf_string: b"inf",
+            infinity_string: b"infinity",
+            nan_aliases: &[],
+            inf_aliases: &[],
+            nan_payload: false,
+            #[cfg(feature = "format")]
+            whitespace: ASCII_WHITESPACE,
+        }
+    }
+
+    /// Create options from the global configuration settings.
+    #[inline]
+    pub(crate) fn from_config(lossy: bool, radix: u32, format: NumberFormat) -> ParseFloatOptions {
+        ParseFloatOptions {
+            lossy,
+            radix: radix as u8,
+            exponent_base: 0,
+            exponent_radix: 0,
+            format,
+            exponent_default_char: get_exponent_default_char(),
+            #[cfg(feature = "radix")]
+            exponent_backup_char: get_exponent_backup_char(),
+            #[cfg(not(feature = "radix"))]
+            exponent_backup_char: b'^',
+            #[cfg(feature = "rounding")]
+            rounding: get_float_rounding(),
+            #[cfg(not(feature = "rounding"))]
+            rounding: RoundingKind::NearestTieEven,
+            nan_string: get_nan_string(),
+            inf_string: get_inf_string(),
+            infinity_string: get_infinity_string(),
+            nan_aliases: &[],
+            inf_aliases: &[],
+            nan_payload: false,
+            #[cfg(feature = "format")]
+            whitespace: ASCII_WHITESPACE,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> ParseFloatOptionsBuilder {
+        ParseFloatOptionsBuilder { options: Self::new() }
+    }
+
+    /// Get if the lossy, fast algorithms are used.
+    #[inline]
+    pub fn lossy(&self) -> bool {
+        self.lossy
+    }
+
+    /// Get the radix for the number parsing.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(&self) -> u8 {
+        self.radix
+    }
+
+    /// Get the base of the power the exponent is applied to.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_base(&self) -> u8 {
+        or_radix(self.radix, self.exponent_base)
+    }
+
+    /// Get the radix for the exponent digits.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_radix(&self) -> u8 {
+        or_radix(self.radix, self.exponent_radix)
+    }
+
+    /// Get the numerical format for the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the character for the exponent symbol when the `radix < 15`,
+    /// or the exponent base differs from the radix.
+    #[inline]
+    pub fn exponent_default_char(&self) -> u8 {
+        self.exponent_default_char
+    }
+
+    /// Get the character for the exponent symbol when the `radix >= 15`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_backup_char(&self) -> u8 {
+        self.exponent_backup_char
+    }
+
+    /// Get the rounding scheme for float conversions.
+    #[inline]
+    #[cfg(feature = "rounding")]
+    pub fn rounding(&self) -> RoundingKind {
+        self.rounding
+    }
+
+    /// Get the string representation of Not a Number.
+    #[inline]
+    pub fn nan_string(&self) -> &'static [u8] {
+        self.nan_string
+    }
+
+    /// Get the short representation of Infinity.
+    #[inline]
+    pub fn inf_string(&self) -> &'static [u8] {
+        self.inf_string
+    }
+
+    /// Get the long representation of Infinity.
+    #[inline]
+    pub fn infinity_string(&self) -> &'static [u8] {
+        self.infinity_string
+    }
+
+    /// Get the additional spellings of Not a Number.
+    #[inline]
+    pub fn nan_aliases(&self) -> &'static [&'static [u8]] {
+        self.nan_aliases
+    }
+
+    /// Get the additional spellings of Infinity.
+    #[inline]
+    pub fn inf_aliases(&self) -> &'static [&'static [u8]] {
+        self.inf_aliases
+    }
+
+    /// Get if a payload in parentheses after Not a Number is parsed.
+    #[inline]
+    pub fn nan_payload(&self) -> bool {
+        self.nan_payload
+    }
+
+    /// Get the characters skipped as whitespace around the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn whitespace(&self) -> &'static [u8] {
+        self.whitespace
+    }
+
+    /// Get the radix as a 32-bit integer for internal use.
+    #[inline]
+    pub(crate) fn radix_u32(&self) -> u32 {
+        self.radix as u32
+    }
+
+    /// Get the exponent base as a 32-bit integer for internal use.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub(crate) fn exponent_base_u32(&self) -> u32 {
+        or_radix(self.radix, self.exponent_base) as u32
+    }
+
+    /// Get the exponent radix as a 32-bit integer for internal use.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub(crate) fn exponent_radix_u32(&self) -> u32 {
+        or_radix(self.radix, self.exponent_radix) as u32
+    }
+
+    /// Get the numerical format for internal use.
+    #[inline]
+    pub(crate) fn number_format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the exponent character for the radix.
+    #[inline]
+    pub(crate) fn exponent_char(&self) -> u8 {
+        let exponent_base = or_radix(self.radix, self.exponent_base);
+        exponent_char(self.radix, exponent_base, self.exponent_default_char, self.exponent_backup_char)
+    }
+
+    /// Get the rounding scheme for internal use.
+    #[inline]
+    pub(crate) fn rounding_kind(&self) -> RoundingKind {
+        self.rounding
+    }
+}
+
+impl Default for ParseFloatOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for [`ParseFloatOptions`](struct.ParseFloatOptions.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseFloatOptionsBuilder {
+    options: ParseFloatOptions,
+}
+
+impl ParseFloatOptionsBuilder {
+    /// Set if the lossy, fast algorithms are used.
+    #[inline]
+    pub fn lossy(mut self, lossy: bool) -> Self {
+        self.options.lossy = lossy;
+        self
+    }
+
+    /// Set the radix for the number parsing.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(mut self, radix: u8) -> Self {
+        self.options.radix = radix;
+        self
+    }
+
+    /// Set the base of the power the exponent is applied to.
+    ///
+    /// By default, this is the radix. For hexadecimal floats, such as
+    /// `1.8p3` (`12.0`), use a radix of 16, an exponent base of 2, an
+    /// exponent radix of 10, and `p` as the default exponent character.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_base(mut self, exponent_base: u8) -> Self {
+        self.options.exponent_base = exponent_base;
+        self
+    }
+
+    /// Set the radix for the exponent digits.
+    ///
+    /// By default, this is the radix.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_radix(mut self, exponent_radix: u8) -> Self {
+        self.options.exponent_radix = exponent_radix;
+        self
+    }
+
+    /// Set the numerical format for the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.options.format = format;
+        self
+    }
+
+    /// Set the character for the exponent symbol when the `radix < 15`,
+    /// or the exponent base differs from the radix.
+    #[inline]
+    pub fn exponent_default_char(mut self, ch: u8) -> Self {
+        self.options.exponent_default_char = ch;
+        self
+    }
+
+    /// Set the character for the exponent symbol when the `radix >= 15`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_backup_char(mut self, ch: u8) -> Self {
+        self.options.exponent_backup_char = ch;
+        self
+    }
+
+    /// Set the rounding scheme for float conversions.
+    #[inline]
+    #[cfg(feature = "rounding")]
+    pub fn rounding(mut self, rounding: RoundingKind) -> Self {
+        self.options.rounding = rounding;
+        self
+    }
+
+    /// Set the string representation of Not a Number.
+    #[inline]
+    pub fn nan_string(mut self, bytes: &'static [u8]) -> Self {
+        self.options.nan_string = bytes;
+        self
+    }
+
+    /// Set the short representation of Infinity.
+    #[inline]
+    pub fn inf_string(mut self, bytes: &'static [u8]) -> Self {
+        self.options.inf_string = bytes;
+        self
+    }
+
+    /// Set the long representation of Infinity.
+    #[inline]
+    pub fn infinity_string(mut self, bytes: &'static [u8]) -> Self {
+        self.options.infinity_string = bytes;
+        self
+    }
+
+    /// Set the additional spellings of Not a Number.
+    ///
+    /// The aliases are accepted as well as the NaN string, and may
+    /// start with any character, such as `1.#QNAN`. They are matched
+    /// before any number, with the same case-sensitivity as the NaN
+    /// string, and without digit separators.
+    #[inline]
+    pub fn nan_aliases(mut self, aliases: &'static [&'static [u8]]) -> Self {
+        self.options.nan_aliases = aliases;
+        self
+    }
+
+    /// Set the additional spellings of Infinity.
+    ///
+    /// The aliases are accepted as well as the Infinity strings, and
+    /// may start with any character, such as `1.#INF`. They are matched
+    /// before any number, with the same case-sensitivity as the
+    /// Infinity strings, and without digit separators.
+    #[inline]
+    pub fn inf_aliases(mut self, aliases: &'static [&'static [u8]]) -> Self {
+        self.options.inf_aliases = aliases;
+        self
+    }
+
+    /// Set if a payload in parentheses after Not a Number is parsed.
+    ///
+    /// Like C's `strtod`, the payload is a sequence of ASCII letters,
+    /// digits and underscores, such as `nan(0x7ff)`. Hexadecimal,
+    /// octal and decimal integers are stored in the mantissa bits
+    /// below the quiet bit of a quiet NaN, and any other sequence is
+    /// ignored, so `nan(0x7ff)` is `0x7FF80000000007FF` for an `f64`.
+    /// If the closing parenthesis is missing, only the NaN string is
+    /// parsed.
+    ///
+    /// Signaling NaNs are spelled with an `s` before the NaN string,
+    /// such as `sNaN(0x7ff)`, which is `0x7FF00000000007FF` for an
+    /// `f64`. Without a payload, or with a payload of 0, the highest
+    /// mantissa bit below the quiet bit is set.
+    #[inline]
+    pub fn nan_payload(mut self, nan_payload: bool) -> Self {
+        self.options.nan_payload = nan_payload;
+        self
+    }
+
+    /// Set the characters skipped as whitespace around the number.
+    ///
+    /// Whitespace is only skipped if the format allows leading
+    /// or trailing whitespace.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn whitespace(mut self, whitespace: &'static [u8]) -> Self {
+        self.options.whitespace = whitespace;
+        self
+    }
+
+    /// Build the options, returning `None` if any option is invalid.
+    ///
+    /// The options are invalid if:
+    /// - the radix is not in the range `[2, 36]`.
+    /// - the exponent base or radix differ from the radix, and the radix
+    ///     or exponent base is not a power of two.
+    /// - the exponent base or radix is not in the range `[2, 36]`.
+    /// - the default exponent character is in the character set `[A-Da-d0-9.+\-]`.
+    /// - the backup exponent character is in the character set `[A-Za-z0-9.+\-]`.
+    /// - the exponent character for the radix is a digit in the radix.
+    /// - the NaN string does not start with an `'N'` or `'n'`.
+    /// - the Infinity strings do not start with an `'I'` or `'i'`.
+    /// - the short Infinity string is longer than the long Infinity string.
+    /// - any special string has `f32::FORMATTED_SIZE` or more bytes.
+    /// - any alias is empty.
+    /// - any whitespace character is in the character set `[A-Za-z0-9.+\-"]`,
+    ///     or is the digit separator, if the format allows whitespace.
+    #[inline]
+    pub fn build(self) -> Option<ParseFloatOptions> {
+        let options = self.options;
+        let is_valid_alias = |alias: &&[u8]| !alias.is_empty();
+        let exponent_base = or_radix(options.radix, options.exponent_base);
+        let exponent_radix = or_radix(options.radix, options.exponent_radix);
+        let is_valid = is_valid_radix(options.radix)
+            && is_valid_exponent_base(options.radix, exponent_base, exponent_radix)
+            && is_valid_exponent(options.exponent_default_char, options.exponent_backup_char)
+            && is_valid_exponent_char(options.radix, options.exponent_char())
+            && is_valid_special(options.nan_string, options.inf_string, options.infinity_string)
+            && options.nan_aliases.iter().all(is_valid_alias)
+            && options.inf_aliases.iter().all(is_valid_alias);
+
+        #[cfg(feature = "format")]
+        let is_valid = is_valid && is_valid_whitespace(options.whitespace, options.format);
+
+        match is_valid {
+            true  => Some(options),
+            false => None,
+        }
+    }
+}
+
+// WRITE INTEGER
+
+/// Options to customize writing integers.
+///
+/// Create the default options with [`WriteIntegerOptions::new`], or
+/// customize them with [`WriteIntegerOptions::builder`].
+///
+/// [`WriteIntegerOptions::new`]: #method.new
+/// [`WriteIntegerOptions::builder`]: #method.builder
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WriteIntegerOptions {
+    /// Radix for number encoding.
+    radix: u8,
+    /// Numerical format for the number.
+    format: NumberFormat,
+    /// Character to separate groups of digits.
+    digit_separator: u8,
+    /// Grouping of digits with the digit separator.
+    grouping: DigitGrouping,
+    /// Characters for the digits, or `None` for the default digits.
+    #[cfg(feature = "radix")]
+    alphabet: Option<DigitAlphabet>,
+}
+
+impl WriteIntegerOptions {
+    /// Create options with the default values.
+    #[inline]
+    pub fn new() -> WriteIntegerOptions {
+        WriteIntegerOptions {
+            radix: 10,
+            format: NumberFormat::standard().unwrap(),
+            digit_separator: b'_',
+            grouping: DigitGrouping::None,
+            #[cfg(feature = "radix")]
+            alphabet: None,
+        }
+    }
+
+    /// Create options from the radix and numerical format.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub(crate) fn from_config(radix: u32, format: NumberFormat) -> WriteIntegerOptions {
+        WriteIntegerOptions {
+            radix: radix as u8,
+            format,
+            ..Self::new()
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> WriteIntegerOptionsBuilder {
+        WriteIntegerOptionsBuilder { options: Self::new() }
+    }
+
+    /// Get the radix for number encoding.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(&self) -> u8 {
+        self.radix
+    }
+
+    /// Get the numerical format for the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the character to separate groups of digits.
+    #[inline]
+    pub fn digit_separator(&self) -> u8 {
+        self.digit_separator
+    }
+
+    /// Get the grouping of digits with the digit separator.
+    #[inline]
+    pub fn grouping(&self) -> DigitGrouping {
+        self.grouping
+    }
+
+    /// Get the characters for the digits, or `None` for the default digits.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn alphabet(&self) -> Option<&DigitAlphabet> {
+        self.alphabet.as_ref()
+    }
+
+    /// Get the radix as a 32-bit integer for internal use.
+    #[inline]
+    pub(crate) fn radix_u32(&self) -> u32 {
+        self.radix as u32
+    }
+
+    /// Get if the radix and alphabet are valid.
+    ///
+    /// The digit separator cannot be a digit in the alphabet,
+    /// if the digits are grouped.
+    #[inline]
+    fn is_valid_digits(&self) -> bool {
+        #[cfg(feature = "radix")]
+        return is_valid_alphabet(self.radix, self.alphabet.as_ref()) && match self.alphabet.as_ref() {
+            Some(alphabet) => (self.grouping == DigitGrouping::None || alphabet.to_digit(self.digit_separator).is_none())
+                && (!self.required_mantissa_sign() || alphabet.to_digit(b'+').is_none()),
+            None           => true,
+        };
+
+        #[cfg(not(feature = "radix"))]
+        return is_valid_radix(self.radix);
+    }
+
+    /// Get if a positive sign must be written before the number.
+    #[inline]
+    pub(crate) fn required_mantissa_sign(&self) -> bool {
+        #[cfg(feature = "format")]
+        return self.format.required_mantissa_sign();
+
+        #[cfg(not(feature = "format"))]
+        return false;
+    }
+
+    /// Get if negative numbers can be written, which requires the
+    /// minus sign to not be a digit in the alphabet.
+    #[inline]
+    pub(crate) fn is_negative_valid(&self) -> bool {
+        #[cfg(feature = "radix")]
+        return self.alphabet.as_ref().map_or(true, |alphabet| alphabet.to_digit(b'-').is_none());
+
+        #[cfg(not(feature = "radix"))]
+        return true;
+    }
+}
+
+impl Default for WriteIntegerOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for [`WriteIntegerOptions`](struct.WriteIntegerOptions.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WriteIntegerOptionsBuilder {
+    options: WriteIntegerOptions,
+}
+
+impl WriteIntegerOptionsBuilder {
+    /// Set the radix for number encoding.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(mut self, radix: u8) -> Self {
+        self.options.radix = radix;
+        self
+    }
+
+    /// Set the numerical format for the number.
+    ///
+    /// Only the mantissa sign rules affect how integers are written:
+    /// a `+` is written before positive numbers if the format requires
+    /// a mantissa sign.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.options.format = format;
+        self
+    }
+
+    /// Set the character to separate groups of digits.
+    #[inline]
+    pub fn digit_separator(mut self, ch: u8) -> Self {
+        self.options.digit_separator = ch;
+        self
+    }
+
+    /// Set the grouping of digits with the digit separator.
+    #[inline]
+    pub fn grouping(mut self, grouping: DigitGrouping) -> Self {
+        self.options.grouping = grouping;
+        self
+    }
+
+    /// Set the characters for the digits, or `None` for the default digits.
+    ///
+    /// An alphabet also sets the radix to the number of digits. If `-`
+    /// is a digit in the alphabet, such as in [`DigitAlphabet::BASE64_URL`],
+    /// negative numbers cannot be written, since the sign would be
+    /// parsed as a digit.
+    ///
+    /// [`DigitAlphabet::BASE64_URL`]: struct.DigitAlphabet.html#associatedconstant.BASE64_URL
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn alphabet(mut self, alphabet: Option<DigitAlphabet>) -> Self {
+        if let Some(ref alphabet) = alphabet {
+            self.options.radix = alphabet.radix();
+        }
+        self.options.alphabet = alphabet;
+        self
+    }
+
+    /// Build the options, returning `None` if any option is invalid.
+    ///
+    /// The options are invalid if:
+    /// - the radix is not in the range `[2, 36]`, without an alphabet.
+    /// - the radix is not the radix of the alphabet.
+    /// - the digit separator is not an ASCII punctuation character or a space,
+    ///     or is in the character set `[.+\-]`.
+    /// - the digit separator is a digit in the alphabet, and the digits
+    ///     are grouped.
+    /// - `+` is a digit in the alphabet, and the format requires a
+    ///     mantissa sign.
+    /// - the grouping has groups of 0 digits.
+    #[inline]
+    pub fn build(self) -> Option<WriteIntegerOptions> {
+        let options = self.options;
+        let is_valid = options.is_valid_digits()
+            && is_valid_group_separator(options.digit_separator)
+            && options.grouping.is_valid();
+        match is_valid {
+            true  => Some(options),
+            false => None,
+        }
+    }
+}
+
+// WRITE FLOAT
+
+/// Maximum precision for writing floats.
+///
+/// This is large enough to write every digit of any float in
+/// positional notation.
+pub const MAX_FLOAT_PRECISION: usize = 2048;
+
+/// Options to customize writing floats.
+///
+/// Create the default options with [`WriteFloatOptions::new`], or
+/// customize them with [`WriteFloatOptions::builder`].
+///
+/// [`WriteFloatOptions::new`]: #method.new
+/// [`WriteFloatOptions::builder`]: #method.builder
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WriteFloatOptions {
+    /// Radix for number encoding.
+    radix: u8,
+    /// Base of the power the exponent is applied to, or 0 for the radix.
+    exponent_base: u8,
+    /// Radix for the exponent digits, or 0 for the radix.
+    exponent_radix: u8,
+    /// Character for the exponent symbol, used when the `radix < 15`,
+    /// or the exponent base differs from the radix.
+    exponent_default_char: u8,
+    /// Character for the exponent symbol, used when the `radix >= 15`.
+    exponent_backup_char: u8,
+    /// Not a Number literal.
+    nan_string: &'static [u8],
+    /// Short infinity literal.
+    inf_string: &'static [u8],
+    /// Notation for the float.
+    notation: FloatNotation,
+    /// Number of digits, interpreted according to the notation.
+    precision: Option<usize>,
+    /// Numerical format for the number.
+    format: NumberFormat,
+    /// Character to separate groups of digits.
+    digit_separator: u8,
+    /// Grouping of digits with the digit separator.
+    grouping: DigitGrouping,
+    /// If fraction digits are grouped as well as integer digits.
+    group_fraction: bool,
+    /// Write the payload of Not a Number in parentheses.
+    nan_payload: bool,
+}
+
+impl WriteFloatOptions {
+    /// Create options with the default values.
+    #[inline]
+    pub fn new() -> WriteFloatOptions {
+        WriteFloatOptions {
+            radix: 10,
+            exponent_base: 0,
+            exponent_radix: 0,
+            exponent_default_char: b'e',
+            exponent_backup_char: b'^',
+            nan_string: b"NaN",
+            inf_string: b"inf",
+            notation: FloatNotation::Shortest,
+            precision: None,
+            format: NumberFormat::standard().unwrap(),
+            digit_separator: b'_',
+            grouping: DigitGrouping::None,
+            group_fraction: false,
+            nan_payload: false,
+        }
+    }
+
+    /// Create options from the global configuration settings.
+    #[inline]
+    pub(crate) fn from_config(radix: u32, format: NumberFormat) -> WriteFloatOptions {
+        WriteFloatOptions {
+            radix: radix as u8,
+            exponent_base: 0,
+            exponent_radix: 0,
+            exponent_default_char: get_exponent_default_char(),
+            #[cfg(feature = "radix")]
+            exponent_backup_char: get_exponent_backup_char(),
+            #[cfg(not(feature = "radix"))]
+            exponent_backup_char: b'^',
+            nan_string: get_nan_string(),
+            inf_string: get_inf_string(),
+            notation: FloatNotation::Shortest,
+            precision: None,
+            format,
+            digit_separator: b'_',
+            grouping: DigitGrouping::None,
+            group_fraction: false,
+            nan_payload: false,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> WriteFloatOptionsBuilder {
+        WriteFloatOptionsBuilder { options: Self::new() }
+    }
+
+    /// Get the radix for number encoding.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(&self) -> u8 {
+        self.radix
+    }
+
+    /// Get the base of the power the exponent is applied to.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_base(&self) -> u8 {
+        or_radix(self.radix, self.exponent_base)
+    }
+
+    /// Get the radix for the exponent digits.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_radix(&self) -> u8 {
+        or_radix(self.radix, self.exponent_radix)
+    }
+
+    /// Get the character for the exponent symbol when the `radix < 15`,
+    /// or the exponent base differs from the radix.
+    #[inline]
+    pub fn exponent_default_char(&self) -> u8 {
+        self.exponent_default_char
+    }
+
+    /// Get the character for the exponent symbol when the `radix >= 15`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_backup_char(&self) -> u8 {
+        self.exponent_backup_char
+    }
+
+    /// Get the string representation of Not a Number.
+    #[inline]
+    pub fn nan_string(&self) -> &'static [u8] {
+        self.nan_string
+    }
+
+    /// Get the short representation of Infinity.
+    #[inline]
+    pub fn inf_string(&self) -> &'static [u8] {
+        self.inf_string
+    }
+
+    /// Get the notation for the float.
+    #[inline]
+    pub fn notation(&self) -> FloatNotation {
+        self.notation
+    }
+
+    /// Get the number of digits, interpreted according to the notation.
+    #[inline]
+    pub fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Get the numerical format for the number.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get the character to separate groups of digits.
+    #[inline]
+    pub fn digit_separator(&self) -> u8 {
+        self.digit_separator
+    }
+
+    /// Get the grouping of digits with the digit separator.
+    #[inline]
+    pub fn grouping(&self) -> DigitGrouping {
+        self.grouping
+    }
+
+    /// Get if fraction digits are grouped as well as integer digits.
+    #[inline]
+    pub fn group_fraction(&self) -> bool {
+        self.group_fraction
+    }
+
+    /// Get if the payload of Not a Number is written in parentheses.
+    #[inline]
+    pub fn nan_payload(&self) -> bool {
+        self.nan_payload
+    }
+
+    /// Get the radix as a 32-bit integer for internal use.
+    #[inline]
+    pub(crate) fn radix_u32(&self) -> u32 {
+        self.radix as u32
+    }
+
+    /// Get the exponent base as a 32-bit integer for internal use.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub(crate) fn exponent_base_u32(&self) -> u32 {
+        or_radix(self.radix, self.exponent_base) as u32
+    }
+
+    /// Get the exponent radix as a 32-bit integer for internal use.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub(crate) fn exponent_radix_u32(&self) -> u32 {
+        or_radix(self.radix, self.exponent_radix) as u32
+    }
+
+    /// Get the exponent character for the radix.
+    #[inline]
+    pub(crate) fn exponent_char(&self) -> u8 {
+        let exponent_base = or_radix(self.radix, self.exponent_base);
+        exponent_char(self.radix, exponent_base, self.exponent_default_char, self.exponent_backup_char)
+    }
+
+    /// Get if the exponent base or radix differ from the radix.
+    #[inline]
+    pub(crate) fn is_pow2(&self) -> bool {
+        #[cfg(feature = "radix")]
+        return self.exponent_base_u32() != self.radix_u32() || self.exponent_radix_u32() != self.radix_u32();
+
+        #[cfg(not(feature = "radix"))]
+        return false;
+    }
+
+    /// Get if the shortest representation is written without a precision.
+    #[inline]
+    pub(crate) fn is_shortest(&self) -> bool {
+        self.notation == FloatNotation::Shortest && self.precision.is_none()
+    }
+
+    /// Get the numerical format for internal use.
+    #[inline]
+    #[cfg(feature = "format")]
+    pub(crate) fn number_format(&self) -> NumberFormat {
+        self.format
+    }
+
+    /// Get if the float must be written in positional notation.
+    #[inline]
+    pub(crate) fn no_exponent_notation(&self) -> bool {
+        #[cfg(feature = "format")]
+        return self.format.no_exponent_notation();
+
+        #[cfg(not(feature = "format"))]
+        return false;
+    }
+
+    /// Get if a positive sign must be written before the mantissa.
+    #[inline]
+    pub(crate) fn required_mantissa_sign(&self) -> bool {
+        #[cfg(feature = "format")]
+        return self.format.required_mantissa_sign();
+
+        #[cfg(not(feature = "format"))]
+        return false;
+    }
+
+    /// Get if a positive sign must be written before the exponent.
+    #[inline]
+    pub(crate) fn required_exponent_sign(&self) -> bool {
+        #[cfg(feature = "format")]
+        return self.format.required_exponent_sign();
+
+        #[cfg(not(feature = "format"))]
+        return false;
+    }
+
+    /// Get if fraction digits must be written before the exponent.
+    #[inline]
+    pub(crate) fn no_exponent_without_fraction(&self) -> bool {
+        #[cfg(feature = "format")]
+        return self.format.no_exponent_without_fraction();
+
+        #[cfg(not(feature = "format"))]
+        return false;
+    }
+
+    /// Get the base prefix written before the digits, if any.
+    #[inline]
+    pub(crate) fn base_prefix(&self) -> Option<&'static [u8]> {
+        #[cfg(feature = "format")]
+        return float_base_prefix(self.radix_u32(), self.format);
+
+        #[cfg(not(feature = "format"))]
+        return None;
+    }
+
+    /// Get if the format changes how the shortest representation is written.
+    ///
+    /// The backends write the shortest representation with the default
+    /// syntax, so these floats are written from the digits instead.
+    /// The backends always write integer and exponent digits, but the
+    /// radix backend may write a decimal point without fraction digits.
+    #[inline]
+    pub(crate) fn is_formatted(&self) -> bool {
+        #[cfg(feature = "format")]
+        return (self.format.required_fraction_digits() && self.radix != 10)
+            || self.no_exponent_notation()
+            || self.required_mantissa_sign()
+            || self.required_exponent_sign()
+            || self.no_exponent_without_fraction()
+            || self.base_prefix().is_some();
+
+        #[cfg(not(feature = "format"))]
+        return false;
+    }
+}
+
+impl Default for WriteFloatOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for [`WriteFloatOptions`](struct.WriteFloatOptions.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WriteFloatOptionsBuilder {
+    options: WriteFloatOptions,
+}
+
+impl WriteFloatOptionsBuilder {
+    /// Set the radix for number encoding.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(mut self, radix: u8) -> Self {
+        self.options.radix = radix;
+        self
+    }
+
+    /// Set the base of the power the exponent is applied to.
+    ///
+    /// By default, this is the radix. If the exponent base or radix
+    /// differ from the radix, the float is written exactly, with a
+    /// single leading digit and an exponent, such as `1.8p3` for `12.0`
+    /// with a radix of 16, an exponent base of 2, an exponent radix
+    /// of 10, and `p` as the default exponent character.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_base(mut self, exponent_base: u8) -> Self {
+        self.options.exponent_base = exponent_base;
+        self
+    }
+
+    /// Set the radix for the exponent digits.
+    ///
+    /// By default, this is the radix.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_radix(mut self, exponent_radix: u8) -> Self {
+        self.options.exponent_radix = exponent_radix;
+        self
+    }
+
+    /// Set the character for the exponent symbol when the `radix < 15`,
+    /// or the exponent base differs from the radix.
+    #[inline]
+    pub fn exponent_default_char(mut self, ch: u8) -> Self {
+        self.options.exponent_default_char = ch;
+        self
+    }
+
+    /// Set the character for the exponent symbol when the `radix >= 15`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn exponent_backup_char(mut self, ch: u8) -> Self {
+        self.options.exponent_backup_char = ch;
+        self
+    }
+
+    /// Set the string representation of Not a Number.
+    #[inline]
+    pub fn nan_string(mut self, bytes: &'static [u8]) -> Self {
+        self.options.nan_string = bytes;
+        self
+    }
+
+    /// Set the short representation of Infinity.
+    #[inline]
+    pub fn inf_string(mut self, bytes: &'static [u8]) -> Self {
+        self.options.inf_string = bytes;
+        self
+    }
+
+    /// Set the notation for the float.
+    #[inline]
+    pub fn notation(mut self, notation: FloatNotation) -> Self {
+        self.options.notation = notation;
+        self
+    }
+
+    /// Set the number of digits, interpreted according to the notation.
+    ///
+    /// If `None`, the shortest digits that round-trip are written.
+    #[inline]
+    pub fn precision(mut self, precision: Option<usize>) -> Self {
+        self.options.precision = precision;
+        self
+    }
+
+    /// Set the character to separate groups of digits.
+    #[inline]
+    pub fn digit_separator(mut self, ch: u8) -> Self {
+        self.options.digit_separator = ch;
+        self
+    }
+
+    /// Set the grouping of digits with the digit separator.
+    #[inline]
+    pub fn grouping(mut self, grouping: DigitGrouping) -> Self {
+        self.options.grouping = grouping;
+        self
+    }
+
+    /// Set if fraction digits are grouped as well as integer digits.
+    ///
+    /// Fraction digits are grouped from the decimal point, using
+    /// the size of the first integer group.
+    #[inline]
+    pub fn group_fraction(mut self, group_fraction: bool) -> Self {
+        self.options.group_fraction = group_fraction;
+        self
+    }
+
+    /// Set if the payload of Not a Number is written in parentheses.
+    ///
+    /// The payload is the mantissa bits below the quiet bit, written
+    /// in hexadecimal after the NaN string, such as `NaN(0x7ff)`, and
+    /// nothing is written for a payload of 0. Signaling NaNs are written
+    /// with an `s` before the NaN string, such as `sNaN(0x7ff)`, so
+    /// quiet and signaling NaNs round-trip when parsed with
+    /// [`ParseFloatOptionsBuilder::nan_payload`].
+    ///
+    /// [`ParseFloatOptionsBuilder::nan_payload`]: struct.ParseFloatOptionsBuilder.html#method.nan_payload
+    #[inline]
+    pub fn nan_payload(mut self, nan_payload: bool) -> Self {
+        self.options.nan_payload = nan_payload;
+        self
+    }
+
+    /// Set the numerical format for the number.
+    ///
+    /// The format controls the signs written before the mantissa and
+    /// the exponent, if exponent notation may be used, and if a fraction
+    /// is written before the exponent, so the output is valid under the
+    /// same format. Special values are written even if the format does
+    /// not allow them: use [`write_format`] to reject them.
+    ///
+    /// [`write_format`]: fn.write_format.html
+    #[inline]
+    #[cfg(feature = "format")]
+    pub fn format(mut self, format: NumberFormat) -> Self {
+        self.options.format = format;
+        self
+    }
+
+    /// Build the options, returning `None` if any option is invalid.
+    ///
+    /// The options are invalid if:
+    /// - the radix is not in the range `[2, 36]`.
+    /// - the exponent base or radix differ from the radix, and the radix
+    ///     or exponent base is not a power of two.
+    /// - the exponent base or radix is not in the range `[2, 36]`.
+    /// - the default exponent character is in the character set `[A-Da-d0-9.+\-]`.
+    /// - the backup exponent character is in the character set `[A-Za-z0-9.+\-]`.
+    /// - the exponent character for the radix is a digit in the radix.
+    /// - the NaN string does not start with an `'N'` or `'n'`.
+    /// - the Infinity string does not start with an `'I'` or `'i'`.
+    /// - any special string has `f32::FORMATTED_SIZE` or more bytes.
+    /// - the precision is larger than `MAX_FLOAT_PRECISION`.
+    /// - a notation other than `Shortest` or a precision is used with a radix other than 10.
+    /// - the format does not allow exponent notation, and the radix is not 10.
+    /// - the format does not allow exponent notation, and the notation is `Scientific`.
+    /// - the digit separator is not an ASCII punctuation character or a space,
+    ///     is in the character set `[.+\-]`, or is an exponent character.
+    /// - the grouping has groups of 0 digits.
+    #[inline]
+    pub fn build(self) -> Option<WriteFloatOptions> {
+        let options = self.options;
+        let is_valid_precision = match options.precision {
+            Some(precision) => precision <= MAX_FLOAT_PRECISION,
+            None            => true,
+        };
+        let exponent_base = or_radix(options.radix, options.exponent_base);
+        let exponent_radix = or_radix(options.radix, options.exponent_radix);
+        let is_valid = is_valid_radix(options.radix)
+            && is_valid_exponent_base(options.radix, exponent_base, exponent_radix)
+            && is_valid_exponent(options.exponent_default_char, options.exponent_backup_char)
+            && is_valid_exponent_char(options.radix, options.exponent_char())
+            && is_valid_special(options.nan_string, options.inf_string, options.inf_string)
+            && is_valid_precision
+            && (options.is_shortest() || options.radix == 10)
+            && (!options.no_exponent_notation() || is_valid_positional(options.radix, options.notation))
+            && is_valid_group_separator(options.digit_separator)
+            && options.digit_separator != options.exponent_default_char
+            && options.digit_separator != options.exponent_backup_char
+            && options.grouping.is_valid();
+        match is_valid {
+            true  => Some(options),
+            false => None,
+        }
+    }
+}
+
+// PARSE MANY
+
+/// Options to customize parsing many delimited numbers.
+///
+/// Create the default options with [`ParseManyOptions::new`], or
+/// customize them with [`ParseManyOptions::builder`].
+///
+/// [`ParseManyOptions::new`]: #method.new
+/// [`ParseManyOptions::builder`]: #method.builder
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseManyOptions {
+    /// Character separating fields within a record.
+    delimiter: u8,
+    /// Skip spaces and tabs surrounding each field.
+    skip_whitespace: bool,
+}
+
+impl ParseManyOptions {
+    /// Create options with the default values.
+    #[inline]
+    pub fn new() -> ParseManyOptions {
+        ParseManyOptions {
+            delimiter: b',',
+            skip_whitespace: true,
+        }
+    }
+
+    /// Create a builder to customize the options.
+    #[inline]
+    pub fn builder() -> ParseManyOptionsBuilder {
+        ParseManyOptionsBuilder { options: Self::new() }
+    }
+
+    /// Get the character separating fields within a record.
+    #[inline]
+    pub fn delimiter(&self) -> u8 {
+        self.delimiter
+    }
+
+    /// Get if spaces and tabs surrounding each field are skipped.
+    #[inline]
+    pub fn skip_whitespace(&self) -> bool {
+        self.skip_whitespace
+    }
+}
+
+impl Default for ParseManyOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builder for [`ParseManyOptions`](struct.ParseManyOptions.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseManyOptionsBuilder {
+    options: ParseManyOptions,
+}
+
+impl ParseManyOptionsBuilder {
+    /// Set the character separating fields within a record.
+    #[inline]
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.options.delimiter = delimiter;
+        self
+    }
+
+    /// Set if spaces and tabs surrounding each field are skipped.
+    #[inline]
+    pub fn skip_whitespace(mut self, skip_whitespace: bool) -> Self {
+        self.options.skip_whitespace = skip_whitespace;
+        self
+    }
+
+    /// Build the options, returning `None` if any option is invalid.
+    ///
+    /// The delimiter must be an ASCII character that cannot be part of
+    /// a number, and cannot be a line terminator.
+    #[inline]
+    pub fn build(self) -> Option<ParseManyOptions> {
+        match is_valid_delimiter(self.options.delimiter) {
+            true  => Some(self.options),
+            false => None,
+        }
+    }
+}
+
+// TEST
+// ----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_integer_options_test() {
+        let options = ParseIntegerOptions::builder().build().unwrap();
+        assert_eq!(options, ParseIntegerOptions::new());
+        assert_eq!(options.radix_u32(), 10);
+        assert_eq!(options.overflow_mode(), OverflowMode::Checked);
+
+        let options = ParseIntegerOptions::builder()
+            .overflow_mode(OverflowMode::Saturating)
+            .build()
+            .unwrap();
+        assert_eq!(options.overflow_mode(), OverflowMode::Saturating);
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_integer_options_radix_test() {
+        let options = ParseIntegerOptions::builder().radix(2).build().unwrap();
+        assert_eq!(options.radix(), 2);
+        assert!(ParseIntegerOptions::builder().radix(1).build().is_none());
+        assert!(ParseIntegerOptions::builder().radix(37).build().is_none());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_integer_options_alphabet_test() {
+        let options = ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE58)).build().unwrap();
+        assert_eq!(options.radix(), 58);
+        assert_eq!(options.alphabet(), Some(&DigitAlphabet::BASE58));
+        assert_eq!(ParseIntegerOptions::new().alphabet(), None);
+        assert!(ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE58)).radix(36).build().is_none());
+
+        #[cfg(feature = "format")] {
+            let builder = ParseIntegerOptions::builder().alphabet(Some(DigitAlphabet::BASE64));
+            assert!(builder.format(NumberFormat::JSON).build().is_none());
+            assert!(builder.format(NumberFormat::RUST_LITERAL).build().is_none());
+            assert!(builder.format(NumberFormat::ignore(b'_').unwrap()).build().is_none());
+
+            // Whitespace and quotes cannot be digits.
+            let builder = builder.format(NumberFormat::WHITESPACE_FLAG_MASK).whitespace(b" /");
+            assert!(builder.build().is_none());
+            assert!(builder.whitespace(b" ").build().is_some());
+            assert!(builder.format(NumberFormat::STANDARD).build().is_some());
+        }
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_integer_options_whitespace_test() {
+        assert_eq!(ParseIntegerOptions::new().whitespace(), b" \t\n\x0B\x0C\r");
+        let options = ParseIntegerOptions::builder().whitespace(b"\t").build().unwrap();
+        assert_eq!(options.whitespace(), b"\t");
+        assert!(ParseIntegerOptions::builder().whitespace(b"").build().is_some());
+        assert!(ParseIntegerOptions::builder().whitespace(b" 0").build().is_none());
+        assert!(ParseIntegerOptions::builder().whitespace(b"-").build().is_none());
+        assert!(ParseIntegerOptions::builder().whitespace(b"\"").build().is_none());
+
+        let format = NumberFormat::from_separator(b' ') | NumberFormat::INTEGER_INTERNAL_DIGIT_SEPARATOR;
+        assert!(ParseIntegerOptions::builder().format(format).build().is_some());
+        let format = format | NumberFormat::LEADING_WHITESPACE;
+        assert!(ParseIntegerOptions::builder().format(format).build().is_none());
+        assert!(ParseIntegerOptions::builder().format(format).whitespace(b"\t").build().is_some());
+    }
+
+    #[test]
+    fn parse_float_options_test() {
+        let options = ParseFloatOptions::builder().build().unwrap();
+        assert_eq!(options, ParseFloatOptions::new());
+        assert_eq!(options.exponent_char(), b'e');
+
+        let options = ParseFloatOptions::builder()
+            .lossy(true)
+            .exponent_default_char(b'^')
+            .nan_string(b"nan")
+            .inf_string(b"Inf")
+            .infinity_string(b"Infinity")
+            .build()
+            .unwrap();
+        assert!(options.lossy());
+        assert_eq!(options.exponent_char(), b'^');
+        assert_eq!(options.nan_string(), b"nan");
+        assert_eq!(options.inf_string(), b"Inf");
+        assert_eq!(options.infinity_string(), b"Infinity");
+    }
+
+    #[test]
+    fn parse_float_options_invalid_test() {
+        assert!(ParseFloatOptions::builder().exponent_default_char(b'0').build().is_none());
+        assert!(ParseFloatOptions::builder().exponent_default_char(b'.').build().is_none());
+        assert!(ParseFloatOptions::builder().exponent_default_char(b'+').build().is_none());
+        assert!(ParseFloatOptions::builder().exponent_default_char(b'-').build().is_none());
+        assert!(ParseFloatOptions::builder().nan_string(b"").build().is_none());
+        assert!(ParseFloatOptions::builder().nan_string(b"i").build().is_none());
+        assert!(ParseFloatOptions::builder().inf_string(b"").build().is_none());
+        assert!(ParseFloatOptions::builder().inf_string(b"n").build().is_none());
+        assert!(ParseFloatOptions::builder().inf_string(b"infinityinfinf").build().is_none());
+        assert!(ParseFloatOptions::builder().infinity_string(b"").build().is_none());
+        assert!(ParseFloatOptions::builder().infinity_string(b"n").build().is_none());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn parse_float_options_radix_test() {
+        let options = ParseFloatOptions::builder().radix(16).build().unwrap();
+        assert_eq!(options.exponent_char(), b'^');
+        let options = ParseFloatOptions::builder().radix(16).exponent_backup_char(b'#').build().unwrap();
+        assert_eq!(options.exponent_char(), b'#');
+        assert!(ParseFloatOptions::builder().exponent_backup_char(b'z').build().is_none());
+        assert!(ParseFloatOptions::builder().radix(37).build().is_none());
+
+        let options = ParseFloatOptions::builder()
+            .radix(16)
+            .exponent_base(2)
+            .exponent_default_char(b'p')
+            .build()
+            .unwrap();
+        assert_eq!(options.exponent_base(), 2);
+        assert_eq!(options.exponent_radix(), 16);
+        assert_eq!(options.exponent_char(), b'p');
+        let options = ParseFloatOptions::builder().radix(16).build().unwrap();
+        assert_eq!(options.exponent_base(), 16);
+        assert_eq!(options.exponent_radix(), 16);
+        assert!(ParseFloatOptions::builder().radix(16).exponent_base(2).build().is_none());
+        assert!(ParseFloatOptions::builder().radix(10).exponent_base(2).build().is_none());
+        assert!(ParseFloatOptions::builder().radix(16).exponent_base(3).exponent_default_char(b'p').build().is_none());
+        assert!(ParseFloatOptions::builder().radix(16).exponent_radix(37).build().is_none());
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn parse_float_options_whitespace_test() {
+        assert_eq!(ParseFloatOptions::new().whitespace(), b" \t\n\x0B\x0C\r");
+        let options = ParseFloatOptions::builder().whitespace(b"\xA0").build().unwrap();
+        assert_eq!(options.whitespace(), b"\xA0");
+        assert!(ParseFloatOptions::builder().whitespace(b"e").build().is_none());
+        assert!(ParseFloatOptions::builder().whitespace(b".").build().is_none());
+        assert!(ParseFloatOptions::builder().whitespace(b"+").build().is_none());
+    }
+
+    #[test]
+    fn parse_many_options_test() {
+        let options = ParseManyOptions::builder().build().unwrap();
+        assert_eq!(options, ParseManyOptions::new());
+        assert_eq!(options.delimiter(), b',');
+        assert!(options.skip_whitespace());
+
+        let options = ParseManyOptions::builder()
+            .delimiter(b'\t')
+            .skip_whitespace(false)
+            .build()
+            .unwrap();
+        assert_eq!(options.delimiter(), b'\t');
+        assert!(!options.skip_whitespace());
+        assert!(ParseManyOptions::builder().delimiter(b'0').build().is_none());
+        assert!(ParseManyOptions::builder().delimiter(b'e').build().is_none());
+        assert!(ParseManyOptions::builder().delimiter(b'-').build().is_none());
+        assert!(ParseManyOptions::builder().delimiter(b'\n').build().is_none());
+        assert!(ParseManyOptions::builder().delimiter(b'\xFF').build().is_none());
+    }
+
+    #[test]
+    fn write_integer_options_test() {
+        let options = WriteIntegerOptions::builder().build().unwrap();
+        assert_eq!(options, WriteIntegerOptions::new());
+        assert_eq!(options.radix_u32(), 10);
+    }
+
+    #[test]
+    fn write_float_options_test() {
+        let options = WriteFloatOptions::builder().build().unwrap();
+        assert_eq!(options, WriteFloatOptions::new());
+        assert_eq!(options.exponent_char(), b'e');
+
+        let options = WriteFloatOptions::builder()
+            .exponent_default_char(b'E')
+            .nan_string(b"nan")
+            .inf_string(b"Infinity")
+            .build()
+            .unwrap();
+        assert_eq!(options.exponent_char(), b'E');
+        assert_eq!(options.nan_string(), b"nan");
+        assert_eq!(options.inf_string(), b"Infinity");
+
+        assert!(WriteFloatOptions::builder().exponent_default_char(b'1').build().is_none());
+        assert!(WriteFloatOptions::builder().nan_string(b"inf").build().is_none());
+        assert!(WriteFloatOptions::builder().inf_string(b"nan").build().is_none());
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn write_format_options_test() {
+        let options = WriteIntegerOptions::builder()
+            .format(NumberFormat::REQUIRED_MANTISSA_SIGN)
+            .build()
+            .unwrap();
+        assert!(options.required_mantissa_sign());
+
+        let options = WriteFloatOptions::builder()
+            .format(NumberFormat::NO_EXPONENT_NOTATION)
+            .build()
+            .unwrap();
+        assert!(options.no_exponent_notation());
+        assert_eq!(options.format(), NumberFormat::NO_EXPONENT_NOTATION);
+
+        let builder = WriteFloatOptions::builder().format(NumberFormat::NO_EXPONENT_NOTATION);
+        assert!(builder.notation(FloatNotation::Fixed).build().is_some());
+        assert!(builder.notation(FloatNotation::General).build().is_some());
+        assert!(builder.notation(FloatNotation::Scientific).build().is_none());
+    }
+
+    #[test]
+    fn write_float_options_notation_test() {
+        let options = WriteFloatOptions::builder()
+            .notation(FloatNotation::Fixed)
+            .precision(Some(3))
+            .build()
+            .unwrap();
+        assert_eq!(options.notation(), FloatNotation::Fixed);
+        assert_eq!(options.precision(), Some(3));
+        assert!(!options.is_shortest());
+
+        let builder = WriteFloatOptions::builder();
+        assert!(builder.precision(Some(MAX_FLOAT_PRECISION)).build().is_some());
+        assert!(builder.precision(Some(MAX_FLOAT_PRECISION + 1)).build().is_none());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn write_float_options_notation_radix_test() {
+        let builder = WriteFloatOptions::builder().radix(2);
+        assert!(builder.build().is_some());
+        assert!(builder.precision(Some(3)).build().is_none());
+        assert!(builder.notation(FloatNotation::Scientific).build().is_none());
+    }
+}
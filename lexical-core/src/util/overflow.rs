@@ -0,0 +1,32 @@
+//! Integer overflow-mode identifiers.
+
+/// Overflow handling for integer-parsing.
+///
+/// Defines the value returned when the parsed digits do not fit in the
+/// integer type. The wrapping and saturating modes consume every
+/// remaining digit, so the number of processed bytes is the same as
+/// for a value within the range of the type.
+///
+/// # FFI
+///
+/// For interfacing with FFI-code, this may be approximated by:
+/// ```text
+/// const int32_t CHECKED = 0;
+/// const int32_t WRAPPING = 1;
+/// const int32_t SATURATING = 2;
+/// ```
+///
+/// # Safety
+///
+/// Assigning any value outside the range `[0-2]` to value of type
+/// OverflowMode may invoke undefined-behavior.
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OverflowMode {
+    /// Return an `Overflow` or `Underflow` error at the overflowing digit.
+    Checked = 0,
+    /// Wrap the value modulo `2^N`, where `N` is the number of bits in the type.
+    Wrapping = 1,
+    /// Clamp the value to the minimum or maximum value of the type, like `strtol`.
+    Saturating = 2,
+}
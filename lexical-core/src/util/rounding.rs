@@ -2,6 +2,8 @@
 
 #![allow(dead_code)]
 
+use super::sign::Sign;
+
 /// Rounding type for float-parsing.
 ///
 /// Defines the IEEE754 rounding scheme to be used during float parsing.
@@ -64,3 +66,18 @@ pub(crate) fn is_nearest(kind: RoundingKind) -> bool {
 pub(crate) fn is_toward(kind: RoundingKind) -> bool {
     !is_nearest(kind)
 }
+
+/// Get the rounding scheme for the magnitude of a number with the sign.
+///
+/// The directed rounding schemes are converted to `Upward` or `Downward`.
+#[inline]
+pub(crate) fn magnitude_rounding(kind: RoundingKind, sign: Sign) -> RoundingKind {
+    match (kind, sign) {
+        (RoundingKind::TowardPositiveInfinity, Sign::Positive)  => RoundingKind::Upward,
+        (RoundingKind::TowardPositiveInfinity, Sign::Negative)  => RoundingKind::Downward,
+        (RoundingKind::TowardNegativeInfinity, Sign::Positive)  => RoundingKind::Downward,
+        (RoundingKind::TowardNegativeInfinity, Sign::Negative)  => RoundingKind::Upward,
+        (RoundingKind::TowardZero, _)                           => RoundingKind::Downward,
+        _                                                       => kind,
+    }
+}
@@ -2,33 +2,285 @@
 
 #![allow(dead_code)]
 
-use crate::lib::{cmp, iter, marker, ops, ptr, slice};
-use arrayvec;
+use crate::lib::{cmp, fmt, iter, marker, ops, ptr, slice};
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 use crate::lib::Vec;
 
 // ARRVEC
 
-/// Macro to automate simplify the creation of an ArrayVec.
+/// Macro to automate simplify the creation of a StackVec.
 #[macro_export]
 macro_rules! arrvec {
-    // This only works if the ArrayVec is the same size as the input array.
+    // This only works if the StackVec is the same size as the input array.
     ($elem:expr; $n:expr) => ({
-        $crate::arrayvec::ArrayVec::from([$elem; $n])
+        $crate::util::StackVec::from([$elem; $n])
     });
     // This just repeatedly calls `push`. I don't believe there's a concise way to count the number of expressions.
     ($($x:expr),*$(,)*) => ({
         // Allow an unused mut variable, since if the sequence is empty,
         // the vec will never be mutated.
         #[allow(unused_mut)] {
-            let mut vec = $crate::arrayvec::ArrayVec::new();
+            let mut vec = $crate::util::StackVec::new();
             $(vec.push($x);)*
             vec
         }
     });
 }
 
+// STACKVEC
+
+/// Fixed-size array used as the storage for a StackVec.
+pub trait Array: Copy {
+    /// Type of the array elements.
+    type Item: Copy + Default;
+
+    /// Number of elements in the array.
+    const CAPACITY: usize;
+
+    /// Create array with default elements.
+    fn new() -> Self;
+
+    /// Get the array as a slice.
+    fn as_slice(&self) -> &[Self::Item];
+
+    /// Get the array as a mutable slice.
+    fn as_mut_slice(&mut self) -> &mut [Self::Item];
+}
+
+macro_rules! array_impl {
+    ($($n:expr)*) => ($(
+        impl<T: Copy + Default> Array for [T; $n] {
+            type Item = T;
+            const CAPACITY: usize = $n;
+
+            #[inline]
+            fn new() -> Self {
+                [T::default(); $n]
+            }
+
+            #[inline]
+            fn as_slice(&self) -> &[T] {
+                self
+            }
+
+            #[inline]
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                self
+            }
+        }
+    )*);
+}
+
+array_impl! { 8 20 36 64 128 768 1024 2048 }
+
+/// Vector with a fixed capacity, stored on the stack.
+///
+/// Only holds `Copy` elements, so the unused elements are always
+/// initialized, and no elements need to be dropped.
+#[derive(Copy, Clone)]
+pub struct StackVec<A: Array> {
+    data: A,
+    length: usize,
+}
+
+impl<A: Array> StackVec<A> {
+    /// Create new, empty vector.
+    #[inline]
+    pub fn new() -> Self {
+        StackVec { data: A::new(), length: 0 }
+    }
+
+    /// Get the number of elements the vector can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        A::CAPACITY
+    }
+
+    /// Get the elements as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[A::Item] {
+        &self.data.as_slice()[..self.length]
+    }
+
+    /// Get the elements as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        let length = self.length;
+        &mut self.data.as_mut_slice()[..length]
+    }
+
+    /// Shorten the vector, keeping the first `len` elements.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.length = cmp::min(self.length, len);
+    }
+
+    /// Set the length of the vector.
+    ///
+    /// The elements are always initialized, so this only requires
+    /// `new_len` is not greater than the capacity.
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= A::CAPACITY);
+        self.length = new_len;
+    }
+
+    /// Remove an element, replacing it with the last element.
+    #[inline]
+    pub fn swap_remove(&mut self, index: usize) -> A::Item {
+        let last = self.length - 1;
+        self.as_mut_slice().swap(index, last);
+        self.remove(last)
+    }
+
+    /// Insert an element at position `index`, shifting elements after it.
+    #[inline]
+    pub fn insert(&mut self, index: usize, element: A::Item) {
+        assert!(index <= self.length);
+        self.push(element);
+        self.as_mut_slice()[index..].rotate_right(1);
+    }
+
+    /// Remove and return the element at position `index`, shifting elements after it.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> A::Item {
+        let element = self.as_slice()[index];
+        self.as_mut_slice()[index..].rotate_left(1);
+        self.length -= 1;
+        element
+    }
+
+    /// Append an element to the back of the vector.
+    ///
+    /// Panics if the vector is full.
+    #[inline]
+    pub fn push(&mut self, value: A::Item) {
+        assert!(self.length < A::CAPACITY);
+        self.data.as_mut_slice()[self.length] = value;
+        self.length += 1;
+    }
+
+    /// Remove the last element from the vector and return it.
+    #[inline]
+    pub fn pop(&mut self) -> Option<A::Item> {
+        match self.length {
+            0 => None,
+            _ => {
+                self.length -= 1;
+                Some(self.data.as_slice()[self.length])
+            },
+        }
+    }
+
+    /// Remove all elements from the vector.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+}
+
+impl<A: Array> Default for StackVec<A> {
+    #[inline]
+    fn default() -> Self {
+        StackVec::new()
+    }
+}
+
+impl<A: Array> From<A> for StackVec<A> {
+    #[inline]
+    fn from(data: A) -> Self {
+        StackVec { data, length: A::CAPACITY }
+    }
+}
+
+impl<A: Array> PartialEq for StackVec<A>
+    where A::Item: PartialEq
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<A: Array> Eq for StackVec<A>
+    where A::Item: Eq
+{
+}
+
+impl<A: Array> fmt::Debug for StackVec<A>
+    where A::Item: fmt::Debug
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<A: Array> ops::Deref for StackVec<A> {
+    type Target = [A::Item];
+
+    #[inline]
+    fn deref(&self) -> &[A::Item] {
+        self.as_slice()
+    }
+}
+
+impl<A: Array> ops::DerefMut for StackVec<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [A::Item] {
+        self.as_mut_slice()
+    }
+}
+
+impl<A: Array> Extend<A::Item> for StackVec<A> {
+    /// Extend the vector with the items from an iterator.
+    ///
+    /// Panics if the vector is full.
+    #[inline]
+    fn extend<I: iter::IntoIterator<Item=A::Item>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<A: Array> iter::FromIterator<A::Item> for StackVec<A> {
+    #[inline]
+    fn from_iter<I: iter::IntoIterator<Item=A::Item>>(iter: I) -> Self {
+        let mut vec = StackVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+/// Iterator over the elements of a StackVec, by value.
+pub struct IntoIter<A: Array> {
+    vec: StackVec<A>,
+    index: usize,
+}
+
+impl<A: Array> Iterator for IntoIter<A> {
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<A::Item> {
+        let value = self.vec.as_slice().get(self.index).cloned();
+        self.index += 1;
+        value
+    }
+}
+
+impl<A: Array> iter::IntoIterator for StackVec<A> {
+    type Item = A::Item;
+    type IntoIter = IntoIter<A>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<A> {
+        IntoIter { vec: self, index: 0 }
+    }
+}
+
 // INSERT MANY
 
 /// Insert multiple elements at position `index`.
@@ -281,7 +533,7 @@ impl<T> SliceLikeImpl<T> for [T] {
     }
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> SliceLikeImpl<T> for Vec<T> {
     // AS SLICE
 
@@ -296,17 +548,17 @@ impl<T> SliceLikeImpl<T> for Vec<T> {
     }
 }
 
-impl<A: arrayvec::Array> SliceLikeImpl<A::Item> for arrayvec::ArrayVec<A> {
+impl<A: Array> SliceLikeImpl<A::Item> for StackVec<A> {
     // AS SLICE
 
     #[inline]
     fn as_slice(&self) -> &[A::Item] {
-        arrayvec::ArrayVec::as_slice(self)
+        StackVec::as_slice(self)
     }
 
     #[inline]
     fn as_mut_slice(&mut self) -> &mut [A::Item] {
-        arrayvec::ArrayVec::as_mut_slice(self)
+        StackVec::as_mut_slice(self)
     }
 }
 
@@ -363,11 +615,15 @@ impl<T: Ord> BinarySearch<T> for dyn SliceLikeImpl<T> {
 }
 
 /// Collection that has a `sort()` method.
+///
+/// Requires an allocator, since the stable sort allocates.
+#[cfg(feature = "std")]
 pub trait Sort<T: Ord> {
     /// Sort sequence.
     fn sort(&mut self);
 }
 
+#[cfg(feature = "std")]
 impl<T: Ord> Sort<T> for dyn SliceLikeImpl<T> {
     #[inline]
     fn sort(&mut self) {
@@ -924,7 +1180,7 @@ impl<T> SliceLike<T> for [T] {
     }
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> SliceLike<T> for Vec<T> {
     // GET
 
@@ -1007,7 +1263,7 @@ impl<T> SliceLike<T> for Vec<T> {
     }
 }
 
-impl<A: arrayvec::Array> SliceLike<A::Item> for arrayvec::ArrayVec<A> {
+impl<A: Array> SliceLike<A::Item> for StackVec<A> {
     // GET
 
     /// Get an immutable reference to item at index.
@@ -1152,7 +1408,7 @@ pub trait VecLike<T>:
     fn remove_many<R: ops::RangeBounds<usize>>(&mut self, range: R);
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> VecLike<T> for Vec<T> {
     #[inline]
     fn new() -> Vec<T> {
@@ -1235,22 +1491,22 @@ impl<T> VecLike<T> for Vec<T> {
     }
 }
 
-impl<A: arrayvec::Array> VecLike<A::Item> for arrayvec::ArrayVec<A> {
+impl<A: Array> VecLike<A::Item> for StackVec<A> {
     #[inline]
-    fn new() -> arrayvec::ArrayVec<A> {
-        arrayvec::ArrayVec::new()
+    fn new() -> StackVec<A> {
+        StackVec::new()
     }
 
     #[inline]
-    fn with_capacity(capacity: usize) -> arrayvec::ArrayVec<A> {
-        let mut v = arrayvec::ArrayVec::new();
+    fn with_capacity(capacity: usize) -> StackVec<A> {
+        let mut v = StackVec::new();
         v.reserve(capacity);
         v
     }
 
     #[inline]
     fn capacity(&self) -> usize {
-        arrayvec::ArrayVec::capacity(self)
+        StackVec::capacity(self)
     }
 
     #[inline]
@@ -1269,42 +1525,42 @@ impl<A: arrayvec::Array> VecLike<A::Item> for arrayvec::ArrayVec<A> {
 
     #[inline]
     fn truncate(&mut self, len: usize) {
-        arrayvec::ArrayVec::truncate(self, len)
+        StackVec::truncate(self, len)
     }
 
     #[inline]
     unsafe fn set_len(&mut self, new_len: usize) {
-        arrayvec::ArrayVec::set_len(self, new_len);
+        StackVec::set_len(self, new_len);
     }
 
     #[inline]
     fn swap_remove(&mut self, index: usize) -> A::Item {
-        arrayvec::ArrayVec::swap_remove(self, index)
+        StackVec::swap_remove(self, index)
     }
 
     #[inline]
     fn insert(&mut self, index: usize, element: A::Item) {
-        arrayvec::ArrayVec::insert(self, index, element)
+        StackVec::insert(self, index, element)
     }
 
     #[inline]
     fn remove(&mut self, index: usize) -> A::Item {
-        arrayvec::ArrayVec::remove(self, index)
+        StackVec::remove(self, index)
     }
 
     #[inline]
     fn push(&mut self, value: A::Item) {
-        arrayvec::ArrayVec::push(self, value);
+        StackVec::push(self, value);
     }
 
     #[inline]
     fn pop(&mut self) -> Option<A::Item> {
-        arrayvec::ArrayVec::pop(self)
+        StackVec::pop(self)
     }
 
     #[inline]
     fn clear(&mut self) {
-        arrayvec::ArrayVec::clear(self);
+        StackVec::clear(self);
     }
 
     #[inline]
@@ -1332,7 +1588,7 @@ pub trait CloneableVecLike<T: Clone + Copy + Send>: Send + VecLike<T>
     fn resize(&mut self, len: usize, value: T);
 }
 
-#[cfg(all(feature = "correct", feature = "radix"))]
+#[cfg(feature = "radix")]
 impl<T> CloneableVecLike<T> for Vec<T>
     where T: Clone + Copy + Send
 {
@@ -1347,10 +1603,9 @@ impl<T> CloneableVecLike<T> for Vec<T>
     }
 }
 
-impl<A: arrayvec::Array> CloneableVecLike<A::Item> for arrayvec::ArrayVec<A>
+impl<A: Array> CloneableVecLike<A::Item> for StackVec<A>
     where A: Send,
-          A::Index: Send,
-          A::Item: Clone + Copy + Send
+          A::Item: Send
 {
     #[inline]
     fn extend_from_slice(&mut self, other: &[A::Item]) {
@@ -1378,7 +1633,7 @@ mod tests {
 
     #[test]
     fn test_insert_many() {
-        type V = arrayvec::ArrayVec<[u8; 8]>;
+        type V = StackVec<[u8; 8]>;
         let mut v: V = V::new();
         for x in 0..4 {
             v.push(x);
@@ -1388,7 +1643,23 @@ mod tests {
         assert_eq!(&v[..], &[0, 5, 6, 1, 2, 3]);
     }
 
-    #[cfg(all(feature = "correct", feature = "radix"))]
+    #[test]
+    fn stack_vec_test() {
+        type V = StackVec<[u8; 8]>;
+        let mut v: V = [1, 2, 3].iter().cloned().collect();
+        v.insert(0, 4);
+        assert_eq!(&v[..], &[4, 1, 2, 3]);
+        assert_eq!(v.remove(1), 1);
+        assert_eq!(v.swap_remove(0), 4);
+        assert_eq!(&v[..], &[3, 2]);
+        assert_eq!(v.pop(), Some(2));
+        v.resize(3, 5);
+        assert_eq!(v, arrvec![3, 5, 5]);
+        assert_eq!(v.into_iter().collect::<V>(), arrvec![3, 5, 5]);
+        assert_eq!(V::from([0; 8]).len(), 8);
+    }
+
+    #[cfg(feature = "radix")]
     #[test]
     fn remove_many_test() {
         let mut x = vec![0, 1, 2, 3, 4, 5];
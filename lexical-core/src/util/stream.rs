@@ -0,0 +1,1194 @@
+//! Incremental parser for numbers split across chunks of input.
+//!
+//! The parser is a state machine over the standard grammar, which keeps
+//! the significant digits and the exponent of the number, rather than
+//! the bytes, so the memory used does not depend on the input. Once the
+//! number is complete, the value is parsed from a short, canonical
+//! string with the same options, such as `-12345e-4` for `-1.2345`.
+
+use crate::lib::fmt;
+use super::error::ErrorCode;
+use super::format::NumberFormat;
+use super::num::Number;
+use super::options::*;
+use super::overflow::OverflowMode;
+use super::result::Result;
+use super::sequence::StackVec;
+use super::table::digit_to_char;
+use super::traits::FromLexicalOptions;
+
+// CONSTANTS
+
+// Storage for the mantissa sign, the significant digits, and the suffix
+// of the canonical string. The number of digits exceeds the digits
+// required to break any tie, for every radix.
+#[cfg(not(feature = "radix"))]
+type Buffer = StackVec<[u8; 1024]>;
+#[cfg(feature = "radix")]
+type Buffer = StackVec<[u8; 2048]>;
+
+// Bytes reserved after the significant digits, for the truncated digit,
+// the padding zeros, the exponent, and the NaN payload.
+const RESERVED: usize = 96;
+
+// Every integer overflows within the significant digits stored,
+// including the digits parsed at once with the overflowing digit.
+const MAX_INTEGER_DIGITS: usize = 256;
+
+// OPTIONS
+
+/// Options supported by the incremental parser.
+///
+/// Implemented for [`ParseIntegerOptions`] and [`ParseFloatOptions`].
+///
+/// [`ParseIntegerOptions`]: struct.ParseIntegerOptions.html
+/// [`ParseFloatOptions`]: struct.ParseFloatOptions.html
+#[doc(hidden)]
+pub trait StreamOptions: Copy + Default + fmt::Debug {
+    /// Get if the options can be parsed incrementally.
+    fn is_supported(&self) -> bool;
+
+    /// Get the value of a mantissa digit.
+    fn to_digit(&self, c: u8) -> Option<u32>;
+
+    /// Get the character for the digit zero.
+    fn zero_char(&self) -> u8;
+
+    /// Get if integers saturate on overflow.
+    fn is_saturating(&self) -> bool;
+
+    /// Get the options for floats.
+    fn float_options(&self) -> Option<&ParseFloatOptions>;
+}
+
+impl StreamOptions for ParseIntegerOptions {
+    #[inline]
+    fn is_supported(&self) -> bool {
+        #[cfg(feature = "format")]
+        let is_standard = self.number_format() == NumberFormat::standard().unwrap();
+        #[cfg(not(feature = "format"))]
+        let is_standard = true;
+
+        is_standard && self.overflow_mode() != OverflowMode::Wrapping
+    }
+
+    #[inline]
+    fn to_digit(&self, c: u8) -> Option<u32> {
+        #[cfg(feature = "radix")] {
+            if let Some(alphabet) = self.alphabet() {
+                return alphabet.to_digit(c);
+            }
+        }
+        (c as char).to_digit(self.radix_u32())
+    }
+
+    #[inline]
+    fn zero_char(&self) -> u8 {
+        #[cfg(feature = "radix")] {
+            if let Some(alphabet) = self.alphabet() {
+                return alphabet.digits()[0];
+            }
+        }
+        b'0'
+    }
+
+    #[inline]
+    fn is_saturating(&self) -> bool {
+        self.overflow_mode() == OverflowMode::Saturating
+    }
+
+    #[inline]
+    fn float_options(&self) -> Option<&ParseFloatOptions> {
+        None
+    }
+}
+
+impl StreamOptions for ParseFloatOptions {
+    #[inline]
+    fn is_supported(&self) -> bool {
+        self.number_format() == NumberFormat::standard().unwrap()
+    }
+
+    #[inline]
+    fn to_digit(&self, c: u8) -> Option<u32> {
+        (c as char).to_digit(self.radix_u32())
+    }
+
+    #[inline]
+    fn zero_char(&self) -> u8 {
+        b'0'
+    }
+
+    #[inline]
+    fn is_saturating(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn float_options(&self) -> Option<&ParseFloatOptions> {
+        Some(self)
+    }
+}
+
+// SPECIAL
+
+// Get the special string at the index, in order of precedence.
+//
+// The aliases are checked before the NaN and Infinity strings, and the
+// long Infinity string before the short one.
+#[inline]
+fn special_string(options: &ParseFloatOptions, index: usize) -> Option<&'static [u8]> {
+    let nan_aliases = options.nan_aliases();
+    let inf_aliases = options.inf_aliases();
+    if index < nan_aliases.len() {
+        return Some(nan_aliases[index]);
+    }
+    let index = index - nan_aliases.len();
+    if index < inf_aliases.len() {
+        return Some(inf_aliases[index]);
+    }
+    match index - inf_aliases.len() {
+        0 => Some(options.infinity_string()),
+        1 => Some(options.inf_string()),
+        2 => Some(options.nan_string()),
+        _ => None,
+    }
+}
+
+// Determine if the special string at the index is Not a Number.
+#[inline]
+fn is_nan_string(options: &ParseFloatOptions, index: usize) -> bool {
+    let nan_aliases = options.nan_aliases().len();
+    let inf_aliases = options.inf_aliases().len();
+    index < nan_aliases || index == nan_aliases + inf_aliases + 2
+}
+
+// Add a digit to a NaN payload, or `None` if any digit is invalid.
+#[inline]
+fn payload_digit(value: Option<u64>, c: u8, radix: u32) -> Option<u64> {
+    let digit = (c as char).to_digit(radix)? as u64;
+    value.map(|value| {
+        value.checked_mul(radix as u64)
+            .and_then(|v| v.checked_add(digit))
+            .unwrap_or(u64::max_value())
+    })
+}
+
+/// Position within a special value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum SpecialState {
+    /// Matching the special strings.
+    Match,
+    /// Before the opening parenthesis of a NaN payload.
+    PayloadStart,
+    /// Within the NaN payload.
+    Payload,
+    /// After the closing parenthesis of a NaN payload.
+    PayloadEnd,
+    /// The special value is complete, or no special strings match.
+    Done,
+}
+
+/// Special value parsed so far.
+///
+/// Every special string which matches the bytes so far matches any
+/// other, so a single candidate represents the bytes, and each byte is
+/// checked against every special string.
+#[derive(Copy, Clone, Debug)]
+struct Special {
+    /// Current state of the special value.
+    state: SpecialState,
+    /// If the NaN is signaling, spelled with an `s` before the NaN string.
+    is_signaling: bool,
+    /// Number of bytes matched, after the mantissa sign and any `s`.
+    len: usize,
+    /// Special string matching every byte so far.
+    candidate: &'static [u8],
+    /// Index and length of the matched special string first in precedence.
+    matched: Option<(usize, usize)>,
+    /// Number of bytes in the NaN payload.
+    payload_len: usize,
+    /// If the NaN payload starts with `0`, and then with `x` or `X`.
+    payload_prefix: (bool, bool),
+    /// Decimal, octal and hexadecimal values of the NaN payload.
+    payload: (Option<u64>, Option<u64>, Option<u64>),
+}
+
+impl Special {
+    #[inline]
+    fn new() -> Special {
+        Special {
+            state: SpecialState::Match,
+            is_signaling: false,
+            len: 0,
+            candidate: b"",
+            matched: None,
+            payload_len: 0,
+            payload_prefix: (false, false),
+            payload: (Some(0), Some(0), Some(0)),
+        }
+    }
+
+    // Process a byte, returning if it continues the special value.
+    fn next(&mut self, c: u8, options: &ParseFloatOptions) -> bool {
+        match self.state {
+            SpecialState::Match => self.next_match(c, options),
+            SpecialState::Done  => false,
+            _                   => self.next_payload(c),
+        }
+    }
+
+    // Match a byte against the special strings.
+    fn next_match(&mut self, c: u8, options: &ParseFloatOptions) -> bool {
+        let len = self.len;
+        let previous = &self.candidate[..len];
+        let mut candidate = None;
+        let mut index = 0;
+        while let Some(string) = special_string(options, index) {
+            // Signaling NaNs only match the NaN string.
+            let is_match = (!self.is_signaling || is_nan_string(options, index))
+                && string.len() > len
+                && string[..len].eq_ignore_ascii_case(previous)
+                && string[len].eq_ignore_ascii_case(&c);
+            if is_match {
+                candidate = candidate.or(Some(string));
+                let is_first = self.matched.map_or(true, |(i, _)| index < i);
+                if string.len() == len + 1 && is_first {
+                    self.matched = Some((index, len + 1));
+                }
+            }
+            index += 1;
+        }
+
+        if let Some(candidate) = candidate {
+            self.candidate = candidate;
+            self.len += 1;
+            return true;
+        } else if len == 0 && !self.is_signaling && options.nan_payload() && c.eq_ignore_ascii_case(&b's') {
+            self.is_signaling = true;
+            return true;
+        }
+
+        // No special strings continue, parse any NaN payload, starting
+        // with the bytes already matched after the NaN string.
+        self.state = SpecialState::Done;
+        match self.matched {
+            Some((index, matched)) if options.nan_payload() && is_nan_string(options, index) => {
+                self.state = SpecialState::PayloadStart;
+                let candidate = self.candidate;
+                for &c in &candidate[matched..len] {
+                    if !self.next_payload(c) {
+                        return false;
+                    }
+                }
+                self.next_payload(c)
+            },
+            _ => false,
+        }
+    }
+
+    // Process a byte of the NaN payload.
+    fn next_payload(&mut self, c: u8) -> bool {
+        match (self.state, c) {
+            (SpecialState::PayloadStart, b'(') => self.state = SpecialState::Payload,
+            (SpecialState::Payload, b')')      => self.state = SpecialState::PayloadEnd,
+            (SpecialState::Payload, _) if c.is_ascii_alphanumeric() || c == b'_' => {
+                let (decimal, octal, hex) = self.payload;
+                let index = self.payload_len;
+                self.payload = (
+                    payload_digit(decimal, c, 10),
+                    if index >= 1 { payload_digit(octal, c, 8) } else { octal },
+                    if index >= 2 { payload_digit(hex, c, 16) } else { hex },
+                );
+                match index {
+                    0 => self.payload_prefix.0 = c == b'0',
+                    1 => self.payload_prefix.1 = c == b'x' || c == b'X',
+                    _ => (),
+                }
+                self.payload_len += 1;
+            },
+            _ => {
+                // The payload is only consumed if the parentheses are closed.
+                if self.state != SpecialState::PayloadEnd {
+                    self.state = SpecialState::Done;
+                }
+                return false;
+            },
+        }
+        true
+    }
+
+    // Get the value of the NaN payload, if the parentheses were closed.
+    #[inline]
+    fn payload(&self) -> Option<u64> {
+        let (decimal, octal, hex) = self.payload;
+        let value = match self.payload_prefix {
+            (true, true) if self.payload_len > 2 => hex,
+            (true, _)                            => octal,
+            _                                    => decimal,
+        };
+        match self.state {
+            SpecialState::PayloadEnd => Some(value.unwrap_or(0)),
+            _                        => None,
+        }
+    }
+}
+
+// Write an unsigned integer in the radix, returning the number of digits.
+#[inline]
+fn write_integer(value: u64, radix: u32, bytes: &mut [u8]) -> usize {
+    let mut digits = [0u8; 64];
+    let mut index = digits.len();
+    let mut value = value;
+    loop {
+        index -= 1;
+        digits[index] = digit_to_char(value % radix as u64);
+        value /= radix as u64;
+        if value == 0 {
+            break;
+        }
+    }
+    let count = digits.len() - index;
+    bytes[..count].copy_from_slice(&digits[index..]);
+    count
+}
+
+// STATE
+
+/// Position within the grammar of the number parsed so far.
+///
+/// The grammar accepts a superset of the valid numbers, so the number
+/// is only complete once a byte is found that cannot continue it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum State {
+    /// No bytes have been processed.
+    Start,
+    /// After the mantissa sign.
+    Sign,
+    /// Within the integer digits.
+    Integer,
+    /// Within the fraction, after the decimal point.
+    Fraction,
+    /// After the exponent character.
+    ExponentStart,
+    /// After the exponent sign.
+    ExponentSign,
+    /// Within the exponent digits.
+    Exponent,
+}
+
+/// Status of an incremental parse after processing a chunk.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParseStatus<N> {
+    /// The number may continue in the next chunk.
+    Incomplete,
+    /// The number is complete, with the parsed value and the number of
+    /// bytes in the number, counted from the start of the first chunk.
+    Complete(N, usize),
+}
+
+// PARSER
+
+/// Resumable parser for numbers split across chunks of input.
+///
+/// Bytes are fed to the parser as they are received, and the parser
+/// reports if the number may continue, if it is complete, or if an
+/// error occurred. The result is identical to [`parse_partial_with_options`]
+/// on the joined chunks, and [`finish`] is identical to [`parse_with_options`]
+/// on the joined chunks. The number is only complete once a byte is
+/// found that cannot continue the number, so the end of input must be
+/// signalled with [`finish`].
+///
+/// The parser stores the state of the grammar, the significant digits,
+/// and the exponent, rather than the chunks, and does not allocate.
+/// Significant digits past the digits required to break any tie only
+/// determine if the number is above the tie. Options with a non-standard
+/// number format, or wrapping integer overflow, are not supported.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{NumberParser, ParseFloatOptions, ParseStatus};
+/// # pub fn main() {
+/// let mut parser = NumberParser::<f64>::new();
+/// assert_eq!(parser.feed(b"1.2"), Ok(ParseStatus::Incomplete));
+/// assert_eq!(parser.feed(b"5e1,"), Ok(ParseStatus::Complete(12.5, 6)));
+///
+/// parser.reset();
+/// assert_eq!(parser.feed(b"-3"), Ok(ParseStatus::Incomplete));
+/// assert_eq!(parser.finish(), Ok(-3.0));
+///
+/// let options = ParseFloatOptions::builder()
+///     .exponent_default_char(b'^')
+///     .build()
+///     .unwrap();
+/// let mut parser = NumberParser::<f64>::with_options(options).unwrap();
+/// assert_eq!(parser.feed(b"1.5^"), Ok(ParseStatus::Incomplete));
+/// assert_eq!(parser.finish(), Err((lexical_core::ErrorCode::EmptyExponent, 4).into()));
+/// # }
+/// ```
+///
+/// [`parse_with_options`]: fn.parse_with_options.html
+/// [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+/// [`finish`]: #method.finish
+#[derive(Clone, Debug)]
+pub struct NumberParser<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    /// Options to parse the number.
+    options: N::Options,
+    /// Current state of the grammar.
+    state: State,
+    /// If the grammar may continue after the bytes processed.
+    is_number: bool,
+    /// Number of bytes processed for the current number.
+    count: usize,
+    /// Number of bytes accepted by the grammar.
+    length: usize,
+    /// Number of bytes in the mantissa sign.
+    sign_len: usize,
+    /// First byte after the mantissa sign.
+    first: u8,
+    /// Mantissa sign, and then the significant digits.
+    buffer: Buffer,
+    /// If any mantissa digits were found.
+    has_digits: bool,
+    /// Number of leading zeros stored before the significant digits.
+    leading_zeros: usize,
+    /// Number of leading zeros not stored.
+    zeros: usize,
+    /// If any non-zero significant digits were not stored.
+    is_truncated: bool,
+    /// Power of the radix applied to the significant digits.
+    scale: i64,
+    /// Index of the exponent character.
+    exponent_index: usize,
+    /// If the exponent is negative.
+    is_negative_exponent: bool,
+    /// Saturated value of the exponent.
+    exponent: i64,
+    /// Special value parsed so far.
+    special: Special,
+    /// Result once the number is complete.
+    result: Option<Result<(N, usize)>>,
+}
+
+impl<N> NumberParser<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    /// Create new parser for a number, with the default options.
+    #[inline]
+    pub fn new() -> Self {
+        NumberParser {
+            options: N::Options::default(),
+            state: State::Start,
+            is_number: true,
+            count: 0,
+            length: 0,
+            sign_len: 0,
+            first: 0,
+            buffer: Buffer::new(),
+            has_digits: false,
+            leading_zeros: 0,
+            zeros: 0,
+            is_truncated: false,
+            scale: 0,
+            exponent_index: 0,
+            is_negative_exponent: false,
+            exponent: 0,
+            special: Special::new(),
+            result: None,
+        }
+    }
+
+    /// Create new parser for a number, with custom options.
+    ///
+    /// Returns `None` if the options cannot be parsed incrementally,
+    /// which are options with a non-standard number format, or with
+    /// wrapping integer overflow.
+    #[inline]
+    pub fn with_options(options: N::Options) -> Option<Self> {
+        match options.is_supported() {
+            true  => Some(NumberParser { options, ..Self::new() }),
+            false => None,
+        }
+    }
+
+    /// Reset the parser to parse a new number.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = NumberParser { options: self.options, ..Self::new() };
+    }
+
+    /// Process a chunk of input.
+    ///
+    /// Returns `ParseStatus::Incomplete` if every byte may be part of
+    /// the number, and otherwise the value or error from parsing the
+    /// number. Once the number is complete, further chunks are ignored,
+    /// and the same result is returned.
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<ParseStatus<N>> {
+        if self.result.is_none() {
+            for &c in chunk {
+                if !self.next(c) {
+                    self.result = Some(self.complete(false));
+                    break;
+                }
+                self.count += 1;
+                if self.is_overflow() {
+                    self.result = Some(self.complete(false));
+                    break;
+                }
+            }
+        }
+
+        match self.result {
+            None                     => Ok(ParseStatus::Incomplete),
+            Some(Ok((value, count))) => Ok(ParseStatus::Complete(value, count)),
+            Some(Err(error))         => Err(error),
+        }
+    }
+
+    /// Signal the end of input, and parse the number.
+    ///
+    /// Returns the same result as [`parse_with_options`] on the joined
+    /// chunks, which is an error if any bytes follow the number.
+    ///
+    /// [`parse_with_options`]: fn.parse_with_options.html
+    pub fn finish(&mut self) -> Result<N> {
+        let (result, is_end) = match self.result {
+            Some(result) => (result, false),
+            None         => (self.complete(true), true),
+        };
+        match result {
+            Ok((value, count)) if is_end && count == self.count => Ok(value),
+            Ok((_, count))                                      => Err((ErrorCode::InvalidDigit, count).into()),
+            Err(error)                                          => Err(error),
+        }
+    }
+
+    // Signal the end of input, and parse the number and any trailing bytes.
+    #[inline]
+    pub(crate) fn finish_partial(&mut self) -> Result<(N, usize)> {
+        match self.result {
+            Some(result) => result,
+            None         => self.complete(true),
+        }
+    }
+
+    // PROCESS
+
+    // Process a byte, returning if it continues the number.
+    #[inline]
+    fn next(&mut self, c: u8) -> bool {
+        let is_digit = self.options.to_digit(c).is_some();
+        let is_sign = c == b'+' || (c == b'-' && N::IS_SIGNED);
+        if self.count == 0 && is_sign && !is_digit {
+            self.sign_len = 1;
+            self.length = 1;
+            self.state = State::Sign;
+            self.buffer.push(c);
+            return true;
+        } else if self.count == self.sign_len {
+            self.first = c;
+        }
+
+        let is_number = self.is_number && self.next_number(c);
+        self.is_number = is_number;
+        if is_number {
+            self.length = self.count + 1;
+        }
+        let is_special = match self.options.float_options() {
+            Some(options) => self.special.next(c, options),
+            None          => false,
+        };
+        is_number || is_special
+    }
+
+    // Process a byte of the number grammar.
+    #[inline]
+    fn next_number(&mut self, c: u8) -> bool {
+        let options = match self.options.float_options() {
+            Some(options) => *options,
+            None          => return self.next_integer(c),
+        };
+
+        #[cfg(not(feature = "radix"))]
+        let exponent_radix = options.radix_u32();
+        #[cfg(feature = "radix")]
+        let exponent_radix = options.exponent_radix_u32();
+
+        let digit = self.options.to_digit(c);
+        let is_exponent = c.eq_ignore_ascii_case(&options.exponent_char());
+        let exponent_digit = (c as char).to_digit(exponent_radix);
+        self.state = match (self.state, c) {
+            (State::Start, _)       |
+            (State::Sign, _)        |
+            (State::Integer, _) if digit.is_some() => {
+                self.push_digit(c, digit.unwrap(), false);
+                State::Integer
+            },
+            (State::Fraction, _) if digit.is_some() => {
+                self.push_digit(c, digit.unwrap(), true);
+                State::Fraction
+            },
+            (State::Start, b'.')    |
+            (State::Sign, b'.')     |
+            (State::Integer, b'.')  => State::Fraction,
+            (State::Integer, _)     |
+            (State::Fraction, _) if is_exponent => {
+                self.exponent_index = self.count;
+                State::ExponentStart
+            },
+            (State::ExponentStart, b'+') => State::ExponentSign,
+            (State::ExponentStart, b'-') => {
+                self.is_negative_exponent = true;
+                State::ExponentSign
+            },
+            (State::ExponentStart, _)   |
+            (State::ExponentSign, _)    |
+            (State::Exponent, _) if exponent_digit.is_some() => {
+                let digit = exponent_digit.unwrap() as i64;
+                self.exponent = self.exponent.saturating_mul(exponent_radix as i64).saturating_add(digit);
+                State::Exponent
+            },
+            _ => return false,
+        };
+        true
+    }
+
+    // Process a byte of an integer.
+    #[inline]
+    fn next_integer(&mut self, c: u8) -> bool {
+        match self.options.to_digit(c) {
+            Some(digit) => {
+                self.push_digit(c, digit, false);
+                self.state = State::Integer;
+                true
+            },
+            None        => false,
+        }
+    }
+
+    // Add a mantissa digit.
+    //
+    // The leading zeros of integers are stored up to a limit, since the
+    // index of an overflow depends on the groups of digits parsed at once.
+    #[inline]
+    fn push_digit(&mut self, c: u8, digit: u32, is_fraction: bool) {
+        self.has_digits = true;
+        let count = self.buffer.len() - self.sign_len - self.leading_zeros;
+        let is_leading_zero = count == 0 && digit == 0;
+        let max_digits = self.buffer.capacity() - RESERVED;
+        if !N::IS_FLOAT {
+            if is_leading_zero && self.leading_zeros < max_digits - MAX_INTEGER_DIGITS {
+                self.buffer.push(c);
+                self.leading_zeros += 1;
+            } else if is_leading_zero {
+                self.zeros += 1;
+            } else if count < MAX_INTEGER_DIGITS {
+                self.buffer.push(c);
+            }
+        } else if is_leading_zero {
+            self.scale -= is_fraction as i64;
+        } else if count < max_digits {
+            self.buffer.push(c);
+            self.scale -= is_fraction as i64;
+        } else {
+            self.is_truncated |= digit != 0;
+            self.scale = self.scale.saturating_add(!is_fraction as i64);
+        }
+    }
+
+    // Determine if an integer is known to overflow.
+    #[inline]
+    fn is_overflow(&self) -> bool {
+        let count = self.buffer.len() - self.sign_len - self.leading_zeros;
+        !N::IS_FLOAT && count == MAX_INTEGER_DIGITS && !self.options.is_saturating()
+    }
+
+    // COMPLETE
+
+    // Parse the number, once it is complete.
+    //
+    // The number is followed by the end of input, or a byte which
+    // cannot continue the number.
+    fn complete(&mut self, is_end: bool) -> Result<(N, usize)> {
+        if is_end && self.count == self.sign_len {
+            return Err((ErrorCode::Empty, self.sign_len).into());
+        }
+
+        let options = self.options;
+        let options = match options.float_options() {
+            Some(options) => options,
+            None          => return self.complete_integer(),
+        };
+        let is_special = match self.first {
+            b'i' | b'I' | b'n' | b'N' => true,
+            _                         => false,
+        };
+        if let Some((index, len)) = self.special.matched {
+            self.complete_special(options, index, len)
+        } else if is_special && !cfg!(feature = "radix") {
+            Err((ErrorCode::InvalidDigit, self.sign_len).into())
+        } else if !self.has_digits {
+            Err((ErrorCode::EmptyMantissa, self.sign_len).into())
+        } else {
+            match self.state {
+                State::ExponentStart | State::ExponentSign => {
+                    Err((ErrorCode::EmptyExponent, self.exponent_index + 1).into())
+                },
+                _ => self.complete_float(options),
+            }
+        }
+    }
+
+    // Parse the canonical string, and then restore the buffer.
+    #[inline]
+    fn parse_buffer(&mut self, len: usize) -> Result<(N, usize)> {
+        let result = N::from_lexical_partial_with_options(&self.buffer, &self.options);
+        self.buffer.truncate(len);
+        result
+    }
+
+    // Parse an integer from the sign and the significant digits.
+    fn complete_integer(&mut self) -> Result<(N, usize)> {
+        let len = self.buffer.len();
+        if len == self.sign_len {
+            self.buffer.push(self.options.zero_char());
+        }
+        match self.parse_buffer(len) {
+            Ok((value, _))  => Ok((value, self.length)),
+            Err(error)      => Err((error.code, error.index + self.zeros).into()),
+        }
+    }
+
+    // Parse a float from the canonical string, `digits * radix^scale * base^exponent`.
+    fn complete_float(&mut self, options: &ParseFloatOptions) -> Result<(N, usize)> {
+        let len = self.buffer.len();
+        if len == self.sign_len {
+            self.buffer.push(b'0');
+            return self.parse_buffer(len).map(|(value, _)| (value, self.length));
+        }
+
+        // Digits past the maximum are represented by a non-zero digit.
+        let mut scale = self.scale;
+        if self.is_truncated {
+            self.buffer.push(b'1');
+            scale -= 1;
+        }
+        let exponent = match self.is_negative_exponent {
+            true  => -self.exponent,
+            false => self.exponent,
+        };
+
+        #[cfg(not(feature = "radix"))]
+        let exponent = scale.saturating_add(exponent);
+
+        // The radix and exponent base are powers of two, so pad the
+        // digits with zeros until the power of the radix is a power of
+        // the exponent base.
+        #[cfg(feature = "radix")]
+        let exponent = match (options.radix_u32(), options.exponent_base_u32()) {
+            (radix, base) if radix == base => scale.saturating_add(exponent),
+            (radix, base) => {
+                let radix_bits = radix.trailing_zeros() as i64;
+                let base_bits = base.trailing_zeros() as i64;
+                let mut gcd = (radix_bits, base_bits);
+                while gcd.1 != 0 {
+                    gcd = (gcd.1, gcd.0 % gcd.1);
+                }
+                let step = base_bits / gcd.0;
+                let padding = ((scale % step) + step) % step;
+                for _ in 0..padding {
+                    self.buffer.push(b'0');
+                }
+                let power = (scale - padding) / step;
+                power.saturating_mul(radix_bits / gcd.0).saturating_add(exponent)
+            },
+        };
+
+        #[cfg(not(feature = "radix"))]
+        let exponent_radix = options.radix_u32();
+        #[cfg(feature = "radix")]
+        let exponent_radix = options.exponent_radix_u32();
+
+        self.buffer.push(options.exponent_char());
+        if exponent < 0 {
+            self.buffer.push(b'-');
+        }
+        let mut digits = [0u8; 64];
+        let count = write_integer(exponent.wrapping_abs() as u64, exponent_radix, &mut digits);
+        self.buffer.extend(digits[..count].iter().cloned());
+        self.parse_buffer(len).map(|(value, _)| (value, self.length))
+    }
+
+    // Parse a special value from the sign, the special string and the payload.
+    fn complete_special(&mut self, options: &ParseFloatOptions, index: usize, len: usize)
+        -> Result<(N, usize)>
+    {
+        let string = match is_nan_string(options, index) {
+            true  => options.nan_string(),
+            false => options.inf_string(),
+        };
+        let mut bytes = [0u8; 64];
+        let mut count = self.sign_len;
+        bytes[..count].copy_from_slice(&self.buffer[..count]);
+        if self.special.is_signaling {
+            bytes[count] = b's';
+            count += 1;
+        }
+        bytes[count..count + string.len()].copy_from_slice(string);
+        count += string.len();
+        let mut length = self.sign_len + self.special.is_signaling as usize + len;
+        if let Some(payload) = self.special.payload() {
+            bytes[count..count + 3].copy_from_slice(b"(0x");
+            count += 3;
+            count += write_integer(payload, 16, &mut bytes[count..]);
+            bytes[count] = b')';
+            count += 1;
+            length += self.special.payload_len + 2;
+        }
+
+        N::from_lexical_partial_with_options(&bytes[..count], &self.options)
+            .map(|(value, _)| (value, length))
+    }
+}
+
+impl<N> Default for NumberParser<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    #[inline]
+    fn default() -> Self {
+        NumberParser::new()
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::*;
+
+    // Parse the chunks, returning the status after the last chunk.
+    fn feed<N>(chunks: &[&[u8]]) -> Result<ParseStatus<N>>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        feed_options(chunks, N::Options::default())
+    }
+
+    // Parse the chunks with the options, returning the status after the last chunk.
+    fn feed_options<N>(chunks: &[&[u8]], options: N::Options) -> Result<ParseStatus<N>>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        let mut parser = NumberParser::<N>::with_options(options).unwrap();
+        let mut status = Ok(ParseStatus::Incomplete);
+        for chunk in chunks {
+            status = parser.feed(chunk);
+        }
+        status
+    }
+
+    // Parse the chunks and then finish the parse.
+    fn finish<N>(chunks: &[&[u8]]) -> Result<N>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        finish_options(chunks, N::Options::default())
+    }
+
+    // Parse the chunks with the options, and then finish the parse.
+    fn finish_options<N>(chunks: &[&[u8]], options: N::Options) -> Result<N>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        let mut parser = NumberParser::<N>::with_options(options).unwrap();
+        for chunk in chunks {
+            let _ = parser.feed(chunk);
+        }
+        parser.finish()
+    }
+
+    #[test]
+    fn integer_test() {
+        assert_eq!(feed::<u32>(&[b"12", b"34"]), Ok(ParseStatus::Incomplete));
+        assert_eq!(feed::<u32>(&[b"12", b"34", b" 5"]), Ok(ParseStatus::Complete(1234, 4)));
+        assert_eq!(feed::<i32>(&[b"-", b"12."]), Ok(ParseStatus::Complete(-12, 3)));
+        assert_eq!(feed::<u32>(&[b"-", b"12"]), Ok(ParseStatus::Complete(0, 0)));
+        assert_eq!(feed::<u8>(&[b"25", b"6;"]), Err((ErrorCode::Overflow, 2).into()));
+        assert_eq!(finish::<u32>(&[b"12", b"34"]), Ok(1234));
+        assert_eq!(finish::<u32>(&[b"12", b"34", b" 5"]), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(finish::<i32>(&[b"+"]), Err((ErrorCode::Empty, 1).into()));
+        assert_eq!(finish::<i32>(&[]), Err((ErrorCode::Empty, 0).into()));
+    }
+
+    #[test]
+    fn float_test() {
+        assert_eq!(feed::<f64>(&[b"1", b".", b"5", b"e", b"-", b"1"]), Ok(ParseStatus::Incomplete));
+        assert_eq!(feed::<f64>(&[b"1.", b"5e-1", b","]), Ok(ParseStatus::Complete(0.15, 6)));
+        assert_eq!(feed::<f64>(&[b"1", b"E", b"2x"]), Ok(ParseStatus::Complete(100.0, 3)));
+        assert_eq!(feed::<f64>(&[b"1", b"e", b"x"]), Err((ErrorCode::EmptyExponent, 2).into()));
+        assert_eq!(feed::<f64>(&[b"-", b"in", b"finity,"]), Ok(ParseStatus::Complete(f64::NEG_INFINITY, 9)));
+        assert_eq!(feed::<f64>(&[b"in", b"fin", b"x"]), Ok(ParseStatus::Complete(f64::INFINITY, 3)));
+        assert_eq!(feed::<f64>(&[b"in", b"x"]).err(), f64::from_lexical_partial(b"inx").err());
+        assert_eq!(finish::<f64>(&[b"1.", b"5e-1"]), Ok(0.15));
+        assert_eq!(finish::<f64>(&[b"In", b"F"]), Ok(f64::INFINITY));
+        assert!(finish::<f64>(&[b"N", b"aN"]).unwrap().is_nan());
+        assert_eq!(finish::<f64>(&[b"."]), Err((ErrorCode::EmptyMantissa, 0).into()));
+        assert_eq!(finish::<f32>(&[b"1", b"e"]), Err((ErrorCode::EmptyExponent, 2).into()));
+    }
+
+    #[test]
+    fn reset_test() {
+        let mut parser = NumberParser::<f32>::new();
+        assert_eq!(parser.feed(b"1 2"), Ok(ParseStatus::Complete(1.0, 1)));
+        assert_eq!(parser.feed(b"3"), Ok(ParseStatus::Complete(1.0, 1)));
+        parser.reset();
+        assert_eq!(parser.feed(b"2"), Ok(ParseStatus::Incomplete));
+        assert_eq!(parser.finish(), Ok(2.0));
+    }
+
+    #[test]
+    fn long_test() {
+        // Halfway between two floats, which requires the slow path.
+        let bytes = b"9007199254740993.00000000000000000000000000000000000000000000000000000000000000000000000000000000001";
+        let expected = f64::from_lexical(bytes).unwrap();
+        let chunks: Vec<&[u8]> = bytes.chunks(7).collect();
+        assert_eq!(finish::<f64>(&chunks), Ok(expected));
+        let mut parser = NumberParser::<f64>::new();
+        for chunk in chunks.iter() {
+            assert_eq!(parser.feed(chunk), Ok(ParseStatus::Incomplete));
+        }
+        assert_eq!(parser.feed(b";"), Ok(ParseStatus::Complete(expected, bytes.len())));
+    }
+
+    #[cfg(feature = "correct")]
+    #[test]
+    fn many_digits_test() {
+        // More significant digits than are stored, halfway between two floats.
+        let mut bytes = vec![b'0'; 5000];
+        bytes[..17].copy_from_slice(b"9007199254740993.");
+        bytes.extend_from_slice(b"e-3");
+        let expected = f64::from_lexical(&bytes).unwrap();
+        let chunks: Vec<&[u8]> = bytes.chunks(100).collect();
+        assert_eq!(finish::<f64>(&chunks), Ok(expected));
+        bytes[4000] = b'1';
+        let expected = f64::from_lexical(&bytes).unwrap();
+        let chunks: Vec<&[u8]> = bytes.chunks(100).collect();
+        assert_eq!(finish::<f64>(&chunks), Ok(expected));
+
+        // More leading zeros than are stored.
+        let mut bytes = vec![b'0'; 5000];
+        bytes[1] = b'.';
+        bytes.extend_from_slice(b"15e4998");
+        let chunks: Vec<&[u8]> = bytes.chunks(100).collect();
+        assert_eq!(finish::<f64>(&chunks), Ok(0.15));
+        let mut bytes = vec![b'0'; 5000];
+        bytes.extend_from_slice(b"255");
+        let chunks: Vec<&[u8]> = bytes.chunks(100).collect();
+        assert_eq!(finish::<u8>(&chunks), Ok(255));
+
+        // Integers overflow once more digits are found than the type holds.
+        let bytes = vec![b'1'; 5000];
+        let chunks: Vec<&[u8]> = bytes.chunks(100).collect();
+        assert_eq!(feed::<u128>(&chunks), u128::from_lexical_partial(&bytes).map(|(v, n)| ParseStatus::Complete(v, n)));
+        assert_eq!(feed::<i8>(&[b"-00", b"129;"]), Err((ErrorCode::Underflow, 5).into()));
+    }
+
+    #[test]
+    fn options_test() {
+        let options = ParseFloatOptions::builder()
+            .exponent_default_char(b'^')
+            .nan_string(b"nan")
+            .inf_aliases(&[b"1.#INF"])
+            .nan_aliases(&[b"1.#QNAN"])
+            .build()
+            .unwrap();
+        assert_eq!(feed_options::<f64>(&[b"1.5", b"^", b"2;"], options), Ok(ParseStatus::Complete(150.0, 5)));
+        assert_eq!(feed_options::<f64>(&[b"1.5", b"e", b"2;"], options), Ok(ParseStatus::Complete(1.5, 3)));
+        assert_eq!(finish_options::<f64>(&[b"1.5", b"^"], options), Err((ErrorCode::EmptyExponent, 4).into()));
+        assert_eq!(feed_options::<f64>(&[b"-1", b".#", b"INF", b" "], options), Ok(ParseStatus::Complete(f64::NEG_INFINITY, 7)));
+        assert!(finish_options::<f64>(&[b"1", b".#q", b"nan"], options).unwrap().is_nan());
+        assert_eq!(finish_options::<f64>(&[b"1", b".#", b"5"], options), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(finish_options::<f64>(&[b"1", b".", b"5"], options), Ok(1.5));
+        assert!(finish_options::<f64>(&[b"N", b"aN"], options).unwrap().is_nan());
+
+        let options = ParseFloatOptions::builder()
+            .nan_payload(true)
+            .build()
+            .unwrap();
+        let parse = |bytes: &[u8]| f64::from_lexical_partial_with_options(bytes, &options).unwrap();
+        for bytes in [&b"nan(0x7F),"[..], b"nan(17),", b"nan(0x),", b"nan(017);", b"nan(x1);", b"nan(1;", b"nan;", b"snan(0x7F),", b"-sNaN(1),", b"snan;"].iter() {
+            let (expected, count) = parse(bytes);
+            match feed_options::<f64>(&[&bytes[..4], &bytes[4..]], options) {
+                Ok(ParseStatus::Complete(value, n)) => {
+                    assert_eq!(value.to_bits(), expected.to_bits());
+                    assert_eq!(n, count);
+                },
+                status => panic!("unexpected status {:?}", status),
+            }
+        }
+        for bytes in [&b"sna;"[..], b"sinf;", b"s;"].iter() {
+            let expected = f64::from_lexical_partial_with_options(bytes, &options).err();
+            assert_eq!(feed_options::<f64>(&[&bytes[..1], &bytes[1..]], options).err(), expected);
+        }
+
+        let options = ParseIntegerOptions::builder()
+            .overflow_mode(OverflowMode::Saturating)
+            .build()
+            .unwrap();
+        let bytes = vec![b'9'; 500];
+        let chunks: Vec<&[u8]> = bytes.chunks(7).collect();
+        assert_eq!(finish_options::<i16>(&chunks, options), Ok(i16::max_value()));
+        assert_eq!(finish_options::<i16>(&[b"-", &bytes], options), Ok(i16::min_value()));
+
+        let options = ParseIntegerOptions::builder()
+            .overflow_mode(OverflowMode::Wrapping)
+            .build()
+            .unwrap();
+        assert!(NumberParser::<i16>::with_options(options).is_none());
+    }
+
+    #[cfg(feature = "radix")]
+    #[test]
+    fn radix_test() {
+        let options = ParseFloatOptions::builder()
+            .radix(36)
+            .build()
+            .unwrap();
+        let expected = f64::from_lexical_partial_with_options(b"inf^1,", &options).unwrap();
+        assert_eq!(feed_options::<f64>(&[b"in", b"f^1,"], options), Ok(ParseStatus::Complete(expected.0, expected.1)));
+        let expected = f64::from_lexical_partial_with_options(b"inz^1,", &options).unwrap();
+        assert_eq!(feed_options::<f64>(&[b"in", b"z^1,"], options), Ok(ParseStatus::Complete(expected.0, expected.1)));
+
+        let options = ParseFloatOptions::builder()
+            .radix(2)
+            .exponent_base(16)
+            .exponent_radix(10)
+            .build()
+            .unwrap();
+        for bytes in [&b"1.1e1"[..], b"0.001e-1", b"101e2", b"1e-270", b"1.1e256"].iter() {
+            let expected = f64::from_lexical_with_options(bytes, &options);
+            assert_eq!(finish_options::<f64>(&[bytes], options), expected);
+        }
+
+        let options = ParseIntegerOptions::builder()
+            .alphabet(Some(DigitAlphabet::BASE64))
+            .build()
+            .unwrap();
+        let expected = u64::from_lexical_partial_with_options(b"AA+/9.", &options);
+        assert_eq!(feed_options::<u64>(&[b"AA+", b"/9."], options), expected.map(|(v, n)| ParseStatus::Complete(v, n)));
+
+        // The index of an overflow depends on the leading zeros.
+        let options = ParseIntegerOptions::builder()
+            .radix(2)
+            .build()
+            .unwrap();
+        let mut bytes = vec![b'0'; 10];
+        bytes.extend_from_slice(&[b'1'; 300]);
+        let expected = u128::from_lexical_partial_with_options(&bytes, &options);
+        let chunks: Vec<&[u8]> = bytes.chunks(7).collect();
+        assert_eq!(feed_options::<u128>(&chunks, options), expected.map(|(v, n)| ParseStatus::Complete(v, n)));
+    }
+
+    #[cfg(feature = "format")]
+    #[test]
+    fn format_test() {
+        let options = ParseFloatOptions::builder()
+            .format(NumberFormat::ignore(b'_').unwrap())
+            .build()
+            .unwrap();
+        assert!(NumberParser::<f64>::with_options(options).is_none());
+        let options = ParseIntegerOptions::builder()
+            .format(NumberFormat::ignore(b'_').unwrap())
+            .build()
+            .unwrap();
+        assert!(NumberParser::<u32>::with_options(options).is_none());
+    }
+
+    // Split the bytes into chunks at the given indexes.
+    fn split(bytes: &[u8], mut indexes: Vec<usize>) -> Vec<&[u8]> {
+        indexes.iter_mut().for_each(|x| *x %= bytes.len() + 1);
+        indexes.sort();
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        for index in indexes {
+            chunks.push(&bytes[start..index]);
+            start = index;
+        }
+        chunks.push(&bytes[start..]);
+        chunks
+    }
+
+    // Check the incremental parse matches the parse of the joined bytes.
+    fn check<N>(bytes: &[u8], indexes: Vec<usize>) -> bool
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        check_options::<N>(bytes, indexes, N::Options::default())
+    }
+
+    // Check the incremental parse with the options matches the parse of the joined bytes.
+    fn check_options<N>(bytes: &[u8], indexes: Vec<usize>, options: N::Options) -> bool
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        let chunks = split(bytes, indexes);
+        // The incorrect parser depends on the representation of the number.
+        let is_equal = |x: &N, y: &N| {
+            x == y || (x != x && y != y) || (N::IS_FLOAT && cfg!(not(feature = "correct")))
+        };
+        let is_status_equal = match (feed_options::<N>(&chunks, options), N::from_lexical_partial_with_options(bytes, &options)) {
+            (Ok(ParseStatus::Incomplete), _)                => true,
+            (Ok(ParseStatus::Complete(x, n)), Ok((y, m)))   => n == m && n < bytes.len() && is_equal(&x, &y),
+            (Err(x), Err(y))                                => x == y,
+            _                                               => false,
+        };
+        let is_finish_equal = match (finish_options::<N>(&chunks, options), N::from_lexical_with_options(bytes, &options)) {
+            (Ok(x), Ok(y))  => is_equal(&x, &y),
+            (x, y)          => x == y,
+        };
+        is_status_equal && is_finish_equal
+    }
+
+    #[cfg(feature = "std")]
+    proptest! {
+        #[test]
+        fn f64_proptest(i in r"[+-]?([0-9]{0,30}(\.[0-9]{0,30})?([eE][+-]?[0-9]{0,3})?|(?i:nan|inf|infinity))[ ,.eE+-]?[0-9]?", indexes: Vec<usize>) {
+            prop_assert!(check::<f64>(i.as_bytes(), indexes));
+        }
+
+        #[test]
+        fn f32_proptest(i in r"[+-]?[0-9]{0,10}(\.[0-9]{0,10})?([eE][+-]?[0-9]{0,2})?[ ,.eE+-]?", indexes: Vec<usize>) {
+            prop_assert!(check::<f32>(i.as_bytes(), indexes));
+        }
+
+        #[test]
+        fn i64_proptest(i in r"[+-]?[0-9]{0,20}[ ,.+-]?[0-9]?", indexes: Vec<usize>) {
+            prop_assert!(check::<i64>(i.as_bytes(), indexes));
+        }
+
+        #[test]
+        fn f64_options_proptest(i in r"[+-]?([0-9]{0,30}(\.[0-9]{0,30})?([eE^][+-]?[0-9]{0,3})?|(?i:s?nan|inf|infinity|1\.#inf))(\([0-9a-z_]{0,3}\)?)?[ ,.eE^+-]?[0-9]?", indexes: Vec<usize>) {
+            let options = ParseFloatOptions::builder()
+                .exponent_default_char(b'^')
+                .inf_aliases(&[b"1.#INF"])
+                .nan_payload(true)
+                .build()
+                .unwrap();
+            prop_assert!(check_options::<f64>(i.as_bytes(), indexes, options));
+        }
+
+        #[test]
+        fn u8_proptest(i in r"[+-]?[0-9]{0,4}[ ,.+-]?", indexes: Vec<usize>) {
+            prop_assert!(check::<u8>(i.as_bytes(), indexes));
+        }
+    }
+}
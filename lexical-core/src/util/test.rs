@@ -2,11 +2,7 @@
 
 use super::config::BUFFER_SIZE;
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-    use arrayvec;
-    use super::sequence::{CloneableVecLike, VecLike};
-}}  // cfg_if
+use super::sequence::{CloneableVecLike, StackVec, VecLike};
 
 // BASES
 
@@ -40,16 +36,13 @@ pub(crate) fn as_slice<'a, T>(x: &'a [T]) -> &'a [T] {
     x
 }
 
-cfg_if! {
-if #[cfg(feature = "correct")] {
-
 // FROM U32
 
 #[cfg(limb_width_32)]
-pub(crate) type DataType = arrayvec::ArrayVec<[u32; 128]>;
+pub(crate) type DataType = StackVec<[u32; 128]>;
 
 #[cfg(limb_width_64)]
-pub(crate) type DataType = arrayvec::ArrayVec<[u64; 64]>;
+pub(crate) type DataType = StackVec<[u64; 64]>;
 
 
 #[cfg(limb_width_32)]
@@ -84,7 +77,6 @@ pub(crate) fn deduce_from_u32<T: CloneableVecLike<u64>>(x: &[u32]) -> T
     from_u32(x).iter().cloned().collect()
 }
 
-}}  // cfg_if
 
 // LITERAL BYTE SLICES
 
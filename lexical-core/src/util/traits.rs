@@ -462,6 +462,84 @@ macro_rules! from_lexical_lossy_format {
     )
 }
 
+// FROM LEXICAL OPTIONS
+
+/// Trait for numerical types that can be parsed from bytes with custom options.
+///
+/// The options are passed to each call, and therefore do not require
+/// modifying any global state.
+pub trait FromLexicalOptions: FromLexical {
+    /// Custom options to control parsing.
+    type Options;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing. The parser is
+    /// configured by the options, rather than any global settings.
+    ///
+    /// Returns a `Result` containing either the parsed value,
+    /// or an error containing any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_with_options(bytes: &[u8], options: &Self::Options) -> Result<Self>;
+
+    /// Checked parser for a string-to-number conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point. The parser is
+    /// configured by the options, rather than any global settings.
+    ///
+    /// Returns a `Result` containing either the parsed value
+    /// and the number of processed digits, or an error containing
+    /// any errors that occurred during parsing.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_partial_with_options(bytes: &[u8], options: &Self::Options) -> Result<(Self, usize)>;
+
+    /// Get the index of the start of the number, after any leading
+    /// whitespace and opening quote allowed by the options.
+    #[doc(hidden)]
+    fn number_start(bytes: &[u8], options: &Self::Options) -> usize;
+}
+
+// Implement FromLexicalOptions for numeric type.
+macro_rules! from_lexical_options {
+    ($cb:expr, $options:ty, $t:ty) => (
+        impl FromLexicalOptions for $t {
+            type Options = $options;
+
+            #[inline]
+            fn from_lexical_with_options(bytes: &[u8], options: &$options) -> Result<$t>
+            {
+                to_complete!($cb, bytes, options)
+            }
+
+            #[inline]
+            fn from_lexical_partial_with_options(bytes: &[u8], options: &$options) -> Result<($t, usize)>
+            {
+                $cb(bytes, options)
+            }
+
+            #[inline]
+            fn number_start(bytes: &[u8], options: &$options) -> usize
+            {
+                #[cfg(feature = "format")]
+                return number_start(bytes, options.number_format(), options.whitespace());
+
+                #[cfg(not(feature = "format"))]
+                return {
+                    let _ = (bytes, options);
+                    0
+                };
+            }
+        }
+    )
+}
+
 // TO LEXICAL
 
 /// Trait for numerical types that can be serialized to bytes.
@@ -541,3 +619,345 @@ macro_rules! to_lexical {
         }
     )
 }
+
+// TO LEXICAL OPTIONS
+
+/// Trait for numerical types that can be serialized to bytes with custom options.
+///
+/// The options are passed to each call, and therefore do not require
+/// modifying any global state.
+pub trait ToLexicalOptions: ToLexical {
+    /// Custom options to control serialization.
+    type Options;
+
+    /// Get the number of bytes required to serialize any value with the options.
+    ///
+    /// This is [`FORMATTED_SIZE_DECIMAL`] for decimal numbers, or
+    /// [`FORMATTED_SIZE`] for any other radix, unless the options
+    /// require more space (for example, floats with a large precision).
+    ///
+    /// [`FORMATTED_SIZE`]: trait.Number.html#associatedconstant.FORMATTED_SIZE
+    /// [`FORMATTED_SIZE_DECIMAL`]: trait.Number.html#associatedconstant.FORMATTED_SIZE_DECIMAL
+    fn buffer_size(options: &Self::Options) -> usize;
+
+    /// Serializer for a number-to-string conversion.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice. The
+    /// serializer is configured by the options, rather than any global
+    /// settings.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number serialization.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`buffer_size`] elements.
+    ///
+    /// [`buffer_size`]: #tymethod.buffer_size
+    fn to_lexical_with_options<'a>(self, bytes: &'a mut [u8], options: &Self::Options) -> &'a mut [u8];
+}
+
+// Get the number of bytes required to serialize a number in the radix.
+#[inline]
+#[allow(unused_variables)]
+pub(crate) fn formatted_size<T: Number>(radix: u32) -> usize {
+    #[cfg(feature = "radix")]
+    match radix {
+        10 => T::FORMATTED_SIZE_DECIMAL,
+        _  => T::FORMATTED_SIZE,
+    }
+
+    #[cfg(not(feature = "radix"))]
+    T::FORMATTED_SIZE
+}
+
+// Implement ToLexicalOptions for numeric type.
+macro_rules! to_lexical_options {
+    ($cb:expr, $size:expr, $options:ty, $t:ty) => (
+        impl ToLexicalOptions for $t {
+            type Options = $options;
+
+            #[inline]
+            fn buffer_size(options: &$options) -> usize {
+                $size(options)
+            }
+
+            #[inline]
+            fn to_lexical_with_options<'a>(self, bytes: &'a mut [u8], options: &$options)
+                -> &'a mut [u8]
+            {
+                assert!(bytes.len() >= Self::buffer_size(options));
+                let len = $cb(self, bytes, options);
+                &mut index_mut!(bytes[..len])
+            }
+        }
+    )
+}
+
+// TO LEXICAL FORMAT
+
+/// Trait for numerical types that can be serialized using a custom format specification.
+///
+/// The output is guaranteed to be valid under the same format, so it
+/// may be parsed back with [`FromLexicalFormat`].
+///
+/// [`FromLexicalFormat`]: trait.FromLexicalFormat.html
+#[cfg(feature = "format")]
+pub trait ToLexicalFormat: ToLexicalOptions {
+    /// Get the number of bytes required to serialize any value with the format.
+    ///
+    /// * `radix`   - Radix for number encoding.
+    /// * `format`  - Numerical format.
+    fn format_buffer_size(radix: u8, format: NumberFormat) -> usize;
+
+    /// Serializer for a number-to-string conversion with a custom format.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice, or
+    /// `None` if the value cannot be represented in the format (for
+    /// example, a special float when the format does not allow special
+    /// values). The numerical format is specified by the format bitflags,
+    /// which customize the required signs, and whether exponent notation
+    /// may be used.
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `format`  - Numerical format.
+    /// * `bytes`   - Slice containing a numeric string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`format_buffer_size`] elements.
+    ///
+    /// [`format_buffer_size`]: #tymethod.format_buffer_size
+    fn to_lexical_format<'a>(self, format: NumberFormat, bytes: &'a mut [u8]) -> Option<&'a mut [u8]>;
+
+    /// Serializer for a number-to-string conversion with a custom radix and format.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice, or
+    /// `None` if the value cannot be represented in the format (for
+    /// example, a special float when the format does not allow special
+    /// values, or a float in a radix other than 10 when the format does
+    /// not allow exponent notation).
+    ///
+    /// * `value`   - Number to serialize.
+    /// * `radix`   - Radix for number encoding.
+    /// * `format`  - Numerical format.
+    /// * `bytes`   - Slice containing a numeric string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    ///
+    /// Also panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`format_buffer_size`] elements.
+    ///
+    /// [`format_buffer_size`]: #tymethod.format_buffer_size
+    #[cfg(feature = "radix")]
+    fn to_lexical_format_radix<'a>(self, radix: u8, format: NumberFormat, bytes: &'a mut [u8]) -> Option<&'a mut [u8]>;
+}
+
+// Implement ToLexicalFormat for numeric type.
+#[cfg(feature = "format")]
+macro_rules! to_lexical_format {
+    ($cb:expr, $options:ident, $t:ty) => (
+        impl ToLexicalFormat for $t {
+            #[inline]
+            fn format_buffer_size(radix: u8, format: NumberFormat) -> usize {
+                Self::buffer_size(&$options::from_config(radix.as_u32(), format))
+            }
+
+            #[inline]
+            fn to_lexical_format<'a>(self, format: NumberFormat, bytes: &'a mut [u8])
+                -> Option<&'a mut [u8]>
+            {
+                let options = $options::from_config(10, format);
+                assert!(bytes.len() >= Self::buffer_size(&options));
+                let len = $cb(self, bytes, &options)?;
+                Some(&mut index_mut!(bytes[..len]))
+            }
+
+            #[cfg(feature = "radix")]
+            #[inline]
+            fn to_lexical_format_radix<'a>(self, radix: u8, format: NumberFormat, bytes: &'a mut [u8])
+                -> Option<&'a mut [u8]>
+            {
+                assert_radix!(radix);
+                let options = $options::from_config(radix.as_u32(), format);
+                assert!(bytes.len() >= Self::buffer_size(&options));
+                let len = $cb(self, bytes, &options)?;
+                Some(&mut index_mut!(bytes[..len]))
+            }
+        }
+    )
+}
+
+// FROM LEXICAL VALUE
+
+/// Trait for types that are parsed from bytes through a number.
+///
+/// Unlike [`FromLexical`], the type does not need to be a number, such
+/// as a newtype around a number or a non-zero integer, and may reject
+/// values of the number it is parsed from. These types are parsed with
+/// [`parse_value`], or with [`parse_value_with_options`] for a custom
+/// radix or numerical format, rather than [`parse`] and the radix and
+/// format variants, which require a number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical_core;
+/// # use lexical_core::{ErrorCode, FromLexicalValue};
+/// # use std::num::NonZeroU8;
+/// # pub fn main() {
+/// assert_eq!(NonZeroU8::from_lexical(b"12"), Ok(NonZeroU8::new(12).unwrap()));
+/// assert_eq!(NonZeroU8::from_lexical_partial(b"12,"), Ok((NonZeroU8::new(12).unwrap(), 2)));
+///
+/// let error = NonZeroU8::from_lexical(b"0").err().unwrap();
+/// assert_eq!(error.code, ErrorCode::InvalidZero);
+/// # }
+/// ```
+///
+/// [`FromLexical`]: trait.FromLexical.html
+/// [`parse`]: fn.parse.html
+/// [`parse_value`]: fn.parse_value.html
+/// [`parse_value_with_options`]: fn.parse_value_with_options.html
+pub trait FromLexicalValue: Sized {
+    /// Checked parser for a string-to-value conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, or if the value
+    /// is rejected.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical(bytes: &[u8]) -> Result<Self>;
+
+    /// Checked parser for a string-to-value conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point, or an error if the
+    /// value is rejected.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    fn from_lexical_partial(bytes: &[u8]) -> Result<(Self, usize)>;
+}
+
+// Implement FromLexicalValue for value type.
+macro_rules! from_lexical_value {
+    ($cb:expr, $t:ty) => (
+        impl FromLexicalValue for $t {
+            #[inline]
+            fn from_lexical(bytes: &[u8]) -> Result<$t>
+            {
+                to_complete!($cb, bytes, 10)
+            }
+
+            #[inline]
+            fn from_lexical_partial(bytes: &[u8]) -> Result<($t, usize)>
+            {
+                $cb(bytes, 10)
+            }
+        }
+    )
+}
+
+// FROM LEXICAL VALUE OPTIONS
+
+/// Trait for types that are parsed from bytes through a number with custom options.
+///
+/// The options are passed to each call, and therefore do not require
+/// modifying any global state. For non-zero integers, the options are
+/// [`ParseIntegerOptions`], which set the radix and numerical format.
+///
+/// [`ParseIntegerOptions`]: struct.ParseIntegerOptions.html
+pub trait FromLexicalValueOptions: FromLexicalValue {
+    /// Custom options to control parsing.
+    type Options;
+
+    /// Checked parser for a string-to-value conversion.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing, or if the value
+    /// is rejected. The parser is configured by the options, rather
+    /// than any global settings.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_with_options(bytes: &[u8], options: &Self::Options) -> Result<Self>;
+
+    /// Checked parser for a string-to-value conversion.
+    ///
+    /// This method parses until an invalid digit is found (or the end
+    /// of the string), returning the number of processed digits
+    /// and the parsed value until that point, or an error if the
+    /// value is rejected. The parser is configured by the options,
+    /// rather than any global settings.
+    ///
+    /// * `bytes`   - Slice containing a numeric string.
+    /// * `options` - Options to customize number parsing.
+    fn from_lexical_partial_with_options(bytes: &[u8], options: &Self::Options) -> Result<(Self, usize)>;
+}
+
+// Implement FromLexicalValueOptions for value type.
+macro_rules! from_lexical_value_options {
+    ($cb:expr, $options:ty, $t:ty) => (
+        impl FromLexicalValueOptions for $t {
+            type Options = $options;
+
+            #[inline]
+            fn from_lexical_with_options(bytes: &[u8], options: &$options) -> Result<$t>
+            {
+                to_complete!($cb, bytes, options)
+            }
+
+            #[inline]
+            fn from_lexical_partial_with_options(bytes: &[u8], options: &$options) -> Result<($t, usize)>
+            {
+                $cb(bytes, options)
+            }
+        }
+    )
+}
+
+// TO LEXICAL VALUE
+
+/// Trait for types that are serialized to bytes through a number.
+///
+/// Unlike [`ToLexical`], the type does not need to be a number, such
+/// as a newtype around a number.
+///
+/// [`ToLexical`]: trait.ToLexical.html
+pub trait ToLexicalValue: Sized {
+    /// Get the number of bytes required to serialize any value.
+    fn buffer_size() -> usize;
+
+    /// Serializer for a value-to-string conversion.
+    ///
+    /// Returns a subslice of the input buffer containing the written bytes,
+    /// starting from the same address in memory as the input slice, or
+    /// `None` if the value cannot be represented.
+    ///
+    /// * `value`   - Value to serialize.
+    /// * `bytes`   - Slice containing a numeric string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not of sufficient size. The caller
+    /// must provide a slice of sufficient size. In order to ensure
+    /// the function will not panic, ensure the buffer has at least
+    /// [`buffer_size`] elements.
+    ///
+    /// [`buffer_size`]: #tymethod.buffer_size
+    fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> Option<&'a mut [u8]>;
+}
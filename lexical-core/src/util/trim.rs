@@ -0,0 +1,123 @@
+//! Whitespace and surrounding quotes around a number.
+
+use super::error::ErrorCode;
+use super::format::NumberFormat;
+use super::result::Result;
+
+/// ASCII whitespace characters: space, `\t`, `\n`, `\x0B`, `\x0C` and `\r`.
+pub(crate) const ASCII_WHITESPACE: &[u8] = b" \t\n\x0B\x0C\r";
+
+// Get the number of whitespace characters at the start of the bytes.
+#[inline]
+fn whitespace_len(bytes: &[u8], whitespace: &[u8]) -> usize {
+    bytes.iter()
+        .take_while(|c| whitespace.contains(c))
+        .count()
+}
+
+/// Get the index of the start of the number.
+///
+/// Skips leading whitespace and an opening quote, if allowed by the format.
+#[inline]
+pub(crate) fn number_start(bytes: &[u8], format: NumberFormat, whitespace: &[u8]) -> usize {
+    let mut start = 0;
+    if format.leading_whitespace() {
+        start += whitespace_len(bytes, whitespace);
+    }
+    if format.surrounding_quotes() && bytes.get(start) == Some(&b'"') {
+        start += 1;
+    }
+    start
+}
+
+/// Parse a number surrounded by whitespace and quotes.
+///
+/// Skips leading whitespace and an opening quote, if allowed by the
+/// format, and parses the number after them with the callback. If
+/// there was an opening quote, the quoted number cannot be empty, and
+/// the closing quote is required after it. Any trailing whitespace
+/// allowed by the format is then consumed, if a number was parsed.
+/// Error indexes and the number of processed bytes are relative to
+/// the start of the bytes.
+#[inline]
+pub(crate) fn parse_trimmed<'a, T, Cb>(bytes: &'a [u8], format: NumberFormat, whitespace: &[u8], cb: Cb)
+    -> Result<(T, usize)>
+    where Cb: FnOnce(&'a [u8]) -> Result<(T, usize)>
+{
+    let start = number_start(bytes, format, whitespace);
+    // Whitespace cannot be a quote, so the number is quoted if the
+    // last skipped character is a quote.
+    let is_quoted = format.surrounding_quotes() && start != 0 && index!(bytes[start - 1]) == b'"';
+
+    let (value, count) = match cb(&index!(bytes[start..])) {
+        Ok(result) => result,
+        Err(error) => return Err((error.code, start + error.index).into()),
+    };
+
+    let mut end = start + count;
+    if is_quoted {
+        match bytes.get(end) {
+            Some(&b'"') if count == 0 => return Err((ErrorCode::Empty, start).into()),
+            Some(&b'"')               => end += 1,
+            _                         => return Err((ErrorCode::InvalidDigit, end).into()),
+        }
+    }
+    if format.trailing_whitespace() && count != 0 {
+        end += whitespace_len(&index!(bytes[end..]), whitespace);
+    }
+    Ok((value, end))
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Parse the leading ASCII digits, as a simple number parser.
+    fn parse_digits(bytes: &[u8]) -> Result<(usize, usize)> {
+        let count = bytes.iter().take_while(|c| c.is_ascii_digit()).count();
+        match count {
+            0 => Err((ErrorCode::Empty, 0).into()),
+            _ => Ok((count, count)),
+        }
+    }
+
+    fn parse(bytes: &[u8], format: NumberFormat) -> Result<(usize, usize)> {
+        parse_trimmed(bytes, format, ASCII_WHITESPACE, parse_digits)
+    }
+
+    #[test]
+    fn parse_trimmed_test() {
+        let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(parse(b"42", format), Ok((2, 2)));
+        assert_eq!(parse(b" \t42\r\n", format), Ok((2, 6)));
+        assert_eq!(parse(b"\"42\"", format), Ok((2, 4)));
+        assert_eq!(parse(b" \"42\" ", format), Ok((2, 6)));
+        assert_eq!(parse(b"\"42", format), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"\"42a\"", format), Err((ErrorCode::InvalidDigit, 3).into()));
+        assert_eq!(parse(b"\"\"", format), Err((ErrorCode::Empty, 1).into()));
+        assert_eq!(parse(b"\" 42\"", format), Err((ErrorCode::Empty, 1).into()));
+        assert_eq!(parse(b"  ", format), Err((ErrorCode::Empty, 2).into()));
+        assert_eq!(parse(b"42\"", format), Ok((2, 2)));
+
+        assert_eq!(parse(b" 42", NumberFormat::TRAILING_WHITESPACE), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse(b"42 ", NumberFormat::TRAILING_WHITESPACE), Ok((2, 3)));
+        assert_eq!(parse(b" 42 ", NumberFormat::LEADING_WHITESPACE), Ok((2, 3)));
+        assert_eq!(parse(b"\"42\"", NumberFormat::STANDARD), Err((ErrorCode::Empty, 0).into()));
+
+        let result = parse_trimmed(b"__42_", NumberFormat::WHITESPACE_FLAG_MASK, b"_", parse_digits);
+        assert_eq!(result, Ok((2, 5)));
+    }
+
+    #[test]
+    fn number_start_test() {
+        let format = NumberFormat::WHITESPACE_FLAG_MASK | NumberFormat::SURROUNDING_QUOTES;
+        assert_eq!(number_start(b"42", format, ASCII_WHITESPACE), 0);
+        assert_eq!(number_start(b" \t42", format, ASCII_WHITESPACE), 2);
+        assert_eq!(number_start(b" \"42\"", format, ASCII_WHITESPACE), 2);
+        assert_eq!(number_start(b" \"42\"", NumberFormat::LEADING_WHITESPACE, ASCII_WHITESPACE), 1);
+        assert_eq!(number_start(b" 42", NumberFormat::STANDARD, ASCII_WHITESPACE), 0);
+    }
+}
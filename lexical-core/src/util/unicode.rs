@@ -0,0 +1,433 @@
+//! Parsing numbers from UTF-16 and from Unicode digit scripts.
+//!
+//! Code units are converted to the canonical ASCII format, one byte
+//! per character, so the existing parsers handle every code unit type
+//! and digit script. Numbers are parsed incrementally, so transcoding
+//! stops at the end of the number. Options that cannot be parsed
+//! incrementally use a buffer on the stack, which falls back to an
+//! allocated buffer for very long numbers if the `std` feature is
+//! enabled.
+
+use crate::lib::{char, str};
+use super::error::ErrorCode;
+use super::result::Result;
+use super::stream::{NumberParser, ParseStatus, StreamOptions};
+use super::traits::FromLexicalOptions;
+
+#[cfg(feature = "std")]
+use crate::lib::vec::Vec;
+
+// CODE UNIT
+
+mod private {
+    use super::*;
+
+    pub trait CodeUnitImpl: Copy {
+        // Decode the character at the start of the code units.
+        //
+        // Returns the character and its number of code units, or `None`
+        // if the code units do not start with a valid character.
+        fn decode(units: &[Self]) -> Option<(char, usize)>;
+    }
+
+    impl CodeUnitImpl for u8 {
+        #[inline]
+        fn decode(units: &[u8]) -> Option<(char, usize)> {
+            let count = match *units.first()? {
+                0x00 ..= 0x7F   => 1,
+                0xC0 ..= 0xDF   => 2,
+                0xE0 ..= 0xEF   => 3,
+                0xF0 ..= 0xF7   => 4,
+                _               => return None,
+            };
+            let c = str::from_utf8(units.get(..count)?).ok()?.chars().next()?;
+            Some((c, count))
+        }
+    }
+
+    impl CodeUnitImpl for u16 {
+        #[inline]
+        fn decode(units: &[u16]) -> Option<(char, usize)> {
+            let c = char::decode_utf16(units.iter().cloned()).next()?.ok()?;
+            Some((c, c.len_utf16()))
+        }
+    }
+}
+
+/// Code unit of an encoded string, which numbers can be parsed from.
+///
+/// Implemented for UTF-8 (`u8`) and UTF-16 (`u16`) code units. Indexes
+/// and the number of processed code units are reported in code units,
+/// not characters.
+pub trait CodeUnit: private::CodeUnitImpl {
+}
+
+impl CodeUnit for u8 {
+}
+
+impl CodeUnit for u16 {
+}
+
+// DIGIT SCRIPT
+
+/// Characters accepted when parsing numbers from code units.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DigitScript {
+    /// Only ASCII characters, such as `-123.45`.
+    Ascii,
+    /// Unicode characters mapped to ASCII, such as fullwidth `１２３`
+    /// or Arabic-Indic `٣٫١٤`.
+    ///
+    /// Decimal digits (the `Nd` category) of any script are mapped to
+    /// their ASCII digits, and may be mixed within a number. Fullwidth
+    /// forms are mapped to their ASCII characters, the Arabic decimal
+    /// separator (`U+066B`) and the small full stop (`U+FE52`) to `.`,
+    /// and the minus sign (`U+2212`) to `-`.
+    Unicode,
+}
+
+// Zero of each range of 10 decimal digits (the `Nd` category), from Unicode 14.0.
+const DIGIT_ZEROS: [u32; 66] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6,
+    0x0C66, 0x0CE6, 0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0,
+    0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620,
+    0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10, 0x104A0, 0x10D30, 0x11066,
+    0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650, 0x116C0, 0x11730, 0x118E0,
+    0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2,
+    0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+// Get the value of a Unicode decimal digit.
+#[inline]
+fn unicode_digit(c: u32) -> Option<u8> {
+    let index = match DIGIT_ZEROS.binary_search(&c) {
+        Ok(index)   => index,
+        Err(0)      => return None,
+        Err(index)  => index - 1,
+    };
+    match c - DIGIT_ZEROS[index] {
+        digit @ 0 ..= 9 => Some(digit as u8),
+        _               => None,
+    }
+}
+
+// Get the ASCII character for a character in the script.
+#[inline]
+fn to_ascii(c: char, script: DigitScript) -> Option<u8> {
+    let c = c as u32;
+    if c < 0x80 {
+        return Some(c as u8);
+    } else if script == DigitScript::Ascii {
+        return None;
+    } else if let Some(digit) = unicode_digit(c) {
+        return Some(b'0' + digit);
+    }
+    match c {
+        0xFF01 ..= 0xFF5E   => Some((c - 0xFEE0) as u8),
+        0x066B | 0xFE52     => Some(b'.'),
+        0x2212              => Some(b'-'),
+        _                   => None,
+    }
+}
+
+// Iterator over the characters of a number in code units.
+//
+// Each item is the number of code units and the equivalent ASCII byte
+// for a character. The iterator stops at the first code unit that does
+// not start a valid character, or a character without an equivalent.
+struct Characters<'a, U: CodeUnit> {
+    units: &'a [U],
+    script: DigitScript,
+}
+
+impl<'a, U: CodeUnit> Characters<'a, U> {
+    #[inline]
+    fn new(units: &'a [U], script: DigitScript) -> Self {
+        Characters { units, script }
+    }
+}
+
+impl<'a, U: CodeUnit> Iterator for Characters<'a, U> {
+    type Item = (usize, u8);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (c, count) = U::decode(self.units)?;
+        let ascii = to_ascii(c, self.script)?;
+        self.units = &self.units[count..];
+        Some((count, ascii))
+    }
+}
+
+// PARSE
+
+// Size of the stack buffer for canonical numbers.
+const TRANSCODE_SIZE: usize = 1024;
+
+// Convert code units to the canonical ASCII format.
+//
+// Returns the canonical number and if every character fit in the buffer.
+#[inline]
+fn transcode<'a, U: CodeUnit>(units: &[U], script: DigitScript, buffer: &'a mut [u8]) -> (&'a [u8], bool) {
+    let mut len = 0;
+    for (_, ascii) in Characters::new(units, script) {
+        match buffer.get_mut(len) {
+            Some(dst) => *dst = ascii,
+            None      => return (buffer, false),
+        }
+        len += 1;
+    }
+    (&buffer[..len], true)
+}
+
+// Convert code units to the canonical ASCII format, in an allocated buffer.
+#[inline]
+#[cfg(feature = "std")]
+fn transcode_vec<U: CodeUnit>(units: &[U], script: DigitScript) -> Vec<u8> {
+    Characters::new(units, script)
+        .map(|(_, ascii)| ascii)
+        .collect()
+}
+
+// Get the index in code units from the index in the canonical number.
+#[inline]
+fn unit_index<U: CodeUnit>(units: &[U], script: DigitScript, index: usize) -> usize {
+    Characters::new(units, script)
+        .take(index)
+        .map(|(count, _)| count)
+        .sum()
+}
+
+// Parse a canonical number incrementally, one character at a time.
+//
+// Transcoding stops at the first character that cannot be part of
+// the number, so the length of the input is not limited.
+#[inline]
+fn parse_streamed<N, U>(units: &[U], script: DigitScript, mut parser: NumberParser<N>)
+    -> Result<(N, usize)>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit
+{
+    for (_, ascii) in Characters::new(units, script) {
+        match parser.feed(&[ascii]) {
+            Ok(ParseStatus::Incomplete) => continue,
+            _                           => break,
+        }
+    }
+    parser.finish_partial()
+}
+
+// Parse a canonical number from a buffer, for options that cannot be
+// parsed incrementally.
+//
+// The parsers do not backtrack, so if the number ends before the last
+// byte in the full buffer, the remaining characters cannot change the
+// result. Only a number longer than the buffer requires allocating,
+// and overflows the buffer without the `std` feature.
+#[inline]
+fn parse_buffered<N, U>(units: &[U], script: DigitScript, options: &N::Options)
+    -> Result<(N, usize)>
+    where N: FromLexicalOptions,
+          U: CodeUnit
+{
+    let mut buffer = [0u8; TRANSCODE_SIZE];
+    let (canonical, is_complete) = transcode(units, script, &mut buffer);
+    let result = N::from_lexical_partial_with_options(canonical, options);
+    let index = match result {
+        Ok((_, count)) => count,
+        Err(error)     => error.index,
+    };
+    if is_complete || index < TRANSCODE_SIZE - 1 {
+        return result;
+    }
+
+    #[cfg(feature = "std")]
+    return N::from_lexical_partial_with_options(&transcode_vec(units, script), options);
+
+    #[cfg(not(feature = "std"))]
+    return Err((ErrorCode::Overflow, TRANSCODE_SIZE).into());
+}
+
+/// Parse a number from code units.
+///
+/// Returns the value and the number of processed code units. Error
+/// indexes are converted from bytes to code units.
+pub(crate) fn parse_partial_transcoded<N, U>(units: &[U], script: DigitScript, options: &N::Options)
+    -> Result<(N, usize)>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit
+{
+    let result = match NumberParser::<N>::with_options(*options) {
+        Some(parser) => parse_streamed(units, script, parser),
+        None         => parse_buffered(units, script, options),
+    };
+
+    let index = | index | unit_index(units, script, index);
+    match result {
+        Ok((value, count)) => Ok((value, index(count))),
+        Err(error)         => Err((error.code, index(error.index)).into()),
+    }
+}
+
+/// Parse a complete number from code units.
+pub(crate) fn parse_transcoded<N, U>(units: &[U], script: DigitScript, options: &N::Options)
+    -> Result<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit
+{
+    let (value, count) = parse_partial_transcoded(units, script, options)?;
+    match count == units.len() {
+        true  => Ok(value),
+        false => Err((ErrorCode::InvalidDigit, count).into()),
+    }
+}
+
+// TESTS
+// -----
+
+#[cfg(test)]
+mod tests {
+    use crate::util::*;
+    use super::*;
+
+    #[cfg(feature = "std")]
+    use crate::lib::iter;
+    #[cfg(feature = "std")]
+    use crate::lib::string::String;
+
+    fn parse<N, U>(units: &[U], script: DigitScript) -> Result<N>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions,
+              U: CodeUnit
+    {
+        parse_transcoded(units, script, &N::Options::default())
+    }
+
+    fn parse_partial<N, U>(units: &[U], script: DigitScript) -> Result<(N, usize)>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions,
+              U: CodeUnit
+    {
+        parse_partial_transcoded(units, script, &N::Options::default())
+    }
+
+    // Encode a string as UTF-16.
+    fn utf16(string: &str, buffer: &mut [u16]) -> usize {
+        let mut len = 0;
+        for unit in string.encode_utf16() {
+            buffer[len] = unit;
+            len += 1;
+        }
+        len
+    }
+
+    fn parse_utf16<N>(string: &str, script: DigitScript) -> Result<N>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        let mut buffer = [0u16; 64];
+        let len = utf16(string, &mut buffer);
+        parse(&buffer[..len], script)
+    }
+
+    fn parse_partial_utf16<N>(string: &str, script: DigitScript) -> Result<(N, usize)>
+        where N: FromLexicalOptions,
+              N::Options: StreamOptions
+    {
+        let mut buffer = [0u16; 64];
+        let len = utf16(string, &mut buffer);
+        parse_partial(&buffer[..len], script)
+    }
+
+    #[test]
+    fn unicode_digit_test() {
+        assert_eq!(unicode_digit('0' as u32), Some(0));
+        assert_eq!(unicode_digit('9' as u32), Some(9));
+        assert_eq!(unicode_digit(':' as u32), None);
+        assert_eq!(unicode_digit('\u{0663}' as u32), Some(3));
+        assert_eq!(unicode_digit('\u{FF19}' as u32), Some(9));
+        assert_eq!(unicode_digit('\u{1D7CF}' as u32), Some(1));
+        assert_eq!(unicode_digit('\u{1D7FF}' as u32), Some(9));
+        assert_eq!(unicode_digit('\u{00B2}' as u32), None);
+        assert_eq!(unicode_digit('\u{2160}' as u32), None);
+        assert_eq!(unicode_digit('\u{1FBFA}' as u32), None);
+    }
+
+    #[test]
+    fn parse_utf8_test() {
+        let unicode = DigitScript::Unicode;
+        assert_eq!(parse::<i32, u8>(b"-123", DigitScript::Ascii), Ok(-123));
+        assert_eq!(parse::<i32, u8>("\u{FF11}\u{FF12}\u{FF13}".as_bytes(), DigitScript::Ascii), Err((ErrorCode::Empty, 0).into()));
+        assert_eq!(parse::<i32, u8>("\u{FF11}\u{FF12}\u{FF13}".as_bytes(), unicode), Ok(123));
+        assert_eq!(parse::<i32, u8>("\u{2212}\u{0661}2".as_bytes(), unicode), Ok(-12));
+        assert_eq!(parse::<f64, u8>("\u{0663}\u{066B}\u{0661}\u{0664}".as_bytes(), unicode), Ok(3.14));
+        assert_eq!(parse::<f64, u8>("\u{FF11}\u{FF0E}\u{FF15}\u{FF45}\u{FF13}".as_bytes(), unicode), Ok(1500.0));
+        assert_eq!(parse::<i32, u8>("\u{0661}\u{0662}x".as_bytes(), unicode), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse::<i32, u8>("\u{0661}\u{0662}\u{00B2}".as_bytes(), unicode), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse::<u8, u8>("\u{0662}\u{0665}\u{0666}".as_bytes(), unicode), Err((ErrorCode::Overflow, 4).into()));
+        assert_eq!(parse::<i32, u8>(b"12\xFF", unicode), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(parse_partial::<f64, u8>("\u{06F1}\u{06F2} apples".as_bytes(), unicode), Ok((12.0, 4)));
+    }
+
+    #[test]
+    fn parse_utf16_test() {
+        let unicode = DigitScript::Unicode;
+        assert_eq!(parse_utf16::<i32>("-123", DigitScript::Ascii), Ok(-123));
+        assert_eq!(parse_utf16::<f64>("1.5e3", DigitScript::Ascii), Ok(1500.0));
+        assert_eq!(parse_utf16::<i32>("12a", DigitScript::Ascii), Err((ErrorCode::InvalidDigit, 2).into()));
+        assert_eq!(parse_utf16::<i32>("\u{FF11}\u{FF12}\u{FF13}", unicode), Ok(123));
+        assert_eq!(parse_utf16::<f64>("\u{0663}\u{066B}\u{0661}\u{0664}", unicode), Ok(3.14));
+        assert_eq!(parse_utf16::<i32>("\u{1D7CF}\u{1D7D0}", unicode), Ok(12));
+        assert_eq!(parse_utf16::<i32>("\u{1D7CF}\u{1D7D0}a", unicode), Err((ErrorCode::InvalidDigit, 4).into()));
+        assert_eq!(parse::<i32, u16>(&[0x31, 0xD835], unicode), Err((ErrorCode::InvalidDigit, 1).into()));
+        assert_eq!(parse_partial_utf16::<i32>("\u{0967}\u{0968},", unicode), Ok((12, 2)));
+    }
+
+    #[test]
+    fn parse_options_test() {
+        let options = ParseIntegerOptions::builder()
+            .overflow_mode(OverflowMode::Saturating)
+            .build()
+            .unwrap();
+        assert_eq!(parse_transcoded::<u8, u8>("\u{FF19}\u{FF19}\u{FF19}".as_bytes(), DigitScript::Unicode, &options), Ok(255));
+
+        // Wrapping overflow cannot be parsed incrementally.
+        let options = ParseIntegerOptions::builder()
+            .overflow_mode(OverflowMode::Wrapping)
+            .build()
+            .unwrap();
+        assert_eq!(parse_transcoded::<u8, u8>("\u{0662}\u{0665}\u{0667}".as_bytes(), DigitScript::Unicode, &options), Ok(1));
+        assert_eq!(parse_partial_transcoded::<u8, u8>("\u{0662}\u{0665}\u{0667} apples".as_bytes(), DigitScript::Unicode, &options), Ok((1, 6)));
+    }
+
+    #[test]
+    fn parse_long_input_test() {
+        // Only the number is transcoded, so the input is not limited.
+        let mut units = [b'a'; 4096];
+        units[..3].copy_from_slice(b"-12");
+        assert_eq!(parse_partial::<i32, u8>(&units, DigitScript::Unicode), Ok((-12, 3)));
+        assert_eq!(parse::<i32, u8>(&units, DigitScript::Unicode), Err((ErrorCode::InvalidDigit, 3).into()));
+
+        let options = ParseIntegerOptions::builder()
+            .overflow_mode(OverflowMode::Wrapping)
+            .build()
+            .unwrap();
+        assert_eq!(parse_partial_transcoded::<i32, u8>(&units, DigitScript::Unicode, &options), Ok((-12, 3)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_long_test() {
+        let mut string = String::from("\u{0660}\u{066B}");
+        string.extend(iter::repeat('\u{0660}').take(2000));
+        string.push('\u{0661}');
+        let mut expected = String::from("0.");
+        expected.extend(iter::repeat('0').take(2000));
+        expected.push('1');
+        let expected = expected.parse::<f64>().unwrap();
+        assert_eq!(parse::<f64, u8>(string.as_bytes(), DigitScript::Unicode), Ok(expected));
+    }
+}
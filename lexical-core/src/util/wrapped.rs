@@ -291,6 +291,7 @@ impl<T: Float> Number for WrappedFloat<T> {
     const FORMATTED_SIZE: usize = T::FORMATTED_SIZE;
     const FORMATTED_SIZE_DECIMAL: usize = T::FORMATTED_SIZE_DECIMAL;
     const IS_SIGNED: bool = T::IS_SIGNED;
+    const IS_FLOAT: bool = T::IS_FLOAT;
 }
 
 // IMPL INTEGER
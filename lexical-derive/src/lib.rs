@@ -1,35 +1,321 @@
-//! Dummy crate to ensure lexical-core works with proc-macros.
+//! Derive macros to parse and write numeric newtypes with lexical-core.
+//!
+//! The derives apply to structs with a single numeric field, such as
+//! `struct Meters(f64)` or `struct Port { value: u16 }`, and implement
+//! the lexical-core traits forwarding to the field type:
+//!
+//! - `FromLexical` implements `FromLexicalValue`, to parse the value
+//!   with `from_lexical(bytes) -> lexical_core::Result<Self>`, or the
+//!   leading value with `from_lexical_partial`.
+//! - `ToLexical` implements `ToLexicalValue`, to write the value with
+//!   `to_lexical(self, bytes) -> Option<&mut [u8]>`, and adds the
+//!   `FORMATTED_SIZE` constant, the minimum buffer size to write any
+//!   value.
+//!
+//! The conversions may be customized with the `#[lexical(...)]` attribute:
+//!
+//! - `radix = 16` parses and writes the number with a custom radix,
+//!   which requires the `radix` feature in lexical-core.
+//! - `format = "JSON"` uses the `NumberFormat` preset of that name,
+//!   which requires the `format` feature in lexical-core. Since the
+//!   format may reject a value, `to_lexical` returns `None` for those
+//!   values, and only `buffer_size()` provides the buffer size.
+//! - `suffix = "m"` requires and writes a unit suffix after the number,
+//!   such as `"12.5m"`. A missing suffix is reported with
+//!   `ErrorCode::InvalidDigit` at the end of the number.
+//! - `min = 0` and `max = 100` reject parsed values outside the range
+//!   with `ErrorCode::BelowMinimum` or `ErrorCode::AboveMaximum`,
+//!   respectively, and NaN with `ErrorCode::InvalidNan`. Negative
+//!   bounds must be quoted, such as `min = "-1.5"`.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use lexical_core::{FromLexicalValue, ToLexicalValue};
+//!
+//! #[derive(FromLexical, ToLexical)]
+//! #[lexical(suffix = "m", min = 0.0)]
+//! struct Meters(f64);
+//!
+//! let meters = Meters::from_lexical(b"12.5m").unwrap();
+//! let mut buffer = [b'0'; Meters::FORMATTED_SIZE];
+//! assert_eq!(meters.to_lexical(&mut buffer).unwrap(), b"12.5m");
+//! ```
 
-#![allow(unused)]
-
-extern crate lexical_core;
 extern crate proc_macro;
+extern crate proc_macro2;
 extern crate quote;
 extern crate syn;
 
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, Ident, Lit, LitStr, Meta, NestedMeta, Type};
 
-// Require an associated type and a single value of that type.
-#[proc_macro_derive(Lexical)]
-pub fn lexical(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
+// ATTRIBUTES
+
+/// Options parsed from the `#[lexical(...)]` attributes.
+#[derive(Default)]
+struct Options {
+    /// Radix for the number encoding.
+    radix: Option<Lit>,
+    /// Name of the `NumberFormat` preset.
+    format: Option<Ident>,
+    /// Unit suffix after the number.
+    suffix: Option<LitStr>,
+    /// Minimum valid value, inclusive.
+    min: Option<Expr>,
+    /// Maximum valid value, inclusive.
+    max: Option<Expr>,
+}
+
+// Parse a bound from an integer, float or quoted literal.
+fn parse_bound(lit: &Lit) -> syn::Result<Expr> {
+    match lit {
+        Lit::Int(_) | Lit::Float(_) => Ok(syn::parse_quote!(#lit)),
+        Lit::Str(s)                 => s.parse(),
+        _                           => Err(syn::Error::new_spanned(lit, "expected a number")),
+    }
+}
+
+// Parse the options from the attributes on the struct.
+fn parse_options(input: &DeriveInput) -> syn::Result<Options> {
+    let mut options = Options::default();
+    for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("lexical")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta             => return Err(syn::Error::new_spanned(meta, "expected `#[lexical(...)]`")),
+        };
+        for nested in list.nested.iter() {
+            let pair = match nested {
+                NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                _ => return Err(syn::Error::new_spanned(nested, "expected `key = value`")),
+            };
+            let key = pair.path.get_ident().map(|x| x.to_string()).unwrap_or_default();
+            match (key.as_str(), &pair.lit) {
+                ("radix", Lit::Int(_))  => options.radix = Some(pair.lit.clone()),
+                ("format", Lit::Str(s)) => options.format = Some(s.parse()?),
+                ("suffix", Lit::Str(s)) => options.suffix = Some(s.clone()),
+                ("min", lit)            => options.min = Some(parse_bound(lit)?),
+                ("max", lit)            => options.max = Some(parse_bound(lit)?),
+                ("radix", lit)          |
+                ("format", lit)         |
+                ("suffix", lit)         => return Err(syn::Error::new_spanned(lit, "invalid value for lexical attribute")),
+                _                       => return Err(syn::Error::new_spanned(&pair.path, "unknown lexical attribute")),
+            }
+        }
+    }
+    Ok(options)
+}
+
+// FIELD
+
+/// The single field of the struct.
+struct Field {
+    /// Type of the field.
+    ty: Type,
+    /// Name of the field, or `None` for a tuple struct.
+    name: Option<Ident>,
+}
+
+impl Field {
+    // Get the expression to construct the struct from the value.
+    fn construct(&self, value: TokenStream2) -> TokenStream2 {
+        match &self.name {
+            Some(name) => quote!(Self { #name: #value }),
+            None       => quote!(Self(#value)),
+        }
+    }
+
+    // Get the expression to access the field from `self`.
+    fn access(&self) -> TokenStream2 {
+        match &self.name {
+            Some(name) => quote!(self.#name),
+            None       => quote!(self.0),
+        }
+    }
+}
+
+// Get the single field of the struct.
+fn parse_field(input: &DeriveInput) -> syn::Result<Field> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _                  => return Err(syn::Error::new_spanned(&input.ident, "lexical derives require a struct")),
+    };
+    let field = match fields {
+        Fields::Named(f) if f.named.len() == 1      => &f.named[0],
+        Fields::Unnamed(f) if f.unnamed.len() == 1  => &f.unnamed[0],
+        _ => return Err(syn::Error::new_spanned(fields, "lexical derives require a struct with a single field")),
+    };
+    Ok(Field { ty: field.ty.clone(), name: field.ident.clone() })
+}
+
+// DERIVE
+
+// Generate the parse expression for the digits of the number.
+fn parse_expr(options: &Options, ty: &Type) -> TokenStream2 {
+    match (&options.radix, &options.format) {
+        (None, None)                => quote!(::lexical_core::parse::<#ty>(digits)),
+        (Some(radix), None)         => quote!(::lexical_core::parse_radix::<#ty>(digits, #radix)),
+        (None, Some(format))        => quote!(::lexical_core::parse_format::<#ty>(digits, ::lexical_core::NumberFormat::#format)),
+        (Some(radix), Some(format)) => quote!(::lexical_core::parse_format_radix::<#ty>(digits, #radix, ::lexical_core::NumberFormat::#format)),
+    }
+}
+
+// Generate the partial parse expression for the leading digits of the number.
+fn parse_partial_expr(options: &Options, ty: &Type) -> TokenStream2 {
+    match (&options.radix, &options.format) {
+        (None, None)                => quote!(::lexical_core::parse_partial::<#ty>(bytes)),
+        (Some(radix), None)         => quote!(::lexical_core::parse_partial_radix::<#ty>(bytes, #radix)),
+        (None, Some(format))        => quote!(::lexical_core::parse_partial_format::<#ty>(bytes, ::lexical_core::NumberFormat::#format)),
+        (Some(radix), Some(format)) => quote!(::lexical_core::parse_partial_format_radix::<#ty>(bytes, #radix, ::lexical_core::NumberFormat::#format)),
+    }
+}
+
+// Generate the index of the start of the number, for out-of-range values.
+fn start_expr(options: &Options) -> TokenStream2 {
+    match &options.format {
+        None            => quote!(0),
+        Some(format)    => quote!(::lexical_core::format_number_start(bytes, ::lexical_core::NumberFormat::#format)),
+    }
+}
+
+// Generate the write expression for the value.
+fn write_expr(options: &Options) -> TokenStream2 {
+    match (&options.radix, &options.format) {
+        (None, None)                => quote!(::lexical_core::write(value, bytes)),
+        (Some(radix), None)         => quote!(::lexical_core::write_radix(value, #radix, bytes)),
+        (None, Some(format))        => quote!(::lexical_core::write_format(value, ::lexical_core::NumberFormat::#format, bytes)),
+        (Some(radix), Some(format)) => quote!(::lexical_core::write_format_radix(value, #radix, ::lexical_core::NumberFormat::#format, bytes)),
+    }
+}
+
+fn derive_from_lexical(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let options = parse_options(input)?;
+    let field = parse_field(input)?;
+    let ty = &field.ty;
     let name = &input.ident;
-    let expanded = quote! {
-        impl Lexical for #name {
-            fn to_lexical<'a>(self, bytes: &'a mut [u8])
-                -> &'a mut [u8]
-            {
-                ::lexical_core::write(self.value, bytes)
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let parse = parse_expr(&options, ty);
+    let parse_partial = parse_partial_expr(&options, ty);
+    let strip_suffix = options.suffix.as_ref().map(|suffix| quote! {
+        let suffix: &[u8] = #suffix.as_bytes();
+        let digits = match digits.len().checked_sub(suffix.len()) {
+            Some(length) if &digits[length..] == suffix => &digits[..length],
+            // Report the missing suffix at the end of the number.
+            _ => {
+                let (_, count): (#ty, usize) = #parse_partial?;
+                return Err((::lexical_core::ErrorCode::InvalidDigit, count).into());
+            },
+        };
+    });
+    let consume_suffix = options.suffix.as_ref().map(|suffix| quote! {
+        let suffix: &[u8] = #suffix.as_bytes();
+        let count = match bytes[count..].starts_with(suffix) {
+            true  => count + suffix.len(),
+            false => return Err((::lexical_core::ErrorCode::InvalidDigit, count).into()),
+        };
+    });
+    let range = match (&options.min, &options.max) {
+        (Some(min), Some(max))  => Some(quote!(#min..=#max)),
+        (Some(min), None)       => Some(quote!(#min..)),
+        (None, Some(max))       => Some(quote!(..=#max)),
+        (None, None)            => None,
+    };
+    let start = start_expr(&options);
+    let check_range = range.map(|range| quote! {
+        ::lexical_core::check_bounds::<#ty, _>(&value, &(#range), #start)?;
+    });
+    let construct = field.construct(quote!(value));
+
+    Ok(quote! {
+        impl #impl_generics ::lexical_core::FromLexicalValue for #name #ty_generics #where_clause {
+            fn from_lexical(bytes: &[u8]) -> ::lexical_core::Result<Self> {
+                let digits = bytes;
+                #strip_suffix
+                let value: #ty = #parse?;
+                #check_range
+                Ok(#construct)
             }
 
-            fn from_lexical(bytes: &[u8])
-                -> ::lexical_core::Result<Self>
-            {
-                Ok(Self { value: ::lexical_core::parse(bytes)? })
+            fn from_lexical_partial(bytes: &[u8]) -> ::lexical_core::Result<(Self, usize)> {
+                let (value, count): (#ty, usize) = #parse_partial?;
+                #consume_suffix
+                #check_range
+                Ok((#construct, count))
             }
         }
+    })
+}
+
+fn derive_to_lexical(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let options = parse_options(input)?;
+    let field = parse_field(input)?;
+    let ty = &field.ty;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let suffix = options.suffix.as_ref().map_or_else(|| quote!(""), |s| quote!(#s));
+    let write = write_expr(&options);
+    let access = field.access();
+
+    // Formats may reject values and change the buffer size at runtime.
+    let (formatted_size, buffer_size, written) = match &options.format {
+        None         => {
+            let size = match options.radix {
+                Some(_) => quote!(<#ty as ::lexical_core::Number>::FORMATTED_SIZE),
+                None    => quote!(<#ty as ::lexical_core::Number>::FORMATTED_SIZE_DECIMAL),
+            };
+            let formatted_size = quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Minimum number of bytes required to write any value.
+                    pub const FORMATTED_SIZE: usize = #size + #suffix.len();
+                }
+            };
+            (formatted_size, quote!(Self::FORMATTED_SIZE), quote!(#write))
+        },
+        Some(format) => {
+            let radix = options.radix.as_ref().map_or_else(|| quote!(10), |r| quote!(#r));
+            let buffer_size = quote! {
+                let format = ::lexical_core::NumberFormat::#format;
+                <#ty as ::lexical_core::ToLexicalFormat>::format_buffer_size(#radix, format) + #suffix.len()
+            };
+            (quote!(), buffer_size, quote!(#write?))
+        },
     };
-    TokenStream::from(expanded)
+
+    Ok(quote! {
+        #formatted_size
+
+        impl #impl_generics ::lexical_core::ToLexicalValue for #name #ty_generics #where_clause {
+            fn buffer_size() -> usize {
+                #buffer_size
+            }
+
+            fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> Option<&'a mut [u8]> {
+                assert!(bytes.len() >= <Self as ::lexical_core::ToLexicalValue>::buffer_size());
+                let suffix: &[u8] = #suffix.as_bytes();
+                let value = #access;
+                let length = #written.len();
+                let end = length + suffix.len();
+                bytes[length..end].copy_from_slice(suffix);
+                Some(&mut bytes[..end])
+            }
+        }
+    })
+}
+
+/// Derive `FromLexicalValue` for a struct with a single numeric field.
+#[proc_macro_derive(FromLexical, attributes(lexical))]
+pub fn from_lexical(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_from_lexical(&input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// Derive `ToLexicalValue` for a struct with a single numeric field.
+#[proc_macro_derive(ToLexical, attributes(lexical))]
+pub fn to_lexical(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_to_lexical(&input).unwrap_or_else(|e| e.to_compile_error()).into()
 }
@@ -1,29 +1,132 @@
-//! Test the proc-macro.
+//! Test the proc-macros.
 
 extern crate lexical_core;
 extern crate lexical_derive;
 
-use lexical_derive::Lexical;
+use lexical_core::{ErrorCode, FromLexicalValue, Number, ToLexicalValue};
+use lexical_derive::{FromLexical, ToLexical};
 
-trait Lexical: Sized {
-    fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
-    fn from_lexical(bytes: &[u8]) -> lexical_core::Result<Self>;
-}
-
-#[derive(Lexical)]
+#[derive(Debug, PartialEq, FromLexical, ToLexical)]
 struct Wrapper {
     pub value: i32,
 }
 
+#[derive(Debug, PartialEq, FromLexical, ToLexical)]
+struct Meters(f64);
+
+#[derive(Debug, PartialEq, FromLexical, ToLexical)]
+#[lexical(suffix = "m", min = 0.0, max = "1e3")]
+struct Length(f64);
+
+#[derive(Debug, PartialEq, FromLexical, ToLexical)]
+#[lexical(min = "-10", max = 10)]
+struct Offset(i8);
+
 #[test]
 fn to_lexical_test() {
-    let wrapper = Wrapper { value: 15 };
     let mut bytes = [b'0'; 256];
-    assert_eq!(wrapper.to_lexical(&mut bytes), b"15");
+    assert_eq!(Wrapper { value: 15 }.to_lexical(&mut bytes).unwrap(), b"15");
+    assert_eq!(Meters(1.5).to_lexical(&mut bytes).unwrap(), b"1.5");
+    assert_eq!(Length(12.5).to_lexical(&mut bytes).unwrap(), b"12.5m");
+    assert_eq!(Offset(-5).to_lexical(&mut bytes).unwrap(), b"-5");
+}
+
+#[test]
+fn formatted_size_test() {
+    assert_eq!(Wrapper::FORMATTED_SIZE, i32::FORMATTED_SIZE_DECIMAL);
+    assert_eq!(Wrapper::buffer_size(), Wrapper::FORMATTED_SIZE);
+    assert_eq!(Length::FORMATTED_SIZE, f64::FORMATTED_SIZE_DECIMAL + 1);
+    let mut bytes = [b'0'; Length::FORMATTED_SIZE];
+    assert_eq!(Length(-1.5e-300).to_lexical(&mut bytes).unwrap(), b"-1.5e-300m");
+}
+
+#[test]
+#[should_panic]
+fn buffer_test() {
+    let mut bytes = [b'0'; Length::FORMATTED_SIZE - 1];
+    Length(12.5).to_lexical(&mut bytes);
 }
 
 #[test]
 fn from_lexical_test() {
-    let res = Wrapper::from_lexical(b"15").unwrap();
-    assert_eq!(res.value, 15);
+    assert_eq!(Wrapper::from_lexical(b"15"), Ok(Wrapper { value: 15 }));
+    assert_eq!(Meters::from_lexical(b"1.5"), Ok(Meters(1.5)));
+    assert_eq!(Meters::from_lexical(b"1.5m"), Err((ErrorCode::InvalidDigit, 3).into()));
+}
+
+#[test]
+fn suffix_test() {
+    assert_eq!(Length::from_lexical(b"12.5m"), Ok(Length(12.5)));
+    assert_eq!(Length::from_lexical(b"12.5"), Err((ErrorCode::InvalidDigit, 4).into()));
+    assert_eq!(Length::from_lexical(b"12.5km"), Err((ErrorCode::InvalidDigit, 4).into()));
+    assert_eq!(Length::from_lexical(b"m"), Err(ErrorCode::Empty.into()));
+    assert_eq!(Length::from_lexical(b"12.5x"), Err((ErrorCode::InvalidDigit, 4).into()));
+    assert_eq!(Length::from_lexical(b""), Err(ErrorCode::Empty.into()));
+}
+
+#[test]
+fn partial_test() {
+    assert_eq!(Meters::from_lexical_partial(b"1.5,"), Ok((Meters(1.5), 3)));
+    assert_eq!(Length::from_lexical_partial(b"12.5m,"), Ok((Length(12.5), 5)));
+    assert_eq!(Length::from_lexical_partial(b"12.5,"), Err((ErrorCode::InvalidDigit, 4).into()));
+    assert_eq!(Offset::from_lexical_partial(b"-11,"), Err((ErrorCode::BelowMinimum, 0).into()));
+}
+
+#[test]
+fn range_test() {
+    assert_eq!(Length::from_lexical(b"0m"), Ok(Length(0.0)));
+    assert_eq!(Length::from_lexical(b"1000m"), Ok(Length(1000.0)));
+    assert_eq!(Length::from_lexical(b"-1m"), Err((ErrorCode::BelowMinimum, 0).into()));
+    assert_eq!(Length::from_lexical(b"1001m"), Err((ErrorCode::AboveMaximum, 0).into()));
+    assert_eq!(Length::from_lexical(b"NaNm"), Err((ErrorCode::InvalidNan, 0).into()));
+    assert_eq!(Offset::from_lexical(b"-10"), Ok(Offset(-10)));
+    assert_eq!(Offset::from_lexical(b"-11"), Err((ErrorCode::BelowMinimum, 0).into()));
+    assert_eq!(Offset::from_lexical(b"11"), Err((ErrorCode::AboveMaximum, 0).into()));
+    assert_eq!(Offset::from_lexical(b"128"), Err((ErrorCode::Overflow, 2).into()));
+}
+
+#[cfg(feature = "radix")]
+mod radix {
+    use super::*;
+
+    #[derive(Debug, PartialEq, FromLexical, ToLexical)]
+    #[lexical(radix = 16, suffix = "h")]
+    struct Hex(u32);
+
+    #[test]
+    fn radix_test() {
+        let mut bytes = [b'0'; Hex::FORMATTED_SIZE];
+        assert_eq!(Hex::FORMATTED_SIZE, u32::FORMATTED_SIZE + 1);
+        assert_eq!(Hex::from_lexical(b"FFh"), Ok(Hex(255)));
+        assert_eq!(Hex(255).to_lexical(&mut bytes).unwrap(), b"FFh");
+    }
+}
+
+#[cfg(feature = "format")]
+mod format {
+    use super::*;
+
+    #[derive(Debug, PartialEq, FromLexical, ToLexical)]
+    #[lexical(format = "JSON")]
+    struct Json(f64);
+
+    #[derive(Debug, PartialEq, FromLexical)]
+    #[lexical(format = "WHITESPACE_FLAG_MASK", min = 1)]
+    struct Padded(u32);
+
+    #[test]
+    fn format_test() {
+        let mut bytes = vec![b'0'; Json::buffer_size()];
+        assert_eq!(Json::from_lexical(b"1.5"), Ok(Json(1.5)));
+        assert_eq!(Json::from_lexical(b"+1.5"), Err(ErrorCode::InvalidPositiveMantissaSign.into()));
+        assert_eq!(Json(1.5).to_lexical(&mut bytes).map(|x| &*x), Some(&b"1.5"[..]));
+        assert_eq!(Json(f64::NAN).to_lexical(&mut bytes), None);
+    }
+
+    #[test]
+    fn format_range_test() {
+        assert_eq!(Padded::from_lexical(b"  1 "), Ok(Padded(1)));
+        assert_eq!(Padded::from_lexical(b"  0 "), Err((ErrorCode::BelowMinimum, 2).into()));
+        assert_eq!(Padded::from_lexical_partial(b" 0 ,"), Err((ErrorCode::BelowMinimum, 1).into()));
+    }
 }
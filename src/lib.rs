@@ -27,6 +27,13 @@
 //! **To String**
 //! - [`to_string`]
 #![cfg_attr(feature = "radix", doc = " - [`to_string_radix`]")]
+#![cfg_attr(feature = "format", doc = " - [`to_string_format`]")]
+#![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`to_string_format_radix`]")]
+//! - [`to_string_with_options`]
+//! - [`to_string_exact`]
+//! - [`to_string_exact_digits`]
+//! - [`big_integer_to_string`]
+//! - [`to_string_locale`]
 //!
 //! **From String**
 //! - [`parse`]
@@ -45,9 +52,40 @@
 #![cfg_attr(feature = "radix", doc = " - [`parse_partial_lossy_radix`]")]
 #![cfg_attr(feature = "format", doc = " - [`parse_partial_lossy_format`]")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " - [`parse_partial_lossy_format_radix`]")]
+//! - [`parse_with_options`]
+//! - [`parse_bounded`]
+//! - [`parse_value`]
+//! - [`parse_value_with_options`]
+//! - [`parse_partial_with_options`]
+#![cfg_attr(feature = "std", doc = " - [`parse_decimal`]")]
+#![cfg_attr(all(feature = "std", feature = "format"), doc = " - [`parse_decimal_format`]")]
+//! - [`parse_components`]
+#![cfg_attr(feature = "format", doc = " - [`parse_components_format`]")]
+//! - [`parse_big_integer`]
+//! - [`parse_big_integer_with_options`]
+//! - [`parse_locale`]
+//! - [`parse_units`]
+//!
+//! # Options API
+//!
+//! The options API customizes parsing and writing on a per-call basis,
+//! without modifying any global state, and is therefore thread-safe.
+//!
+//! ```rust
+//! extern crate lexical;
+//!
+//! let options = lexical::WriteFloatOptions::builder()
+//!     .nan_string(b"nan")
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(lexical::to_string_with_options(f64::NAN, &options), "nan");
+//! ```
 //!
 //! # Configuration Settings
 //!
+//! The global configuration settings only affect the functions that
+//! do not take options, and are not thread-safe. Prefer the options API.
+//!
 //! **Get Configuration**
 //! - [`get_exponent_default_char`]
 #![cfg_attr(feature = "radix", doc = " - [`get_exponent_backup_char`]")]
@@ -66,6 +104,13 @@
 //!
 //! [`to_string`]: fn.to_string.html
 #![cfg_attr(feature = "radix", doc = " [`to_string_radix`]: fn.to_string_radix.html")]
+#![cfg_attr(feature = "format", doc = " [`to_string_format`]: fn.to_string_format.html")]
+#![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`to_string_format_radix`]: fn.to_string_format_radix.html")]
+//! [`to_string_with_options`]: fn.to_string_with_options.html
+//! [`to_string_exact`]: fn.to_string_exact.html
+//! [`to_string_exact_digits`]: fn.to_string_exact_digits.html
+//! [`big_integer_to_string`]: fn.big_integer_to_string.html
+//! [`to_string_locale`]: fn.to_string_locale.html
 //! [`parse`]: fn.parse.html
 #![cfg_attr(feature = "radix", doc = " [`parse_radix`]: fn.parse_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_format`]: fn.parse_format.html")]
@@ -82,6 +127,19 @@
 #![cfg_attr(feature = "radix", doc = " [`parse_partial_lossy_radix`]: fn.parse_partial_lossy_radix.html")]
 #![cfg_attr(feature = "format", doc = " [`parse_partial_lossy_format`]: fn.parse_partial_lossy_format.html")]
 #![cfg_attr(all(feature = "format", feature = "radix"), doc = " [`parse_partial_lossy_format_radix`]: fn.parse_partial_lossy_format_radix.html")]
+//! [`parse_with_options`]: fn.parse_with_options.html
+//! [`parse_bounded`]: fn.parse_bounded.html
+//! [`parse_value`]: fn.parse_value.html
+//! [`parse_value_with_options`]: fn.parse_value_with_options.html
+//! [`parse_partial_with_options`]: fn.parse_partial_with_options.html
+#![cfg_attr(feature = "std", doc = " [`parse_decimal`]: fn.parse_decimal.html")]
+#![cfg_attr(all(feature = "std", feature = "format"), doc = " [`parse_decimal_format`]: fn.parse_decimal_format.html")]
+//! [`parse_components`]: fn.parse_components.html
+#![cfg_attr(feature = "format", doc = " [`parse_components_format`]: fn.parse_components_format.html")]
+//! [`parse_big_integer`]: fn.parse_big_integer.html
+//! [`parse_big_integer_with_options`]: fn.parse_big_integer_with_options.html
+//! [`parse_locale`]: fn.parse_locale.html
+//! [`parse_units`]: fn.parse_units.html
 //!
 //! [`get_exponent_default_char`]: fn.get_exponent_default_char.html
 #![cfg_attr(feature = "radix", doc = " [`get_exponent_backup_char`]: fn.get_exponent_backup_char.html")]
@@ -147,27 +205,80 @@ pub use lexical_core::{get_inf_string, get_infinity_string, get_nan_string};
 pub use lexical_core::{set_inf_string, set_infinity_string, set_nan_string};
 
 // Re-export the float rounding scheme used.
+pub use lexical_core::RoundingKind;
 #[cfg(all(feature = "correct", feature = "rounding"))]
-pub use lexical_core::{get_float_rounding, set_float_rounding, RoundingKind};
+pub use lexical_core::{get_float_rounding, set_float_rounding};
 
 // Re-export the numerical format.
 #[cfg(feature = "format")]
 pub use lexical_core::NumberFormat;
 
+// Re-export the half-precision float types.
+#[cfg(feature = "f16")]
+pub use lexical_core::{bf16, f16};
+
+// Re-export the extended-precision float types.
+#[cfg(feature = "f128")]
+pub use lexical_core::{f128, f80, ExtendedPrecision, FloatParts, EXTENDED_FORMATTED_SIZE_DECIMAL};
+
+// Re-export the incremental parser for chunked input.
+#[cfg(feature = "std")]
+pub use lexical_core::{NumberParser, ParseStatus};
+
+// Re-export the exact decimal number.
+#[cfg(feature = "std")]
+pub use lexical_core::Decimal;
+
+// Re-export the components of a number string.
+pub use lexical_core::NumberComponents;
+
+// Re-export the pluggable arbitrary-precision integer.
+pub use lexical_core::BigInteger;
+
+// Re-export the per-call options and their builders.
+pub use lexical_core::{DigitGrouping, FloatNotation, MAX_FLOAT_PRECISION};
+pub use lexical_core::{Locale, LocaleBuilder};
+pub use lexical_core::{ParseFloatOptions, ParseFloatOptionsBuilder};
+pub use lexical_core::{ParseIntegerOptions, ParseIntegerOptionsBuilder};
+pub use lexical_core::OverflowMode;
+pub use lexical_core::{ParseManyOptions, ParseManyOptionsBuilder};
+pub use lexical_core::{WriteFloatOptions, WriteFloatOptionsBuilder};
+pub use lexical_core::{WriteIntegerOptions, WriteIntegerOptionsBuilder};
+
 // Re-export the Result, Error and ErrorCode globally.
 pub use lexical_core::{Error, ErrorCode, Result};
 
+// Re-export the context for parse errors.
+pub use lexical_core::{ErrorContext, Expected};
+
+// Re-export the iterator over delimited numbers.
+pub use lexical_core::{FieldError, ParseManyIter};
+
+// Re-export the code units and digit scripts numbers are parsed from.
+pub use lexical_core::{CodeUnit, DigitScript};
+
+// Re-export the buffer size for exact float digits.
+pub use lexical_core::EXACT_BUFFER_SIZE;
+
 // Publicly expose traits so they may be used for generic programming.
-pub use lexical_core::{FromLexical, FromLexicalLossy, ToLexical};
+pub use lexical_core::{Float, FromLexical, FromLexicalLossy, ToLexical};
+pub use lexical_core::{FromLexicalOptions, StreamOptions, ToLexicalOptions};
+pub use lexical_core::{FromLexicalValue, FromLexicalValueOptions};
 #[cfg(feature = "format")]
 pub use lexical_core::{FromLexicalFormat,FromLexicalLossyFormat};
+#[cfg(feature = "format")]
+pub use lexical_core::ToLexicalFormat;
+
+// Serde helpers for string-encoded numbers.
+#[cfg(feature = "serde")]
+pub mod serde;
 
 // HELPERS
 
 /// Get a vector as a slice, including the capacity.
 #[inline]
-unsafe fn vector_as_slice<'a, T>(buf: &'a mut lib::Vec<T>)
-    -> &'a mut [T]
+unsafe fn vector_as_slice<T>(buf: &mut lib::Vec<T>)
+    -> &mut [T]
 {
     let first = buf.as_mut_ptr();
     lib::slice::from_raw_parts_mut(first, buf.capacity())
@@ -231,6 +342,215 @@ pub fn to_string_radix<N: ToLexical>(n: N, radix: u8) -> lib::String {
     }
 }
 
+/// High-level conversion of a number to a decimal-encoded string with a custom numerical format.
+///
+/// Returns `None` if the number cannot be represented in the format,
+/// for example, a special float when the format does not allow special
+/// values. Otherwise, the string is valid under the same format.
+///
+/// * `n`       - Number to convert to string.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::NumberFormat;
+/// # pub fn main() {
+/// let format = NumberFormat::REQUIRED_MANTISSA_SIGN | NumberFormat::REQUIRED_EXPONENT_SIGN;
+/// assert_eq!(lexical::to_string_format(5, format), Some("+5".to_string()));
+/// assert_eq!(lexical::to_string_format(1.5e-300, format), Some("+1.5e-300".to_string()));
+/// assert_eq!(lexical::to_string_format(f64::NAN, NumberFormat::JSON), None);
+/// # }
+/// ```
+#[cfg(feature = "format")]
+#[inline]
+pub fn to_string_format<N: ToLexicalFormat>(n: N, format: NumberFormat) -> Option<lib::String> {
+    unsafe {
+        let mut buf = lib::Vec::<u8>::with_capacity(N::format_buffer_size(10, format));
+        let len = lexical_core::write_format(n, format, vector_as_slice(&mut buf))?.len();
+        buf.set_len(len);
+        Some(lib::String::from_utf8_unchecked(buf))
+    }
+}
+
+/// High-level conversion of a number to string with a custom radix and numerical format.
+///
+/// Returns `None` if the number cannot be represented in the format,
+/// for example, a special float when the format does not allow special
+/// values. Otherwise, the string is valid under the same format.
+///
+/// * `n`       - Number to convert to string.
+/// * `base`    - Number of unique digits for the number (radix).
+/// * `format`  - Numerical format.
+///
+/// # Panics
+///
+/// Panics if radix is not in the range `[2, 36]`
+#[cfg(all(feature = "format", feature = "radix"))]
+#[inline]
+pub fn to_string_format_radix<N: ToLexicalFormat>(n: N, radix: u8, format: NumberFormat) -> Option<lib::String> {
+    unsafe {
+        let mut buf = lib::Vec::<u8>::with_capacity(N::format_buffer_size(radix, format));
+        let len = lexical_core::write_format_radix(n, radix, format, vector_as_slice(&mut buf))?.len();
+        buf.set_len(len);
+        Some(lib::String::from_utf8_unchecked(buf))
+    }
+}
+
+/// High-level conversion of a number to string with custom options.
+///
+/// * `n`       - Number to convert to string.
+/// * `options` - Options to customize number serialization.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let options = lexical::WriteFloatOptions::builder()
+///     .exponent_default_char(b'E')
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::to_string_with_options(1.5e-300, &options), "1.5E-300");
+/// assert_eq!(lexical::to_string_with_options(5, &lexical::WriteIntegerOptions::new()), "5");
+///
+/// let options = lexical::WriteFloatOptions::builder()
+///     .notation(lexical::FloatNotation::Scientific)
+///     .precision(Some(6))
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::to_string_with_options(1234.5, &options), "1.234500e3");
+/// assert_eq!(lexical::to_string_with_options(f64::MAX, &options), "1.797693e308");
+///
+/// let options = lexical::WriteIntegerOptions::builder()
+///     .digit_separator(b',')
+///     .grouping(lexical::DigitGrouping::Uniform(3))
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::to_string_with_options(-1234567, &options), "-1,234,567");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_with_options<N: ToLexicalOptions>(n: N, options: &N::Options) -> lib::String {
+    unsafe {
+        let mut buf = lib::Vec::<u8>::with_capacity(N::buffer_size(options));
+        let len = lexical_core::write_with_options(n, vector_as_slice(&mut buf), options).len();
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of a float to a string with its exact decimal value.
+///
+/// Unlike [`to_string`], which writes the shortest digits that round-trip,
+/// this writes every significant digit of the float in positional notation.
+///
+/// * `value`   - Float to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_exact(0.1f64), "0.1000000000000000055511151231257827021181583404541015625");
+/// assert_eq!(lexical::to_string_exact(0.5f32), "0.5");
+/// # }
+/// ```
+///
+/// [`to_string`]: fn.to_string.html
+#[inline]
+pub fn to_string_exact<F: Float>(value: F) -> lib::String {
+    unsafe {
+        let mut buf = lib::Vec::<u8>::with_capacity(EXACT_BUFFER_SIZE);
+        let len = lexical_core::write_exact(value, vector_as_slice(&mut buf)).len();
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of a float to a string with exact, rounded digits.
+///
+/// The exact value of the float is rounded to at most `digits`
+/// significant digits using the rounding kind.
+///
+/// * `value`       - Float to convert to string.
+/// * `digits`      - Maximum number of significant digits.
+/// * `rounding`    - Rounding kind for the last digit.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::RoundingKind;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_exact_digits(0.1f64, 20, RoundingKind::NearestTieEven), "0.10000000000000000555");
+/// assert_eq!(lexical::to_string_exact_digits(1.25f64, 2, RoundingKind::TowardZero), "1.2");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_exact_digits<F: Float>(value: F, digits: usize, rounding: RoundingKind) -> lib::String {
+    unsafe {
+        let mut buf = lib::Vec::<u8>::with_capacity(EXACT_BUFFER_SIZE);
+        let len = lexical_core::write_exact_digits(value, digits, rounding, vector_as_slice(&mut buf)).len();
+        buf.set_len(len);
+        lib::String::from_utf8_unchecked(buf)
+    }
+}
+
+/// High-level conversion of an arbitrary-precision integer to a decimal-encoded string.
+///
+/// * `value`   - Number to convert to string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// assert_eq!(lexical::big_integer_to_string(&[0u64, 1]), "18446744073709551616");
+/// # }
+/// ```
+#[inline]
+pub fn big_integer_to_string<T: BigInteger>(value: &T) -> lib::String {
+    // Calculate the required size with an empty buffer.
+    let mut buf = lib::Vec::<u8>::new();
+    if let Err(error) = lexical_core::write_big_integer(value, &mut buf) {
+        buf.resize(error.required, 0);
+    }
+    let len = lexical_core::write_big_integer(value, &mut buf).unwrap().len();
+    buf.truncate(len);
+    unsafe { lib::String::from_utf8_unchecked(buf) }
+}
+
+/// High-level conversion of a number to a localized string.
+///
+/// * `n`       - Number to convert to string.
+/// * `locale`  - Locale-specific symbols for the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::Locale;
+/// # pub fn main() {
+/// assert_eq!(lexical::to_string_locale(-1234567.89f64, &Locale::DE_DE), "-1.234.567,89");
+/// # }
+/// ```
+#[inline]
+pub fn to_string_locale<N>(n: N, locale: &Locale) -> lib::String
+    where N: ToLexicalOptions,
+          N::Options: Default
+{
+    // Calculate the required size with an empty buffer.
+    let mut buf = lib::Vec::<u8>::new();
+    if let Err(error) = lexical_core::write_locale(n, locale, &mut buf) {
+        buf.resize(error.required, 0);
+    }
+    let len = lexical_core::write_locale(n, locale, &mut buf).unwrap().len();
+    buf.truncate(len);
+    unsafe { lib::String::from_utf8_unchecked(buf) }
+}
+
 /// High-level conversion of decimal-encoded bytes to a number.
 ///
 /// This function only returns a value if the entire string is
@@ -1007,3 +1327,396 @@ pub fn parse_partial_lossy_format_radix<N: FromLexicalLossyFormat, Bytes: AsRef<
 {
     N::from_lexical_partial_lossy_format_radix(bytes.as_ref(), radix, format)
 }
+
+/// High-level conversion of bytes to a number with custom options.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let options = lexical::ParseFloatOptions::builder()
+///     .exponent_default_char(b'p')
+///     .build()
+///     .unwrap();
+/// assert_eq!(lexical::parse_with_options::<f64, _>("1.5p2", &options), Ok(150.0));
+/// assert_eq!(lexical::parse_with_options::<i32, _>("5", &lexical::ParseIntegerOptions::new()), Ok(5));
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_options<N: FromLexicalOptions, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes.as_ref(), options)
+}
+
+/// High-level conversion of bytes to a number, with context for any error.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed, and otherwise returns the error with the
+/// tokens expected at the position of the error.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let error = lexical::parse_with_context::<i32, _>("12a").err().unwrap();
+/// assert_eq!(error.to_string(), "invalid digit found at index 2, expected a digit or the end of the number");
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_context<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> lib::result::Result<N, ErrorContext>
+{
+    lexical_core::parse_with_context(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number, within a range of values.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed and the value is within the range. Values
+/// outside of the range return `BelowMinimum` or `AboveMaximum`,
+/// at the index of the start of the number, and NaN returns
+/// `InvalidNan` for any bounded range.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `range`   - Range of valid values.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_bounded::<u16, _, _>("443", 1..=65535), Ok(443));
+/// let error = lexical::parse_bounded::<u32, _, _>("0", 1..).err().unwrap();
+/// assert_eq!(error.code, ErrorCode::BelowMinimum);
+/// # }
+/// ```
+#[inline]
+pub fn parse_bounded<N, R, Bytes>(bytes: Bytes, range: R)
+    -> Result<N>
+    where N: FromLexical + PartialOrd,
+          R: lib::ops::RangeBounds<N>,
+          Bytes: AsRef<[u8]>
+{
+    lexical_core::parse_bounded(bytes.as_ref(), range)
+}
+
+/// High-level conversion of bytes to a value parsed through a number.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed and the value is not rejected, such as
+/// zero for a non-zero integer.
+///
+/// * `bytes`   - Byte slice to convert to a value.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::ErrorCode;
+/// # use std::num::NonZeroUsize;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_value::<NonZeroUsize, _>("4"), Ok(NonZeroUsize::new(4).unwrap()));
+/// let error = lexical::parse_value::<NonZeroUsize, _>("0").err().unwrap();
+/// assert_eq!(error.code, ErrorCode::InvalidZero);
+/// # }
+/// ```
+#[inline]
+pub fn parse_value<N: FromLexicalValue, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<N>
+{
+    N::from_lexical(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a value parsed through a number with custom options.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed and the value is not rejected, such as
+/// zero for a non-zero integer.
+///
+/// * `bytes`   - Byte slice to convert to a value.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use std::num::NonZeroUsize;
+/// # pub fn main() {
+/// let options = lexical::ParseIntegerOptions::new();
+/// let value = lexical::parse_value_with_options::<NonZeroUsize, _>("4", &options);
+/// assert_eq!(value, Ok(NonZeroUsize::new(4).unwrap()));
+/// # }
+/// ```
+#[inline]
+pub fn parse_value_with_options<N: FromLexicalValueOptions, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &N::Options)
+    -> Result<N>
+{
+    N::from_lexical_with_options(bytes.as_ref(), options)
+}
+
+/// High-level, partial conversion of bytes to a number with custom options.
+///
+/// This functions parses as many digits as possible, returning the parsed
+/// value and the number of digits processed if at least one character
+/// is processed. If another error, such as numerical overflow or underflow
+/// occurs, this function returns the error code and the index at which
+/// the error occurred.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to customize number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let options = lexical::ParseFloatOptions::new();
+/// assert_eq!(lexical::parse_partial_with_options::<f64, _>("1.5a", &options), Ok((1.5, 3)));
+/// # }
+/// ```
+#[inline]
+pub fn parse_partial_with_options<N: FromLexicalOptions, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &N::Options)
+    -> Result<(N, usize)>
+{
+    N::from_lexical_partial_with_options(bytes.as_ref(), options)
+}
+
+/// High-level conversion of bytes with many delimited numbers to a vector.
+///
+/// Returns the parsed values, or the first error with the index of the
+/// failing field. Fields within a record are separated by the delimiter,
+/// and records are separated by line terminators.
+///
+/// * `bytes`   - Byte slice containing delimited numbers.
+/// * `options` - Options for the delimited fields.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let options = lexical::ParseManyOptions::new();
+/// assert_eq!(lexical::parse_many::<f64, _>("1.5, 2\n3, 4.5\n", &options), Ok(vec![1.5, 2.0, 3.0, 4.5]));
+/// assert_eq!(lexical::parse_many::<i32, _>("1,2,x", &options).err().unwrap().field, 2);
+/// # }
+/// ```
+#[inline]
+pub fn parse_many<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &ParseManyOptions)
+    -> lib::result::Result<lib::Vec<N>, FieldError>
+{
+    ParseManyIter::new(bytes.as_ref(), options).collect()
+}
+
+/// High-level conversion of decimal-encoded bytes to an exact decimal number.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. Every digit is kept, so no rounding occurs,
+/// and the decimal may be written back with `to_string`.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let decimal = lexical::parse_decimal("0.10000000000000000000000001").unwrap();
+/// assert_eq!(decimal.exponent, -26);
+/// assert_eq!(decimal.to_string(), "0.10000000000000000000000001");
+/// assert!(lexical::parse_decimal("1a").is_err());
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_decimal<Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<Decimal>
+{
+    lexical_core::parse_decimal(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to an exact decimal number with a custom numerical format.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. Every digit is kept, so no rounding occurs.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let format = lexical::NumberFormat::JSON;
+/// assert_eq!(lexical::parse_decimal_format("-1.25e2", format).unwrap().to_string(), "-125");
+/// assert!(lexical::parse_decimal_format("01.25", format).is_err());
+/// # }
+/// ```
+#[inline]
+#[cfg(all(feature = "std", feature = "format"))]
+pub fn parse_decimal_format<Bytes: AsRef<[u8]>>(bytes: Bytes, format: NumberFormat)
+    -> Result<Decimal>
+{
+    lexical_core::parse_decimal_format(bytes.as_ref(), format)
+}
+
+/// High-level extraction of the components of a number string.
+///
+/// This function only returns the components if the entire string is
+/// a valid decimal float. The integer and fraction digits are
+/// subslices of the input.
+///
+/// * `bytes`   - Byte slice to extract the components from.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let components = lexical::parse_components("12.50e-1").unwrap();
+/// assert_eq!(components.integer, b"12");
+/// assert_eq!(components.fraction, b"50");
+/// assert_eq!(components.exponent, -1);
+/// assert!(lexical::parse_components("12.5a").is_err());
+/// # }
+/// ```
+#[inline]
+pub fn parse_components<'a, Bytes: AsRef<[u8]> + ?Sized>(bytes: &'a Bytes)
+    -> Result<NumberComponents<'a>>
+{
+    lexical_core::parse_components(bytes.as_ref())
+}
+
+/// High-level conversion of decimal-encoded bytes to an arbitrary-precision integer.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let value: [u64; 4] = lexical::parse_big_integer("18446744073709551616").unwrap();
+/// assert_eq!(value, [0, 1, 0, 0]);
+/// assert!(lexical::parse_big_integer::<[u64; 4], _>("1a").is_err());
+/// # }
+/// ```
+#[inline]
+pub fn parse_big_integer<T: BigInteger, Bytes: AsRef<[u8]>>(bytes: Bytes)
+    -> Result<T>
+{
+    lexical_core::parse_big_integer(bytes.as_ref())
+}
+
+/// High-level conversion of localized bytes to a number.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. Group separators are optional, but must match
+/// the grouping of the locale.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `locale`  - Locale-specific symbols for the number.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::Locale;
+/// # pub fn main() {
+/// assert_eq!(lexical::parse_locale::<f64, _>("1\u{202F}234,5", &Locale::FR_FR), Ok(1234.5));
+/// # }
+/// ```
+#[inline]
+pub fn parse_locale<N, Bytes: AsRef<[u8]>>(bytes: Bytes, locale: &Locale)
+    -> Result<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions
+{
+    lexical_core::parse_locale(bytes.as_ref(), locale)
+}
+
+/// High-level conversion of code units to a number.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed. The code units are UTF-8 (`u8`) or UTF-16
+/// (`u16`), and error indexes are reported in code units.
+///
+/// * `units`   - Slice of code units to convert to number.
+/// * `script`  - Characters accepted in the numeric string.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # use lexical::DigitScript;
+/// # pub fn main() {
+/// let units: Vec<u16> = "\u{FF11}\u{FF12}\u{FF13}".encode_utf16().collect();
+/// assert_eq!(lexical::parse_units::<i32, u16, _>(units, DigitScript::Unicode), Ok(123));
+/// # }
+/// ```
+#[inline]
+pub fn parse_units<N, U, Units>(units: Units, script: DigitScript)
+    -> Result<N>
+    where N: FromLexicalOptions,
+          N::Options: StreamOptions,
+          U: CodeUnit,
+          Units: AsRef<[U]>
+{
+    lexical_core::parse_units(units.as_ref(), script)
+}
+
+/// High-level conversion of bytes to an arbitrary-precision integer with custom options.
+///
+/// This function only returns a value if the entire string is
+/// successfully parsed.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to customize number parsing.
+#[inline]
+pub fn parse_big_integer_with_options<T: BigInteger, Bytes: AsRef<[u8]>>(bytes: Bytes, options: &ParseIntegerOptions)
+    -> Result<T>
+{
+    lexical_core::parse_big_integer_with_options(bytes.as_ref(), options)
+}
+
+/// High-level extraction of the components of a number string with a custom numerical format.
+///
+/// This function only returns the components if the entire string is
+/// valid under the numerical format. The integer and fraction digits
+/// are subslices of the input, including any digit separators.
+///
+/// * `bytes`   - Byte slice to extract the components from.
+/// * `format`  - Numerical format.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # pub fn main() {
+/// let format = lexical::NumberFormat::ignore(b'_').unwrap();
+/// let components = lexical::parse_components_format("1_000.5", format).unwrap();
+/// assert_eq!(components.integer, b"1_000");
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "format")]
+pub fn parse_components_format<'a, Bytes: AsRef<[u8]> + ?Sized>(bytes: &'a Bytes, format: NumberFormat)
+    -> Result<NumberComponents<'a>>
+{
+    lexical_core::parse_components_format(bytes.as_ref(), format)
+}
@@ -0,0 +1,396 @@
+//! Serde helpers to read and write string-encoded numbers.
+//!
+//! The modules may be used with `#[serde(with = "...")]`, serializing
+//! numbers as strings with lexical's shortest representation, and
+//! deserializing numbers from strings with lexical's correct parser.
+//!
+//! - [`float`] uses the default, decimal format.
+#![cfg_attr(feature = "format", doc = " - [`float_format`] validates the format, by default JSON.")]
+#![cfg_attr(feature = "radix", doc = " - [`radix`] uses a custom radix.")]
+//!
+//! # Examples
+//!
+//! ```rust
+//! # extern crate lexical;
+//! # extern crate serde_derive;
+//! # extern crate serde_json;
+//! # use serde_derive::{Deserialize, Serialize};
+//! #[derive(Debug, PartialEq, Deserialize, Serialize)]
+//! struct Price {
+//!     #[serde(with = "lexical::serde::float")]
+//!     value: f64,
+//! }
+//!
+//! # pub fn main() {
+//! let price: Price = serde_json::from_str(r#"{"value":"1.5e3"}"#).unwrap();
+//! assert_eq!(price, Price { value: 1500.0 });
+//! assert_eq!(serde_json::to_string(&price).unwrap(), r#"{"value":"1500.0"}"#);
+//! # }
+//! ```
+//!
+//! [`float`]: float/index.html
+#![cfg_attr(feature = "format", doc = " [`float_format`]: float_format/index.html")]
+#![cfg_attr(feature = "radix", doc = " [`radix`]: radix/index.html")]
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::{self, Serializer};
+use lexical_core::{FromLexical, ToLexical, BUFFER_SIZE};
+#[cfg(feature = "format")]
+use lexical_core::{FromLexicalFormat, NumberFormat, ToLexicalFormat};
+use crate::lib;
+#[cfg(feature = "format")]
+use crate::vector_as_slice;
+
+// VISITOR
+
+/// Parser used by the visitor.
+#[derive(Clone, Copy, Debug)]
+enum Mode {
+    /// Decimal number with the default format.
+    Decimal,
+    /// Number with a custom radix.
+    #[cfg(feature = "radix")]
+    Radix(u8),
+    /// Decimal number with a custom numerical format.
+    #[cfg(feature = "format")]
+    Format(NumberFormat),
+    /// Number with a custom radix and numerical format.
+    #[cfg(all(feature = "format", feature = "radix"))]
+    FormatRadix(u8, NumberFormat),
+}
+
+// Parse a decimal number with the default format.
+#[inline]
+fn parse_decimal<N: FromLexical>(bytes: &[u8], _: Mode) -> lexical_core::Result<N> {
+    N::from_lexical(bytes)
+}
+
+// Parse a number with a custom radix.
+#[inline]
+#[cfg(feature = "radix")]
+fn parse_radix<N: FromLexical>(bytes: &[u8], mode: Mode) -> lexical_core::Result<N> {
+    match mode {
+        Mode::Radix(radix)  => N::from_lexical_radix(bytes, radix),
+        _                   => unreachable!(),
+    }
+}
+
+// Parse a number with a custom radix and numerical format.
+#[inline]
+#[cfg(feature = "format")]
+fn parse_format<N: FromLexicalFormat>(bytes: &[u8], mode: Mode) -> lexical_core::Result<N> {
+    match mode {
+        Mode::Format(format)                => N::from_lexical_format(bytes, format),
+        #[cfg(feature = "radix")]
+        Mode::FormatRadix(radix, format)    => N::from_lexical_format_radix(bytes, radix, format),
+        _                                   => unreachable!(),
+    }
+}
+
+/// Visitor to parse a number from a string or bytes with lexical.
+///
+/// Native numbers in the input are rejected, since the visitor is
+/// designed for string-encoded numbers.
+#[derive(Clone, Copy, Debug)]
+pub struct NumberVisitor<N> {
+    /// Parser options.
+    mode: Mode,
+    /// Callback to parse the number with the options.
+    parse: fn(&[u8], Mode) -> lexical_core::Result<N>,
+}
+
+impl<N: FromLexical> NumberVisitor<N> {
+    /// Create visitor for decimal numbers with the default format.
+    #[inline]
+    pub fn new() -> Self {
+        NumberVisitor { mode: Mode::Decimal, parse: parse_decimal::<N> }
+    }
+
+    /// Create visitor for numbers with a custom radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics when parsing if the radix is not in the range `[2, 36]`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn radix(radix: u8) -> Self {
+        NumberVisitor { mode: Mode::Radix(radix), parse: parse_radix::<N> }
+    }
+}
+
+#[cfg(feature = "format")]
+impl<N: FromLexicalFormat> NumberVisitor<N> {
+    /// Create visitor for decimal numbers with a custom numerical format.
+    #[inline]
+    pub fn format(format: NumberFormat) -> Self {
+        NumberVisitor { mode: Mode::Format(format), parse: parse_format::<N> }
+    }
+
+    /// Create visitor for numbers with a custom radix and numerical format.
+    ///
+    /// # Panics
+    ///
+    /// Panics when parsing if the radix is not in the range `[2, 36]`.
+    #[inline]
+    #[cfg(feature = "radix")]
+    pub fn format_radix(radix: u8, format: NumberFormat) -> Self {
+        NumberVisitor { mode: Mode::FormatRadix(radix, format), parse: parse_format::<N> }
+    }
+}
+
+impl<N: FromLexical> Default for NumberVisitor<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'de, N> Visitor<'de> for NumberVisitor<N> {
+    type Value = N;
+
+    fn expecting(&self, formatter: &mut lib::fmt::Formatter) -> lib::fmt::Result {
+        formatter.write_str("a string containing a number")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> lib::result::Result<N, E> {
+        self.visit_bytes(v.as_bytes())
+    }
+
+    #[inline]
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> lib::result::Result<N, E> {
+        (self.parse)(v, self.mode).map_err(E::custom)
+    }
+}
+
+// SERIALIZE
+
+// Serialize the bytes written by lexical as a string.
+#[inline]
+fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> lib::result::Result<S::Ok, S::Error> {
+    match lib::str::from_utf8(bytes) {
+        Ok(string)  => serializer.serialize_str(string),
+        Err(error)  => Err(ser::Error::custom(error)),
+    }
+}
+
+// Serialize the bytes written by lexical with a format, if the value is valid.
+#[inline]
+#[cfg(feature = "format")]
+fn serialize_option<S: Serializer>(bytes: Option<&mut [u8]>, serializer: S) -> lib::result::Result<S::Ok, S::Error> {
+    match bytes {
+        Some(bytes) => serialize_bytes(bytes, serializer),
+        None        => Err(ser::Error::custom("number cannot be represented in the format")),
+    }
+}
+
+// MODULES
+
+/// Serialize decimal numbers as strings with the default format.
+///
+/// Despite the name, the module supports any integer or float type.
+pub mod float {
+    use super::*;
+
+    /// Serialize the number as a string.
+    #[inline]
+    pub fn serialize<N, S>(value: &N, serializer: S) -> lib::result::Result<S::Ok, S::Error>
+        where N: ToLexical,
+              S: Serializer
+    {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        serialize_bytes(lexical_core::write(*value, &mut buffer), serializer)
+    }
+
+    /// Deserialize the number from a string.
+    #[inline]
+    pub fn deserialize<'de, N, D>(deserializer: D) -> lib::result::Result<N, D::Error>
+        where N: FromLexical,
+              D: Deserializer<'de>
+    {
+        deserializer.deserialize_str(NumberVisitor::new())
+    }
+}
+
+/// Serialize numbers as strings with a numerical format.
+///
+/// The [`serialize`] and [`deserialize`] functions validate the number
+/// with the JSON format. Use [`serialize_format`] and [`deserialize_format`]
+/// from a wrapper function for other formats, such as digit separators.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # extern crate serde;
+/// # extern crate serde_derive;
+/// # extern crate serde_json;
+/// # use serde::Deserializer;
+/// # use serde_derive::Deserialize;
+/// # use lexical::NumberFormat;
+/// fn underscores<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+///     let format = NumberFormat::ignore(b'_').unwrap();
+///     lexical::serde::float_format::deserialize_format(deserializer, format)
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "lexical::serde::float_format")]
+///     ratio: f64,
+///     #[serde(deserialize_with = "underscores")]
+///     size: u64,
+/// }
+///
+/// # pub fn main() {
+/// let config: Config = serde_json::from_str(r#"{"ratio":"0.5","size":"1_000_000"}"#).unwrap();
+/// assert_eq!((config.ratio, config.size), (0.5, 1000000));
+/// assert!(serde_json::from_str::<Config>(r#"{"ratio":".5","size":"1"}"#).is_err());
+/// # }
+/// ```
+///
+/// [`serialize`]: fn.serialize.html
+/// [`deserialize`]: fn.deserialize.html
+/// [`serialize_format`]: fn.serialize_format.html
+/// [`deserialize_format`]: fn.deserialize_format.html
+#[cfg(feature = "format")]
+pub mod float_format {
+    use super::*;
+
+    /// Serialize the number as a string with a numerical format.
+    ///
+    /// Fails if the number cannot be represented in the format.
+    #[inline]
+    pub fn serialize_format<N, S>(value: &N, format: NumberFormat, serializer: S)
+        -> lib::result::Result<S::Ok, S::Error>
+        where N: ToLexicalFormat,
+              S: Serializer
+    {
+        let mut buffer = lib::Vec::<u8>::with_capacity(N::format_buffer_size(10, format));
+        let bytes = unsafe { vector_as_slice(&mut buffer) };
+        serialize_option(lexical_core::write_format(*value, format, bytes), serializer)
+    }
+
+    /// Deserialize the number from a string with a numerical format.
+    #[inline]
+    pub fn deserialize_format<'de, N, D>(deserializer: D, format: NumberFormat)
+        -> lib::result::Result<N, D::Error>
+        where N: FromLexicalFormat,
+              D: Deserializer<'de>
+    {
+        deserializer.deserialize_str(NumberVisitor::format(format))
+    }
+
+    /// Serialize the number as a string with the JSON format.
+    #[inline]
+    pub fn serialize<N, S>(value: &N, serializer: S) -> lib::result::Result<S::Ok, S::Error>
+        where N: ToLexicalFormat,
+              S: Serializer
+    {
+        serialize_format(value, NumberFormat::JSON, serializer)
+    }
+
+    /// Deserialize the number from a string with the JSON format.
+    #[inline]
+    pub fn deserialize<'de, N, D>(deserializer: D) -> lib::result::Result<N, D::Error>
+        where N: FromLexicalFormat,
+              D: Deserializer<'de>
+    {
+        deserialize_format(deserializer, NumberFormat::JSON)
+    }
+}
+
+/// Serialize numbers as strings with a custom radix.
+///
+/// The [`binary`], [`octal`] and [`hex`] modules may be used with
+/// `#[serde(with = "...")]`. Use [`serialize_radix`] and [`deserialize_radix`]
+/// from a wrapper function for other radixes.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate lexical;
+/// # extern crate serde_derive;
+/// # extern crate serde_json;
+/// # use serde_derive::{Deserialize, Serialize};
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Color {
+///     #[serde(with = "lexical::serde::radix::hex")]
+///     rgb: u32,
+/// }
+///
+/// # pub fn main() {
+/// let color: Color = serde_json::from_str(r#"{"rgb":"FF8000"}"#).unwrap();
+/// assert_eq!(color, Color { rgb: 0xFF8000 });
+/// assert_eq!(serde_json::to_string(&color).unwrap(), r#"{"rgb":"FF8000"}"#);
+/// # }
+/// ```
+///
+/// [`binary`]: binary/index.html
+/// [`octal`]: octal/index.html
+/// [`hex`]: hex/index.html
+/// [`serialize_radix`]: fn.serialize_radix.html
+/// [`deserialize_radix`]: fn.deserialize_radix.html
+#[cfg(feature = "radix")]
+pub mod radix {
+    use super::*;
+
+    /// Serialize the number as a string with a custom radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[inline]
+    pub fn serialize_radix<N, S>(value: &N, radix: u8, serializer: S)
+        -> lib::result::Result<S::Ok, S::Error>
+        where N: ToLexical,
+              S: Serializer
+    {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        serialize_bytes(lexical_core::write_radix(*value, radix, &mut buffer), serializer)
+    }
+
+    /// Deserialize the number from a string with a custom radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the radix is not in the range `[2, 36]`.
+    #[inline]
+    pub fn deserialize_radix<'de, N, D>(deserializer: D, radix: u8)
+        -> lib::result::Result<N, D::Error>
+        where N: FromLexical,
+              D: Deserializer<'de>
+    {
+        deserializer.deserialize_str(NumberVisitor::radix(radix))
+    }
+
+    macro_rules! radix_module {
+        ($name:ident, $radix:expr, $doc:expr) => (
+            #[doc = $doc]
+            pub mod $name {
+                use super::*;
+
+                /// Serialize the number as a string.
+                #[inline]
+                pub fn serialize<N, S>(value: &N, serializer: S) -> lib::result::Result<S::Ok, S::Error>
+                    where N: ToLexical,
+                          S: Serializer
+                {
+                    serialize_radix(value, $radix, serializer)
+                }
+
+                /// Deserialize the number from a string.
+                #[inline]
+                pub fn deserialize<'de, N, D>(deserializer: D) -> lib::result::Result<N, D::Error>
+                    where N: FromLexical,
+                          D: Deserializer<'de>
+                {
+                    deserialize_radix(deserializer, $radix)
+                }
+            }
+        );
+    }
+
+    radix_module!(binary, 2, "Serialize numbers as binary strings.");
+    radix_module!(octal, 8, "Serialize numbers as octal strings.");
+    radix_module!(hex, 16, "Serialize numbers as hexadecimal strings.");
+}
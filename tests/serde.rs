@@ -0,0 +1,65 @@
+//! Test the serde helpers.
+
+#![cfg(feature = "serde")]
+
+extern crate lexical;
+extern crate serde_derive;
+extern crate serde_json;
+
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+struct Decimal {
+    #[serde(with = "lexical::serde::float")]
+    float: f32,
+    #[serde(with = "lexical::serde::float")]
+    integer: i64,
+}
+
+#[test]
+fn float_test() {
+    let value: Decimal = serde_json::from_str(r#"{"float":"-1.5","integer":"-12"}"#).unwrap();
+    assert_eq!(value, Decimal { float: -1.5, integer: -12 });
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"float":"-1.5","integer":"-12"}"#);
+
+    let error = serde_json::from_str::<Decimal>(r#"{"float":"1.5x","integer":"1"}"#).unwrap_err();
+    assert!(error.to_string().starts_with("invalid digit found at index 3"));
+    let error = serde_json::from_str::<Decimal>(r#"{"float":1.5,"integer":"1"}"#).unwrap_err();
+    assert!(error.to_string().contains("expected a string containing a number"));
+}
+
+#[cfg(feature = "format")]
+#[test]
+fn float_format_test() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Json {
+        #[serde(with = "lexical::serde::float_format")]
+        value: f64,
+    }
+
+    let value: Json = serde_json::from_str(r#"{"value":"1e5"}"#).unwrap();
+    assert_eq!(value, Json { value: 1e5 });
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"value":"100000.0"}"#);
+    assert!(serde_json::from_str::<Json>(r#"{"value":"+1"}"#).is_err());
+    assert!(serde_json::from_str::<Json>(r#"{"value":"NaN"}"#).is_err());
+    assert!(serde_json::to_string(&Json { value: f64::NAN }).is_err());
+}
+
+#[cfg(feature = "radix")]
+#[test]
+fn radix_test() {
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Radix {
+        #[serde(with = "lexical::serde::radix::binary")]
+        binary: u8,
+        #[serde(with = "lexical::serde::radix::octal")]
+        octal: i32,
+        #[serde(with = "lexical::serde::radix::hex")]
+        hex: f64,
+    }
+
+    let value: Radix = serde_json::from_str(r#"{"binary":"101","octal":"-17","hex":"A.8"}"#).unwrap();
+    assert_eq!(value, Radix { binary: 5, octal: -15, hex: 10.5 });
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"binary":"101","octal":"-17","hex":"A.8"}"#);
+    assert!(serde_json::from_str::<Radix>(r#"{"binary":"2","octal":"0","hex":"0"}"#).is_err());
+}
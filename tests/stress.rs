@@ -1,7 +1,7 @@
 //! Stress tests adapted from:
 //!     https://www.icir.org/vern/papers/testbase-report.pdf
 
-#![allow(dead_code)]
+#![allow(dead_code, clippy::excessive_precision)]
 
 extern crate lexical;
 use std::fmt::Debug;